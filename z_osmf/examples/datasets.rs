@@ -40,7 +40,7 @@ async fn main() -> anyhow::Result<()> {
         .context("failed to get first dataset!")?;
 
     match random_dataset_attributes.organization() {
-        Some(dsorg) if dsorg.starts_with("PO") => {
+        Some(dsorg) if dsorg.is_partitioned() => {
             let member_list = datasets_client.members(random_dataset_name).build().await?;
             let member_names: Vec<&str> = member_list.items().iter().map(|m| m.name()).collect();
 
@@ -1,13 +1,29 @@
 pub mod copy;
 pub mod copy_file;
+pub mod copy_members;
+#[cfg(feature = "copybook")]
+pub mod copybook;
 pub mod create;
 pub mod delete;
+pub mod delete_members;
+pub mod diff;
+pub mod download;
+mod export;
+pub mod handle;
 pub mod list;
+pub mod lock;
 pub mod members;
 pub mod migrate;
+pub mod migration_wait;
 pub mod read;
 pub mod recall;
+pub mod record;
 pub mod rename;
+pub mod rename_hlq;
+pub mod search;
+pub mod space;
+pub mod stats;
+pub mod sync;
 pub mod write;
 
 use std::sync::Arc;
@@ -16,18 +32,32 @@ use reqwest::header::HeaderValue;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::restfiles::Etag;
-use crate::{ClientCore, Result};
+use crate::ClientCore;
+
+pub use crate::utils::{DatasetName, MemberName};
 
 use self::copy::DatasetCopyBuilder;
 use self::copy_file::DatasetCopyFileBuilder;
+use self::copy_members::DatasetCopyMembersBuilder;
 use self::create::DatasetCreateBuilder;
 use self::delete::DatasetDeleteBuilder;
+use self::delete_members::DatasetDeleteMembersBuilder;
+use self::diff::DatasetDiffBuilder;
+use self::download::DatasetDownloadBuilder;
+use self::handle::DatasetHandle;
 use self::list::{DatasetAttributesName, DatasetList, DatasetListBuilder};
+use self::lock::DatasetLockBuilder;
 use self::members::{MemberAttributesName, MemberList, MemberListBuilder};
 use self::migrate::DatasetMigrateBuilder;
+use self::migration_wait::DatasetMigrationWaitBuilder;
 use self::read::{DatasetRead, DatasetReadBuilder};
 use self::recall::DatasetRecallBuilder;
 use self::rename::DatasetRenameBuilder;
+use self::rename_hlq::DatasetRenameHlqBuilder;
+use self::search::DatasetSearchBuilder;
+use self::space::DatasetSpaceBuilder;
+use self::stats::DatasetStatsBuilder;
+use self::sync::{DatasetSyncBuilder, DatasetSyncDirection};
 use self::write::DatasetWriteBuilder;
 
 #[derive(Clone, Debug)]
@@ -110,6 +140,68 @@ impl DatasetsClient {
         DatasetCopyFileBuilder::new(self.core.clone(), from_path, to_dataset)
     }
 
+    /// # Examples
+    ///
+    /// Copy a PDS member under a new name, replacing it if it exists:
+    /// ```
+    /// # use z_osmf::datasets::copy::DatasetCopyEnqueue;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let copy_member = zosmf
+    ///     .datasets()
+    ///     .copy_member("TEST.LOADLIB", "PAYROLL", "PROD.LOADLIB", "PAYROLL2")
+    ///     .alias(true)
+    ///     .enqueue(DatasetCopyEnqueue::Shrw)
+    ///     .replace(true)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn copy_member<FD, FM, TD, TM>(
+        &self,
+        from_dataset: FD,
+        from_member: FM,
+        to_dataset: TD,
+        to_member: TM,
+    ) -> DatasetCopyBuilder<String>
+    where
+        FD: std::fmt::Display,
+        FM: std::fmt::Display,
+        TD: std::fmt::Display,
+        TM: std::fmt::Display,
+    {
+        DatasetCopyBuilder::new(self.core.clone(), from_dataset, to_dataset)
+            .from_member(from_member)
+            .to_member(to_member)
+    }
+
+    /// # Examples
+    ///
+    /// Promote members from a test library to production:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let copy_members = zosmf
+    ///     .datasets()
+    ///     .copy_members("TEST.SRC", "PROD.SRC")
+    ///     .members(["PAYROLL", "BILLING"])
+    ///     .rename("BILLING", "BILLING2")
+    ///     .replace(true)
+    ///     .build()
+    ///     .await?;
+    /// for (member, err) in copy_members.failed() {
+    ///     println!("{}: {}", member, err);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn copy_members<F, T>(&self, from_dataset: F, to_dataset: T) -> DatasetCopyMembersBuilder
+    where
+        F: std::fmt::Display,
+        T: std::fmt::Display,
+    {
+        DatasetCopyMembersBuilder::new(self.core.clone(), from_dataset, to_dataset)
+    }
+
     /// # Examples
     ///
     /// Create a sequential dataset:
@@ -159,22 +251,23 @@ impl DatasetsClient {
     ///
     /// Create a library / partitioned dataset extended (PDS-E):
     /// ```
+    /// # use z_osmf::datasets::create::{DatasetOrganization, DatasetRecordFormat, DatasetType};
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
     /// let create_pdse = zosmf
     ///     .datasets()
     ///     .create("JIAHJ.REST.TEST.NEWDS02")
     ///     .volume("zmf046")
     ///     .device_type("3390")
-    ///     .organization("PO")
+    ///     .organization(DatasetOrganization::Po)
     ///     .space_allocation_unit("TRK")
     ///     .primary_space(10)
     ///     .secondary_space(5)
     ///     .directory_blocks(10)
     ///     .average_block_size(500)
-    ///     .record_format("FB")
+    ///     .record_format(DatasetRecordFormat::Fb)
     ///     .block_size(400)
     ///     .record_length(80)
-    ///     .dataset_type("LIBRARY")
+    ///     .dataset_type(DatasetType::Library)
     ///     .build()
     ///     .await?;
     /// # Ok(())
@@ -187,6 +280,31 @@ impl DatasetsClient {
         DatasetCreateBuilder::new(self.core.clone(), dataset)
     }
 
+    /// # Examples
+    ///
+    /// Work with a PDS and its members without repeating the dataset name:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let pds = zosmf.datasets().dataset("JIAHJ.REST.TEST.PDS");
+    ///
+    /// let member_list = pds.members().build().await?;
+    /// let read_member = pds.member("MEMBER01").read().build().await?;
+    /// pds.member("MEMBER02")
+    ///     .write()
+    ///     .text(read_member.data())
+    ///     .build()
+    ///     .await?;
+    /// pds.member("MEMBER01").delete().build().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dataset<D>(&self, dataset: D) -> DatasetHandle
+    where
+        D: std::fmt::Display,
+    {
+        DatasetHandle::new(self.core.clone(), dataset)
+    }
+
     /// # Examples
     ///
     /// Delete a sequential dataset:
@@ -240,6 +358,27 @@ impl DatasetsClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Retry a delete for up to 10 minutes while a batch job holds the dataset:
+    /// ```
+    /// # use std::time::Duration;
+    /// # use z_osmf::datasets::delete::DatasetDeleteFailure;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let delete_in_use = zosmf
+    ///     .datasets()
+    ///     .delete("JIAHJ.REST.TEST.DATASET")
+    ///     .retry_in_use(Duration::from_secs(600))
+    ///     .build()
+    ///     .await;
+    /// match delete_in_use {
+    ///     Err(err) if DatasetDeleteFailure::from_error(&err).is_not_found() => {}
+    ///     other => {
+    ///         other?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn delete<D>(&self, dataset: D) -> DatasetDeleteBuilder<String>
     where
         D: std::fmt::Display,
@@ -247,6 +386,98 @@ impl DatasetsClient {
         DatasetDeleteBuilder::new(self.core.clone(), dataset)
     }
 
+    /// # Examples
+    ///
+    /// Delete several PDS members, at most 8 at a time:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let delete_members = zosmf
+    ///     .datasets()
+    ///     .delete_members("JIAHJ.REST.TEST.PDS")
+    ///     .members(["MEMBER01", "MEMBER02", "MEMBER03"])
+    ///     .concurrency(8)
+    ///     .build()
+    ///     .await?;
+    /// for (member, err) in delete_members.failed() {
+    ///     println!("{}: {}", member, err);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Delete the PDS members matching a pattern:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let delete_members = zosmf
+    ///     .datasets()
+    ///     .delete_members("JIAHJ.REST.TEST.PDS")
+    ///     .pattern("GEN*")
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_members<D>(&self, dataset: D) -> DatasetDeleteMembersBuilder
+    where
+        D: std::fmt::Display,
+    {
+        DatasetDeleteMembersBuilder::new(self.core.clone(), dataset)
+    }
+
+    /// # Examples
+    ///
+    /// Compare a PDS member with its proposed replacement before overwriting it:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let diff = zosmf
+    ///     .datasets()
+    ///     .diff("SYS1.PARMLIB", "MY.PARMLIB")
+    ///     .from_member("SMFPRM00")
+    ///     .to_member("SMFPRM00")
+    ///     .encoding("IBM-1047")
+    ///     .build()
+    ///     .await?;
+    /// for line in diff.changes() {
+    ///     println!("{:?}", line);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn diff<F, T>(&self, from_dataset: F, to_dataset: T) -> DatasetDiffBuilder
+    where
+        F: std::fmt::Display,
+        T: std::fmt::Display,
+    {
+        DatasetDiffBuilder::new(self.core.clone(), from_dataset, to_dataset)
+    }
+
+    /// # Examples
+    ///
+    /// Back up a load library to a local directory:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let download = zosmf
+    ///     .datasets()
+    ///     .download("MY.LOADLIB", "./backup")
+    ///     .pattern("PAY*")
+    ///     .concurrency(8)
+    ///     .progress(|p| println!("{}/{} {}", p.completed, p.total, p.item.member()))
+    ///     .build()
+    ///     .await?;
+    /// for (member, etag) in download.etags() {
+    ///     println!("{} {}", member, etag);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn download<D, P>(&self, dataset: D, directory: P) -> DatasetDownloadBuilder
+    where
+        D: std::fmt::Display,
+        P: AsRef<std::path::Path>,
+    {
+        DatasetDownloadBuilder::new(self.core.clone(), dataset, directory)
+    }
+
     /// # Examples
     ///
     /// List datasets:
@@ -274,6 +505,57 @@ impl DatasetsClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// List a limited number of datasets, including the total count:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let list_datasets_limited = zosmf
+    ///     .datasets()
+    ///     .list("SYS1.**")
+    ///     .max_items(100)
+    ///     .include_total(true)
+    ///     .build()
+    ///     .await?;
+    /// if list_datasets_limited.more_rows() == Some(true) {
+    ///     println!("{:?}", list_datasets_limited.total_rows());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Find the partitioned datasets, PDS and PDSE, on a volume:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let list_datasets_base = zosmf
+    ///     .datasets()
+    ///     .list("PROD.**")
+    ///     .attributes_base()
+    ///     .build()
+    ///     .await?;
+    /// for dataset in list_datasets_base
+    ///     .by_volume("PROD01")
+    ///     .partitioned()
+    /// {
+    ///     println!("{}", dataset.name());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Write an inventory report of dataset names, volumes and space usage:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let list_datasets_base = zosmf
+    ///     .datasets()
+    ///     .list("PROD.**")
+    ///     .attributes_base()
+    ///     .build()
+    ///     .await?;
+    /// let csv = list_datasets_base.to_csv(&["dsname", "vol", "sizex", "used"])?;
+    /// tokio::fs::write("inventory.csv", csv).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn list<L>(&self, level: L) -> DatasetListBuilder<DatasetList<DatasetAttributesName>>
     where
         L: std::fmt::Display,
@@ -281,6 +563,35 @@ impl DatasetsClient {
         DatasetListBuilder::new(self.core.clone(), level)
     }
 
+    /// # Examples
+    ///
+    /// Obtain an exclusive ENQ on a PDS member, update it, and release the ENQ:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let lock = zosmf
+    ///     .datasets()
+    ///     .lock("SYS1.PARMLIB")
+    ///     .member("SMFPRM00")
+    ///     .build()
+    ///     .await?;
+    ///
+    /// let read_member = lock.read().build().await?;
+    /// lock.write()
+    ///     .text(read_member.data().replace("NOACTIVE", "ACTIVE"))
+    ///     .build()
+    ///     .await?;
+    ///
+    /// lock.release().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn lock<D>(&self, dataset: D) -> DatasetLockBuilder
+    where
+        D: std::fmt::Display,
+    {
+        DatasetLockBuilder::new(self.core.clone(), dataset)
+    }
+
     /// # Examples
     ///
     /// List PDS members:
@@ -360,6 +671,47 @@ impl DatasetsClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Read a large sequential dataset, requesting an etag:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let read_large_dataset = zosmf
+    ///     .datasets()
+    ///     .read("JIAHJ.REST.BIGDS")
+    ///     .return_etag(true)
+    ///     .build()
+    ///     .await?;
+    /// println!("{:?}", read_large_dataset.etag());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Peek at the first and last records of a large sequential dataset:
+    /// ```
+    /// # use std::num::NonZeroU32;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let count = NonZeroU32::new(20).unwrap();
+    ///
+    /// let head = zosmf
+    ///     .datasets()
+    ///     .read("JIAHJ.REST.SRVMP")
+    ///     .first_records(count)
+    ///     .build()
+    ///     .await?;
+    ///
+    /// let tail = zosmf
+    ///     .datasets()
+    ///     .read("JIAHJ.REST.SRVMP")
+    ///     .last_records(count)
+    ///     .await?
+    ///     .build()
+    ///     .await?;
+    /// for line in tail.lines() {
+    ///     println!("{}", line);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn read<D>(&self, dataset: D) -> DatasetReadBuilder<DatasetRead<Arc<str>>>
     where
         D: std::fmt::Display,
@@ -408,6 +760,231 @@ impl DatasetsClient {
         DatasetRenameBuilder::new(self.core.clone(), from_dataset, to_dataset)
     }
 
+    /// # Examples
+    ///
+    /// Preview moving every dataset under OLDHLQ to NEWHLQ, then perform the rename:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let rename_hlq = zosmf.datasets().rename_hlq("OLDHLQ.**", "NEWHLQ");
+    ///
+    /// let preview = rename_hlq.clone().dry_run(true).build().await?;
+    /// for (from_dataset, to_dataset) in preview.planned() {
+    ///     println!("{} -> {}", from_dataset, to_dataset);
+    /// }
+    ///
+    /// let renamed = rename_hlq.build().await?;
+    /// for (dataset, err) in renamed.failed() {
+    ///     println!("{}: {}", dataset, err);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rename_hlq<P, H>(&self, pattern: P, to_hlq: H) -> DatasetRenameHlqBuilder
+    where
+        P: std::fmt::Display,
+        H: std::fmt::Display,
+    {
+        DatasetRenameHlqBuilder::new(self.core.clone(), pattern, to_hlq)
+    }
+
+    /// # Examples
+    ///
+    /// Find the PROCLIB members that reference a dataset:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let search = zosmf
+    ///     .datasets()
+    ///     .search("SYS1.PROCLIB", "SYS1.LINKLIB")
+    ///     .build()
+    ///     .await?;
+    /// for member in search.members() {
+    ///     println!("{}", member);
+    /// }
+    /// for (member, err) in search.failed() {
+    ///     eprintln!("{} was not searched: {}", member, err);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Search members with a case-sensitive regular expression, returning one record per member:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let search = zosmf
+    ///     .datasets()
+    ///     .search("SYS1.PROCLIB", r"DSN=SYS1\.LINK.*")
+    ///     .regex(true)
+    ///     .case_sensitive(true)
+    ///     .max_return(1)
+    ///     .concurrency(8)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search<D, P>(&self, dataset: D, pattern: P) -> DatasetSearchBuilder
+    where
+        D: std::fmt::Display,
+        P: std::fmt::Display,
+    {
+        DatasetSearchBuilder::new(self.core.clone(), dataset, pattern)
+    }
+
+    /// # Examples
+    ///
+    /// Report space usage per volume:
+    /// ```
+    /// # use z_osmf::datasets::space::DatasetSpaceGrouping;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let space = zosmf
+    ///     .datasets()
+    ///     .space("PROD.**")
+    ///     .group_by(DatasetSpaceGrouping::Volume)
+    ///     .build()
+    ///     .await?;
+    /// for group in space.groups().iter() {
+    ///     println!("{} {:.1} cylinders", group.key(), group.cylinders());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn space<L>(&self, level: L) -> DatasetSpaceBuilder
+    where
+        L: std::fmt::Display,
+    {
+        DatasetSpaceBuilder::new(self.core.clone(), level)
+    }
+
+    /// # Examples
+    ///
+    /// Update the ISPF statistics of a member after writing it:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let stats = zosmf
+    ///     .datasets()
+    ///     .stats("MY.SRC.PDS", "PAYROLL")
+    ///     .user("IBMUSER")
+    ///     .version(1)
+    ///     .modification_level(4)
+    ///     .modification_date(chrono::Local::now().naive_local())
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stats<D, M>(&self, dataset: D, member: M) -> DatasetStatsBuilder<String>
+    where
+        D: std::fmt::Display,
+        M: std::fmt::Display,
+    {
+        DatasetStatsBuilder::new(self.core.clone(), dataset, member)
+    }
+
+    /// # Examples
+    ///
+    /// Deploy local COBOL sources to a PDS, skipping unchanged members:
+    /// ```
+    /// # use z_osmf::datasets::sync::DatasetSyncDirection;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let sync = zosmf
+    ///     .datasets()
+    ///     .sync("MY.COBOL.SOURCE", "./src/cobol", DatasetSyncDirection::Upload)
+    ///     .extension("cbl")
+    ///     .exclude("*TEST")
+    ///     .build()
+    ///     .await?;
+    /// for item in sync.changed() {
+    ///     println!("{} <- {}", item.member(), item.path().display());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Preview downloading a PDS to a local directory:
+    /// ```
+    /// # use z_osmf::datasets::sync::DatasetSyncDirection;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let sync = zosmf
+    ///     .datasets()
+    ///     .sync("SYS1.PROCLIB", "./proclib", DatasetSyncDirection::Download)
+    ///     .include("IEF*")
+    ///     .dry_run(true)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sync<D, P>(
+        &self,
+        dataset: D,
+        directory: P,
+        direction: DatasetSyncDirection,
+    ) -> DatasetSyncBuilder
+    where
+        D: std::fmt::Display,
+        P: AsRef<std::path::Path>,
+    {
+        DatasetSyncBuilder::new(self.core.clone(), dataset, directory, direction)
+    }
+
+    /// # Examples
+    ///
+    /// Start migrating a dataset and wait up to five minutes for it to complete:
+    /// ```
+    /// # use std::time::Duration;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// zosmf
+    ///     .datasets()
+    ///     .migrate("MY.TEST.DS")
+    ///     .wait(false)
+    ///     .build()
+    ///     .await?;
+    ///
+    /// let elapsed = zosmf
+    ///     .datasets()
+    ///     .wait_for_migrate("MY.TEST.DS")
+    ///     .timeout(Duration::from_secs(300))
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_for_migrate<D>(&self, dataset: D) -> DatasetMigrationWaitBuilder
+    where
+        D: std::fmt::Display,
+    {
+        DatasetMigrationWaitBuilder::new(self.core.clone(), dataset, true)
+    }
+
+    /// # Examples
+    ///
+    /// Start recalling a dataset and wait for it to complete:
+    /// ```
+    /// # use std::time::Duration;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// zosmf
+    ///     .datasets()
+    ///     .recall("MY.MIGR.DS")
+    ///     .wait(false)
+    ///     .build()
+    ///     .await?;
+    ///
+    /// let elapsed = zosmf
+    ///     .datasets()
+    ///     .wait_for_recall("MY.MIGR.DS")
+    ///     .interval(Duration::from_secs(5))
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_for_recall<D>(&self, dataset: D) -> DatasetMigrationWaitBuilder
+    where
+        D: std::fmt::Display,
+    {
+        DatasetMigrationWaitBuilder::new(self.core.clone(), dataset, false)
+    }
+
     /// # Examples
     ///
     /// Write to a PDS member:
@@ -425,6 +1002,84 @@ impl DatasetsClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Write Windows-originated text to a sequential dataset while holding an exclusive ENQ:
+    /// ```
+    /// # use z_osmf::datasets::DatasetEnqueue;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let write_crlf = zosmf
+    ///     .datasets()
+    ///     .write("JIAHJ.REST.TEST.DATASET")
+    ///     .text("line 1\r\nline 2\r\n")
+    ///     .encoding("IBM-037")
+    ///     .crlf_newlines(true)
+    ///     .obtain_enq(DatasetEnqueue::Exclu)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Write lines to an 80-byte fixed-length member, failing rather than truncating long lines:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let lines = vec!["//IEFBR14 JOB", "//STEP1 EXEC PGM=IEFBR14"];
+    /// let write_lines = zosmf
+    ///     .datasets()
+    ///     .write("MY.JCL")
+    ///     .member("IEFBR14")
+    ///     .lines(lines)
+    ///     .record_length(80)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Hold an ENQ across two writes, releasing it with the second:
+    /// ```
+    /// # use z_osmf::datasets::DatasetEnqueue;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let first_write = zosmf
+    ///     .datasets()
+    ///     .write("JIAHJ.REST.TEST.DATASET")
+    ///     .text("step 1\n")
+    ///     .obtain_enq(DatasetEnqueue::Exclu)
+    ///     .build()
+    ///     .await?;
+    /// let session_ref = first_write.session_ref().unwrap_or_default();
+    ///
+    /// zosmf
+    ///     .datasets()
+    ///     .write("JIAHJ.REST.TEST.DATASET")
+    ///     .text("step 1\nstep 2\n")
+    ///     .session_ref(session_ref)
+    ///     .release_enq(true)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Reject text with lines longer than the existing dataset's records:
+    /// ```
+    /// # use z_osmf::Error;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// # let string_data = "";
+    /// let write_checked = zosmf
+    ///     .datasets()
+    ///     .write("JIAHJ.REST.TEST.DATASET")
+    ///     .text(string_data)
+    ///     .check_record_length()
+    ///     .await?
+    ///     .build()
+    ///     .await;
+    /// if let Err(Error::RecordOverflow { lines, .. }) = write_checked {
+    ///     println!("lines too long: {:?}", lines);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn write<D>(&self, dataset: D) -> DatasetWriteBuilder<Etag>
     where
         D: std::fmt::Display,
@@ -507,6 +1162,30 @@ enum Unknown {
     Unknown,
 }
 
+fn apply_location<B>(
+    builder: B,
+    volume: &Option<Arc<str>>,
+    member: &Option<Arc<str>>,
+    dsname_encoding: &Option<Arc<str>>,
+    set_volume: fn(B, Arc<str>) -> B,
+    set_member: fn(B, Arc<str>) -> B,
+    set_dsname_encoding: fn(B, Arc<str>) -> B,
+) -> B {
+    let builder = match volume {
+        Some(volume) => set_volume(builder, volume.clone()),
+        None => builder,
+    };
+    let builder = match member {
+        Some(member) => set_member(builder, member.clone()),
+        None => builder,
+    };
+
+    match dsname_encoding {
+        Some(dsname_encoding) => set_dsname_encoding(builder, dsname_encoding.clone()),
+        None => builder,
+    }
+}
+
 fn de_unknown<'de, D>(deserializer: D) -> std::result::Result<(), D::Error>
 where
     D: Deserializer<'de>,
@@ -541,15 +1220,6 @@ fn get_member(value: &Option<Arc<str>>) -> String {
         .unwrap_or_default()
 }
 
-fn get_session_ref(response: &reqwest::Response) -> Result<Option<Arc<str>>> {
-    Ok(response
-        .headers()
-        .get("X-IBM-Session-Ref")
-        .map(|v| v.to_str())
-        .transpose()?
-        .map(|v| v.into()))
-}
-
 fn get_volume(value: &Option<Arc<str>>) -> String {
     value
         .as_ref()
@@ -601,20 +1271,6 @@ mod tests {
         assert_eq!(header_value, HeaderValue::from_static("SHRW"));
     }
 
-    #[test]
-    fn test_get_session_ref() {
-        let response = reqwest::Response::from(
-            http::Response::builder()
-                .header("X-IBM-Session-Ref", "ABCD1234")
-                .body("")
-                .unwrap(),
-        );
-        assert_eq!(get_session_ref(&response).unwrap(), Some("ABCD1234".into()));
-
-        let response = reqwest::Response::from(http::Response::new(""));
-        assert_eq!(get_session_ref(&response).unwrap(), None);
-    }
-
     #[test]
     fn test_de_optional_y_n() {
         #[derive(Debug, Deserialize, PartialEq)]
@@ -33,6 +33,8 @@ where
     enqueue: Option<DatasetCopyEnqueue>,
     #[endpoint(skip_builder)]
     replace: Option<bool>,
+    #[endpoint(header = "X-IBM-Dsname-Encoding")]
+    dsname_encoding: Option<Arc<str>>,
 
     target_type: PhantomData<T>,
 }
@@ -96,3 +98,57 @@ where
 {
     get_volume(&builder.volume)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn copy_member() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/ds/PROD.LOADLIB(PAYROLL2)")
+            .json(&serde_json::json!({
+                "request": "copy",
+                "from-dataset": {
+                    "dsn": "TEST.LOADLIB",
+                    "member": "PAYROLL",
+                    "alias": true,
+                },
+                "enq": "SHRW",
+                "replace": true,
+            }))
+            .build()
+            .unwrap();
+
+        let copy_member = zosmf
+            .datasets()
+            .copy_member("TEST.LOADLIB", "PAYROLL", "PROD.LOADLIB", "PAYROLL2")
+            .alias(true)
+            .enqueue(DatasetCopyEnqueue::Shrw)
+            .replace(true)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(manual_request.method(), copy_member.method());
+        assert_eq!(
+            urlencoding::decode(manual_request.url().as_str()).unwrap(),
+            urlencoding::decode(copy_member.url().as_str()).unwrap()
+        );
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                manual_request.body().unwrap().as_bytes().unwrap()
+            )
+            .unwrap(),
+            serde_json::from_slice::<serde_json::Value>(
+                copy_member.body().unwrap().as_bytes().unwrap()
+            )
+            .unwrap()
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use futures::StreamExt;
+
+use crate::{ClientCore, Result};
+
+use super::copy::DatasetCopyBuilder;
+use super::members::{resolve_members, MemberOutcome, MemberReport};
+
+#[derive(Clone, Debug)]
+pub struct DatasetCopyMembersBuilder {
+    core: ClientCore,
+
+    from_dataset: Arc<str>,
+    to_dataset: Arc<str>,
+    members: Option<Arc<[Arc<str>]>>,
+    pattern: Option<Arc<str>>,
+    renames: HashMap<Arc<str>, Arc<str>>,
+    replace: bool,
+    concurrency: usize,
+}
+
+impl DatasetCopyMembersBuilder {
+    pub(crate) fn new<F, T>(core: ClientCore, from_dataset: F, to_dataset: T) -> Self
+    where
+        F: std::fmt::Display,
+        T: std::fmt::Display,
+    {
+        DatasetCopyMembersBuilder {
+            core,
+            from_dataset: from_dataset.to_string().into(),
+            to_dataset: to_dataset.to_string().into(),
+            members: None,
+            pattern: None,
+            renames: HashMap::new(),
+            replace: false,
+            concurrency: 4,
+        }
+    }
+
+    /// Copy the named members.
+    pub fn members<I, M>(self, members: I) -> Self
+    where
+        I: IntoIterator<Item = M>,
+        M: std::fmt::Display,
+    {
+        DatasetCopyMembersBuilder {
+            members: Some(members.into_iter().map(|m| m.to_string().into()).collect()),
+            ..self
+        }
+    }
+
+    /// Copy the members matching a member name pattern, e.g. `PAY*`.
+    pub fn pattern<P>(self, value: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        DatasetCopyMembersBuilder {
+            pattern: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// Copy a member under a different name in the target dataset.
+    ///
+    /// May be called more than once; members without a mapping keep their name.
+    pub fn rename<F, T>(self, from_member: F, to_member: T) -> Self
+    where
+        F: std::fmt::Display,
+        T: std::fmt::Display,
+    {
+        let mut new = self;
+        new.renames.insert(
+            from_member.to_string().to_uppercase().into(),
+            to_member.to_string().to_uppercase().into(),
+        );
+
+        new
+    }
+
+    /// Replace members that already exist in the target dataset.
+    pub fn replace(self, value: bool) -> Self {
+        DatasetCopyMembersBuilder {
+            replace: value,
+            ..self
+        }
+    }
+
+    /// The maximum number of copy requests in flight at once.
+    pub fn concurrency(self, value: usize) -> Self {
+        DatasetCopyMembersBuilder {
+            concurrency: value.max(1),
+            ..self
+        }
+    }
+
+    pub async fn build(self) -> Result<MemberReport> {
+        let members =
+            resolve_members(&self.core, &self.from_dataset, &self.members, &self.pattern).await?;
+
+        let items = futures::stream::iter(members.iter().cloned())
+            .map(|from_member| {
+                let to_member = self.to_member(&from_member);
+                let builder = self.copy_builder(&from_member, &to_member);
+
+                async move {
+                    let result = builder.build().await.map(|txid| txid.into());
+
+                    MemberOutcome::new(from_member, Some(to_member), result)
+                }
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+
+        Ok(MemberReport::new(items))
+    }
+
+    fn to_member(&self, from_member: &str) -> Arc<str> {
+        self.renames
+            .get(from_member.to_uppercase().as_str())
+            .cloned()
+            .unwrap_or_else(|| from_member.into())
+    }
+
+    fn copy_builder(&self, from_member: &str, to_member: &str) -> DatasetCopyBuilder<String> {
+        DatasetCopyBuilder::new(self.core.clone(), &self.from_dataset, &self.to_dataset)
+            .from_member(from_member)
+            .to_member(to_member)
+            .replace(self.replace)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+    use crate::Error;
+
+    #[test]
+    fn copy_request() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/ds/PROD.SRC(PAYROLL2)")
+            .json(&serde_json::json!({
+                "request": "copy",
+                "from-dataset": {
+                    "dsn": "TEST.SRC",
+                    "member": "PAYROLL",
+                },
+                "replace": true,
+            }))
+            .build()
+            .unwrap();
+
+        let copy_members = zosmf
+            .datasets()
+            .copy_members("TEST.SRC", "PROD.SRC")
+            .members(["PAYROLL", "BILLING"])
+            .rename("payroll", "payroll2")
+            .replace(true);
+
+        assert_eq!(copy_members.to_member("BILLING").as_ref(), "BILLING");
+
+        let request = copy_members
+            .copy_builder("PAYROLL", &copy_members.to_member("PAYROLL"))
+            .get_request()
+            .unwrap();
+
+        assert_eq!(manual_request.method(), request.method());
+        assert_eq!(
+            urlencoding::decode(manual_request.url().as_str()).unwrap(),
+            urlencoding::decode(request.url().as_str()).unwrap()
+        );
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                manual_request.body().unwrap().as_bytes().unwrap()
+            )
+            .unwrap(),
+            serde_json::from_slice::<serde_json::Value>(
+                request.body().unwrap().as_bytes().unwrap()
+            )
+            .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn requires_members() {
+        let zosmf = get_zosmf();
+
+        let result = zosmf
+            .datasets()
+            .copy_members("TEST.SRC", "PROD.SRC")
+            .build()
+            .await;
+
+        assert!(matches!(result, Err(Error::InvalidValue(_))));
+    }
+}
@@ -0,0 +1,801 @@
+use std::sync::Arc;
+
+use bytes::Bytes;
+
+use crate::encoding::Codepage;
+use crate::{Error, Result};
+
+use super::record::{split_records, FieldType, FieldValue, Record, RecordLayout};
+
+/// The record layout described by a COBOL copybook.
+///
+/// Group items are flattened into their elementary items, `FILLER` is skipped
+/// and each occurrence of an `OCCURS` item is named with its subscripts, e.g. `AMOUNT(2)`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Copybook {
+    fields: Arc<[CopybookField]>,
+    record_length: usize,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CopybookField {
+    name: Arc<str>,
+    offset: usize,
+    length: usize,
+    field_type: FieldType,
+    redefined: bool,
+}
+
+impl CopybookField {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The 0-based byte offset of the field within the record.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    pub fn field_type(&self) -> FieldType {
+        self.field_type
+    }
+}
+
+impl Copybook {
+    /// Parse the data description entries of a copybook, in fixed or free format.
+    ///
+    /// # Example
+    /// ```
+    /// # use z_osmf::datasets::copybook::Copybook;
+    /// # fn example() -> z_osmf::Result<()> {
+    /// let copybook = Copybook::parse(
+    ///     r#"
+    ///        01  CUSTOMER-RECORD.
+    ///            05  CUST-ID        PIC 9(6).
+    ///            05  CUST-NAME      PIC X(30).
+    ///            05  CUST-BALANCE   PIC S9(7)V99 COMP-3.
+    ///     "#,
+    /// )?;
+    /// assert_eq!(copybook.record_length(), 41);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse(source: &str) -> Result<Self> {
+        let entries = statements(source)
+            .iter()
+            .map(|statement| Entry::parse(statement))
+            .filter_map(Result::transpose)
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut items = build_tree(entries)?;
+
+        // further 01 levels describe the same record area as the first
+        if let Some((first, rest)) = items.split_first_mut() {
+            for item in rest.iter_mut().filter(|item| item.entry.level == 1) {
+                item.entry.redefines = item.entry.redefines.take().or(first.entry.name.clone());
+            }
+        }
+
+        let mut fields = Vec::new();
+        let record_length = layout(&items, 0, &[], None, false, &mut fields)?;
+
+        Ok(Copybook {
+            fields: fields.into(),
+            record_length,
+        })
+    }
+
+    pub fn fields(&self) -> &[CopybookField] {
+        &self.fields
+    }
+
+    pub fn field(&self, name: &str) -> Option<&CopybookField> {
+        self.fields
+            .iter()
+            .find(|field| field.name.eq_ignore_ascii_case(name))
+    }
+
+    /// The length in bytes of a record described by the copybook.
+    pub fn record_length(&self) -> usize {
+        self.record_length
+    }
+
+    /// The copybook as a [`RecordLayout`] with text in the given code page.
+    ///
+    /// Fails if a field is too wide for [`RecordLayout::field`].
+    pub fn layout(&self, codepage: Codepage) -> Result<RecordLayout> {
+        let layout = self
+            .fields
+            .iter()
+            .try_fold(RecordLayout::new().codepage(codepage), |layout, field| {
+                layout.field(&field.name, field.offset, field.length, field.field_type)
+            })?;
+
+        Ok(layout.record_length(self.record_length))
+    }
+
+    /// Decode a single record.
+    ///
+    /// Numeric fields within a `REDEFINES` that do not hold valid data for that view
+    /// are returned as [`FieldValue::Bytes`](super::record::FieldValue::Bytes).
+    pub fn decode(&self, record: &[u8], codepage: Codepage) -> Result<Record> {
+        self.fields
+            .iter()
+            .map(|field| {
+                let data = record
+                    .get(field.offset..field.offset + field.length)
+                    .ok_or_else(|| {
+                        Error::InvalidValue(format!(
+                            "record of {} bytes is too short for field {}",
+                            record.len(),
+                            field.name
+                        ))
+                    })?;
+
+                let value = match field.field_type.decode(data, codepage) {
+                    Err(_) if field.redefined => FieldValue::Bytes(Bytes::copy_from_slice(data)),
+                    value => value?,
+                };
+
+                Ok((field.name.clone(), value))
+            })
+            .collect()
+    }
+
+    /// Decode the body of a record mode read.
+    ///
+    /// # Example
+    /// ```
+    /// # use z_osmf::datasets::copybook::Copybook;
+    /// # use z_osmf::encoding::Codepage;
+    /// # async fn example(zosmf: z_osmf::ZOsmf, copybook: Copybook) -> anyhow::Result<()> {
+    /// let read_records = zosmf
+    ///     .datasets()
+    ///     .read("PROD.CUSTOMER.MASTER")
+    ///     .record()
+    ///     .build()
+    ///     .await?;
+    ///
+    /// for record in copybook.decode_records(read_records.data(), Codepage::Ibm037)? {
+    ///     println!("{:?}", record.get("CUST-NAME"));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decode_records(&self, data: &[u8], codepage: Codepage) -> Result<Vec<Record>> {
+        split_records(data)?
+            .into_iter()
+            .map(|record| self.decode(record, codepage))
+            .collect()
+    }
+
+    /// Encode a single record, filling fields missing from `record` with EBCDIC spaces.
+    pub fn encode(&self, record: &Record, codepage: Codepage) -> Result<Vec<u8>> {
+        self.layout(codepage)?.encode(record)
+    }
+
+    /// Encode records as the body of a record mode write.
+    pub fn encode_records<'a, I>(&self, records: I, codepage: Codepage) -> Result<Bytes>
+    where
+        I: IntoIterator<Item = &'a Record>,
+    {
+        self.layout(codepage)?.encode_records(records)
+    }
+}
+
+impl std::str::FromStr for Copybook {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Copybook::parse(s)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Usage {
+    Display,
+    Binary,
+    Packed,
+    Float(usize),
+}
+
+#[derive(Clone, Debug)]
+struct Entry {
+    level: u8,
+    name: Option<Arc<str>>,
+    picture: Option<Arc<str>>,
+    usage: Option<Usage>,
+    occurs: usize,
+    redefines: Option<Arc<str>>,
+}
+
+impl Entry {
+    /// Parse a data description entry, skipping condition names and `RENAMES`.
+    fn parse(statement: &str) -> Result<Option<Self>> {
+        let tokens = tokenize(statement);
+        let mut tokens = tokens.iter().map(|t| t.as_str()).peekable();
+
+        let level = match tokens.next() {
+            Some(level) => level
+                .parse::<u8>()
+                .map_err(|_| invalid(statement, "expected a level number"))?,
+            None => return Ok(None),
+        };
+
+        if matches!(level, 66 | 88) {
+            return Ok(None);
+        }
+        if !matches!(level, 1..=49 | 77) {
+            return Err(invalid(statement, "invalid level number"));
+        }
+
+        let name = match tokens.peek() {
+            Some(name) if !is_clause(name) => {
+                let name = tokens.next().unwrap();
+
+                (!name.eq_ignore_ascii_case("FILLER")).then(|| name.to_ascii_uppercase().into())
+            }
+            _ => None,
+        };
+
+        let mut entry = Entry {
+            level,
+            name,
+            picture: None,
+            usage: None,
+            occurs: 1,
+            redefines: None,
+        };
+
+        while let Some(token) = tokens.next() {
+            match token.to_ascii_uppercase().as_str() {
+                "PIC" | "PICTURE" => {
+                    skip_is(&mut tokens);
+                    let picture = tokens
+                        .next()
+                        .ok_or_else(|| invalid(statement, "missing picture string"))?;
+                    entry.picture = Some(picture.to_ascii_uppercase().into());
+                }
+                "USAGE" => skip_is(&mut tokens),
+                "DISPLAY" => entry.usage = Some(Usage::Display),
+                "COMP" | "COMPUTATIONAL" | "COMP-4" | "COMPUTATIONAL-4" | "COMP-5"
+                | "COMPUTATIONAL-5" | "BINARY" => entry.usage = Some(Usage::Binary),
+                "COMP-3" | "COMPUTATIONAL-3" | "PACKED-DECIMAL" => {
+                    entry.usage = Some(Usage::Packed)
+                }
+                "COMP-1" | "COMPUTATIONAL-1" => entry.usage = Some(Usage::Float(4)),
+                "COMP-2" | "COMPUTATIONAL-2" => entry.usage = Some(Usage::Float(8)),
+                "OCCURS" => {
+                    let count = tokens
+                        .next()
+                        .and_then(|count| count.parse().ok())
+                        .ok_or_else(|| invalid(statement, "missing OCCURS count"))?;
+
+                    // OCCURS n TO m DEPENDING ON ... is laid out at its maximum
+                    entry.occurs = match tokens.peek() {
+                        Some(to) if to.eq_ignore_ascii_case("TO") => {
+                            tokens.next();
+                            tokens
+                                .next()
+                                .and_then(|max| max.parse().ok())
+                                .ok_or_else(|| invalid(statement, "missing OCCURS maximum"))?
+                        }
+                        _ => count,
+                    };
+                }
+                "REDEFINES" => {
+                    let name = tokens
+                        .next()
+                        .ok_or_else(|| invalid(statement, "missing REDEFINES target"))?;
+                    entry.redefines = Some(name.to_ascii_uppercase().into());
+                }
+                "LEADING" | "SEPARATE" => {
+                    return Err(invalid(
+                        statement,
+                        "only trailing embedded signs are supported",
+                    ));
+                }
+                "VALUE" | "VALUES" => {
+                    // the value has no effect on the layout
+                    skip_is(&mut tokens);
+                    tokens.next();
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Some(entry))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Item {
+    entry: Entry,
+    children: Vec<Item>,
+}
+
+fn build_tree(entries: Vec<Entry>) -> Result<Vec<Item>> {
+    let mut stack: Vec<Item> = Vec::new();
+    let mut roots = Vec::new();
+
+    for entry in entries {
+        let level = normalize_level(entry.level);
+
+        while stack
+            .last()
+            .is_some_and(|item| normalize_level(item.entry.level) >= level)
+        {
+            pop_item(&mut stack, &mut roots);
+        }
+
+        stack.push(Item {
+            entry,
+            children: Vec::new(),
+        });
+    }
+
+    while !stack.is_empty() {
+        pop_item(&mut stack, &mut roots);
+    }
+
+    if roots.is_empty() {
+        return Err(Error::InvalidValue(
+            "copybook has no data description entries".to_string(),
+        ));
+    }
+
+    Ok(roots)
+}
+
+fn normalize_level(level: u8) -> u8 {
+    if level == 77 {
+        1
+    } else {
+        level
+    }
+}
+
+fn pop_item(stack: &mut Vec<Item>, roots: &mut Vec<Item>) {
+    let item = stack.pop().unwrap();
+
+    match stack.last_mut() {
+        Some(parent) => parent.children.push(item),
+        None => roots.push(item),
+    }
+}
+
+/// Append the elementary fields of `items` starting at `offset`, returning the length they occupy.
+fn layout(
+    items: &[Item],
+    offset: usize,
+    subscripts: &[usize],
+    usage: Option<Usage>,
+    redefined: bool,
+    fields: &mut Vec<CopybookField>,
+) -> Result<usize> {
+    let mut position = offset;
+    let mut end = offset;
+    let mut starts: Vec<(Arc<str>, usize)> = Vec::new();
+
+    for item in items {
+        let start = match &item.entry.redefines {
+            Some(target) => starts
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(target))
+                .map(|(_, start)| *start)
+                .ok_or_else(|| {
+                    Error::InvalidValue(format!("REDEFINES target {} not found", target))
+                })?,
+            None => position,
+        };
+
+        let usage = item.entry.usage.or(usage);
+        let redefined = redefined || item.entry.redefines.is_some();
+        let mut length = 0;
+
+        for occurrence in 0..item.entry.occurs {
+            let mut subscripts = subscripts.to_vec();
+            if item.entry.occurs > 1 {
+                subscripts.push(occurrence + 1);
+            }
+
+            let occurrence_offset = start + occurrence * length;
+            length = if item.children.is_empty() {
+                let (field_type, field_length) = elementary(&item.entry, usage)?;
+
+                if let Some(name) = &item.entry.name {
+                    fields.push(CopybookField {
+                        name: subscripted(name, &subscripts),
+                        offset: occurrence_offset,
+                        length: field_length,
+                        field_type,
+                        redefined,
+                    });
+                }
+
+                field_length
+            } else {
+                layout(
+                    &item.children,
+                    occurrence_offset,
+                    &subscripts,
+                    usage,
+                    redefined,
+                    fields,
+                )? - occurrence_offset
+            };
+        }
+
+        if let Some(name) = &item.entry.name {
+            starts.push((name.clone(), start));
+        }
+
+        let item_end = start + length * item.entry.occurs;
+        if item.entry.redefines.is_none() {
+            position = item_end;
+        }
+        end = end.max(item_end);
+    }
+
+    Ok(end.max(position))
+}
+
+fn subscripted(name: &str, subscripts: &[usize]) -> Arc<str> {
+    if subscripts.is_empty() {
+        return name.into();
+    }
+
+    let subscripts: Vec<_> = subscripts.iter().map(|s| s.to_string()).collect();
+
+    format!("{}({})", name, subscripts.join(",")).into()
+}
+
+/// The field type and length in bytes of an elementary item.
+fn elementary(entry: &Entry, usage: Option<Usage>) -> Result<(FieldType, usize)> {
+    let name = entry.name.as_deref().unwrap_or("FILLER");
+
+    if let Some(Usage::Float(length)) = usage {
+        return Ok((FieldType::Bytes, length));
+    }
+
+    let picture = entry
+        .picture
+        .as_deref()
+        .ok_or_else(|| Error::InvalidValue(format!("{} has no PICTURE clause", name)))?;
+    let picture = expand_picture(picture)
+        .ok_or_else(|| Error::InvalidValue(format!("invalid PICTURE for {}: {}", name, picture)))?;
+
+    let signed = picture.starts_with('S');
+    let is_numeric = picture.chars().all(|c| matches!(c, 'S' | 'V' | '9'));
+
+    if !is_numeric {
+        // alphanumeric and edited pictures are stored as display characters
+        return match usage {
+            None | Some(Usage::Display) => Ok((FieldType::Text, picture.len())),
+            _ => Err(Error::InvalidValue(format!(
+                "{} has a non-numeric PICTURE with a numeric USAGE",
+                name
+            ))),
+        };
+    }
+
+    let digits = picture.chars().filter(|c| *c == '9').count();
+    let scale = picture
+        .split_once('V')
+        .map(|(_, fraction)| fraction.len())
+        .unwrap_or_default() as u32;
+
+    let field = match usage.unwrap_or(Usage::Display) {
+        Usage::Display => (FieldType::Zoned { signed, scale }, digits),
+        Usage::Packed => (FieldType::Packed { signed, scale }, digits / 2 + 1),
+        Usage::Binary => {
+            let length = match digits {
+                1..=4 => 2,
+                5..=9 => 4,
+                _ => 8,
+            };
+
+            (FieldType::Binary { signed, scale }, length)
+        }
+        Usage::Float(_) => unreachable!(),
+    };
+
+    Ok(field)
+}
+
+/// Expand repetition factors, e.g. `S9(5)V99` to `S99999V99`.
+fn expand_picture(picture: &str) -> Option<String> {
+    let mut expanded = String::new();
+    let mut chars = picture.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '(' {
+            let count: String = chars.by_ref().take_while(|c| *c != ')').collect();
+            let count: usize = count.parse().ok()?;
+            let last = expanded.pop()?;
+
+            expanded.extend(std::iter::repeat_n(last, count));
+        } else {
+            expanded.push(c);
+        }
+    }
+
+    (!expanded.is_empty()).then_some(expanded)
+}
+
+fn is_clause(token: &str) -> bool {
+    matches!(
+        token.to_ascii_uppercase().as_str(),
+        "PIC"
+            | "PICTURE"
+            | "USAGE"
+            | "DISPLAY"
+            | "COMP"
+            | "COMPUTATIONAL"
+            | "COMP-1"
+            | "COMPUTATIONAL-1"
+            | "COMP-2"
+            | "COMPUTATIONAL-2"
+            | "COMP-3"
+            | "COMPUTATIONAL-3"
+            | "COMP-4"
+            | "COMPUTATIONAL-4"
+            | "COMP-5"
+            | "COMPUTATIONAL-5"
+            | "BINARY"
+            | "PACKED-DECIMAL"
+            | "OCCURS"
+            | "REDEFINES"
+            | "VALUE"
+            | "VALUES"
+            | "SIGN"
+            | "SYNC"
+            | "SYNCHRONIZED"
+            | "JUST"
+            | "JUSTIFIED"
+            | "BLANK"
+    )
+}
+
+fn skip_is<'a, I>(tokens: &mut std::iter::Peekable<I>)
+where
+    I: Iterator<Item = &'a str>,
+{
+    if tokens.peek().is_some_and(|t| t.eq_ignore_ascii_case("IS")) {
+        tokens.next();
+    }
+}
+
+fn invalid(statement: &str, message: &str) -> Error {
+    Error::InvalidValue(format!("{}: {}", message, statement))
+}
+
+/// Split the source into period-terminated statements, removing comments and sequence areas.
+fn statements(source: &str) -> Vec<String> {
+    let code: Vec<&str> = source.lines().filter_map(code_area).collect();
+    let code = code.join(" ");
+
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut quote = None;
+    let mut chars = code.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, quote) {
+            ('\'' | '"', None) => quote = Some(c),
+            (c, Some(q)) if c == q => quote = None,
+            ('.', None) if chars.peek().is_none_or(|next| next.is_whitespace()) => {
+                statements.push(std::mem::take(&mut current));
+                continue;
+            }
+            _ => {}
+        }
+
+        current.push(c);
+    }
+
+    if !current.trim().is_empty() {
+        statements.push(current);
+    }
+
+    statements
+        .into_iter()
+        .map(|statement| statement.trim().to_string())
+        .filter(|statement| !statement.is_empty())
+        .collect()
+}
+
+/// The code portion of a source line, or `None` for comments.
+fn code_area(line: &str) -> Option<&str> {
+    let is_fixed = line.len() > 6
+        && line.is_char_boundary(6)
+        && line[..6].chars().all(|c| c.is_ascii_digit() || c == ' ')
+        && matches!(line.as_bytes()[6], b' ' | b'*' | b'/' | b'-' | b'D' | b'd');
+
+    let code = if is_fixed {
+        if matches!(line.as_bytes()[6], b'*' | b'/') {
+            return None;
+        }
+
+        let end = line
+            .char_indices()
+            .map(|(i, _)| i)
+            .find(|i| *i >= 72)
+            .unwrap_or(line.len());
+
+        &line[7..end]
+    } else if line.trim_start().starts_with('*') {
+        return None;
+    } else {
+        line
+    };
+
+    let code = code.split_once("*>").map(|(code, _)| code).unwrap_or(code);
+
+    Some(code)
+}
+
+/// Split a statement on whitespace, keeping quoted literals together.
+fn tokenize(statement: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut quote = None;
+
+    for c in statement.chars() {
+        match (c, quote) {
+            ('\'' | '"', None) => {
+                quote = Some(c);
+                current.push(c);
+            }
+            (c, Some(q)) if c == q => {
+                quote = None;
+                current.push(c);
+            }
+            (c, None) if c.is_whitespace() || c == ',' || c == ';' => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            _ => current.push(c),
+        }
+    }
+
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::datasets::record::Decimal;
+
+    const COPYBOOK: &str = r#"
+000100* CUSTOMER MASTER RECORD                                          CUSTREC
+000200 01  CUSTOMER-RECORD.                                             CUSTREC
+000300     05  CUST-ID             PIC 9(6).                            CUSTREC
+000400     05  CUST-NAME           PIC X(20).                           CUSTREC
+000500     05  CUST-TYPE           PIC X.                               CUSTREC
+000600         88  CUST-RETAIL     VALUE 'R'.                           CUSTREC
+000700     05  CUST-BALANCE        PIC S9(7)V99 COMP-3.                 CUSTREC
+000800     05  CUST-ORDERS         PIC S9(4) COMP.                      CUSTREC
+000900     05  FILLER              PIC X(3).                            CUSTREC
+001000     05  CUST-HISTORY        OCCURS 2 TIMES.                      CUSTREC
+001100         10  HIST-AMOUNT     PIC S9(5)V99.                        CUSTREC
+001200     05  CUST-DATE           PIC X(8).                            CUSTREC
+001300     05  CUST-DATE-R         REDEFINES CUST-DATE.                 CUSTREC
+001400         10  CUST-YEAR       PIC 9(4).                            CUSTREC
+001500         10  CUST-MMDD       PIC 9(4).                            CUSTREC
+"#;
+
+    #[test]
+    fn parse() {
+        let copybook = Copybook::parse(COPYBOOK).unwrap();
+
+        let layout: Vec<_> = copybook
+            .fields()
+            .iter()
+            .map(|field| (field.name(), field.offset(), field.length()))
+            .collect();
+
+        assert_eq!(
+            layout,
+            vec![
+                ("CUST-ID", 0, 6),
+                ("CUST-NAME", 6, 20),
+                ("CUST-TYPE", 26, 1),
+                ("CUST-BALANCE", 27, 5),
+                ("CUST-ORDERS", 32, 2),
+                ("HIST-AMOUNT(1)", 37, 7),
+                ("HIST-AMOUNT(2)", 44, 7),
+                ("CUST-DATE", 51, 8),
+                ("CUST-YEAR", 51, 4),
+                ("CUST-MMDD", 55, 4),
+            ]
+        );
+        assert_eq!(copybook.record_length(), 59);
+        assert_eq!(copybook.layout(Codepage::Ibm037).unwrap().len(), 59);
+        assert_eq!(
+            copybook.field("cust-balance").unwrap().field_type(),
+            FieldType::Packed {
+                signed: true,
+                scale: 2
+            }
+        );
+    }
+
+    #[test]
+    fn free_format() {
+        let copybook =
+            Copybook::parse("01 REC. *> inline comment\n  05 A PIC X(2). 05 B PIC 9(3) VALUE 1.\n")
+                .unwrap();
+
+        assert_eq!(copybook.record_length(), 5);
+        assert_eq!(copybook.field("B").unwrap().offset(), 2);
+    }
+
+    #[test]
+    fn round_trip() {
+        let copybook = Copybook::parse(COPYBOOK).unwrap();
+
+        let record: Record = [
+            ("CUST-ID", FieldValue::from(42)),
+            ("CUST-NAME", FieldValue::from("ACME CORP")),
+            ("CUST-TYPE", FieldValue::from("R")),
+            ("CUST-BALANCE", FieldValue::from(Decimal::new(-123456, 2))),
+            ("CUST-ORDERS", FieldValue::from(7)),
+            ("HIST-AMOUNT(1)", FieldValue::from(Decimal::new(1050, 2))),
+            ("HIST-AMOUNT(2)", FieldValue::from(Decimal::new(-5, 1))),
+            ("CUST-YEAR", FieldValue::from(2024)),
+            ("CUST-MMDD", FieldValue::from(1231)),
+        ]
+        .into_iter()
+        .collect();
+
+        let data = copybook
+            .encode_records([&record], Codepage::Ibm037)
+            .unwrap();
+        let records = copybook.decode_records(&data, Codepage::Ibm037).unwrap();
+
+        assert_eq!(records.len(), 1);
+        let decoded = &records[0];
+        assert_eq!(
+            decoded.get("CUST-NAME").unwrap().as_text(),
+            Some("ACME CORP")
+        );
+        assert_eq!(decoded.get("CUST-BALANCE").unwrap().to_string(), "-1234.56");
+        assert_eq!(decoded.get("HIST-AMOUNT(2)").unwrap().to_string(), "-0.50");
+        assert_eq!(
+            decoded.get("CUST-DATE").unwrap().as_text(),
+            Some("20241231")
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(Copybook::parse("").is_err());
+        assert!(Copybook::parse("01 REC. 05 A PIC S9(3) SIGN LEADING SEPARATE.").is_err());
+        assert!(Copybook::parse("01 REC. 05 A PIC X(2). 05 B REDEFINES C PIC X.").is_err());
+    }
+
+    #[test]
+    fn redefined_garbage() {
+        let copybook =
+            Copybook::parse("01 REC. 05 A PIC X(2). 05 B REDEFINES A PIC 9(2).").unwrap();
+
+        let record = copybook.decode(&[0xC1, 0x4B], Codepage::Ibm037).unwrap();
+
+        assert_eq!(record.get("A").unwrap().as_text(), Some("A."));
+        assert_eq!(
+            record.get("B").unwrap().as_bytes().unwrap().as_ref(),
+            &[0xC1, 0x4B]
+        );
+    }
+}
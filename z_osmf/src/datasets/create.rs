@@ -1,14 +1,20 @@
+pub use crate::utils::DatasetRecordFormat;
+
 use std::marker::PhantomData;
 use std::sync::Arc;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use z_osmf_macros::Endpoint;
 
 use crate::convert::TryFromResponse;
-use crate::ClientCore;
+use crate::{ClientCore, Error, Result};
 
 #[derive(Clone, Debug, Endpoint)]
-#[endpoint(method = post, path = "/zosmf/restfiles/ds/{dataset}")]
+#[endpoint(
+    method = post,
+    path = "/zosmf/restfiles/ds/{dataset}",
+    validate_fn = validate
+)]
 pub struct DatasetCreateBuilder<T>
 where
     T: TryFromResponse,
@@ -23,7 +29,7 @@ where
     #[endpoint(skip_builder)]
     device_type: Option<Arc<str>>,
     #[endpoint(skip_builder)]
-    organization: Option<Arc<str>>,
+    organization: Option<DatasetOrganization>,
     #[endpoint(skip_builder)]
     space_allocation_unit: Option<Arc<str>>,
     #[endpoint(skip_builder)]
@@ -35,7 +41,7 @@ where
     #[endpoint(skip_builder)]
     average_block_size: Option<i32>,
     #[endpoint(skip_builder)]
-    record_format: Option<Arc<str>>,
+    record_format: Option<DatasetRecordFormat>,
     #[endpoint(skip_builder)]
     block_size: Option<i32>,
     #[endpoint(skip_builder)]
@@ -47,13 +53,175 @@ where
     #[endpoint(skip_builder)]
     data_class: Option<Arc<str>>,
     #[endpoint(skip_builder)]
-    dataset_type: Option<Arc<str>>,
+    dataset_type: Option<DatasetType>,
     #[endpoint(skip_builder)]
     model_dataset: Option<Arc<str>>,
+    #[endpoint(header = "X-IBM-Dsname-Encoding")]
+    dsname_encoding: Option<Arc<str>>,
 
     target_type: PhantomData<T>,
 }
 
+/// The dataset organization (`dsorg`).
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DatasetOrganization {
+    Ps,
+    Po,
+    /// A PDSE as reported when listing, created with [`DatasetOrganization::Po`] and [`DatasetType::Library`].
+    Poe,
+    Da,
+    Vs,
+    Other(String),
+}
+
+impl DatasetOrganization {
+    /// Whether the dataset has members, i.e. it is a PDS or PDSE.
+    pub fn is_partitioned(&self) -> bool {
+        matches!(self, DatasetOrganization::Po | DatasetOrganization::Poe)
+    }
+}
+
+impl From<String> for DatasetOrganization {
+    fn from(value: String) -> Self {
+        match value.to_ascii_uppercase().as_str() {
+            "PS" => DatasetOrganization::Ps,
+            "PO" => DatasetOrganization::Po,
+            "PO-E" => DatasetOrganization::Poe,
+            "DA" => DatasetOrganization::Da,
+            "VS" => DatasetOrganization::Vs,
+            _ => DatasetOrganization::Other(value),
+        }
+    }
+}
+
+impl From<&str> for DatasetOrganization {
+    fn from(value: &str) -> Self {
+        DatasetOrganization::from(value.to_string())
+    }
+}
+
+impl std::str::FromStr for DatasetOrganization {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
+impl std::fmt::Display for DatasetOrganization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            DatasetOrganization::Ps => "PS",
+            DatasetOrganization::Po => "PO",
+            DatasetOrganization::Poe => "PO-E",
+            DatasetOrganization::Da => "DA",
+            DatasetOrganization::Vs => "VS",
+            DatasetOrganization::Other(value) => value.as_ref(),
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+impl<'de> Deserialize<'de> for DatasetOrganization {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+
+        Ok(s.parse().unwrap())
+    }
+}
+
+impl Serialize for DatasetOrganization {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// The dataset name type (`dsntype`).
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DatasetType {
+    Basic,
+    ExtPref,
+    ExtReq,
+    Hfs,
+    Large,
+    Library,
+    Pds,
+    Other(String),
+}
+
+impl From<String> for DatasetType {
+    fn from(value: String) -> Self {
+        match value.to_ascii_uppercase().as_str() {
+            "BASIC" => DatasetType::Basic,
+            "EXTPREF" => DatasetType::ExtPref,
+            "EXTREQ" => DatasetType::ExtReq,
+            "HFS" => DatasetType::Hfs,
+            "LARGE" => DatasetType::Large,
+            "LIBRARY" => DatasetType::Library,
+            "PDS" => DatasetType::Pds,
+            _ => DatasetType::Other(value),
+        }
+    }
+}
+
+impl From<&str> for DatasetType {
+    fn from(value: &str) -> Self {
+        DatasetType::from(value.to_string())
+    }
+}
+
+impl std::str::FromStr for DatasetType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
+impl std::fmt::Display for DatasetType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            DatasetType::Basic => "BASIC",
+            DatasetType::ExtPref => "EXTPREF",
+            DatasetType::ExtReq => "EXTREQ",
+            DatasetType::Hfs => "HFS",
+            DatasetType::Large => "LARGE",
+            DatasetType::Library => "LIBRARY",
+            DatasetType::Pds => "PDS",
+            DatasetType::Other(value) => value.as_ref(),
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+impl<'de> Deserialize<'de> for DatasetType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+
+        Ok(s.parse().unwrap())
+    }
+}
+
+impl Serialize for DatasetType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
 #[derive(Clone, Debug, Default, Serialize)]
 struct RequestJson<'a> {
     #[serde(rename = "volser", skip_serializing_if = "Option::is_none")]
@@ -61,7 +229,7 @@ struct RequestJson<'a> {
     #[serde(rename = "unit", skip_serializing_if = "Option::is_none")]
     device_type: Option<&'a str>,
     #[serde(rename = "dsorg", skip_serializing_if = "Option::is_none")]
-    organization: Option<&'a str>,
+    organization: Option<&'a DatasetOrganization>,
     #[serde(rename = "alcunit", skip_serializing_if = "Option::is_none")]
     space_allocation_unit: Option<&'a str>,
     #[serde(rename = "primary", skip_serializing_if = "Option::is_none")]
@@ -73,7 +241,7 @@ struct RequestJson<'a> {
     #[serde(rename = "avgblk", skip_serializing_if = "Option::is_none")]
     average_block_size: Option<&'a i32>,
     #[serde(rename = "recfm", skip_serializing_if = "Option::is_none")]
-    record_format: Option<&'a str>,
+    record_format: Option<&'a DatasetRecordFormat>,
     #[serde(rename = "blksize", skip_serializing_if = "Option::is_none")]
     block_size: Option<&'a i32>,
     #[serde(rename = "lrecl", skip_serializing_if = "Option::is_none")]
@@ -85,11 +253,87 @@ struct RequestJson<'a> {
     #[serde(rename = "dataclass", skip_serializing_if = "Option::is_none")]
     data_class: Option<&'a str>,
     #[serde(rename = "dsntype", skip_serializing_if = "Option::is_none")]
-    dataset_type: Option<&'a str>,
+    dataset_type: Option<&'a DatasetType>,
     #[serde(rename = "like", skip_serializing_if = "Option::is_none")]
     model_dataset: Option<&'a str>,
 }
 
+fn validate<T>(builder: &DatasetCreateBuilder<T>) -> Result<()>
+where
+    T: TryFromResponse,
+{
+    let DatasetCreateBuilder {
+        organization,
+        primary_space,
+        secondary_space,
+        directory_blocks,
+        record_format,
+        block_size,
+        record_length,
+        dataset_type,
+        model_dataset,
+        ..
+    } = builder;
+
+    let invalid = |message: &str| Err(Error::InvalidValue(message.to_string()));
+
+    if model_dataset.is_none() && primary_space.is_none() {
+        return invalid("primary space is required unless a model dataset is given");
+    }
+
+    for (name, value, min) in [
+        ("primary space", primary_space, 1),
+        ("secondary space", secondary_space, 0),
+        ("directory blocks", directory_blocks, 1),
+        ("record length", record_length, 1),
+        ("block size", block_size, 1),
+    ] {
+        if matches!(value, Some(value) if *value < min) {
+            return Err(Error::InvalidValue(format!(
+                "{} must be at least {}",
+                name, min
+            )));
+        }
+    }
+
+    if matches!(block_size, Some(block_size) if *block_size > 32760) {
+        return invalid("block size must be at most 32760");
+    }
+
+    let is_partitioned = matches!(organization, Some(DatasetOrganization::Po))
+        || matches!(dataset_type, Some(DatasetType::Library | DatasetType::Pds));
+
+    if directory_blocks.is_some() && !is_partitioned {
+        return invalid("directory blocks require a partitioned organization (PO)");
+    }
+
+    if matches!(dataset_type, Some(DatasetType::Library | DatasetType::Pds))
+        && matches!(organization, Some(org) if *org != DatasetOrganization::Po)
+    {
+        return invalid("dataset type LIBRARY or PDS requires organization PO");
+    }
+
+    if let (Some(record_format), Some(block_size), Some(record_length)) =
+        (record_format, block_size, record_length)
+    {
+        if record_format.is_fixed() && block_size % record_length != 0 {
+            return Err(Error::InvalidValue(format!(
+                "block size {} must be a multiple of record length {} for record format {}",
+                block_size, record_length, record_format
+            )));
+        }
+
+        if record_format.is_variable() && *block_size < record_length + 4 {
+            return Err(Error::InvalidValue(format!(
+                "block size {} must be at least record length {} + 4 for record format {}",
+                block_size, record_length, record_format
+            )));
+        }
+    }
+
+    Ok(())
+}
+
 fn build_body<T>(
     request_builder: reqwest::RequestBuilder,
     builder: &DatasetCreateBuilder<T>,
@@ -120,19 +364,19 @@ where
     let request_json = RequestJson {
         volume: volume.as_deref(),
         device_type: device_type.as_deref(),
-        organization: organization.as_deref(),
+        organization: organization.as_ref(),
         space_allocation_unit: space_allocation_unit.as_deref(),
         primary_space: primary_space.as_ref(),
         secondary_space: secondary_space.as_ref(),
         directory_blocks: directory_blocks.as_ref(),
         average_block_size: average_block_size.as_ref(),
-        record_format: record_format.as_deref(),
+        record_format: record_format.as_ref(),
         block_size: block_size.as_ref(),
         record_length: record_length.as_ref(),
         storage_class: storage_class.as_deref(),
         management_class: management_class.as_deref(),
         data_class: data_class.as_deref(),
-        dataset_type: dataset_type.as_deref(),
+        dataset_type: dataset_type.as_ref(),
         model_dataset: model_dataset.as_deref(),
     };
 
@@ -143,6 +387,8 @@ where
 mod tests {
     use crate::tests::*;
 
+    use super::*;
+
     #[test]
     fn example_1() {
         let zosmf = get_zosmf();
@@ -283,16 +529,16 @@ mod tests {
             .create("JIAHJ.REST.TEST.NEWDS02")
             .volume("zmf046")
             .device_type("3390")
-            .organization("PO")
+            .organization(DatasetOrganization::Po)
             .space_allocation_unit("TRK")
             .primary_space(10)
             .secondary_space(5)
             .directory_blocks(10)
             .average_block_size(500)
-            .record_format("FB")
+            .record_format(DatasetRecordFormat::Fb)
             .block_size(400)
             .record_length(80)
-            .dataset_type("LIBRARY")
+            .dataset_type(DatasetType::Library)
             .get_request()
             .unwrap();
 
@@ -303,4 +549,61 @@ mod tests {
 
         assert_eq!(manual_request.json(), create_pdse.json());
     }
+
+    #[test]
+    fn parse_attributes() {
+        assert_eq!(DatasetOrganization::from("po"), DatasetOrganization::Po);
+        assert_eq!(DatasetRecordFormat::from("VBA"), DatasetRecordFormat::Vba);
+        assert_eq!(
+            DatasetType::from("EXTENDED"),
+            DatasetType::Other("EXTENDED".to_string())
+        );
+        assert_eq!(DatasetRecordFormat::Fbs.to_string(), "FBS");
+        assert_eq!(
+            serde_json::to_value(DatasetType::Library).unwrap(),
+            serde_json::json!("LIBRARY")
+        );
+    }
+
+    #[test]
+    fn validation() {
+        let zosmf = get_zosmf();
+
+        let create_dataset = || {
+            zosmf
+                .datasets()
+                .create("JIAHJ.REST.TEST.NEWDS02")
+                .primary_space(10)
+        };
+
+        assert!(create_dataset().get_request().is_ok());
+        assert!(zosmf
+            .datasets()
+            .create("JIAHJ.REST.TEST.NEWDS02")
+            .model_dataset("JIAHJ.REST.TEST.MODEL")
+            .get_request()
+            .is_ok());
+
+        for invalid in [
+            zosmf.datasets().create("JIAHJ.REST.TEST.NEWDS02"),
+            create_dataset().primary_space(0),
+            create_dataset()
+                .organization(DatasetOrganization::Ps)
+                .directory_blocks(10),
+            create_dataset()
+                .organization(DatasetOrganization::Ps)
+                .dataset_type(DatasetType::Library),
+            create_dataset()
+                .record_format(DatasetRecordFormat::Fb)
+                .block_size(27997)
+                .record_length(80),
+            create_dataset()
+                .record_format(DatasetRecordFormat::Vb)
+                .block_size(80)
+                .record_length(80),
+            create_dataset().block_size(32761),
+        ] {
+            assert!(matches!(invalid.get_request(), Err(Error::InvalidValue(_))));
+        }
+    }
 }
@@ -1,10 +1,13 @@
 use std::marker::PhantomData;
 use std::sync::Arc;
+use std::time::Duration;
 
 use z_osmf_macros::Endpoint;
 
 use crate::convert::TryFromResponse;
-use crate::ClientCore;
+use crate::error::ApiError;
+use crate::utils::Backoff;
+use crate::{ClientCore, Error, Result};
 
 use super::{get_member, get_volume};
 
@@ -28,6 +31,162 @@ where
     target_type: PhantomData<T>,
 }
 
+impl<T> DatasetDeleteBuilder<T>
+where
+    T: TryFromResponse + Clone,
+{
+    /// Retry the delete while the dataset is in use by another job or user,
+    /// giving up with [`Error::Timeout`] once `timeout` has elapsed.
+    pub fn retry_in_use(self, timeout: Duration) -> DatasetDeleteRetryBuilder<T> {
+        DatasetDeleteRetryBuilder {
+            builder: self,
+            interval: Duration::from_secs(5),
+            max_interval: Duration::from_secs(60),
+            timeout,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DatasetDeleteRetryBuilder<T>
+where
+    T: TryFromResponse,
+{
+    builder: DatasetDeleteBuilder<T>,
+
+    interval: Duration,
+    max_interval: Duration,
+    timeout: Duration,
+}
+
+impl<T> DatasetDeleteRetryBuilder<T>
+where
+    T: TryFromResponse + Clone,
+{
+    /// The delay before the first retry, doubled after each attempt.
+    pub fn interval(self, value: Duration) -> Self {
+        DatasetDeleteRetryBuilder {
+            interval: value,
+            ..self
+        }
+    }
+
+    /// The upper bound for the delay between retries.
+    pub fn max_interval(self, value: Duration) -> Self {
+        DatasetDeleteRetryBuilder {
+            max_interval: value,
+            ..self
+        }
+    }
+
+    pub async fn build(self) -> Result<T> {
+        let backoff = Backoff::new(self.interval, self.max_interval, self.timeout);
+
+        let (value, _) = backoff
+            .poll(|| async {
+                match self.builder.clone().build().await {
+                    Ok(value) => Ok(Some(value)),
+                    Err(err) if DatasetDeleteFailure::from_error(&err).is_in_use() => Ok(None),
+                    Err(err) => Err(err),
+                }
+            })
+            .await?;
+
+        Ok(value)
+    }
+}
+
+/// The reason a dataset or member delete failed.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DatasetDeleteFailure {
+    /// The dataset or member does not exist, or is not cataloged.
+    NotFound,
+    /// The dataset is allocated to another job or user (ENQ contention).
+    InUse,
+    /// The security product denied access to the dataset.
+    NotAuthorized,
+    Other,
+}
+
+/// Message IDs that mean another job or user holds the dataset.
+const IN_USE_MESSAGES: &[&str] = &[
+    // DATA SET ... ALREADY IN USE, TRY LATER
+    "IKJ56225I",
+    // a DADSM function failed because the dataset is open or allocated
+    "IEC614I",
+    // ISPF: data set in use
+    "ISRZ002",
+];
+
+/// Message IDs that mean the security product denied access.
+const NOT_AUTHORIZED_MESSAGES: &[&str] = &[
+    // RACF: INSUFFICIENT ACCESS AUTHORITY
+    "ICH408I", // ACF2: data set access violation
+    "ACF99913", // Top Secret: access violation
+    "TSS7250E",
+];
+
+/// Message IDs that mean the dataset or member does not exist.
+const NOT_FOUND_MESSAGES: &[&str] = &[
+    // DATA SET ... NOT IN CATALOG OR CATALOG CAN NOT BE ACCESSED
+    "IKJ56228I",
+    // ENTRY ... NOT FOUND
+    "IDC3012I",
+];
+
+impl DatasetDeleteFailure {
+    /// Classify the error returned by a delete request.
+    pub fn from_error(err: &Error) -> Self {
+        let Error::Api(api_error) = err else {
+            return DatasetDeleteFailure::Other;
+        };
+
+        let text = match api_error {
+            ApiError::Json {
+                message, details, ..
+            } => std::iter::once(message.as_str())
+                .chain(details.iter().flatten().map(|d| d.as_str()))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            ApiError::Text { body, .. } => body.clone(),
+        }
+        .to_uppercase();
+
+        // message IDs are matched as whole words, so a dataset name such as
+        // `SYS1.RACF.BACKUP` or `PROD.ENQLOG` is never mistaken for one
+        let has_message = |ids: &[&str]| {
+            text.split(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '$' | '#' | '@')))
+                .any(|word| ids.contains(&word))
+        };
+
+        if has_message(IN_USE_MESSAGES) {
+            DatasetDeleteFailure::InUse
+        } else if api_error.status() == reqwest::StatusCode::FORBIDDEN
+            || has_message(NOT_AUTHORIZED_MESSAGES)
+        {
+            DatasetDeleteFailure::NotAuthorized
+        } else if api_error.status() == reqwest::StatusCode::NOT_FOUND
+            || has_message(NOT_FOUND_MESSAGES)
+        {
+            DatasetDeleteFailure::NotFound
+        } else {
+            DatasetDeleteFailure::Other
+        }
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        *self == DatasetDeleteFailure::NotFound
+    }
+
+    pub fn is_in_use(&self) -> bool {
+        *self == DatasetDeleteFailure::InUse
+    }
+
+    pub fn is_not_authorized(&self) -> bool {
+        *self == DatasetDeleteFailure::NotAuthorized
+    }
+}
+
 fn build_member<T>(builder: &DatasetDeleteBuilder<T>) -> String
 where
     T: TryFromResponse,
@@ -46,6 +205,77 @@ where
 mod tests {
     use crate::tests::*;
 
+    use super::*;
+
+    fn api_error(status: reqwest::StatusCode, message: &str, details: &[&str]) -> Error {
+        Error::Api(ApiError::Json {
+            url: "https://test.com/zosmf/restfiles/ds/JIAHJ.REST.TEST.DATASET".to_string(),
+            status,
+            category: 6,
+            return_code: 8,
+            reason: 0,
+            message: message.to_string(),
+            details: Some(details.iter().map(|d| d.to_string()).collect()),
+            uss: None,
+        })
+    }
+
+    #[test]
+    fn classify_failures() {
+        assert_eq!(
+            DatasetDeleteFailure::from_error(&api_error(
+                reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+                "Dynamic allocation Error",
+                &["IKJ56225I DATA SET JIAHJ.REST.TEST.DATASET ALREADY IN USE, TRY LATER"]
+            )),
+            DatasetDeleteFailure::InUse
+        );
+        assert_eq!(
+            DatasetDeleteFailure::from_error(&api_error(
+                reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+                "Dynamic allocation Error",
+                &["ICH408I USER(IBMUSER ) GROUP(SYS1 ) NAME(IBMUSER )"]
+            )),
+            DatasetDeleteFailure::NotAuthorized
+        );
+        assert_eq!(
+            DatasetDeleteFailure::from_error(&api_error(
+                reqwest::StatusCode::NOT_FOUND,
+                "Data set not found",
+                &[]
+            )),
+            DatasetDeleteFailure::NotFound
+        );
+        assert_eq!(
+            DatasetDeleteFailure::from_error(&api_error(
+                reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+                "Delete of PROD.ENQLOG failed",
+                &["IDC3009I ** VSAM CATALOG RETURN CODE IS 8"]
+            )),
+            DatasetDeleteFailure::Other
+        );
+        assert_eq!(
+            DatasetDeleteFailure::from_error(&api_error(
+                reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+                "Dynamic allocation Error",
+                &["IKJ56228I DATA SET SYS1.RACF.BACKUP NOT IN CATALOG OR CATALOG CAN NOT BE ACCESSED"]
+            )),
+            DatasetDeleteFailure::NotFound
+        );
+        assert_eq!(
+            DatasetDeleteFailure::from_error(&api_error(
+                reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+                "Data set SYS1.RACF.BACKUP not found",
+                &[]
+            )),
+            DatasetDeleteFailure::Other
+        );
+        assert_eq!(
+            DatasetDeleteFailure::from_error(&Error::NoEtag),
+            DatasetDeleteFailure::Other
+        );
+    }
+
     #[test]
     fn example_1() {
         let zosmf = get_zosmf();
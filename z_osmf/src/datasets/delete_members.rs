@@ -0,0 +1,151 @@
+use std::sync::Arc;
+
+use futures::StreamExt;
+
+use crate::{ClientCore, Result};
+
+use super::delete::DatasetDeleteBuilder;
+use super::members::{resolve_members, MemberOutcome, MemberReport};
+
+#[derive(Clone, Debug)]
+pub struct DatasetDeleteMembersBuilder {
+    core: ClientCore,
+
+    dataset: Arc<str>,
+    volume: Option<Arc<str>>,
+    members: Option<Arc<[Arc<str>]>>,
+    pattern: Option<Arc<str>>,
+    concurrency: usize,
+}
+
+impl DatasetDeleteMembersBuilder {
+    pub(crate) fn new<D>(core: ClientCore, dataset: D) -> Self
+    where
+        D: std::fmt::Display,
+    {
+        DatasetDeleteMembersBuilder {
+            core,
+            dataset: dataset.to_string().into(),
+            volume: None,
+            members: None,
+            pattern: None,
+            concurrency: 4,
+        }
+    }
+
+    pub fn volume<V>(self, value: V) -> Self
+    where
+        V: std::fmt::Display,
+    {
+        DatasetDeleteMembersBuilder {
+            volume: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// Delete the named members.
+    pub fn members<I, M>(self, members: I) -> Self
+    where
+        I: IntoIterator<Item = M>,
+        M: std::fmt::Display,
+    {
+        DatasetDeleteMembersBuilder {
+            members: Some(members.into_iter().map(|m| m.to_string().into()).collect()),
+            ..self
+        }
+    }
+
+    /// Delete the members matching a member name pattern, e.g. `GEN*`.
+    pub fn pattern<P>(self, value: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        DatasetDeleteMembersBuilder {
+            pattern: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// The maximum number of delete requests in flight at once.
+    pub fn concurrency(self, value: usize) -> Self {
+        DatasetDeleteMembersBuilder {
+            concurrency: value.max(1),
+            ..self
+        }
+    }
+
+    pub async fn build(self) -> Result<MemberReport> {
+        let members =
+            resolve_members(&self.core, &self.dataset, &self.members, &self.pattern).await?;
+
+        let items = futures::stream::iter(members.iter().cloned())
+            .map(|member| {
+                let builder = self.delete_builder(&member);
+
+                async move {
+                    let result = builder.build().await.map(|txid| txid.into());
+
+                    MemberOutcome::new(member, None, result)
+                }
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+
+        Ok(MemberReport::new(items))
+    }
+
+    fn delete_builder(&self, member: &str) -> DatasetDeleteBuilder<String> {
+        let builder = DatasetDeleteBuilder::new(self.core.clone(), &self.dataset).member(member);
+
+        match &self.volume {
+            Some(volume) => builder.volume(volume),
+            None => builder,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+    use crate::Error;
+
+    #[test]
+    fn delete_request() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .delete("https://test.com/zosmf/restfiles/ds/JIAHJ.REST.TEST.PDS(MEMBER01)")
+            .build()
+            .unwrap();
+
+        let delete_member = zosmf
+            .datasets()
+            .delete_members("JIAHJ.REST.TEST.PDS")
+            .members(["MEMBER01"])
+            .delete_builder("MEMBER01")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(manual_request.method(), delete_member.method());
+        assert_eq!(
+            urlencoding::decode(manual_request.url().as_str()).unwrap(),
+            urlencoding::decode(delete_member.url().as_str()).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn requires_members() {
+        let zosmf = get_zosmf();
+
+        let result = zosmf
+            .datasets()
+            .delete_members("JIAHJ.REST.TEST.PDS")
+            .build()
+            .await;
+
+        assert!(matches!(result, Err(Error::InvalidValue(_))));
+    }
+}
@@ -0,0 +1,415 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ClientCore, Result};
+
+use super::read::{DatasetRead, DatasetReadBuilder};
+
+#[derive(Clone, Debug)]
+pub struct DatasetDiffBuilder {
+    core: ClientCore,
+
+    from_dataset: Arc<str>,
+    from_member: Option<Arc<str>>,
+    to_dataset: Arc<str>,
+    to_member: Option<Arc<str>>,
+    encoding: Option<Arc<str>>,
+}
+
+impl DatasetDiffBuilder {
+    pub(crate) fn new<F, T>(core: ClientCore, from_dataset: F, to_dataset: T) -> Self
+    where
+        F: std::fmt::Display,
+        T: std::fmt::Display,
+    {
+        DatasetDiffBuilder {
+            core,
+            from_dataset: from_dataset.to_string().into(),
+            from_member: None,
+            to_dataset: to_dataset.to_string().into(),
+            to_member: None,
+            encoding: None,
+        }
+    }
+
+    pub fn from_member<M>(self, value: M) -> Self
+    where
+        M: std::fmt::Display,
+    {
+        DatasetDiffBuilder {
+            from_member: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    pub fn to_member<M>(self, value: M) -> Self
+    where
+        M: std::fmt::Display,
+    {
+        DatasetDiffBuilder {
+            to_member: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// The EBCDIC code page used to read both sides, e.g. `IBM-1047`.
+    pub fn encoding<E>(self, value: E) -> Self
+    where
+        E: std::fmt::Display,
+    {
+        DatasetDiffBuilder {
+            encoding: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    pub async fn build(self) -> Result<DatasetDiff> {
+        let (from, to) = futures::try_join!(
+            self.read_builder(&self.from_dataset, &self.from_member)
+                .build(),
+            self.read_builder(&self.to_dataset, &self.to_member).build()
+        )?;
+
+        let mut diff = DatasetDiff::from_text(from.data(), to.data());
+        diff.from_etag = from.etag().map(Arc::from);
+        diff.to_etag = to.etag().map(Arc::from);
+
+        Ok(diff)
+    }
+
+    fn read_builder(
+        &self,
+        dataset: &str,
+        member: &Option<Arc<str>>,
+    ) -> DatasetReadBuilder<DatasetRead<Arc<str>>> {
+        let builder = DatasetReadBuilder::new(self.core.clone(), dataset).return_etag(true);
+        let builder = match member {
+            Some(member) => builder.member(member),
+            None => builder,
+        };
+
+        match &self.encoding {
+            Some(encoding) => builder.encoding(encoding),
+            None => builder,
+        }
+    }
+}
+
+/// A line-by-line comparison of two datasets or members.
+///
+/// Line numbers are 1-based.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum DatasetDiffLine {
+    Unchanged {
+        from_line: usize,
+        to_line: usize,
+        text: Arc<str>,
+    },
+    Removed {
+        from_line: usize,
+        text: Arc<str>,
+    },
+    Added {
+        to_line: usize,
+        text: Arc<str>,
+    },
+    Changed {
+        from_line: usize,
+        to_line: usize,
+        from_text: Arc<str>,
+        to_text: Arc<str>,
+    },
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct DatasetDiff {
+    items: Arc<[DatasetDiffLine]>,
+    from_etag: Option<Arc<str>>,
+    to_etag: Option<Arc<str>>,
+}
+
+impl DatasetDiff {
+    /// Compare two texts without contacting z/OSMF, e.g. a member and its proposed replacement.
+    pub fn from_text(from: &str, to: &str) -> Self {
+        DatasetDiff {
+            items: diff_lines(from, to).into(),
+            from_etag: None,
+            to_etag: None,
+        }
+    }
+
+    pub fn items(&self) -> &[DatasetDiffLine] {
+        &self.items
+    }
+
+    pub fn from_etag(&self) -> Option<&str> {
+        self.from_etag.as_deref()
+    }
+
+    pub fn to_etag(&self) -> Option<&str> {
+        self.to_etag.as_deref()
+    }
+
+    pub fn changes(&self) -> impl Iterator<Item = &DatasetDiffLine> {
+        self.items
+            .iter()
+            .filter(|line| !matches!(line, DatasetDiffLine::Unchanged { .. }))
+    }
+
+    pub fn is_identical(&self) -> bool {
+        self.changes().next().is_none()
+    }
+}
+
+fn diff_lines(from: &str, to: &str) -> Vec<DatasetDiffLine> {
+    let from: Vec<&str> = from.lines().map(|line| line.trim_end()).collect();
+    let to: Vec<&str> = to.lines().map(|line| line.trim_end()).collect();
+
+    let prefix = from
+        .iter()
+        .zip(to.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = from[prefix..]
+        .iter()
+        .rev()
+        .zip(to[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut matches: Vec<(usize, usize)> = (0..prefix).map(|line| (line, line)).collect();
+    common_lines(
+        &from[prefix..from.len() - suffix],
+        &to[prefix..to.len() - suffix],
+        (prefix, prefix),
+        &mut matches,
+    );
+    matches.extend((0..suffix).map(|line| (from.len() - suffix + line, to.len() - suffix + line)));
+
+    let mut items = Vec::new();
+    let mut removed = Vec::new();
+    let mut added = Vec::new();
+    let (mut i, mut j) = (0, 0);
+
+    for (from_match, to_match) in matches
+        .into_iter()
+        .chain(std::iter::once((from.len(), to.len())))
+    {
+        removed.extend((i..from_match).map(|line| (line + 1, from[line])));
+        added.extend((j..to_match).map(|line| (line + 1, to[line])));
+        pair_changes(&mut items, &mut removed, &mut added);
+
+        if from_match < from.len() {
+            items.push(DatasetDiffLine::Unchanged {
+                from_line: from_match + 1,
+                to_line: to_match + 1,
+                text: from[from_match].into(),
+            });
+        }
+        (i, j) = (from_match + 1, to_match + 1);
+    }
+
+    items
+}
+
+/// Collect the line pairs of a longest common subsequence of `from` and `to` in order.
+///
+/// This is Hirschberg's algorithm, so it only keeps two rows of lengths at a time
+/// rather than a table of every pair of lines.
+fn common_lines(
+    from: &[&str],
+    to: &[&str],
+    offset: (usize, usize),
+    matches: &mut Vec<(usize, usize)>,
+) {
+    if from.is_empty() || to.is_empty() {
+        return;
+    }
+
+    if from.len() == 1 {
+        if let Some(j) = to.iter().position(|line| *line == from[0]) {
+            matches.push((offset.0, offset.1 + j));
+        }
+        return;
+    }
+
+    let mid = from.len() / 2;
+    let forward = lcs_lengths(from[..mid].iter(), to.iter());
+    let backward = lcs_lengths(from[mid..].iter().rev(), to.iter().rev());
+
+    let split = (0..=to.len())
+        .max_by_key(|&k| (forward[k] + backward[to.len() - k], std::cmp::Reverse(k)))
+        .unwrap_or(0);
+
+    common_lines(&from[..mid], &to[..split], offset, matches);
+    common_lines(
+        &from[mid..],
+        &to[split..],
+        (offset.0 + mid, offset.1 + split),
+        matches,
+    );
+}
+
+/// The longest common subsequence length of `from` and each prefix of `to`.
+fn lcs_lengths<'a, F, T>(from: F, to: T) -> Vec<u32>
+where
+    F: Iterator<Item = &'a &'a str>,
+    T: Iterator<Item = &'a &'a str> + Clone,
+{
+    let mut previous = vec![0u32; to.clone().count() + 1];
+    let mut current = previous.clone();
+
+    for a in from {
+        for (k, b) in to.clone().enumerate() {
+            current[k + 1] = if a == b {
+                previous[k] + 1
+            } else {
+                previous[k + 1].max(current[k])
+            };
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous
+}
+
+/// Pair up a run of removed and added lines as changes, leaving any excess as removals or additions.
+fn pair_changes(
+    items: &mut Vec<DatasetDiffLine>,
+    removed: &mut Vec<(usize, &str)>,
+    added: &mut Vec<(usize, &str)>,
+) {
+    let mut removed_iter = removed.drain(..);
+    let mut added_iter = added.drain(..);
+
+    loop {
+        match (removed_iter.next(), added_iter.next()) {
+            (Some((from_line, from_text)), Some((to_line, to_text))) => {
+                items.push(DatasetDiffLine::Changed {
+                    from_line,
+                    to_line,
+                    from_text: from_text.into(),
+                    to_text: to_text.into(),
+                })
+            }
+            (Some((from_line, text)), None) => items.push(DatasetDiffLine::Removed {
+                from_line,
+                text: text.into(),
+            }),
+            (None, Some((to_line, text))) => items.push(DatasetDiffLine::Added {
+                to_line,
+                text: text.into(),
+            }),
+            (None, None) => break,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn read_requests() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds/MY.PARMLIB")
+            .header("X-IBM-Data-Type", "text;fileEncoding=IBM-037")
+            .header("X-IBM-Return-Etag", "true")
+            .build()
+            .unwrap();
+
+        let diff = zosmf
+            .datasets()
+            .diff("SYS1.PARMLIB", "MY.PARMLIB")
+            .encoding("IBM-037");
+        let read_to = diff
+            .read_builder("MY.PARMLIB", &None)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", read_to));
+    }
+
+    #[test]
+    fn identical() {
+        let diff = DatasetDiff::from_text("A\nB\n", "A   \nB");
+
+        assert!(diff.is_identical());
+        assert_eq!(diff.items().len(), 2);
+    }
+
+    #[test]
+    fn changes() {
+        let diff = DatasetDiff::from_text(
+            "ACTIVE\nDSNAME(SYS1.MAN1)\nINTVAL(30)\nSYNCVAL(00)\n",
+            "ACTIVE\nDSNAME(SYS1.MAN2)\nINTVAL(30)\nSYNCVAL(00)\nSID(SYSA)\n",
+        );
+
+        assert_eq!(
+            diff.changes().cloned().collect::<Vec<_>>(),
+            vec![
+                DatasetDiffLine::Changed {
+                    from_line: 2,
+                    to_line: 2,
+                    from_text: "DSNAME(SYS1.MAN1)".into(),
+                    to_text: "DSNAME(SYS1.MAN2)".into(),
+                },
+                DatasetDiffLine::Added {
+                    to_line: 5,
+                    text: "SID(SYSA)".into(),
+                },
+            ]
+        );
+
+        let diff = DatasetDiff::from_text("A\nB\nC\n", "A\nC\n");
+
+        assert_eq!(
+            diff.changes().cloned().collect::<Vec<_>>(),
+            vec![DatasetDiffLine::Removed {
+                from_line: 2,
+                text: "B".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn common_subsequence() {
+        let from = "A\nB\nC\nA\nB\nB\nA\nZ";
+        let to = "A\nC\nB\nA\nB\nA\nC\nZ";
+        let diff = DatasetDiff::from_text(from, to);
+
+        let unchanged = diff
+            .items()
+            .iter()
+            .filter(|line| matches!(line, DatasetDiffLine::Unchanged { .. }))
+            .count();
+        assert_eq!(unchanged, 6);
+
+        let mut from_lines = Vec::new();
+        let mut to_lines = Vec::new();
+        for line in diff.items() {
+            match line {
+                DatasetDiffLine::Unchanged {
+                    from_line, to_line, ..
+                }
+                | DatasetDiffLine::Changed {
+                    from_line, to_line, ..
+                } => {
+                    from_lines.push(*from_line);
+                    to_lines.push(*to_line);
+                }
+                DatasetDiffLine::Removed { from_line, .. } => from_lines.push(*from_line),
+                DatasetDiffLine::Added { to_line, .. } => to_lines.push(*to_line),
+            }
+        }
+        assert_eq!(from_lines, (1..=8).collect::<Vec<_>>());
+        assert_eq!(to_lines, (1..=8).collect::<Vec<_>>());
+    }
+}
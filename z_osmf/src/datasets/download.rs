@@ -0,0 +1,390 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use bytes::Bytes;
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{ClientCore, Error, Result};
+
+use super::members::{MemberAttributesName, MemberList, MemberListBuilder};
+use super::read::{DatasetRead, DatasetReadBuilder};
+
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+pub enum DatasetDownloadMode {
+    /// Transfer members byte for byte, e.g. load modules.
+    #[default]
+    Binary,
+    /// Convert members to text, one line per record.
+    Text,
+}
+
+/// Reported after each member of a download finishes, successfully or not.
+#[derive(Clone, Copy, Debug)]
+pub struct DatasetDownloadProgress<'a> {
+    pub item: &'a DatasetDownloadItem,
+    pub completed: usize,
+    pub total: usize,
+}
+
+type ProgressFn = Arc<dyn Fn(DatasetDownloadProgress<'_>) + Send + Sync>;
+
+#[derive(Clone)]
+pub struct DatasetDownloadBuilder {
+    core: ClientCore,
+
+    dataset: Arc<str>,
+    directory: PathBuf,
+    pattern: Option<Arc<str>>,
+    mode: DatasetDownloadMode,
+    encoding: Option<Arc<str>>,
+    extension: Option<Arc<str>>,
+    concurrency: usize,
+    progress: Option<ProgressFn>,
+}
+
+impl std::fmt::Debug for DatasetDownloadBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DatasetDownloadBuilder")
+            .field("dataset", &self.dataset)
+            .field("directory", &self.directory)
+            .field("pattern", &self.pattern)
+            .field("mode", &self.mode)
+            .field("encoding", &self.encoding)
+            .field("extension", &self.extension)
+            .field("concurrency", &self.concurrency)
+            .field("progress", &self.progress.is_some())
+            .finish()
+    }
+}
+
+impl DatasetDownloadBuilder {
+    pub(crate) fn new<D, P>(core: ClientCore, dataset: D, directory: P) -> Self
+    where
+        D: std::fmt::Display,
+        P: AsRef<Path>,
+    {
+        DatasetDownloadBuilder {
+            core,
+            dataset: dataset.to_string().into(),
+            directory: directory.as_ref().to_path_buf(),
+            pattern: None,
+            mode: DatasetDownloadMode::default(),
+            encoding: None,
+            extension: None,
+            concurrency: 4,
+            progress: None,
+        }
+    }
+
+    /// Only download members matching a wildcard pattern, e.g. `PAY*`.
+    pub fn pattern<P>(self, value: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        DatasetDownloadBuilder {
+            pattern: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    pub fn mode(self, value: DatasetDownloadMode) -> Self {
+        DatasetDownloadBuilder {
+            mode: value,
+            ..self
+        }
+    }
+
+    /// The EBCDIC code page used in text mode, e.g. `IBM-1047`.
+    pub fn encoding<E>(self, value: E) -> Self
+    where
+        E: std::fmt::Display,
+    {
+        DatasetDownloadBuilder {
+            mode: DatasetDownloadMode::Text,
+            encoding: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// The local file extension, e.g. `bin` maps member `PAYROLL` to `PAYROLL.bin`.
+    pub fn extension<E>(self, value: E) -> Self
+    where
+        E: std::fmt::Display,
+    {
+        DatasetDownloadBuilder {
+            extension: Some(value.to_string().trim_start_matches('.').into()),
+            ..self
+        }
+    }
+
+    /// The maximum number of members transferred at once.
+    pub fn concurrency(self, value: usize) -> Self {
+        DatasetDownloadBuilder {
+            concurrency: value.max(1),
+            ..self
+        }
+    }
+
+    /// Called after each member is written or fails.
+    pub fn progress<F>(self, callback: F) -> Self
+    where
+        F: Fn(DatasetDownloadProgress<'_>) + Send + Sync + 'static,
+    {
+        DatasetDownloadBuilder {
+            progress: Some(Arc::new(callback)),
+            ..self
+        }
+    }
+
+    pub async fn build(self) -> Result<DatasetDownload> {
+        let member_list: MemberList<MemberAttributesName> =
+            self.member_list_builder().build().await?;
+
+        let members: Vec<Arc<str>> = member_list
+            .items()
+            .iter()
+            .map(|m| m.name().into())
+            .collect();
+
+        tokio::fs::create_dir_all(&self.directory).await?;
+
+        let total = members.len();
+        let completed = AtomicUsize::new(0);
+        let builder = &self;
+        let completed = &completed;
+
+        let items = futures::stream::iter(members)
+            .map(|member| async move {
+                let path = builder.local_path(&member);
+
+                let item = match builder.download(&member, &path).await {
+                    Ok((size, etag)) => DatasetDownloadItem {
+                        member,
+                        path,
+                        size,
+                        etag,
+                        error: None,
+                    },
+                    Err(err) => DatasetDownloadItem {
+                        member,
+                        path,
+                        size: 0,
+                        etag: None,
+                        error: Some(err),
+                    },
+                };
+
+                if let Some(progress) = &builder.progress {
+                    progress(DatasetDownloadProgress {
+                        item: &item,
+                        completed: completed.fetch_add(1, Ordering::SeqCst) + 1,
+                        total,
+                    });
+                }
+
+                item
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+
+        Ok(DatasetDownload { items })
+    }
+
+    fn member_list_builder(&self) -> MemberListBuilder<MemberList<MemberAttributesName>> {
+        let builder = MemberListBuilder::new(self.core.clone(), &self.dataset).max_items(0);
+
+        match &self.pattern {
+            Some(pattern) => builder.pattern(pattern),
+            None => builder,
+        }
+    }
+
+    fn local_path(&self, member: &str) -> PathBuf {
+        match &self.extension {
+            Some(extension) => self.directory.join(format!("{}.{}", member, extension)),
+            None => self.directory.join(member),
+        }
+    }
+
+    async fn download(&self, member: &str, path: &Path) -> Result<(u64, Option<Arc<str>>)> {
+        let (data, etag) = match self.mode {
+            DatasetDownloadMode::Binary => {
+                let read = self.read_builder(member).binary().build().await?;
+
+                (read.data().clone(), read.etag().map(Arc::from))
+            }
+            DatasetDownloadMode::Text => {
+                let read = self.read_builder(member).build().await?;
+
+                (
+                    Bytes::from(read.data().to_string()),
+                    read.etag().map(Arc::from),
+                )
+            }
+        };
+
+        tokio::fs::write(path, &data).await?;
+
+        Ok((data.len() as u64, etag))
+    }
+
+    fn read_builder(&self, member: &str) -> DatasetReadBuilder<DatasetRead<Arc<str>>> {
+        let builder = DatasetReadBuilder::new(self.core.clone(), &self.dataset)
+            .member(member)
+            .return_etag(true);
+
+        match &self.encoding {
+            Some(encoding) => builder.encoding(encoding),
+            None => builder,
+        }
+    }
+}
+
+/// The manifest of a member download.
+#[derive(Debug)]
+pub struct DatasetDownload {
+    items: Vec<DatasetDownloadItem>,
+}
+
+impl DatasetDownload {
+    pub fn items(&self) -> &[DatasetDownloadItem] {
+        &self.items
+    }
+
+    pub fn failed(&self) -> impl Iterator<Item = &DatasetDownloadItem> {
+        self.items.iter().filter(|item| item.error.is_some())
+    }
+
+    /// The etag of each downloaded member, e.g. for a later [`DatasetSyncBuilder::etags`].
+    ///
+    /// [`DatasetSyncBuilder::etags`]: super::sync::DatasetSyncBuilder::etags
+    pub fn etags(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.items
+            .iter()
+            .filter_map(|item| Some((item.member(), item.etag()?)))
+    }
+}
+
+#[derive(Debug)]
+pub struct DatasetDownloadItem {
+    member: Arc<str>,
+    path: PathBuf,
+    size: u64,
+    etag: Option<Arc<str>>,
+    error: Option<Error>,
+}
+
+impl DatasetDownloadItem {
+    pub fn member(&self) -> &str {
+        &self.member
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The number of bytes written to [`path`](Self::path).
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    pub fn error(&self) -> Option<&Error> {
+        self.error.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn requests() {
+        let zosmf = get_zosmf();
+
+        let download = zosmf
+            .datasets()
+            .download("SYS1.LINKLIB", "./backup")
+            .pattern("IEF*")
+            .extension(".bin");
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds/SYS1.LINKLIB/member")
+            .query(&[("pattern", "IEF*")])
+            .header("X-IBM-Max-Items", "0")
+            .build()
+            .unwrap();
+
+        let request = download.member_list_builder().get_request().unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds/SYS1.LINKLIB(IEFBR14)")
+            .header("X-IBM-Data-Type", "binary")
+            .header("X-IBM-Return-Etag", "true")
+            .build()
+            .unwrap();
+
+        let request = download
+            .read_builder("IEFBR14")
+            .binary()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            urlencoding::decode(request.url().as_str()).unwrap(),
+            manual_request.url().as_str()
+        );
+        assert_eq!(
+            format!("{:?}", manual_request.headers()),
+            format!("{:?}", request.headers())
+        );
+        assert_eq!(
+            download.local_path("IEFBR14"),
+            PathBuf::from("./backup/IEFBR14.bin")
+        );
+    }
+
+    #[test]
+    fn text_mode() {
+        let zosmf = get_zosmf();
+
+        let download = zosmf
+            .datasets()
+            .download("MY.SRC.PDS", "./src")
+            .encoding("IBM-037");
+
+        assert_eq!(download.mode, DatasetDownloadMode::Text);
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds/MY.SRC.PDS(PAYROLL)")
+            .header("X-IBM-Data-Type", "text;fileEncoding=IBM-037")
+            .header("X-IBM-Return-Etag", "true")
+            .build()
+            .unwrap();
+
+        let request = download.read_builder("PAYROLL").get_request().unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request.headers()),
+            format!("{:?}", request.headers())
+        );
+    }
+}
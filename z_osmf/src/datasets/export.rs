@@ -0,0 +1,136 @@
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::Result;
+
+/// Serialize list items to a JSON array, keeping only `columns` unless it is empty.
+pub(crate) fn to_json<'a, T, I>(items: I, columns: &[&str]) -> Result<String>
+where
+    T: Serialize + 'a,
+    I: IntoIterator<Item = &'a T>,
+{
+    let rows = to_rows(items)?;
+
+    let rows: Vec<Value> = if columns.is_empty() {
+        rows.into_iter().map(Value::Object).collect()
+    } else {
+        rows.into_iter()
+            .map(|row| {
+                Value::Object(
+                    columns
+                        .iter()
+                        .filter_map(|c| row.get(*c).map(|v| (c.to_string(), v.clone())))
+                        .collect(),
+                )
+            })
+            .collect()
+    };
+
+    Ok(serde_json::to_string(&rows)?)
+}
+
+/// Serialize list items to CSV with a header row.
+///
+/// With no `columns`, every field of every item is included in alphabetical order.
+/// Missing and null values are written as empty fields.
+pub(crate) fn to_csv<'a, T, I>(items: I, columns: &[&str]) -> Result<String>
+where
+    T: Serialize + 'a,
+    I: IntoIterator<Item = &'a T>,
+{
+    let rows = to_rows(items)?;
+
+    let columns: Vec<&str> = if columns.is_empty() {
+        rows.iter()
+            .flat_map(|row| row.keys().map(|k| k.as_str()))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    } else {
+        columns.to_vec()
+    };
+
+    let mut csv = String::new();
+    write_record(&mut csv, columns.iter().map(|c| c.to_string()));
+    for row in rows.iter() {
+        write_record(
+            &mut csv,
+            columns.iter().map(|c| match row.get(*c) {
+                None | Some(Value::Null) => String::new(),
+                Some(Value::String(s)) => s.clone(),
+                Some(value) => value.to_string(),
+            }),
+        );
+    }
+
+    Ok(csv)
+}
+
+fn to_rows<'a, T, I>(items: I) -> Result<Vec<Map<String, Value>>>
+where
+    T: Serialize + 'a,
+    I: IntoIterator<Item = &'a T>,
+{
+    items
+        .into_iter()
+        .map(|item| match serde_json::to_value(item)? {
+            Value::Object(map) => Ok(map),
+            value => Ok(Map::from_iter([("value".to_string(), value)])),
+        })
+        .collect()
+}
+
+fn write_record<I>(csv: &mut String, fields: I)
+where
+    I: Iterator<Item = String>,
+{
+    for (i, field) in fields.enumerate() {
+        if i > 0 {
+            csv.push(',');
+        }
+        if field.contains([',', '"', '\r', '\n']) {
+            csv.push('"');
+            csv.push_str(&field.replace('"', "\"\""));
+            csv.push('"');
+        } else {
+            csv.push_str(&field);
+        }
+    }
+    csv.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn csv() {
+        let items = vec![
+            json!({"dsname": "PROD.SRC", "vol": "PROD01", "lrecl": "80"}),
+            json!({"dsname": "PROD.\"Q\",X", "vol": null}),
+        ];
+
+        assert_eq!(
+            to_csv(&items, &[]).unwrap(),
+            "dsname,lrecl,vol\r\nPROD.SRC,80,PROD01\r\n\"PROD.\"\"Q\"\",X\",,\r\n"
+        );
+        assert_eq!(
+            to_csv(&items, &["vol", "dsname"]).unwrap(),
+            "vol,dsname\r\nPROD01,PROD.SRC\r\n,\"PROD.\"\"Q\"\",X\"\r\n"
+        );
+    }
+
+    #[test]
+    fn json() {
+        let items = vec![json!({"dsname": "PROD.SRC", "vol": "PROD01", "lrecl": "80"})];
+
+        assert_eq!(
+            serde_json::from_str::<Value>(&to_json(&items, &["dsname", "vol"]).unwrap()).unwrap(),
+            json!([{"dsname": "PROD.SRC", "vol": "PROD01"}])
+        );
+    }
+}
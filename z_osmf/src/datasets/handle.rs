@@ -0,0 +1,209 @@
+use std::sync::Arc;
+
+use crate::restfiles::Etag;
+use crate::ClientCore;
+
+use super::apply_location;
+use super::delete::DatasetDeleteBuilder;
+use super::lock::DatasetLockBuilder;
+use super::members::{MemberAttributesName, MemberList, MemberListBuilder};
+use super::read::{DatasetRead, DatasetReadBuilder};
+use super::write::DatasetWriteBuilder;
+
+/// A dataset, or a member of one, that builders can be created for without
+/// repeating its name, volume and dsname encoding.
+#[derive(Clone, Debug)]
+pub struct DatasetHandle {
+    core: ClientCore,
+
+    dataset: Arc<str>,
+    volume: Option<Arc<str>>,
+    member: Option<Arc<str>>,
+    dsname_encoding: Option<Arc<str>>,
+}
+
+impl DatasetHandle {
+    pub(crate) fn new<D>(core: ClientCore, dataset: D) -> Self
+    where
+        D: std::fmt::Display,
+    {
+        DatasetHandle {
+            core,
+            dataset: dataset.to_string().into(),
+            volume: None,
+            member: None,
+            dsname_encoding: None,
+        }
+    }
+
+    /// Scope the handle to an uncataloged dataset on a volume.
+    pub fn volume<V>(self, value: V) -> Self
+    where
+        V: std::fmt::Display,
+    {
+        DatasetHandle {
+            volume: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    pub fn dsname_encoding<E>(self, value: E) -> Self
+    where
+        E: std::fmt::Display,
+    {
+        DatasetHandle {
+            dsname_encoding: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// A handle for a member of this dataset.
+    pub fn member<M>(&self, value: M) -> Self
+    where
+        M: std::fmt::Display,
+    {
+        DatasetHandle {
+            member: Some(value.to_string().into()),
+            ..self.clone()
+        }
+    }
+
+    pub fn dataset(&self) -> &str {
+        &self.dataset
+    }
+
+    pub fn volume_name(&self) -> Option<&str> {
+        self.volume.as_deref()
+    }
+
+    pub fn member_name(&self) -> Option<&str> {
+        self.member.as_deref()
+    }
+
+    pub fn delete(&self) -> DatasetDeleteBuilder<String> {
+        apply_location(
+            DatasetDeleteBuilder::new(self.core.clone(), &self.dataset),
+            &self.volume,
+            &self.member,
+            &self.dsname_encoding,
+            DatasetDeleteBuilder::volume,
+            DatasetDeleteBuilder::member,
+            DatasetDeleteBuilder::dsname_encoding,
+        )
+    }
+
+    pub fn lock(&self) -> DatasetLockBuilder {
+        apply_location(
+            DatasetLockBuilder::new(self.core.clone(), &self.dataset),
+            &self.volume,
+            &self.member,
+            &self.dsname_encoding,
+            DatasetLockBuilder::volume,
+            DatasetLockBuilder::member,
+            DatasetLockBuilder::dsname_encoding,
+        )
+    }
+
+    /// List the members of the dataset; the handle's member, if any, is ignored.
+    pub fn members(&self) -> MemberListBuilder<MemberList<MemberAttributesName>> {
+        let builder = MemberListBuilder::new(self.core.clone(), &self.dataset);
+
+        match &self.dsname_encoding {
+            Some(dsname_encoding) => builder.dsname_encoding(dsname_encoding),
+            None => builder,
+        }
+    }
+
+    pub fn read(&self) -> DatasetReadBuilder<DatasetRead<Arc<str>>> {
+        apply_location(
+            DatasetReadBuilder::new(self.core.clone(), &self.dataset),
+            &self.volume,
+            &self.member,
+            &self.dsname_encoding,
+            DatasetReadBuilder::volume,
+            DatasetReadBuilder::member,
+            DatasetReadBuilder::dsname_encoding,
+        )
+    }
+
+    pub fn write(&self) -> DatasetWriteBuilder<Etag> {
+        apply_location(
+            DatasetWriteBuilder::new(self.core.clone(), &self.dataset),
+            &self.volume,
+            &self.member,
+            &self.dsname_encoding,
+            DatasetWriteBuilder::volume,
+            DatasetWriteBuilder::member,
+            DatasetWriteBuilder::dsname_encoding,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    #[test]
+    fn read_and_delete() {
+        let zosmf = get_zosmf();
+
+        let dataset = zosmf
+            .datasets()
+            .dataset("JIAHJ.REST.PDS")
+            .volume("PEVTS2")
+            .dsname_encoding("IBM-1047");
+        let member = dataset.member("MEMBER01");
+
+        assert_eq!(dataset.member_name(), None);
+        assert_eq!(member.member_name(), Some("MEMBER01"));
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds/-(PEVTS2)/JIAHJ.REST.PDS(MEMBER01)")
+            .header("X-IBM-Dsname-Encoding", "IBM-1047")
+            .build()
+            .unwrap();
+
+        let read = member.read().get_request().unwrap();
+
+        assert_eq!(manual_request.method(), read.method());
+        assert_eq!(
+            urlencoding::decode(manual_request.url().as_str()).unwrap(),
+            urlencoding::decode(read.url().as_str()).unwrap()
+        );
+        assert_eq!(manual_request.headers(), read.headers());
+
+        let delete = dataset.delete().get_request().unwrap();
+
+        assert_eq!(delete.method(), reqwest::Method::DELETE);
+        assert_eq!(
+            urlencoding::decode(delete.url().as_str()).unwrap(),
+            "https://test.com/zosmf/restfiles/ds/-(PEVTS2)/JIAHJ.REST.PDS"
+        );
+    }
+
+    #[test]
+    fn members() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds/JIAHJ.REST.PDS/member")
+            .header("X-IBM-Dsname-Encoding", "IBM-1047")
+            .build()
+            .unwrap();
+
+        let members = zosmf
+            .datasets()
+            .dataset("JIAHJ.REST.PDS")
+            .dsname_encoding("IBM-1047")
+            .member("MEMBER01")
+            .members()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", members));
+    }
+}
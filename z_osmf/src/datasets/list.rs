@@ -10,13 +10,15 @@ use crate::convert::TryFromResponse;
 use crate::restfiles::get_transaction_id;
 use crate::{ClientCore, Result};
 
-use super::{de_optional_y_n, ser_optional_y_n};
+use super::create::{DatasetOrganization, DatasetRecordFormat, DatasetType};
+use super::{de_optional_y_n, export, ser_optional_y_n};
 
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct DatasetAttributesBase {
     #[serde(rename = "dsname")]
     name: Arc<str>,
-    #[serde(rename = "blksz")]
+    #[getter(skip)]
+    #[serde(default, rename = "blksz", deserialize_with = "de_optional_raw_number")]
     block_size: Option<Arc<str>>,
     #[serde(rename = "catnm")]
     catalog: Option<Arc<str>>,
@@ -25,16 +27,18 @@ pub struct DatasetAttributesBase {
     creation_date: Option<NaiveDate>,
     #[serde(rename = "dev")]
     device_type: Option<Arc<str>>,
-    #[serde(rename = "dsntp")]
-    dataset_type: Option<Arc<str>>,
-    #[serde(rename = "dsorg")]
-    organization: Option<Arc<str>>,
+    #[serde(default, rename = "dsntp")]
+    dataset_type: Option<DatasetType>,
+    #[serde(default, rename = "dsorg")]
+    organization: Option<DatasetOrganization>,
     #[getter(copy)]
     #[serde(default, deserialize_with = "de_optional_date", rename = "edate")]
     expiration_date: Option<NaiveDate>,
-    #[serde(rename = "extx")]
+    #[getter(skip)]
+    #[serde(default, rename = "extx", deserialize_with = "de_optional_raw_number")]
     extents_used: Option<Arc<str>>,
-    #[serde(rename = "lrecl")]
+    #[getter(skip)]
+    #[serde(default, rename = "lrecl", deserialize_with = "de_optional_raw_number")]
     record_length: Option<Arc<str>>,
     #[getter(copy)]
     #[serde(
@@ -62,13 +66,15 @@ pub struct DatasetAttributesBase {
     #[getter(copy)]
     #[serde(default, deserialize_with = "de_optional_date", rename = "rdate")]
     last_referenced_date: Option<NaiveDate>,
-    #[serde(rename = "recfm")]
-    record_format: Option<Arc<str>>,
-    #[serde(rename = "sizex")]
+    #[serde(default, rename = "recfm")]
+    record_format: Option<DatasetRecordFormat>,
+    #[getter(skip)]
+    #[serde(default, rename = "sizex", deserialize_with = "de_optional_raw_number")]
     size_in_tracks: Option<Arc<str>>,
     #[serde(rename = "spacu")]
     space_units: Option<Arc<str>>,
-    #[serde(rename = "used")]
+    #[getter(skip)]
+    #[serde(default, rename = "used", deserialize_with = "de_optional_raw_number")]
     percent_used: Option<Arc<str>>,
     #[serde(rename = "vol")]
     volume: DatasetVolume,
@@ -76,6 +82,53 @@ pub struct DatasetAttributesBase {
     volumes: Option<Arc<str>>,
 }
 
+impl DatasetAttributesBase {
+    pub fn block_size(&self) -> Option<i32> {
+        parse_number(&self.block_size)
+    }
+
+    /// The block size as returned by z/OSMF, including values such as `?` that are not numbers.
+    pub fn raw_block_size(&self) -> Option<&str> {
+        self.block_size.as_deref()
+    }
+
+    pub fn extents_used(&self) -> Option<i32> {
+        parse_number(&self.extents_used)
+    }
+
+    /// The extents used as returned by z/OSMF, including values such as `?` that are not numbers.
+    pub fn raw_extents_used(&self) -> Option<&str> {
+        self.extents_used.as_deref()
+    }
+
+    pub fn record_length(&self) -> Option<i32> {
+        parse_number(&self.record_length)
+    }
+
+    /// The record length as returned by z/OSMF, including values such as `?` that are not numbers.
+    pub fn raw_record_length(&self) -> Option<&str> {
+        self.record_length.as_deref()
+    }
+
+    pub fn size_in_tracks(&self) -> Option<i32> {
+        parse_number(&self.size_in_tracks)
+    }
+
+    /// The size in tracks as returned by z/OSMF, including values such as `?` that are not numbers.
+    pub fn raw_size_in_tracks(&self) -> Option<&str> {
+        self.size_in_tracks.as_deref()
+    }
+
+    pub fn percent_used(&self) -> Option<i32> {
+        parse_number(&self.percent_used)
+    }
+
+    /// The percent used as returned by z/OSMF, including values such as `?` that are not numbers.
+    pub fn raw_percent_used(&self) -> Option<&str> {
+        self.percent_used.as_deref()
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct DatasetAttributesName {
     #[serde(rename = "dsname")]
@@ -130,6 +183,182 @@ where
     }
 }
 
+impl<T> DatasetList<T> {
+    /// A filtered view of the listed datasets.
+    pub fn filter<P>(&self, predicate: P) -> DatasetListFilter<'_, T>
+    where
+        P: Fn(&T) -> bool,
+    {
+        DatasetListFilter {
+            items: self.items.iter().filter(|item| predicate(item)).collect(),
+        }
+    }
+
+    fn view(&self) -> DatasetListFilter<'_, T> {
+        self.filter(|_| true)
+    }
+}
+
+impl<T> DatasetList<T>
+where
+    T: Serialize,
+{
+    /// Serialize the items to CSV with a header row.
+    ///
+    /// `columns` are z/OSMF field names, e.g. `dsname` or `vol`; when empty, every field is included.
+    pub fn to_csv(&self, columns: &[&str]) -> Result<String> {
+        export::to_csv(self.items.iter(), columns)
+    }
+
+    /// Serialize the items to a JSON array, keeping only `columns` unless it is empty.
+    pub fn to_json(&self, columns: &[&str]) -> Result<String> {
+        export::to_json(self.items.iter(), columns)
+    }
+}
+
+impl DatasetList<DatasetAttributesBase> {
+    /// Datasets that have been migrated by HSM.
+    pub fn migrated(&self) -> DatasetListFilter<'_, DatasetAttributesBase> {
+        self.view().migrated()
+    }
+
+    /// Datasets residing on a volume, e.g. `PROD01`.
+    pub fn by_volume<V>(&self, volume: V) -> DatasetListFilter<'_, DatasetAttributesBase>
+    where
+        V: std::fmt::Display,
+    {
+        self.view().by_volume(volume)
+    }
+
+    pub fn by_organization(
+        &self,
+        organization: DatasetOrganization,
+    ) -> DatasetListFilter<'_, DatasetAttributesBase> {
+        self.view().by_organization(organization)
+    }
+
+    /// Datasets with members, both PDS and PDSE.
+    pub fn partitioned(&self) -> DatasetListFilter<'_, DatasetAttributesBase> {
+        self.view().partitioned()
+    }
+}
+
+impl DatasetList<DatasetAttributesVolume> {
+    /// Datasets that have been migrated by HSM.
+    pub fn migrated(&self) -> DatasetListFilter<'_, DatasetAttributesVolume> {
+        self.view().migrated()
+    }
+
+    /// Datasets residing on a volume, e.g. `PROD01`.
+    pub fn by_volume<V>(&self, volume: V) -> DatasetListFilter<'_, DatasetAttributesVolume>
+    where
+        V: std::fmt::Display,
+    {
+        self.view().by_volume(volume)
+    }
+}
+
+/// A subset of a [`DatasetList`], narrowed by chaining filters.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DatasetListFilter<'a, T> {
+    items: Vec<&'a T>,
+}
+
+impl<'a, T> DatasetListFilter<'a, T> {
+    pub fn filter<P>(self, predicate: P) -> Self
+    where
+        P: Fn(&T) -> bool,
+    {
+        DatasetListFilter {
+            items: self
+                .items
+                .into_iter()
+                .filter(|item| predicate(item))
+                .collect(),
+        }
+    }
+
+    pub fn items(&self) -> &[&'a T] {
+        &self.items
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.items.iter().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl<T> DatasetListFilter<'_, T>
+where
+    T: Serialize,
+{
+    /// Serialize the items to CSV with a header row.
+    ///
+    /// `columns` are z/OSMF field names, e.g. `dsname` or `vol`; when empty, every field is included.
+    pub fn to_csv(&self, columns: &[&str]) -> Result<String> {
+        export::to_csv(self.iter(), columns)
+    }
+
+    /// Serialize the items to a JSON array, keeping only `columns` unless it is empty.
+    pub fn to_json(&self, columns: &[&str]) -> Result<String> {
+        export::to_json(self.iter(), columns)
+    }
+}
+
+impl<'a, T> IntoIterator for DatasetListFilter<'a, T> {
+    type Item = &'a T;
+    type IntoIter = std::vec::IntoIter<&'a T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl DatasetListFilter<'_, DatasetAttributesBase> {
+    pub fn migrated(self) -> Self {
+        self.filter(|d| d.migrated() || *d.volume() == DatasetVolume::Migrated)
+    }
+
+    pub fn by_volume<V>(self, volume: V) -> Self
+    where
+        V: std::fmt::Display,
+    {
+        let volume = DatasetVolume::from(volume.to_string().to_uppercase());
+
+        self.filter(|d| *d.volume() == volume)
+    }
+
+    pub fn by_organization(self, organization: DatasetOrganization) -> Self {
+        self.filter(|d| d.organization() == Some(&organization))
+    }
+
+    pub fn partitioned(self) -> Self {
+        self.filter(|d| d.organization().is_some_and(|o| o.is_partitioned()))
+    }
+}
+
+impl DatasetListFilter<'_, DatasetAttributesVolume> {
+    pub fn migrated(self) -> Self {
+        self.filter(|d| *d.volume() == DatasetVolume::Migrated)
+    }
+
+    pub fn by_volume<V>(self, volume: V) -> Self
+    where
+        V: std::fmt::Display,
+    {
+        let volume = DatasetVolume::from(volume.to_string().to_uppercase());
+
+        self.filter(|d| *d.volume() == volume)
+    }
+}
+
 #[derive(Clone, Debug, Endpoint)]
 #[endpoint(method = get, path = "/zosmf/restfiles/ds")]
 pub struct DatasetListBuilder<T>
@@ -150,6 +379,8 @@ where
     attributes: Option<Attrs>,
     #[endpoint(skip_builder)]
     include_total: Option<bool>,
+    #[endpoint(header = "X-IBM-Dsname-Encoding")]
+    dsname_encoding: Option<Arc<str>>,
 
     target_type: PhantomData<T>,
 }
@@ -167,6 +398,7 @@ where
             max_items: self.max_items,
             attributes: Some(Attrs::Base),
             include_total: self.include_total,
+            dsname_encoding: self.dsname_encoding,
             target_type: PhantomData,
         }
     }
@@ -180,6 +412,7 @@ where
             max_items: self.max_items,
             attributes: Some(Attrs::Dsname),
             include_total: self.include_total,
+            dsname_encoding: self.dsname_encoding,
             target_type: PhantomData,
         }
     }
@@ -193,6 +426,7 @@ where
             max_items: self.max_items,
             attributes: Some(Attrs::Vol),
             include_total: self.include_total,
+            dsname_encoding: self.dsname_encoding,
             target_type: PhantomData,
         }
     }
@@ -336,6 +570,31 @@ pub fn de_optional_date<'de, D: Deserializer<'de>>(
     }
 }
 
+/// Numeric attributes are usually returned as strings, with `?` or blanks when unknown.
+fn de_optional_raw_number<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<Arc<str>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Number {
+        Int(i64),
+        Str(String),
+    }
+
+    Ok(match Option::<Number>::deserialize(deserializer)? {
+        Some(Number::Int(value)) => Some(value.to_string().into()),
+        Some(Number::Str(s)) => Some(s.into()),
+        None => None,
+    })
+}
+
+fn parse_number(value: &Option<Arc<str>>) -> Option<i32> {
+    value.as_deref()?.trim().parse().ok()
+}
+
 fn de_optional_yes_no<'de, D>(deserializer: D) -> std::result::Result<Option<bool>, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -388,6 +647,50 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn filters() {
+        let list: DatasetList<DatasetAttributesBase> = serde_json::from_value(serde_json::json!({
+            "items": [
+                {"dsname": "PROD.LOADLIB", "dsorg": "PO-E", "migr": "NO", "vol": "PROD01"},
+                {"dsname": "PROD.DATA", "dsorg": "PS", "migr": "NO", "vol": "PROD01"},
+                {"dsname": "PROD.OLD", "migr": "YES", "vol": "MIGRAT"},
+                {"dsname": "TEST.SRC", "dsorg": "PO", "migr": "NO", "vol": "TEST01"},
+            ],
+            "json_version": 1,
+            "more_rows": null,
+            "returned_rows": 4,
+            "total_rows": null,
+            "transaction_id": "",
+        }))
+        .unwrap();
+
+        let names = |filter: DatasetListFilter<'_, DatasetAttributesBase>| {
+            filter
+                .into_iter()
+                .map(|d| d.name().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(list.migrated()), vec!["PROD.OLD"]);
+        assert_eq!(
+            names(list.by_volume("prod01")),
+            vec!["PROD.LOADLIB", "PROD.DATA"]
+        );
+        assert_eq!(
+            names(
+                list.by_volume("PROD01")
+                    .by_organization(DatasetOrganization::Poe)
+            ),
+            vec!["PROD.LOADLIB"]
+        );
+        assert!(list
+            .by_organization(DatasetOrganization::Po)
+            .by_volume("PROD01")
+            .is_empty());
+        assert_eq!(names(list.partitioned()), vec!["PROD.LOADLIB", "TEST.SRC"]);
+        assert_eq!(list.filter(|d| d.name().starts_with("TEST")).len(), 1);
+    }
+
     #[test]
     fn example_1() {
         let zosmf = get_zosmf();
@@ -439,6 +742,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn max_items_and_total() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds")
+            .query(&[("dslevel", "SYS1.**")])
+            .header("X-IBM-Max-Items", 100)
+            .header("X-IBM-Attributes", "dsname,total")
+            .build()
+            .unwrap();
+
+        let list_datasets_limited = zosmf
+            .datasets()
+            .list("SYS1.**")
+            .max_items(100)
+            .include_total(true)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", list_datasets_limited)
+        );
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds")
+            .query(&[("dslevel", "SYS1.**")])
+            .header("X-IBM-Attributes", "base,total")
+            .build()
+            .unwrap();
+
+        let list_datasets_base = zosmf
+            .datasets()
+            .list("SYS1.**")
+            .include_total(true)
+            .attributes_base()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", list_datasets_base)
+        );
+    }
+
+    #[test]
+    fn more_and_total_rows() {
+        let ResponseJson::<DatasetAttributesName> {
+            returned_rows,
+            more_rows,
+            total_rows,
+            ..
+        } = serde_json::from_str(
+            r#"{"items":[{"dsname":"SYS1.PARMLIB"}],"returnedRows":1,"moreRows":true,"totalRows":42,"JSONversion":1}"#,
+        )
+        .unwrap();
+
+        assert_eq!(returned_rows, 1);
+        assert_eq!(more_rows, Some(true));
+        assert_eq!(total_rows, Some(42));
+    }
+
     #[test]
     fn test_de_optional_yes_no() {
         #[derive(Debug, Deserialize, PartialEq)]
@@ -470,6 +840,65 @@ mod tests {
         assert!(serde_json::from_str::<Test>(r#"{"value": "N"}"#).is_err());
     }
 
+    #[test]
+    fn test_de_optional_raw_number() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Test {
+            #[serde(default, deserialize_with = "de_optional_raw_number")]
+            value: Option<Arc<str>>,
+        }
+
+        let value = |json| serde_json::from_str::<Test>(json).unwrap().value;
+
+        assert_eq!(value(r#"{"value": "6160"}"#).as_deref(), Some("6160"));
+        assert_eq!(value(r#"{"value": 80}"#).as_deref(), Some("80"));
+        assert_eq!(value(r#"{"value": "?"}"#).as_deref(), Some("?"));
+        assert_eq!(value(r#"{}"#), None);
+
+        assert_eq!(parse_number(&value(r#"{"value": " 80 "}"#)), Some(80));
+        assert_eq!(parse_number(&value(r#"{"value": "?"}"#)), None);
+    }
+
+    #[test]
+    fn base_attributes() {
+        let json = serde_json::json!({
+            "dsname": "JIAHJ.REST.TEST.PDS",
+            "blksz": "6160",
+            "cdate": "2023/10/01",
+            "dsorg": "PO-E",
+            "dsntp": "LIBRARY",
+            "extx": "1",
+            "lrecl": "80",
+            "migr": "NO",
+            "recfm": "FB",
+            "sizex": "15",
+            "used": "?",
+            "vol": "PEVTS2",
+        });
+
+        let attributes: DatasetAttributesBase = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(attributes.block_size(), Some(6160));
+        assert_eq!(
+            attributes.creation_date(),
+            NaiveDate::from_ymd_opt(2023, 10, 1)
+        );
+        assert_eq!(attributes.organization(), Some(&DatasetOrganization::Poe));
+        assert_eq!(attributes.dataset_type(), Some(&DatasetType::Library));
+        assert_eq!(attributes.extents_used(), Some(1));
+        assert_eq!(attributes.record_length(), Some(80));
+        assert_eq!(attributes.record_format(), Some(&DatasetRecordFormat::Fb));
+        assert_eq!(attributes.size_in_tracks(), Some(15));
+        assert_eq!(attributes.percent_used(), None);
+        assert_eq!(attributes.raw_percent_used(), Some("?"));
+
+        let serialized = serde_json::to_value(&attributes).unwrap();
+        assert_eq!(serialized["blksz"], json["blksz"]);
+        assert_eq!(serialized["dsorg"], json["dsorg"]);
+        assert_eq!(serialized["recfm"], json["recfm"]);
+        assert_eq!(serialized["used"], json["used"]);
+    }
+
     #[test]
     fn test_de_yes_no() {
         let deserializer: StrDeserializer<serde::de::value::Error> = "YES".into_deserializer();
@@ -512,4 +941,65 @@ mod tests {
         let serialized = String::from_utf8(serializer.into_inner()).unwrap();
         assert_eq!(serialized, r#"null"#);
     }
+
+    #[test]
+    fn dsname_encoding() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds")
+            .query(&[("dslevel", "SYS1.PROCLIB")])
+            .header("X-IBM-Attributes", "base")
+            .header("X-IBM-Dsname-Encoding", "IBM-1047")
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .datasets()
+            .list("SYS1.PROCLIB")
+            .dsname_encoding("IBM-1047")
+            .attributes_base()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
+    #[test]
+    fn export() {
+        let dataset_list: DatasetList<DatasetAttributesBase> =
+            serde_json::from_value(serde_json::json!({
+                "items": [
+                    {"dsname": "PROD.SRC", "dsorg": "PO", "migr": "NO", "vol": "PROD01"},
+                    {"dsname": "PROD.OLD", "migr": "YES", "vol": "MIGRAT"}
+                ],
+                "json_version": 1,
+                "more_rows": null,
+                "returned_rows": 2,
+                "total_rows": null,
+                "transaction_id": "TXID0001"
+            }))
+            .unwrap();
+
+        assert_eq!(
+            dataset_list.to_csv(&["dsname", "dsorg", "vol"]).unwrap(),
+            "dsname,dsorg,vol\r\nPROD.SRC,PO,PROD01\r\nPROD.OLD,,MIGRAT\r\n"
+        );
+        assert_eq!(
+            dataset_list.migrated().to_csv(&["dsname"]).unwrap(),
+            "dsname\r\nPROD.OLD\r\n"
+        );
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(
+                &dataset_list
+                    .migrated()
+                    .to_json(&["dsname", "migr"])
+                    .unwrap()
+            )
+            .unwrap(),
+            serde_json::json!([{"dsname": "PROD.OLD", "migr": "YES"}])
+        );
+    }
 }
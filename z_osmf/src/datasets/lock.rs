@@ -0,0 +1,330 @@
+use std::sync::Arc;
+
+use crate::restfiles::Etag;
+use crate::utils::RecordRange;
+use crate::{ClientCore, Error, Result};
+
+use super::read::{DatasetRead, DatasetReadBuilder};
+use super::write::DatasetWriteBuilder;
+use super::{apply_location, DatasetEnqueue};
+
+#[derive(Clone, Debug)]
+pub struct DatasetLockBuilder {
+    core: ClientCore,
+
+    dataset: Arc<str>,
+    volume: Option<Arc<str>>,
+    member: Option<Arc<str>>,
+    enqueue: DatasetEnqueue,
+    dsname_encoding: Option<Arc<str>>,
+}
+
+impl DatasetLockBuilder {
+    pub(crate) fn new<D>(core: ClientCore, dataset: D) -> Self
+    where
+        D: std::fmt::Display,
+    {
+        DatasetLockBuilder {
+            core,
+            dataset: dataset.to_string().into(),
+            volume: None,
+            member: None,
+            enqueue: DatasetEnqueue::Exclu,
+            dsname_encoding: None,
+        }
+    }
+
+    pub fn volume<V>(self, value: V) -> Self
+    where
+        V: std::fmt::Display,
+    {
+        DatasetLockBuilder {
+            volume: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    pub fn member<M>(self, value: M) -> Self
+    where
+        M: std::fmt::Display,
+    {
+        DatasetLockBuilder {
+            member: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    pub fn enqueue(self, value: DatasetEnqueue) -> Self {
+        DatasetLockBuilder {
+            enqueue: value,
+            ..self
+        }
+    }
+
+    pub fn dsname_encoding<E>(self, value: E) -> Self
+    where
+        E: std::fmt::Display,
+    {
+        DatasetLockBuilder {
+            dsname_encoding: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    pub async fn build(self) -> Result<DatasetLock> {
+        let read = self.obtain_builder().build().await?;
+        let session_ref = read.session_ref().ok_or(Error::NoSessionRef)?.into();
+
+        let DatasetLockBuilder {
+            core,
+            dataset,
+            volume,
+            member,
+            dsname_encoding,
+            ..
+        } = self;
+
+        Ok(DatasetLock {
+            core,
+            dataset,
+            volume,
+            member,
+            dsname_encoding,
+            etag: read.etag().map(|etag| etag.into()),
+            session_ref: Some(session_ref),
+        })
+    }
+
+    fn obtain_builder(&self) -> DatasetReadBuilder<DatasetRead<Arc<str>>> {
+        let builder = DatasetReadBuilder::new(self.core.clone(), &self.dataset)
+            .obtain_enq(self.enqueue)
+            .record_range(RecordRange::StartEnd(Some(0), 0));
+
+        apply_location(
+            builder,
+            &self.volume,
+            &self.member,
+            &self.dsname_encoding,
+            DatasetReadBuilder::volume,
+            DatasetReadBuilder::member,
+            DatasetReadBuilder::dsname_encoding,
+        )
+    }
+}
+
+/// An ENQ held on a dataset through a z/OSMF session.
+///
+/// The ENQ is released by [`DatasetLock::release`], or on a best-effort basis
+/// in the background when the lock is dropped inside a Tokio runtime.
+#[derive(Debug)]
+pub struct DatasetLock {
+    core: ClientCore,
+
+    dataset: Arc<str>,
+    volume: Option<Arc<str>>,
+    member: Option<Arc<str>>,
+    dsname_encoding: Option<Arc<str>>,
+    etag: Option<Arc<str>>,
+    session_ref: Option<Arc<str>>,
+}
+
+impl DatasetLock {
+    pub fn dataset(&self) -> &str {
+        &self.dataset
+    }
+
+    pub fn volume(&self) -> Option<&str> {
+        self.volume.as_deref()
+    }
+
+    pub fn member(&self) -> Option<&str> {
+        self.member.as_deref()
+    }
+
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    pub fn session_ref(&self) -> &str {
+        self.session_ref.as_deref().unwrap_or_default()
+    }
+
+    /// Read the locked dataset within the ENQ session.
+    pub fn read(&self) -> DatasetReadBuilder<DatasetRead<Arc<str>>> {
+        let builder = DatasetReadBuilder::new(self.core.clone(), &self.dataset)
+            .session_ref(self.session_ref());
+
+        apply_location(
+            builder,
+            &self.volume,
+            &self.member,
+            &self.dsname_encoding,
+            DatasetReadBuilder::volume,
+            DatasetReadBuilder::member,
+            DatasetReadBuilder::dsname_encoding,
+        )
+    }
+
+    /// Write to the locked dataset within the ENQ session.
+    pub fn write(&self) -> DatasetWriteBuilder<Etag> {
+        let builder = DatasetWriteBuilder::new(self.core.clone(), &self.dataset)
+            .session_ref(self.session_ref());
+
+        apply_location(
+            builder,
+            &self.volume,
+            &self.member,
+            &self.dsname_encoding,
+            DatasetWriteBuilder::volume,
+            DatasetWriteBuilder::member,
+            DatasetWriteBuilder::dsname_encoding,
+        )
+    }
+
+    /// Release the ENQ, waiting for z/OSMF to confirm.
+    pub async fn release(mut self) -> Result<()> {
+        match self.session_ref.take() {
+            Some(session_ref) => self.release_builder(session_ref).build().await,
+            None => Ok(()),
+        }
+    }
+
+    fn release_builder(&self, session_ref: Arc<str>) -> DatasetReadBuilder<()> {
+        let builder = DatasetReadBuilder::new(self.core.clone(), &self.dataset)
+            .session_ref(session_ref)
+            .release_enq(true)
+            .record_range(RecordRange::StartEnd(Some(0), 0));
+
+        apply_location(
+            builder,
+            &self.volume,
+            &self.member,
+            &self.dsname_encoding,
+            DatasetReadBuilder::volume,
+            DatasetReadBuilder::member,
+            DatasetReadBuilder::dsname_encoding,
+        )
+    }
+}
+
+impl Drop for DatasetLock {
+    fn drop(&mut self) {
+        if let Some(session_ref) = self.session_ref.take() {
+            if let Ok(handle) = tokio::runtime::Handle::try_current() {
+                let builder = self.release_builder(session_ref);
+
+                handle.spawn(async move {
+                    let _ = builder.build().await;
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    fn get_lock() -> DatasetLock {
+        let zosmf = get_zosmf();
+
+        DatasetLock {
+            core: zosmf.core.clone(),
+            dataset: "JIAHJ.REST.SRVMP".into(),
+            volume: None,
+            member: None,
+            dsname_encoding: None,
+            etag: None,
+            session_ref: Some("ABCD1234".into()),
+        }
+    }
+
+    #[test]
+    fn obtain() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds/JIAHJ.REST.SRVMP")
+            .header("X-IBM-Record-Range", "0-0")
+            .header("X-IBM-Obtain-ENQ", "EXCLU")
+            .build()
+            .unwrap();
+
+        let obtain_lock = zosmf
+            .datasets()
+            .lock("JIAHJ.REST.SRVMP")
+            .obtain_builder()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", obtain_lock)
+        );
+    }
+
+    #[test]
+    fn read_and_write() {
+        let zosmf = get_zosmf();
+        let lock = get_lock();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds/JIAHJ.REST.SRVMP")
+            .header("X-IBM-Session-Ref", "ABCD1234")
+            .build()
+            .unwrap();
+
+        let locked_read = lock.read().get_request().unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", locked_read)
+        );
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/ds/JIAHJ.REST.SRVMP")
+            .header("X-IBM-Session-Ref", "ABCD1234")
+            .body("ACTIVE")
+            .build()
+            .unwrap();
+
+        let locked_write = lock.write().text("ACTIVE").get_request().unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", locked_write)
+        );
+    }
+
+    #[test]
+    fn release() {
+        let zosmf = get_zosmf();
+        let mut lock = get_lock();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds/JIAHJ.REST.SRVMP")
+            .header("X-IBM-Record-Range", "0-0")
+            .header("X-IBM-Session-Ref", "ABCD1234")
+            .header("X-IBM-Release-ENQ", "true")
+            .build()
+            .unwrap();
+
+        let session_ref = lock.session_ref.take().unwrap();
+        let release_lock = lock.release_builder(session_ref).get_request().unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", release_lock)
+        );
+    }
+}
@@ -6,9 +6,10 @@ use serde::{Deserialize, Serialize};
 use z_osmf_macros::{Endpoint, Getters};
 
 use crate::convert::TryFromResponse;
-use crate::{ClientCore, Result};
+use crate::{ClientCore, Error, Result};
 
-use super::{de_optional_y_n, ser_optional_y_n, DatasetMigratedRecall};
+use super::list::de_optional_date;
+use super::{de_optional_y_n, export, ser_optional_y_n, DatasetMigratedRecall};
 
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct MemberAttributesBase {
@@ -21,10 +22,10 @@ pub struct MemberAttributesBase {
     #[serde(default, rename = "mod")]
     modification_level: Option<i32>,
     #[getter(copy)]
-    #[serde(default, rename = "c4date")]
+    #[serde(default, deserialize_with = "de_optional_date", rename = "c4date")]
     creation_date: Option<NaiveDate>,
     #[getter(copy)]
-    #[serde(default, rename = "m4date")]
+    #[serde(default, deserialize_with = "de_optional_date", rename = "m4date")]
     modification_date: Option<NaiveDate>,
     #[getter(copy)]
     #[serde(default, rename = "cnorc")]
@@ -84,6 +85,23 @@ pub struct MemberList<T> {
     total_rows: Option<i32>,
 }
 
+impl<T> MemberList<T>
+where
+    T: Serialize,
+{
+    /// Serialize the items to CSV with a header row.
+    ///
+    /// `columns` are z/OSMF field names, e.g. `member` or `user`; when empty, every field is included.
+    pub fn to_csv(&self, columns: &[&str]) -> Result<String> {
+        export::to_csv(self.items.iter(), columns)
+    }
+
+    /// Serialize the items to a JSON array, keeping only `columns` unless it is empty.
+    pub fn to_json(&self, columns: &[&str]) -> Result<String> {
+        export::to_json(self.items.iter(), columns)
+    }
+}
+
 impl<T> TryFromResponse for MemberList<T>
 where
     T: for<'de> Deserialize<'de>,
@@ -129,6 +147,8 @@ where
     include_total: Option<bool>,
     #[endpoint(header = "X-IBM-Migrated-Recall")]
     migrated_recall: Option<DatasetMigratedRecall>,
+    #[endpoint(header = "X-IBM-Dsname-Encoding")]
+    dsname_encoding: Option<Arc<str>>,
 
     target_type: PhantomData<T>,
 }
@@ -147,6 +167,7 @@ where
             attributes: Some(Attrs::Base),
             include_total: self.include_total,
             migrated_recall: self.migrated_recall,
+            dsname_encoding: self.dsname_encoding,
             target_type: PhantomData,
         }
     }
@@ -161,6 +182,7 @@ where
             attributes: Some(Attrs::Member),
             include_total: self.include_total,
             migrated_recall: self.migrated_recall,
+            dsname_encoding: self.dsname_encoding,
             target_type: PhantomData,
         }
     }
@@ -226,10 +248,138 @@ where
     }
 }
 
+/// The members named with `members`, or else the members of `dataset` matching `pattern`.
+pub(crate) async fn resolve_members(
+    core: &ClientCore,
+    dataset: &str,
+    members: &Option<Arc<[Arc<str>]>>,
+    pattern: &Option<Arc<str>>,
+) -> Result<Arc<[Arc<str>]>> {
+    match (members, pattern) {
+        (Some(members), _) => Ok(members.clone()),
+        (None, Some(pattern)) => {
+            let member_list: MemberList<MemberAttributesName> =
+                MemberListBuilder::new(core.clone(), dataset)
+                    .pattern(pattern)
+                    .max_items(0)
+                    .build()
+                    .await?;
+
+            Ok(member_list
+                .items()
+                .iter()
+                .map(|m| m.name().into())
+                .collect())
+        }
+        (None, None) => Err(Error::InvalidValue(
+            "either members or a member pattern is required".to_string(),
+        )),
+    }
+}
+
+/// The result of copying or deleting one member.
+#[derive(Debug)]
+pub struct MemberOutcome {
+    member: Arc<str>,
+    to_member: Option<Arc<str>>,
+    result: Result<Arc<str>>,
+}
+
+impl MemberOutcome {
+    pub(crate) fn new(
+        member: Arc<str>,
+        to_member: Option<Arc<str>>,
+        result: Result<Arc<str>>,
+    ) -> Self {
+        MemberOutcome {
+            member,
+            to_member,
+            result,
+        }
+    }
+
+    pub fn member(&self) -> &str {
+        &self.member
+    }
+
+    /// The name of the member in the target dataset, when copying.
+    pub fn to_member(&self) -> Option<&str> {
+        self.to_member.as_deref()
+    }
+
+    /// The transaction id of the request, or the error that caused it to fail.
+    pub fn result(&self) -> std::result::Result<&str, &Error> {
+        self.result.as_deref()
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+/// The results of copying or deleting several members, in the order they were requested.
+#[derive(Debug)]
+pub struct MemberReport {
+    items: Vec<MemberOutcome>,
+}
+
+impl MemberReport {
+    pub(crate) fn new(items: Vec<MemberOutcome>) -> Self {
+        MemberReport { items }
+    }
+
+    pub fn items(&self) -> &[MemberOutcome] {
+        &self.items
+    }
+
+    pub fn succeeded(&self) -> impl Iterator<Item = &str> {
+        self.items
+            .iter()
+            .filter(|item| item.is_success())
+            .map(|item| item.member())
+    }
+
+    pub fn failed(&self) -> impl Iterator<Item = (&str, &Error)> {
+        self.items
+            .iter()
+            .filter_map(|item| item.result().err().map(|err| (item.member(), err)))
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.items.iter().all(|item| item.is_success())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::tests::*;
 
+    use super::*;
+
+    #[test]
+    fn report() {
+        let report = MemberReport::new(vec![
+            MemberOutcome::new(
+                "PAYROLL".into(),
+                Some("PAYROLL2".into()),
+                Ok("TXID0001".into()),
+            ),
+            MemberOutcome::new(
+                "BILLING".into(),
+                Some("BILLING".into()),
+                Err(Error::NoTransactionId),
+            ),
+        ]);
+
+        assert_eq!(report.succeeded().collect::<Vec<_>>(), vec!["PAYROLL"]);
+        assert_eq!(report.items()[0].to_member(), Some("PAYROLL2"));
+        assert_eq!(
+            report.failed().map(|(m, _)| m).collect::<Vec<_>>(),
+            vec!["BILLING"]
+        );
+        assert!(!report.is_success());
+    }
+
     #[test]
     fn example_1() {
         let zosmf = get_zosmf();
@@ -277,4 +427,28 @@ mod tests {
             format!("{:?}", list_members_base)
         );
     }
+
+    #[test]
+    fn dsname_encoding() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds/NOTSYS1.PROCLIB/member")
+            .header("X-IBM-Attributes", "base")
+            .header("X-IBM-Dsname-Encoding", "IBM-1047")
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .datasets()
+            .members("NOTSYS1.PROCLIB")
+            .dsname_encoding("IBM-1047")
+            .attributes_base()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
 }
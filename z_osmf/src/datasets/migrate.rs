@@ -23,6 +23,8 @@ where
     member: Option<Arc<str>>,
     #[endpoint(builder_fn = build_body )]
     wait: Option<bool>,
+    #[endpoint(header = "X-IBM-Dsname-Encoding")]
+    dsname_encoding: Option<Arc<str>>,
 
     target_type: PhantomData<T>,
 }
@@ -0,0 +1,145 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::utils::Backoff;
+use crate::{ClientCore, Error, Result};
+
+use super::list::{DatasetAttributesBase, DatasetAttributesName, DatasetList, DatasetListBuilder};
+
+#[derive(Clone, Debug)]
+pub struct DatasetMigrationWaitBuilder {
+    core: ClientCore,
+
+    dataset: Arc<str>,
+    migrated: bool,
+    interval: Duration,
+    max_interval: Duration,
+    timeout: Duration,
+}
+
+impl DatasetMigrationWaitBuilder {
+    pub(crate) fn new<D>(core: ClientCore, dataset: D, migrated: bool) -> Self
+    where
+        D: std::fmt::Display,
+    {
+        DatasetMigrationWaitBuilder {
+            core,
+            dataset: dataset.to_string().into(),
+            migrated,
+            interval: Duration::from_secs(2),
+            max_interval: Duration::from_secs(30),
+            timeout: Duration::from_secs(600),
+        }
+    }
+
+    /// The delay before the first re-check, doubled after each attempt.
+    pub fn interval(self, value: Duration) -> Self {
+        DatasetMigrationWaitBuilder {
+            interval: value,
+            ..self
+        }
+    }
+
+    /// The upper bound for the delay between checks.
+    pub fn max_interval(self, value: Duration) -> Self {
+        DatasetMigrationWaitBuilder {
+            max_interval: value,
+            ..self
+        }
+    }
+
+    /// How long to wait before giving up with [`Error::Timeout`].
+    pub fn timeout(self, value: Duration) -> Self {
+        DatasetMigrationWaitBuilder {
+            timeout: value,
+            ..self
+        }
+    }
+
+    /// Wait for the dataset to reach the requested state, returning the elapsed time.
+    pub async fn build(self) -> Result<Duration> {
+        let backoff = Backoff::new(self.interval, self.max_interval, self.timeout);
+
+        let ((), elapsed) = backoff
+            .poll(|| async {
+                let dataset_list = self.list_builder().build().await?;
+
+                is_done(&dataset_list, &self.dataset, self.migrated).map(|done| done.then_some(()))
+            })
+            .await?;
+
+        Ok(elapsed)
+    }
+
+    fn list_builder(&self) -> DatasetListBuilder<DatasetList<DatasetAttributesBase>> {
+        DatasetListBuilder::<DatasetList<DatasetAttributesName>>::new(
+            self.core.clone(),
+            &self.dataset,
+        )
+        .attributes_base()
+    }
+}
+
+fn is_done(
+    dataset_list: &DatasetList<DatasetAttributesBase>,
+    dataset: &str,
+    migrated: bool,
+) -> Result<bool> {
+    dataset_list
+        .items()
+        .iter()
+        .find(|attributes| attributes.name().eq_ignore_ascii_case(dataset))
+        .map(|attributes| attributes.migrated() == migrated)
+        .ok_or_else(|| Error::InvalidValue(format!("dataset not found: {}", dataset)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn list_request() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds")
+            .query(&[("dslevel", "MY.MIGR.DS")])
+            .header("X-IBM-Attributes", "base")
+            .build()
+            .unwrap();
+
+        let wait_for_recall = zosmf
+            .datasets()
+            .wait_for_recall("MY.MIGR.DS")
+            .list_builder()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", wait_for_recall)
+        );
+    }
+
+    #[test]
+    fn migrated_state() {
+        let dataset_list: DatasetList<DatasetAttributesBase> =
+            serde_json::from_value(serde_json::json!({
+                "items": [{"dsname": "MY.MIGR.DS", "migr": "YES", "vol": "MIGRAT"}],
+                "json_version": 1,
+                "more_rows": null,
+                "returned_rows": 1,
+                "total_rows": null,
+                "transaction_id": "TXID0001"
+            }))
+            .unwrap();
+
+        assert!(is_done(&dataset_list, "MY.MIGR.DS", true).unwrap());
+        assert!(!is_done(&dataset_list, "MY.MIGR.DS", false).unwrap());
+        assert!(is_done(&dataset_list, "MY.OTHER.DS", true).is_err());
+    }
+}
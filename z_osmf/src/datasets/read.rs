@@ -1,6 +1,7 @@
 pub use crate::utils::RecordRange;
 
 use std::marker::PhantomData;
+use std::num::NonZeroU32;
 use std::sync::Arc;
 
 use bytes::Bytes;
@@ -9,12 +10,13 @@ use serde::{Deserialize, Serialize};
 use z_osmf_macros::{Endpoint, Getters};
 
 use crate::convert::TryFromResponse;
-use crate::restfiles::{get_etag, get_transaction_id};
+use crate::encoding::Codepage;
+use crate::restfiles::{get_etag, get_session_ref, get_transaction_id};
+use crate::utils::count_records;
 use crate::{ClientCore, Result};
 
-use super::{
-    get_member, get_session_ref, get_volume, DatasetDataType, DatasetEnqueue, DatasetMigratedRecall,
-};
+use super::record::split_records;
+use super::{get_member, get_volume, DatasetDataType, DatasetEnqueue, DatasetMigratedRecall};
 
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct DatasetRead<T> {
@@ -29,6 +31,14 @@ impl DatasetRead<Arc<str>> {
     pub fn data(&self) -> &str {
         &self.data
     }
+
+    /// The records of a text read, one line each.
+    ///
+    /// z/OSMF terminates every record with a newline, so a trailing newline
+    /// does not produce an extra empty record.
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        self.data.lines()
+    }
 }
 
 impl TryFromResponse for DatasetRead<Arc<str>> {
@@ -50,6 +60,21 @@ impl DatasetRead<Bytes> {
     pub fn data(&self) -> &Bytes {
         &self.data
     }
+
+    /// The records of a record mode read, without their length prefixes.
+    pub fn records(&self) -> Result<Vec<&[u8]>> {
+        split_records(&self.data)
+    }
+
+    /// The data of a binary read, converted from an EBCDIC code page.
+    pub fn decode(&self, codepage: Codepage) -> String {
+        codepage.decode(&self.data)
+    }
+
+    /// The records of a record mode read, converted from an EBCDIC code page.
+    pub fn decode_records(&self, codepage: Codepage) -> Result<Vec<String>> {
+        Ok(codepage.decode_records(&self.records()?))
+    }
 }
 
 impl TryFromResponse for DatasetRead<Bytes> {
@@ -71,6 +96,11 @@ impl DatasetRead<Option<Arc<str>>> {
     pub fn data(&self) -> Option<&str> {
         self.data.as_deref()
     }
+
+    /// The records of a text read, or `None` if the data was not modified.
+    pub fn lines(&self) -> Option<std::str::Lines<'_>> {
+        self.data.as_deref().map(str::lines)
+    }
 }
 
 impl TryFromResponse for DatasetRead<Option<Arc<str>>> {
@@ -96,6 +126,24 @@ impl DatasetRead<Option<Bytes>> {
     pub fn data(&self) -> Option<&Bytes> {
         self.data.as_ref()
     }
+
+    /// The records of a record mode read, or `None` if the data was not modified.
+    pub fn records(&self) -> Option<Result<Vec<&[u8]>>> {
+        self.data.as_deref().map(split_records)
+    }
+
+    /// The data of a binary read converted from an EBCDIC code page,
+    /// or `None` if the data was not modified.
+    pub fn decode(&self, codepage: Codepage) -> Option<String> {
+        self.data.as_deref().map(|data| codepage.decode(data))
+    }
+
+    /// The records of a record mode read converted from an EBCDIC code page,
+    /// or `None` if the data was not modified.
+    pub fn decode_records(&self, codepage: Codepage) -> Option<Result<Vec<String>>> {
+        self.records()
+            .map(|records| Ok(codepage.decode_records(&records?)))
+    }
 }
 
 impl TryFromResponse for DatasetRead<Option<Bytes>> {
@@ -165,6 +213,63 @@ where
     target_type: PhantomData<T>,
 }
 
+impl<T> DatasetReadBuilder<T>
+where
+    T: TryFromResponse,
+{
+    /// Read only the first `count` records.
+    pub fn first_records(self, count: NonZeroU32) -> Self {
+        DatasetReadBuilder {
+            record_range: Some(RecordRange::StartCount(0, count)),
+            ..self
+        }
+    }
+
+    /// Read only the last `count` records.
+    ///
+    /// The total record count is found by probing for single records first,
+    /// which takes a number of requests logarithmic in the size of the dataset.
+    pub async fn last_records(self, count: NonZeroU32) -> Result<Self> {
+        let total = count_records(|record| self.has_record(record)).await?;
+
+        Ok(DatasetReadBuilder {
+            record_range: Some(RecordRange::last(count, total.into())),
+            ..self
+        })
+    }
+
+    async fn has_record(&self, record: u32) -> Result<bool> {
+        let read = self.probe_builder(record).build().await?;
+
+        Ok(!read.data().is_empty())
+    }
+
+    fn probe_builder(&self, record: u32) -> DatasetReadBuilder<DatasetRead<Bytes>> {
+        DatasetReadBuilder {
+            core: self.core.clone(),
+            dataset: self.dataset.clone(),
+            volume: self.volume.clone(),
+            member: self.member.clone(),
+            search: None,
+            regex_search: None,
+            search_is_regex: None,
+            search_case_sensitive: None,
+            search_max_return: None,
+            if_none_match: None,
+            data_type: Some(DatasetDataType::Record),
+            encoding: None,
+            return_etag: None,
+            migrated_recall: self.migrated_recall,
+            record_range: Some(RecordRange::StartCount(record, NonZeroU32::MIN)),
+            obtain_enq: None,
+            session_ref: self.session_ref.clone(),
+            release_enq: None,
+            dsname_encoding: self.dsname_encoding.clone(),
+            target_type: PhantomData,
+        }
+    }
+}
+
 impl<U> DatasetReadBuilder<DatasetRead<U>>
 where
     DatasetRead<U>: TryFromResponse,
@@ -448,6 +553,8 @@ where
 mod tests {
     use crate::tests::*;
 
+    use super::*;
+
     #[test]
     fn example_1() {
         let zosmf = get_zosmf();
@@ -494,4 +601,137 @@ mod tests {
             format!("{:?}", read_dataset)
         )
     }
+
+    #[test]
+    fn return_etag() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds/JIAHJ.REST.BIGDS")
+            .header("X-IBM-Return-Etag", "true")
+            .build()
+            .unwrap();
+
+        let read_large_dataset = zosmf
+            .datasets()
+            .read("JIAHJ.REST.BIGDS")
+            .return_etag(true)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", read_large_dataset)
+        );
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds/JIAHJ.REST.BIGDS")
+            .build()
+            .unwrap();
+
+        let read_dataset = zosmf
+            .datasets()
+            .read("JIAHJ.REST.BIGDS")
+            .return_etag(false)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", read_dataset)
+        );
+    }
+
+    #[test]
+    fn lines() {
+        let read = DatasetRead {
+            data: Arc::<str>::from("LINE 1\n\nLINE 3\n"),
+            etag: None,
+            session_ref: None,
+            transaction_id: "".into(),
+        };
+
+        assert_eq!(
+            read.lines().collect::<Vec<_>>(),
+            vec!["LINE 1", "", "LINE 3"]
+        );
+
+        let empty = DatasetRead {
+            data: Some(Arc::<str>::from("")),
+            etag: None,
+            session_ref: None,
+            transaction_id: "".into(),
+        };
+
+        assert_eq!(empty.lines().unwrap().count(), 0);
+    }
+
+    #[test]
+    fn records() {
+        let read = DatasetRead {
+            data: Bytes::from_static(&[0, 0, 0, 2, 0xC1, 0xC2, 0, 0, 0, 0, 0, 0, 0, 1, 0xC3]),
+            etag: None,
+            session_ref: None,
+            transaction_id: "".into(),
+        };
+
+        assert_eq!(
+            read.records().unwrap(),
+            vec![&[0xC1, 0xC2][..], &[][..], &[0xC3][..]]
+        );
+        assert_eq!(
+            read.decode_records(Codepage::Ibm1047).unwrap(),
+            vec!["AB", "", "C"]
+        );
+    }
+
+    #[test]
+    fn first_records() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds/JIAHJ.REST.SRVMP")
+            .header("X-IBM-Record-Range", "0,10")
+            .build()
+            .unwrap();
+
+        let read_head = zosmf
+            .datasets()
+            .read("JIAHJ.REST.SRVMP")
+            .first_records(NonZeroU32::new(10).unwrap())
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", read_head));
+    }
+
+    #[test]
+    fn probe_record() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds/JIAHJ.REST.SRVMP")
+            .header("X-IBM-Data-Type", "record")
+            .header("X-IBM-Record-Range", "512,1")
+            .build()
+            .unwrap();
+
+        let probe = zosmf
+            .datasets()
+            .read("JIAHJ.REST.SRVMP")
+            .search("ERROR")
+            .probe_builder(512)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", probe));
+    }
 }
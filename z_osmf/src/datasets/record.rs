@@ -0,0 +1,843 @@
+use std::sync::Arc;
+
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::encoding::Codepage;
+use crate::{Error, Result};
+
+/// How a field is stored within a record.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum FieldType {
+    /// EBCDIC characters, e.g. `PIC X(n)`. Trailing spaces are trimmed when decoding.
+    Text,
+    /// Zoned decimal, e.g. `PIC S9(5)V99`, with the sign held in the zone of the last byte.
+    Zoned { signed: bool, scale: u32 },
+    /// Packed decimal, e.g. `PIC S9(7)V99 COMP-3`.
+    Packed { signed: bool, scale: u32 },
+    /// Big-endian binary integer, e.g. `PIC S9(9) COMP`.
+    Binary { signed: bool, scale: u32 },
+    /// Bytes passed through unchanged.
+    Bytes,
+}
+
+impl FieldType {
+    /// Decode the bytes of a field.
+    pub fn decode(self, data: &[u8], codepage: Codepage) -> Result<FieldValue> {
+        let value = match self {
+            FieldType::Text => FieldValue::Text(codepage.decode(data).trim_end_matches(' ').into()),
+            FieldType::Zoned { signed, scale } => {
+                FieldValue::Number(Decimal::new(decode_zoned(data, signed)?, scale))
+            }
+            FieldType::Packed { signed, scale } => {
+                FieldValue::Number(Decimal::new(decode_packed(data, signed)?, scale))
+            }
+            FieldType::Binary { signed, scale } => {
+                FieldValue::Number(Decimal::new(decode_binary(data, signed)?, scale))
+            }
+            FieldType::Bytes => FieldValue::Bytes(Bytes::copy_from_slice(data)),
+        };
+
+        Ok(value)
+    }
+
+    /// Encode a value as a field of `length` bytes.
+    pub fn encode(self, value: &FieldValue, length: usize, codepage: Codepage) -> Result<Vec<u8>> {
+        match (self, value) {
+            (FieldType::Text, FieldValue::Text(text)) => {
+                let mut data = codepage.encode(text)?;
+                if data.len() > length {
+                    return Err(Error::InvalidValue(format!(
+                        "text {:?} is longer than {} bytes",
+                        text, length
+                    )));
+                }
+                // EBCDIC space
+                data.resize(length, 0x40);
+
+                Ok(data)
+            }
+            (FieldType::Bytes, FieldValue::Bytes(bytes)) if bytes.len() == length => {
+                Ok(bytes.to_vec())
+            }
+            (
+                FieldType::Zoned { signed, scale }
+                | FieldType::Packed { signed, scale }
+                | FieldType::Binary { signed, scale },
+                FieldValue::Number(number),
+            ) => {
+                let value = number.rescale(scale).ok_or_else(|| {
+                    Error::InvalidValue(format!("{} does not fit {} decimal places", number, scale))
+                })?;
+
+                if value < 0 && !signed {
+                    return Err(Error::InvalidValue(format!(
+                        "{} is negative but the field is unsigned",
+                        number
+                    )));
+                }
+
+                match self {
+                    FieldType::Zoned { .. } => encode_zoned(value, length, signed),
+                    FieldType::Packed { .. } => encode_packed(value, length, signed),
+                    _ => encode_binary(value, length, signed),
+                }
+                .ok_or_else(|| {
+                    Error::InvalidValue(format!("{} does not fit {} bytes", number, length))
+                })
+            }
+            (field_type, value) => Err(Error::InvalidValue(format!(
+                "{:?} cannot be stored in a {:?} field of {} bytes",
+                value, field_type, length
+            ))),
+        }
+    }
+}
+
+/// A fixed-point decimal number, `value * 10^-scale`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Decimal {
+    value: i128,
+    scale: u32,
+}
+
+impl Decimal {
+    pub fn new(value: i128, scale: u32) -> Self {
+        Decimal { value, scale }
+    }
+
+    /// The unscaled value.
+    pub fn value(&self) -> i128 {
+        self.value
+    }
+
+    /// The number of implied decimal places.
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.value as f64 / 10f64.powi(self.scale as i32)
+    }
+
+    fn rescale(&self, scale: u32) -> Option<i128> {
+        if scale >= self.scale {
+            self.value
+                .checked_mul(10i128.checked_pow(scale - self.scale)?)
+        } else {
+            let divisor = 10i128.checked_pow(self.scale - scale)?;
+
+            (self.value % divisor == 0).then_some(self.value / divisor)
+        }
+    }
+}
+
+impl From<i64> for Decimal {
+    fn from(value: i64) -> Self {
+        Decimal::new(value.into(), 0)
+    }
+}
+
+impl From<i32> for Decimal {
+    fn from(value: i32) -> Self {
+        Decimal::new(value.into(), 0)
+    }
+}
+
+impl std::fmt::Display for Decimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.scale == 0 {
+            return write!(f, "{}", self.value);
+        }
+
+        let digits = format!(
+            "{:0>width$}",
+            self.value.unsigned_abs(),
+            width = self.scale as usize + 1
+        );
+        let (whole, fraction) = digits.split_at(digits.len() - self.scale as usize);
+        let sign = if self.value < 0 { "-" } else { "" };
+
+        write!(f, "{}{}.{}", sign, whole, fraction)
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum FieldValue {
+    Text(Arc<str>),
+    Number(Decimal),
+    Bytes(Bytes),
+}
+
+impl FieldValue {
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            FieldValue::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn as_number(&self) -> Option<Decimal> {
+        match self {
+            FieldValue::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&Bytes> {
+        match self {
+            FieldValue::Bytes(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldValue::Text(text) => write!(f, "{}", text),
+            FieldValue::Number(number) => write!(f, "{}", number),
+            FieldValue::Bytes(bytes) => bytes.iter().try_for_each(|b| write!(f, "{:02X}", b)),
+        }
+    }
+}
+
+impl From<&str> for FieldValue {
+    fn from(value: &str) -> Self {
+        FieldValue::Text(value.into())
+    }
+}
+
+impl From<String> for FieldValue {
+    fn from(value: String) -> Self {
+        FieldValue::Text(value.into())
+    }
+}
+
+impl From<Decimal> for FieldValue {
+    fn from(value: Decimal) -> Self {
+        FieldValue::Number(value)
+    }
+}
+
+impl From<i64> for FieldValue {
+    fn from(value: i64) -> Self {
+        FieldValue::Number(value.into())
+    }
+}
+
+impl From<i32> for FieldValue {
+    fn from(value: i32) -> Self {
+        FieldValue::Number(value.into())
+    }
+}
+
+impl From<Bytes> for FieldValue {
+    fn from(value: Bytes) -> Self {
+        FieldValue::Bytes(value)
+    }
+}
+
+/// The named field values of a decoded record, in layout order.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Record {
+    items: Vec<(Arc<str>, FieldValue)>,
+}
+
+impl Record {
+    pub fn new() -> Self {
+        Record::default()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&FieldValue> {
+        self.items
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+
+    /// Set the value of a field, replacing any existing value.
+    pub fn set<N, V>(&mut self, name: N, value: V)
+    where
+        N: std::fmt::Display,
+        V: Into<FieldValue>,
+    {
+        let name = name.to_string();
+        let value = value.into();
+
+        match self
+            .items
+            .iter_mut()
+            .find(|(n, _)| n.eq_ignore_ascii_case(&name))
+        {
+            Some((_, v)) => *v = value,
+            None => self.items.push((name.into(), value)),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &FieldValue)> {
+        self.items
+            .iter()
+            .map(|(name, value)| (name.as_ref(), value))
+    }
+}
+
+impl<N, V> FromIterator<(N, V)> for Record
+where
+    N: std::fmt::Display,
+    V: Into<FieldValue>,
+{
+    fn from_iter<I: IntoIterator<Item = (N, V)>>(iter: I) -> Self {
+        let mut record = Record::new();
+        for (name, value) in iter {
+            record.set(name, value);
+        }
+
+        record
+    }
+}
+
+/// A fixed-width record layout built from field positions.
+///
+/// # Example
+/// ```
+/// # use z_osmf::datasets::record::{FieldType, RecordLayout};
+/// # use z_osmf::encoding::Codepage;
+/// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+/// let layout = RecordLayout::new()
+///     .codepage(Codepage::Ibm037)
+///     .field("ACCOUNT", 0, 10, FieldType::Text)?
+///     .field("BALANCE", 10, 5, FieldType::Packed { signed: true, scale: 2 })?
+///     .field("BRANCH", 15, 2, FieldType::Binary { signed: false, scale: 0 })?;
+///
+/// let read_records = zosmf
+///     .datasets()
+///     .read("PROD.ACCOUNT.EXTRACT")
+///     .record()
+///     .build()
+///     .await?;
+///
+/// for record in layout.records(read_records.data()) {
+///     let record = record?;
+///     println!("{:?} {:?}", record.get("ACCOUNT"), record.get("BALANCE"));
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct RecordLayout {
+    fields: Vec<LayoutField>,
+    codepage: Codepage,
+    record_length: Option<usize>,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LayoutField {
+    name: Arc<str>,
+    offset: usize,
+    length: usize,
+    field_type: FieldType,
+    codepage: Codepage,
+}
+
+impl LayoutField {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The 0-based byte offset of the field within the record.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    pub fn field_type(&self) -> FieldType {
+        self.field_type
+    }
+
+    pub fn codepage(&self) -> Codepage {
+        self.codepage
+    }
+}
+
+impl RecordLayout {
+    pub fn new() -> Self {
+        RecordLayout::default()
+    }
+
+    /// The code page of text in the fields added after this call, `IBM-1047` by default.
+    pub fn codepage(self, value: Codepage) -> Self {
+        RecordLayout {
+            codepage: value,
+            ..self
+        }
+    }
+
+    /// Add a field of `length` bytes starting at the 0-based byte `offset`.
+    ///
+    /// Fails with [`Error::InvalidValue`] if the field is empty, or if a numeric field
+    /// can hold more digits than an `i128` (38 decimal digits or 16 bytes of binary).
+    pub fn field<N>(
+        mut self,
+        name: N,
+        offset: usize,
+        length: usize,
+        field_type: FieldType,
+    ) -> Result<Self>
+    where
+        N: std::fmt::Display,
+    {
+        let max_length = match field_type {
+            FieldType::Zoned { .. } => MAX_DIGITS,
+            FieldType::Packed { .. } => MAX_DIGITS.div_ceil(2),
+            FieldType::Binary { .. } => 16,
+            _ => usize::MAX,
+        };
+        if length == 0 || length > max_length {
+            return Err(Error::InvalidValue(format!(
+                "invalid length {} for field {}",
+                length, name
+            )));
+        }
+
+        self.fields.push(LayoutField {
+            name: name.to_string().into(),
+            offset,
+            length,
+            field_type,
+            codepage: self.codepage,
+        });
+
+        Ok(self)
+    }
+
+    /// The length of encoded records, by default the end of the last field.
+    pub fn record_length(self, value: usize) -> Self {
+        RecordLayout {
+            record_length: Some(value),
+            ..self
+        }
+    }
+
+    pub fn fields(&self) -> &[LayoutField] {
+        &self.fields
+    }
+
+    pub fn len(&self) -> usize {
+        self.record_length.unwrap_or_else(|| {
+            self.fields
+                .iter()
+                .map(|field| field.offset + field.length)
+                .max()
+                .unwrap_or_default()
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Decode a single record.
+    pub fn decode(&self, record: &[u8]) -> Result<Record> {
+        self.fields
+            .iter()
+            .map(|field| {
+                let data = record
+                    .get(field.offset..field.offset + field.length)
+                    .ok_or_else(|| {
+                        Error::InvalidValue(format!(
+                            "record of {} bytes is too short for field {}",
+                            record.len(),
+                            field.name
+                        ))
+                    })?;
+
+                Ok((
+                    field.name.clone(),
+                    field.field_type.decode(data, field.codepage)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Decode the records in the body of a record mode read, one at a time.
+    pub fn records<'a>(&'a self, data: &'a [u8]) -> impl Iterator<Item = Result<Record>> + 'a {
+        iter_records(data).map(|record| self.decode(record?))
+    }
+
+    /// Encode a single record, filling fields missing from `record` with EBCDIC spaces.
+    pub fn encode(&self, record: &Record) -> Result<Vec<u8>> {
+        let record_length = self.len();
+        let mut data = vec![0x40; record_length];
+
+        for field in self.fields.iter() {
+            if let Some(value) = record.get(&field.name) {
+                let bytes = field
+                    .field_type
+                    .encode(value, field.length, field.codepage)
+                    .map_err(|err| Error::InvalidValue(format!("field {}: {}", field.name, err)))?;
+
+                data.get_mut(field.offset..field.offset + field.length)
+                    .ok_or_else(|| {
+                        Error::InvalidValue(format!(
+                            "field {} ends past the record length {}",
+                            field.name, record_length
+                        ))
+                    })?
+                    .copy_from_slice(&bytes);
+            }
+        }
+
+        Ok(data)
+    }
+
+    /// Encode records as the body of a record mode write.
+    pub fn encode_records<'a, I>(&self, records: I) -> Result<Bytes>
+    where
+        I: IntoIterator<Item = &'a Record>,
+    {
+        let records = records
+            .into_iter()
+            .map(|record| self.encode(record))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(join_records(records))
+    }
+}
+
+/// Split the body of a record mode read into records, each prefixed by a 4 byte big-endian length.
+pub fn split_records(data: &[u8]) -> Result<Vec<&[u8]>> {
+    iter_records(data).collect()
+}
+
+fn iter_records(data: &[u8]) -> impl Iterator<Item = Result<&[u8]>> {
+    let mut rest = data;
+
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        let Some((prefix, tail)) = rest.split_first_chunk::<4>() else {
+            rest = &[];
+            return Some(Err(Error::InvalidValue(
+                "truncated record length prefix".to_string(),
+            )));
+        };
+        let length = u32::from_be_bytes(*prefix) as usize;
+
+        if tail.len() < length {
+            let remaining = tail.len();
+            rest = &[];
+            return Some(Err(Error::InvalidValue(format!(
+                "record length {} exceeds the {} bytes remaining",
+                length, remaining
+            ))));
+        }
+
+        let (record, tail) = tail.split_at(length);
+        rest = tail;
+
+        Some(Ok(record))
+    })
+}
+
+/// Join records into the body of a record mode write.
+pub fn join_records<I, R>(records: I) -> Bytes
+where
+    I: IntoIterator<Item = R>,
+    R: AsRef<[u8]>,
+{
+    let mut data = BytesMut::new();
+    for record in records {
+        let record = record.as_ref();
+        data.put_u32(record.len() as u32);
+        data.put_slice(record);
+    }
+
+    data.freeze()
+}
+
+/// The most decimal digits that always fit in an `i128`.
+const MAX_DIGITS: usize = 38;
+
+fn decode_zoned(data: &[u8], signed: bool) -> Result<i128> {
+    let invalid = || Error::InvalidValue(format!("invalid zoned decimal: {:02X?}", data));
+
+    let mut value: i128 = 0;
+    for b in data {
+        let digit = b & 0x0F;
+        if digit > 9 {
+            return Err(invalid());
+        }
+        value = push_digit(value, digit).ok_or_else(invalid)?;
+    }
+
+    let negative = signed && data.last().is_some_and(|b| matches!(b >> 4, 0xB | 0xD));
+
+    Ok(if negative { -value } else { value })
+}
+
+fn encode_zoned(value: i128, length: usize, signed: bool) -> Option<Vec<u8>> {
+    let digits = format!("{:0>width$}", value.unsigned_abs(), width = length);
+    if digits.len() > length {
+        return None;
+    }
+
+    let mut data: Vec<u8> = digits.bytes().map(|d| 0xF0 | (d - b'0')).collect();
+    if signed {
+        let zone = if value < 0 { 0xD0 } else { 0xC0 };
+        if let Some(last) = data.last_mut() {
+            *last = zone | (*last & 0x0F);
+        }
+    }
+
+    Some(data)
+}
+
+fn decode_packed(data: &[u8], signed: bool) -> Result<i128> {
+    let invalid = || Error::InvalidValue(format!("invalid packed decimal: {:02X?}", data));
+
+    let (last, digits) = data.split_last().ok_or_else(invalid)?;
+
+    let mut value: i128 = 0;
+    for nibble in digits
+        .iter()
+        .flat_map(|b| [b >> 4, b & 0x0F])
+        .chain([last >> 4])
+    {
+        if nibble > 9 {
+            return Err(invalid());
+        }
+        value = push_digit(value, nibble).ok_or_else(invalid)?;
+    }
+
+    match last & 0x0F {
+        0xB | 0xD if signed => Ok(-value),
+        0xA..=0xF => Ok(value),
+        _ => Err(invalid()),
+    }
+}
+
+fn push_digit(value: i128, digit: u8) -> Option<i128> {
+    value.checked_mul(10)?.checked_add(i128::from(digit))
+}
+
+fn encode_packed(value: i128, length: usize, signed: bool) -> Option<Vec<u8>> {
+    if length == 0 {
+        return None;
+    }
+
+    let width = length * 2 - 1;
+    let digits = format!("{:0>width$}", value.unsigned_abs(), width = width);
+    if digits.len() > width {
+        return None;
+    }
+
+    let sign = match (signed, value < 0) {
+        (false, _) => 0x0F,
+        (true, false) => 0x0C,
+        (true, true) => 0x0D,
+    };
+
+    let nibbles: Vec<u8> = digits.bytes().map(|d| d - b'0').chain([sign]).collect();
+
+    Some(
+        nibbles
+            .chunks(2)
+            .map(|pair| (pair[0] << 4) | pair[1])
+            .collect(),
+    )
+}
+
+fn decode_binary(data: &[u8], signed: bool) -> Result<i128> {
+    if data.is_empty() || data.len() > 16 {
+        return Err(Error::InvalidValue(format!(
+            "unsupported binary field length: {}",
+            data.len()
+        )));
+    }
+
+    let fill = if signed && data[0] & 0x80 != 0 {
+        0xFF
+    } else {
+        0x00
+    };
+    let mut bytes = [fill; 16];
+    bytes[16 - data.len()..].copy_from_slice(data);
+
+    Ok(i128::from_be_bytes(bytes))
+}
+
+fn encode_binary(value: i128, length: usize, signed: bool) -> Option<Vec<u8>> {
+    if length == 0 || length > 16 {
+        return None;
+    }
+
+    let bits = length as u32 * 8;
+    let fits = match (signed, bits) {
+        (_, 128) => true,
+        (true, _) => value >= -(1i128 << (bits - 1)) && value < (1i128 << (bits - 1)),
+        (false, _) => value < (1i128 << bits),
+    };
+
+    fits.then(|| value.to_be_bytes()[16 - length..].to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decimal_display() {
+        assert_eq!(Decimal::new(12345, 2).to_string(), "123.45");
+        assert_eq!(Decimal::new(-5, 3).to_string(), "-0.005");
+        assert_eq!(Decimal::new(42, 0).to_string(), "42");
+    }
+
+    #[test]
+    fn zoned() {
+        let field_type = FieldType::Zoned {
+            signed: true,
+            scale: 2,
+        };
+
+        let value = field_type
+            .decode(&[0xF1, 0xF2, 0xD3], Codepage::Ibm037)
+            .unwrap();
+        assert_eq!(value, FieldValue::Number(Decimal::new(-123, 2)));
+        assert_eq!(
+            field_type.encode(&value, 3, Codepage::Ibm037).unwrap(),
+            vec![0xF1, 0xF2, 0xD3]
+        );
+        assert!(field_type
+            .encode(&FieldValue::from(1000), 3, Codepage::Ibm037)
+            .is_err());
+        assert!(field_type.decode(&[0xF9; 40], Codepage::Ibm037).is_err());
+    }
+
+    #[test]
+    fn packed() {
+        let field_type = FieldType::Packed {
+            signed: true,
+            scale: 2,
+        };
+
+        let value = field_type
+            .decode(&[0x01, 0x23, 0x4C], Codepage::Ibm037)
+            .unwrap();
+        assert_eq!(value, FieldValue::Number(Decimal::new(1234, 2)));
+
+        let negative = FieldValue::Number(Decimal::new(-1234, 2));
+        assert_eq!(
+            field_type.encode(&negative, 3, Codepage::Ibm037).unwrap(),
+            vec![0x01, 0x23, 0x4D]
+        );
+        assert!(field_type
+            .decode(&[0x01, 0x2A, 0x4C], Codepage::Ibm037)
+            .is_err());
+        assert!(field_type.encode(&negative, 0, Codepage::Ibm037).is_err());
+
+        let mut overflow = vec![0x99; 20];
+        overflow.push(0x9C);
+        assert!(field_type.decode(&overflow, Codepage::Ibm037).is_err());
+    }
+
+    #[test]
+    fn binary() {
+        let field_type = FieldType::Binary {
+            signed: true,
+            scale: 0,
+        };
+
+        let value = field_type.decode(&[0xFF, 0xFE], Codepage::Ibm037).unwrap();
+        assert_eq!(value, FieldValue::from(-2));
+        assert_eq!(
+            field_type
+                .encode(&FieldValue::from(300), 2, Codepage::Ibm037)
+                .unwrap(),
+            vec![0x01, 0x2C]
+        );
+        assert!(field_type
+            .encode(&FieldValue::from(40000), 2, Codepage::Ibm037)
+            .is_err());
+    }
+
+    #[test]
+    fn text() {
+        let value = FieldType::Text
+            .decode(&[0xC1, 0xC2, 0x40, 0x40], Codepage::Ibm037)
+            .unwrap();
+        assert_eq!(value.as_text(), Some("AB"));
+        assert_eq!(
+            FieldType::Text.encode(&value, 4, Codepage::Ibm037).unwrap(),
+            vec![0xC1, 0xC2, 0x40, 0x40]
+        );
+    }
+
+    #[test]
+    fn records() {
+        let data = join_records([&b"ABC"[..], &b""[..], &b"DE"[..]]);
+        assert_eq!(
+            split_records(&data).unwrap(),
+            vec![&b"ABC"[..], &b""[..], &b"DE"[..]]
+        );
+        assert!(split_records(&data[..data.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn layout() {
+        let layout = RecordLayout::new()
+            .codepage(Codepage::Ibm037)
+            .field("NAME", 0, 4, FieldType::Text)
+            .unwrap()
+            .codepage(Codepage::Ibm1047)
+            .field("CODE", 4, 1, FieldType::Text)
+            .unwrap()
+            .field(
+                "AMOUNT",
+                5,
+                2,
+                FieldType::Packed {
+                    signed: true,
+                    scale: 1,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(layout.len(), 7);
+
+        let record: Record = [
+            ("NAME", FieldValue::from("AB")),
+            ("CODE", FieldValue::from("[")),
+            ("AMOUNT", FieldValue::from(Decimal::new(-125, 1))),
+        ]
+        .into_iter()
+        .collect();
+
+        let encoded = layout.encode(&record).unwrap();
+        assert_eq!(encoded, vec![0xC1, 0xC2, 0x40, 0x40, 0xAD, 0x12, 0x5D]);
+
+        let data = layout.encode_records([&record, &record]).unwrap();
+        let records: Vec<_> = layout.records(&data).collect::<Result<_>>().unwrap();
+        assert_eq!(records, vec![record.clone(), record]);
+
+        assert!(layout
+            .records(&data[..data.len() - 1])
+            .nth(1)
+            .unwrap()
+            .is_err());
+
+        let packed = FieldType::Packed {
+            signed: false,
+            scale: 0,
+        };
+        assert!(RecordLayout::new()
+            .field("EMPTY", 0, 0, FieldType::Text)
+            .is_err());
+        assert!(RecordLayout::new().field("WIDE", 0, 19, packed).is_ok());
+        assert!(RecordLayout::new().field("WIDE", 0, 20, packed).is_err());
+    }
+}
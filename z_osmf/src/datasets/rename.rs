@@ -27,6 +27,8 @@ where
     to_member: Option<Arc<str>>,
     #[endpoint(skip_builder)]
     enqueue: Option<DatasetEnqueue>,
+    #[endpoint(header = "X-IBM-Dsname-Encoding")]
+    dsname_encoding: Option<Arc<str>>,
 
     target_type: PhantomData<T>,
 }
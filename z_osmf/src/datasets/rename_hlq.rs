@@ -0,0 +1,298 @@
+use std::sync::Arc;
+
+use futures::StreamExt;
+
+use crate::utils::is_valid_qualifier;
+use crate::{ClientCore, Error, Result};
+
+use super::list::{DatasetAttributesName, DatasetList, DatasetListBuilder};
+use super::rename::DatasetRenameBuilder;
+
+/// The maximum length of a fully qualified dataset name.
+const MAX_DATASET_NAME_LENGTH: usize = 44;
+
+#[derive(Clone, Debug)]
+pub struct DatasetRenameHlqBuilder {
+    core: ClientCore,
+
+    pattern: Arc<str>,
+    to_hlq: Arc<str>,
+    dry_run: bool,
+    concurrency: usize,
+}
+
+impl DatasetRenameHlqBuilder {
+    pub(crate) fn new<P, H>(core: ClientCore, pattern: P, to_hlq: H) -> Self
+    where
+        P: std::fmt::Display,
+        H: std::fmt::Display,
+    {
+        DatasetRenameHlqBuilder {
+            core,
+            pattern: pattern.to_string().into(),
+            to_hlq: to_hlq.to_string().to_uppercase().into(),
+            dry_run: false,
+            concurrency: 4,
+        }
+    }
+
+    /// List the datasets and plan their new names without renaming anything.
+    pub fn dry_run(self, value: bool) -> Self {
+        DatasetRenameHlqBuilder {
+            dry_run: value,
+            ..self
+        }
+    }
+
+    /// The maximum number of rename requests in flight at once.
+    pub fn concurrency(self, value: usize) -> Self {
+        DatasetRenameHlqBuilder {
+            concurrency: value.max(1),
+            ..self
+        }
+    }
+
+    pub async fn build(self) -> Result<DatasetRenameReport> {
+        validate_qualifier(&self.to_hlq)?;
+
+        let dataset_list = self.list_builder().build().await?;
+        let datasets: Vec<Arc<str>> = dataset_list
+            .items()
+            .iter()
+            .map(|d| d.name().into())
+            .collect();
+
+        let items = futures::stream::iter(datasets)
+            .map(|from_dataset| {
+                let to_dataset = self.to_dataset(&from_dataset);
+                let builder = match (&to_dataset, self.dry_run) {
+                    (Ok(to_dataset), false) => Some(self.rename_builder(&from_dataset, to_dataset)),
+                    _ => None,
+                };
+
+                async move {
+                    let (to_dataset, result) = match (to_dataset, builder) {
+                        (Err(err), _) => (None, Err(err)),
+                        (Ok(to_dataset), None) => (Some(to_dataset), Ok(None)),
+                        (Ok(to_dataset), Some(builder)) => (
+                            Some(to_dataset),
+                            builder.build().await.map(|txid| Some(txid.into())),
+                        ),
+                    };
+
+                    DatasetRenameOutcome {
+                        from_dataset,
+                        to_dataset,
+                        result,
+                    }
+                }
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+
+        Ok(DatasetRenameReport {
+            items,
+            dry_run: self.dry_run,
+        })
+    }
+
+    fn list_builder(&self) -> DatasetListBuilder<DatasetList<DatasetAttributesName>> {
+        DatasetListBuilder::new(self.core.clone(), &self.pattern).max_items(0)
+    }
+
+    fn rename_builder(&self, from_dataset: &str, to_dataset: &str) -> DatasetRenameBuilder<String> {
+        DatasetRenameBuilder::new(self.core.clone(), from_dataset, to_dataset)
+    }
+
+    fn to_dataset(&self, from_dataset: &str) -> Result<Arc<str>> {
+        let to_dataset = match from_dataset.split_once('.') {
+            Some((_, rest)) => format!("{}.{}", self.to_hlq, rest),
+            None => self.to_hlq.to_string(),
+        };
+
+        if to_dataset.len() > MAX_DATASET_NAME_LENGTH {
+            return Err(Error::InvalidValue(format!(
+                "{} exceeds {} characters",
+                to_dataset, MAX_DATASET_NAME_LENGTH
+            )));
+        }
+
+        Ok(to_dataset.into())
+    }
+}
+
+fn validate_qualifier(qualifier: &str) -> Result<()> {
+    if is_valid_qualifier(qualifier) {
+        Ok(())
+    } else {
+        Err(Error::InvalidValue(format!(
+            "invalid high-level qualifier: {}",
+            qualifier
+        )))
+    }
+}
+
+#[derive(Debug)]
+pub struct DatasetRenameOutcome {
+    from_dataset: Arc<str>,
+    to_dataset: Option<Arc<str>>,
+    result: Result<Option<Arc<str>>>,
+}
+
+impl DatasetRenameOutcome {
+    pub fn from_dataset(&self) -> &str {
+        &self.from_dataset
+    }
+
+    /// The new name of the dataset, if one could be formed.
+    pub fn to_dataset(&self) -> Option<&str> {
+        self.to_dataset.as_deref()
+    }
+
+    /// The transaction id of the rename request, `None` in dry-run mode,
+    /// or the error that caused it to fail.
+    pub fn result(&self) -> std::result::Result<Option<&str>, &Error> {
+        self.result.as_ref().map(|txid| txid.as_deref())
+    }
+
+    pub fn is_renamed(&self) -> bool {
+        matches!(self.result, Ok(Some(_)))
+    }
+}
+
+#[derive(Debug)]
+pub struct DatasetRenameReport {
+    items: Vec<DatasetRenameOutcome>,
+    dry_run: bool,
+}
+
+impl DatasetRenameReport {
+    pub fn items(&self) -> &[DatasetRenameOutcome] {
+        &self.items
+    }
+
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// The old and new names of the datasets that were, or in dry-run mode would be, renamed.
+    pub fn planned(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.items.iter().filter_map(|item| {
+            item.result
+                .is_ok()
+                .then(|| item.to_dataset())
+                .flatten()
+                .map(|to_dataset| (item.from_dataset(), to_dataset))
+        })
+    }
+
+    /// The new names of the datasets that were renamed.
+    pub fn renamed(&self) -> impl Iterator<Item = &str> {
+        self.items
+            .iter()
+            .filter(|item| item.is_renamed())
+            .filter_map(|item| item.to_dataset())
+    }
+
+    pub fn failed(&self) -> impl Iterator<Item = (&str, &Error)> {
+        self.items
+            .iter()
+            .filter_map(|item| item.result().err().map(|err| (item.from_dataset(), err)))
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.items.iter().all(|item| item.result.is_ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn rename_request() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/ds/NEWHLQ.PAYROLL.DATA")
+            .json(&serde_json::json!({
+                "request": "rename",
+                "from-dataset": {
+                    "dsn": "OLDHLQ.PAYROLL.DATA",
+                },
+            }))
+            .build()
+            .unwrap();
+
+        let rename_hlq = zosmf.datasets().rename_hlq("OLDHLQ.**", "newhlq");
+        let to_dataset = rename_hlq.to_dataset("OLDHLQ.PAYROLL.DATA").unwrap();
+        let request = rename_hlq
+            .rename_builder("OLDHLQ.PAYROLL.DATA", &to_dataset)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+
+        let list_request = rename_hlq.list_builder().get_request().unwrap();
+
+        assert_eq!(
+            urlencoding::decode(list_request.url().as_str()).unwrap(),
+            "https://test.com/zosmf/restfiles/ds?dslevel=OLDHLQ.**"
+        );
+    }
+
+    #[test]
+    fn target_names() {
+        let zosmf = get_zosmf();
+        let rename_hlq = zosmf.datasets().rename_hlq("A.**", "LONGHLQ1");
+
+        assert_eq!(
+            rename_hlq.to_dataset("A.B.C").unwrap().as_ref(),
+            "LONGHLQ1.B.C"
+        );
+        assert!(rename_hlq
+            .to_dataset("A.QUALIF01.QUALIF02.QUALIF03.QUALIF04.QUALIF05")
+            .is_err());
+
+        assert!(validate_qualifier("NEWHLQ").is_ok());
+        assert!(validate_qualifier("TOOLONGHLQ").is_err());
+        assert!(validate_qualifier("1HLQ").is_err());
+        assert!(validate_qualifier("NEW.HLQ").is_err());
+    }
+
+    #[test]
+    fn report() {
+        let report = DatasetRenameReport {
+            items: vec![
+                DatasetRenameOutcome {
+                    from_dataset: "OLD.SRC".into(),
+                    to_dataset: Some("NEW.SRC".into()),
+                    result: Ok(Some("TXID0001".into())),
+                },
+                DatasetRenameOutcome {
+                    from_dataset: "OLD.LOAD".into(),
+                    to_dataset: Some("NEW.LOAD".into()),
+                    result: Err(Error::NoTransactionId),
+                },
+            ],
+            dry_run: false,
+        };
+
+        assert_eq!(report.renamed().collect::<Vec<_>>(), vec!["NEW.SRC"]);
+        assert_eq!(
+            report.planned().collect::<Vec<_>>(),
+            vec![("OLD.SRC", "NEW.SRC")]
+        );
+        assert_eq!(
+            report.failed().map(|(d, _)| d).collect::<Vec<_>>(),
+            vec!["OLD.LOAD"]
+        );
+        assert!(!report.is_success());
+    }
+}
@@ -0,0 +1,274 @@
+use std::sync::Arc;
+
+use futures::StreamExt;
+
+use crate::{ClientCore, Error, Result};
+
+use super::members::{MemberAttributesName, MemberList, MemberListBuilder};
+use super::read::{DatasetRead, DatasetReadBuilder};
+
+#[derive(Clone, Debug)]
+pub struct DatasetSearchBuilder {
+    core: ClientCore,
+
+    dataset: Arc<str>,
+    pattern: Arc<str>,
+    regex: bool,
+    case_sensitive: bool,
+    max_return: Option<i32>,
+    member_pattern: Option<Arc<str>>,
+    concurrency: usize,
+}
+
+impl DatasetSearchBuilder {
+    pub(crate) fn new<D, P>(core: ClientCore, dataset: D, pattern: P) -> Self
+    where
+        D: std::fmt::Display,
+        P: std::fmt::Display,
+    {
+        DatasetSearchBuilder {
+            core,
+            dataset: dataset.to_string().into(),
+            pattern: pattern.to_string().into(),
+            regex: false,
+            case_sensitive: false,
+            max_return: None,
+            member_pattern: None,
+            concurrency: 4,
+        }
+    }
+
+    /// Treat the search pattern as a regular expression.
+    pub fn regex(self, value: bool) -> Self {
+        DatasetSearchBuilder {
+            regex: value,
+            ..self
+        }
+    }
+
+    pub fn case_sensitive(self, value: bool) -> Self {
+        DatasetSearchBuilder {
+            case_sensitive: value,
+            ..self
+        }
+    }
+
+    /// The maximum number of records returned for each member.
+    pub fn max_return(self, value: i32) -> Self {
+        DatasetSearchBuilder {
+            max_return: Some(value),
+            ..self
+        }
+    }
+
+    /// Only search the members matching a member name pattern, e.g. `IEF*`.
+    pub fn member_pattern<P>(self, value: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        DatasetSearchBuilder {
+            member_pattern: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// The maximum number of member searches in flight at once.
+    pub fn concurrency(self, value: usize) -> Self {
+        DatasetSearchBuilder {
+            concurrency: value.max(1),
+            ..self
+        }
+    }
+
+    /// Search each member, recording members that could not be read in
+    /// [`DatasetSearch::failed`] rather than ending the search.
+    pub async fn build(self) -> Result<DatasetSearch> {
+        let member_list = self.list_builder().build().await?;
+
+        let results: Vec<_> = futures::stream::iter(member_list.items().iter())
+            .map(|member| {
+                let builder = self.read_builder(member.name());
+
+                async move { (Arc::<str>::from(member.name()), builder.build().await) }
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+
+        let mut search = DatasetSearch {
+            items: Vec::new(),
+            failed: Vec::new(),
+        };
+        for (member, result) in results {
+            match result {
+                Ok(read) if read.data().is_empty() => {}
+                Ok(read) => search.items.push(DatasetSearchMatch {
+                    member,
+                    data: read.data().into(),
+                }),
+                Err(err) => search.failed.push((member, err)),
+            }
+        }
+
+        Ok(search)
+    }
+
+    fn list_builder(&self) -> MemberListBuilder<MemberList<MemberAttributesName>> {
+        let builder = MemberListBuilder::new(self.core.clone(), &self.dataset).max_items(0);
+
+        match &self.member_pattern {
+            Some(pattern) => builder.pattern(pattern),
+            None => builder,
+        }
+    }
+
+    fn read_builder(&self, member: &str) -> DatasetReadBuilder<DatasetRead<Arc<str>>> {
+        let builder = DatasetReadBuilder::new(self.core.clone(), &self.dataset)
+            .member(member)
+            .search_case_sensitive(self.case_sensitive);
+
+        let builder = if self.regex {
+            builder.regex_search(&self.pattern)
+        } else {
+            builder.search(&self.pattern)
+        };
+
+        match self.max_return {
+            Some(max_return) => builder.search_max_return(max_return),
+            None => builder,
+        }
+    }
+}
+
+/// The members of a partitioned dataset containing a search pattern.
+#[derive(Debug)]
+pub struct DatasetSearch {
+    items: Vec<DatasetSearchMatch>,
+    failed: Vec<(Arc<str>, Error)>,
+}
+
+impl DatasetSearch {
+    pub fn items(&self) -> &[DatasetSearchMatch] {
+        &self.items
+    }
+
+    pub fn members(&self) -> impl Iterator<Item = &str> {
+        self.items.iter().map(|item| item.member())
+    }
+
+    /// The members that could not be searched, with the error for each.
+    pub fn failed(&self) -> impl Iterator<Item = (&str, &Error)> {
+        self.failed
+            .iter()
+            .map(|(member, err)| (member.as_ref(), err))
+    }
+
+    /// Whether every member was searched.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DatasetSearchMatch {
+    member: Arc<str>,
+    data: Arc<str>,
+}
+
+impl DatasetSearchMatch {
+    pub fn member(&self) -> &str {
+        &self.member
+    }
+
+    /// The records returned by z/OSMF, starting with the first record that matched.
+    pub fn data(&self) -> &str {
+        &self.data
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.data.lines()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn failed_members() {
+        let search = DatasetSearch {
+            items: vec![DatasetSearchMatch {
+                member: "IEFPROC".into(),
+                data: "//STEPLIB DD DSN=SYS1.LINKLIB,DISP=SHR".into(),
+            }],
+            failed: vec![("IEFBR14".into(), Error::NoEtag)],
+        };
+
+        assert_eq!(search.members().collect::<Vec<_>>(), vec!["IEFPROC"]);
+        assert_eq!(
+            search.failed().map(|(m, _)| m).collect::<Vec<_>>(),
+            vec!["IEFBR14"]
+        );
+        assert!(!search.is_complete());
+    }
+
+    #[test]
+    fn list_request() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds/SYS1.PROCLIB/member")
+            .query(&[("pattern", "IEF*")])
+            .header("X-IBM-Max-Items", 0)
+            .build()
+            .unwrap();
+
+        let search_list = zosmf
+            .datasets()
+            .search("SYS1.PROCLIB", "SYS1.LINKLIB")
+            .member_pattern("IEF*")
+            .list_builder()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", search_list)
+        );
+    }
+
+    #[test]
+    fn read_request() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds/SYS1.PROCLIB(IEFPROC)")
+            .query(&[("research", "SYS1\\.LINK.*")])
+            .query(&[("insensitive", "false")])
+            .query(&[("maxreturnsize", "1")])
+            .build()
+            .unwrap();
+
+        let search_member = zosmf
+            .datasets()
+            .search("SYS1.PROCLIB", "SYS1\\.LINK.*")
+            .regex(true)
+            .case_sensitive(true)
+            .max_return(1)
+            .read_builder("IEFPROC")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(manual_request.method(), search_member.method());
+        assert_eq!(
+            urlencoding::decode(manual_request.url().as_str()).unwrap(),
+            urlencoding::decode(search_member.url().as_str()).unwrap()
+        );
+    }
+}
@@ -0,0 +1,266 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::Getters;
+
+use crate::{ClientCore, Result};
+
+use super::list::{DatasetAttributesBase, DatasetAttributesName, DatasetList, DatasetListBuilder};
+
+/// The number of tracks in a 3390 cylinder.
+const TRACKS_PER_CYLINDER: u64 = 15;
+
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+pub enum DatasetSpaceGrouping {
+    /// Group datasets by their high-level qualifier.
+    #[default]
+    Hlq,
+    /// Group datasets by the volume they reside on; migrated datasets are grouped under `MIGRAT`.
+    Volume,
+}
+
+#[derive(Clone, Debug)]
+pub struct DatasetSpaceBuilder {
+    core: ClientCore,
+
+    level: Arc<str>,
+    volume: Option<Arc<str>>,
+    grouping: DatasetSpaceGrouping,
+}
+
+impl DatasetSpaceBuilder {
+    pub(crate) fn new<L>(core: ClientCore, level: L) -> Self
+    where
+        L: std::fmt::Display,
+    {
+        DatasetSpaceBuilder {
+            core,
+            level: level.to_string().into(),
+            volume: None,
+            grouping: DatasetSpaceGrouping::default(),
+        }
+    }
+
+    /// Only report on datasets residing on a volume.
+    pub fn volume<V>(self, value: V) -> Self
+    where
+        V: std::fmt::Display,
+    {
+        DatasetSpaceBuilder {
+            volume: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    pub fn group_by(self, value: DatasetSpaceGrouping) -> Self {
+        DatasetSpaceBuilder {
+            grouping: value,
+            ..self
+        }
+    }
+
+    pub async fn build(self) -> Result<DatasetSpace> {
+        let list = self.list_builder().build().await?;
+
+        Ok(DatasetSpace::from_attributes(list.items(), self.grouping))
+    }
+
+    fn list_builder(&self) -> DatasetListBuilder<DatasetList<DatasetAttributesBase>> {
+        let builder = DatasetListBuilder::<DatasetList<DatasetAttributesName>>::new(
+            self.core.clone(),
+            &self.level,
+        )
+        .attributes_base()
+        .max_items(0);
+
+        match &self.volume {
+            Some(volume) => builder.volume(volume),
+            None => builder,
+        }
+    }
+}
+
+/// Space usage aggregated across the datasets matching a pattern.
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, PartialEq, Serialize)]
+pub struct DatasetSpace {
+    groups: Arc<[DatasetSpaceSummary]>,
+    total: DatasetSpaceSummary,
+}
+
+impl DatasetSpace {
+    /// Aggregate base attributes that have already been listed, without contacting z/OSMF.
+    pub fn from_attributes(
+        datasets: &[DatasetAttributesBase],
+        grouping: DatasetSpaceGrouping,
+    ) -> Self {
+        let mut groups: BTreeMap<String, DatasetSpaceSummary> = BTreeMap::new();
+        let mut total = DatasetSpaceSummary::new("*");
+
+        for dataset in datasets {
+            let key = match grouping {
+                DatasetSpaceGrouping::Hlq => dataset
+                    .name()
+                    .split('.')
+                    .next()
+                    .unwrap_or_default()
+                    .to_string(),
+                DatasetSpaceGrouping::Volume => dataset.volume().to_string(),
+            };
+
+            groups
+                .entry(key)
+                .or_insert_with_key(|key| DatasetSpaceSummary::new(key))
+                .add(dataset);
+            total.add(dataset);
+        }
+
+        DatasetSpace {
+            groups: groups.into_values().collect(),
+            total,
+        }
+    }
+
+    /// The summary for a high-level qualifier or volume.
+    pub fn group(&self, key: &str) -> Option<&DatasetSpaceSummary> {
+        self.groups
+            .iter()
+            .find(|group| group.key.eq_ignore_ascii_case(key))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, PartialEq, Serialize)]
+pub struct DatasetSpaceSummary {
+    key: Arc<str>,
+    #[getter(copy)]
+    datasets: u64,
+    #[getter(copy)]
+    migrated: u64,
+    #[getter(copy)]
+    extents: u64,
+    #[getter(copy)]
+    tracks: u64,
+    #[getter(copy)]
+    used_tracks: u64,
+}
+
+impl DatasetSpaceSummary {
+    fn new(key: &str) -> Self {
+        DatasetSpaceSummary {
+            key: key.into(),
+            datasets: 0,
+            migrated: 0,
+            extents: 0,
+            tracks: 0,
+            used_tracks: 0,
+        }
+    }
+
+    fn add(&mut self, dataset: &DatasetAttributesBase) {
+        self.datasets += 1;
+
+        if dataset.migrated() {
+            self.migrated += 1;
+            return;
+        }
+
+        let tracks = to_count(dataset.size_in_tracks());
+        let percent_used = to_count(dataset.percent_used());
+
+        self.extents += to_count(dataset.extents_used()).unwrap_or(0);
+        self.tracks += tracks.unwrap_or(0);
+        if let (Some(tracks), Some(percent_used)) = (tracks, percent_used) {
+            self.used_tracks += tracks * percent_used.min(100) / 100;
+        }
+    }
+
+    /// The allocated space in 3390 cylinders.
+    pub fn cylinders(&self) -> f64 {
+        self.tracks as f64 / TRACKS_PER_CYLINDER as f64
+    }
+
+    /// The used space in 3390 cylinders.
+    pub fn used_cylinders(&self) -> f64 {
+        self.used_tracks as f64 / TRACKS_PER_CYLINDER as f64
+    }
+
+    /// The percentage of allocated tracks in use, if any tracks are allocated.
+    pub fn percent_used(&self) -> Option<f64> {
+        (self.tracks > 0).then(|| self.used_tracks as f64 * 100.0 / self.tracks as f64)
+    }
+}
+
+fn to_count(value: Option<i32>) -> Option<u64> {
+    u64::try_from(value?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn list_request() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds")
+            .query(&[("dslevel", "PROD.**"), ("volser", "PROD01")])
+            .header("X-IBM-Max-Items", "0")
+            .header("X-IBM-Attributes", "base")
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .datasets()
+            .space("PROD.**")
+            .volume("PROD01")
+            .list_builder()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
+    #[test]
+    fn aggregate() {
+        let datasets: Vec<DatasetAttributesBase> = serde_json::from_value(serde_json::json!([
+            {"dsname": "PROD.LOADLIB", "extx": "2", "migr": "NO", "sizex": "30", "used": "50", "vol": "PROD01"},
+            {"dsname": "PROD.DATA", "extx": "1", "migr": "NO", "sizex": "15", "used": "100", "vol": "PROD02"},
+            {"dsname": "PROD.OLD", "migr": "YES", "vol": "MIGRAT"},
+            {"dsname": "TEST.SRC", "extx": "1", "migr": "NO", "sizex": "45", "used": "?", "vol": "PROD01"},
+        ]))
+        .unwrap();
+
+        let by_hlq = DatasetSpace::from_attributes(&datasets, DatasetSpaceGrouping::Hlq);
+
+        assert_eq!(by_hlq.groups().len(), 2);
+        let prod = by_hlq.group("prod").unwrap();
+        assert_eq!(prod.datasets(), 3);
+        assert_eq!(prod.migrated(), 1);
+        assert_eq!(prod.extents(), 3);
+        assert_eq!(prod.tracks(), 45);
+        assert_eq!(prod.used_tracks(), 30);
+        assert_eq!(prod.cylinders(), 3.0);
+        assert_eq!(by_hlq.total().tracks(), 90);
+        assert_eq!(by_hlq.total().used_tracks(), 30);
+
+        let by_volume = DatasetSpace::from_attributes(&datasets, DatasetSpaceGrouping::Volume);
+
+        assert_eq!(
+            by_volume
+                .groups()
+                .iter()
+                .map(|g| g.key())
+                .collect::<Vec<_>>(),
+            vec!["MIGRAT", "PROD01", "PROD02"]
+        );
+        assert_eq!(by_volume.group("PROD01").unwrap().tracks(), 75);
+        assert_eq!(by_volume.group("MIGRAT").unwrap().percent_used(), None);
+    }
+}
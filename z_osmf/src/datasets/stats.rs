@@ -0,0 +1,147 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::Serialize;
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = put, path = "/zosmf/restfiles/ds/{dataset}({member})")]
+pub struct DatasetStatsBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    dataset: Arc<str>,
+    #[endpoint(path)]
+    member: Arc<str>,
+    #[endpoint(builder_fn = build_body)]
+    user: Option<Arc<str>>,
+    #[endpoint(skip_builder)]
+    version: Option<i32>,
+    #[endpoint(skip_builder)]
+    modification_level: Option<i32>,
+    #[endpoint(skip_builder)]
+    creation_date: Option<NaiveDate>,
+    #[endpoint(skip_builder)]
+    modification_date: Option<NaiveDateTime>,
+    #[endpoint(skip_builder)]
+    current_number_of_records: Option<i32>,
+    #[endpoint(skip_builder)]
+    initial_number_of_records: Option<i32>,
+    #[endpoint(skip_builder)]
+    modified_number_of_records: Option<i32>,
+    #[endpoint(header = "X-IBM-Dsname-Encoding")]
+    dsname_encoding: Option<Arc<str>>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Serialize)]
+struct RequestJson<'a> {
+    request: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<&'a str>,
+    #[serde(rename = "vers", skip_serializing_if = "Option::is_none")]
+    version: Option<i32>,
+    #[serde(rename = "mod", skip_serializing_if = "Option::is_none")]
+    modification_level: Option<i32>,
+    #[serde(rename = "cdate", skip_serializing_if = "Option::is_none")]
+    creation_date: Option<String>,
+    #[serde(rename = "mdate", skip_serializing_if = "Option::is_none")]
+    modification_date: Option<String>,
+    #[serde(rename = "mtime", skip_serializing_if = "Option::is_none")]
+    modification_time: Option<String>,
+    #[serde(rename = "cnorc", skip_serializing_if = "Option::is_none")]
+    current_number_of_records: Option<i32>,
+    #[serde(rename = "inorc", skip_serializing_if = "Option::is_none")]
+    initial_number_of_records: Option<i32>,
+    #[serde(rename = "mnorc", skip_serializing_if = "Option::is_none")]
+    modified_number_of_records: Option<i32>,
+}
+
+fn build_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &DatasetStatsBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    request_builder.json(&RequestJson {
+        request: "stats",
+        user: builder.user.as_deref(),
+        version: builder.version,
+        modification_level: builder.modification_level,
+        creation_date: builder
+            .creation_date
+            .map(|d| d.format("%Y/%m/%d").to_string()),
+        modification_date: builder
+            .modification_date
+            .map(|d| d.format("%Y/%m/%d").to_string()),
+        modification_time: builder
+            .modification_date
+            .map(|d| d.format("%H:%M:%S").to_string()),
+        current_number_of_records: builder.current_number_of_records,
+        initial_number_of_records: builder.initial_number_of_records,
+        modified_number_of_records: builder.modified_number_of_records,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn example_1() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/ds/MY.SRC.PDS(PAYROLL)")
+            .json(&serde_json::json!({
+                "request": "stats",
+                "user": "IBMUSER",
+                "vers": 1,
+                "mod": 3,
+                "mdate": "2024/03/15",
+                "mtime": "13:45:07",
+            }))
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .datasets()
+            .stats("MY.SRC.PDS", "PAYROLL")
+            .user("IBMUSER")
+            .version(1)
+            .modification_level(3)
+            .modification_date(
+                NaiveDate::from_ymd_opt(2024, 3, 15)
+                    .unwrap()
+                    .and_hms_opt(13, 45, 7)
+                    .unwrap(),
+            )
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                manual_request.body().unwrap().as_bytes().unwrap()
+            )
+            .unwrap(),
+            serde_json::from_slice::<serde_json::Value>(
+                request.body().unwrap().as_bytes().unwrap()
+            )
+            .unwrap()
+        );
+    }
+}
@@ -0,0 +1,668 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use chrono::{DateTime, Local, NaiveTime};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::{wildcard_match, MemberName};
+use crate::{ClientCore, Error, Result};
+
+use super::members::{MemberAttributesBase, MemberAttributesName, MemberList, MemberListBuilder};
+use super::read::{DatasetRead, DatasetReadBuilder};
+use super::write::DatasetWriteBuilder;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum DatasetSyncDirection {
+    /// Mirror local files to PDS members.
+    Upload,
+    /// Mirror PDS members to local files.
+    Download,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum DatasetSyncAction {
+    Upload,
+    Download,
+    Unchanged,
+    Failed,
+}
+
+#[derive(Clone, Debug)]
+pub struct DatasetSyncBuilder {
+    core: ClientCore,
+
+    dataset: Arc<str>,
+    directory: PathBuf,
+    direction: DatasetSyncDirection,
+    include: Vec<Arc<str>>,
+    exclude: Vec<Arc<str>>,
+    extension: Option<Arc<str>>,
+    etags: HashMap<Arc<str>, Arc<str>>,
+    dry_run: bool,
+    skip_by_stats: bool,
+    concurrency: usize,
+}
+
+impl DatasetSyncBuilder {
+    pub(crate) fn new<D, P>(
+        core: ClientCore,
+        dataset: D,
+        directory: P,
+        direction: DatasetSyncDirection,
+    ) -> Self
+    where
+        D: std::fmt::Display,
+        P: AsRef<Path>,
+    {
+        DatasetSyncBuilder {
+            core,
+            dataset: dataset.to_string().into(),
+            directory: directory.as_ref().to_path_buf(),
+            direction,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            extension: None,
+            etags: HashMap::new(),
+            dry_run: false,
+            skip_by_stats: false,
+            concurrency: 4,
+        }
+    }
+
+    /// Only synchronize members matching a wildcard pattern, e.g. `PAY*`.
+    ///
+    /// May be called more than once; a member is included if it matches any pattern.
+    pub fn include<P>(self, pattern: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        let mut new = self;
+        new.include.push(pattern.to_string().to_uppercase().into());
+
+        new
+    }
+
+    /// Skip members matching a wildcard pattern, e.g. `*TEST`.
+    pub fn exclude<P>(self, pattern: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        let mut new = self;
+        new.exclude.push(pattern.to_string().to_uppercase().into());
+
+        new
+    }
+
+    /// The local file extension, e.g. `cbl` maps member `PAYROLL` to `PAYROLL.cbl`.
+    ///
+    /// Members are matched to local files regardless of case. Downloads keep the case of an
+    /// existing file, e.g. `payroll.cbl`, and name new files after the member in upper case.
+    pub fn extension<E>(self, value: E) -> Self
+    where
+        E: std::fmt::Display,
+    {
+        DatasetSyncBuilder {
+            extension: Some(value.to_string().trim_start_matches('.').into()),
+            ..self
+        }
+    }
+
+    /// Member etags from a previous synchronization, see [`DatasetSync::etags`].
+    ///
+    /// Downloads of members whose etag is unchanged are skipped without transferring data.
+    pub fn etags<I, M, E>(self, etags: I) -> Self
+    where
+        I: IntoIterator<Item = (M, E)>,
+        M: std::fmt::Display,
+        E: std::fmt::Display,
+    {
+        DatasetSyncBuilder {
+            etags: etags
+                .into_iter()
+                .map(|(m, e)| (m.to_string().into(), e.to_string().into()))
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Report what would change without writing anything.
+    pub fn dry_run(self, value: bool) -> Self {
+        DatasetSyncBuilder {
+            dry_run: value,
+            ..self
+        }
+    }
+
+    /// When uploading, take a member as unchanged without reading it if its ISPF statistics
+    /// show it was changed after the local file and it has a record for each of the file's lines.
+    ///
+    /// Off unless set, as this trusts the statistics: a host edit made after the local file was
+    /// saved that keeps the number of lines is not overwritten, and the statistics, in the host's
+    /// local time, are compared with the file's modification time in this machine's time zone.
+    pub fn skip_by_stats(self, value: bool) -> Self {
+        DatasetSyncBuilder {
+            skip_by_stats: value,
+            ..self
+        }
+    }
+
+    /// The maximum number of members transferred at once.
+    pub fn concurrency(self, value: usize) -> Self {
+        DatasetSyncBuilder {
+            concurrency: value.max(1),
+            ..self
+        }
+    }
+
+    /// Synchronize the selected members.
+    ///
+    /// Each member is read and compared with its local file line by line, ignoring trailing
+    /// blanks and line endings, unless [`skip_by_stats`](Self::skip_by_stats) is set.
+    pub async fn build(self) -> Result<DatasetSync> {
+        let (entries, stats) = match self.direction {
+            DatasetSyncDirection::Upload if self.skip_by_stats => {
+                let stats: HashMap<Arc<str>, MemberAttributesBase> = self
+                    .member_list()
+                    .await?
+                    .items()
+                    .iter()
+                    .map(|m| (m.name().into(), m.clone()))
+                    .collect();
+
+                (self.local_entries().await?, stats)
+            }
+            DatasetSyncDirection::Upload => (self.local_entries().await?, HashMap::new()),
+            DatasetSyncDirection::Download => {
+                let members = self.member_list().await?;
+
+                (self.remote_entries(&members).await?, HashMap::new())
+            }
+        };
+
+        let builder = &self;
+        let stats = &stats;
+
+        let items = futures::stream::iter(entries)
+            .map(|(member, path)| async move {
+                let result = match builder.direction {
+                    DatasetSyncDirection::Upload => {
+                        builder.upload(&member, &path, stats.get(&member)).await
+                    }
+                    DatasetSyncDirection::Download => builder.download(&member, &path).await,
+                };
+
+                match result {
+                    Ok((action, etag)) => DatasetSyncItem {
+                        member,
+                        path,
+                        action,
+                        etag,
+                        error: None,
+                    },
+                    Err(err) => DatasetSyncItem {
+                        member,
+                        path,
+                        action: DatasetSyncAction::Failed,
+                        etag: None,
+                        error: Some(err),
+                    },
+                }
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+
+        Ok(DatasetSync {
+            items,
+            dry_run: self.dry_run,
+        })
+    }
+
+    fn is_selected(&self, member: &str) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|p| wildcard_match(p, member)))
+            && !self.exclude.iter().any(|p| wildcard_match(p, member))
+    }
+
+    fn local_path(&self, member: &str) -> PathBuf {
+        match &self.extension {
+            Some(extension) => self.directory.join(format!("{}.{}", member, extension)),
+            None => self.directory.join(member),
+        }
+    }
+
+    async fn local_entries(&self) -> Result<Vec<(Arc<str>, PathBuf)>> {
+        let mut read_dir = tokio::fs::read_dir(&self.directory).await?;
+        let mut entries = Vec::new();
+
+        while let Some(entry) = read_dir.next_entry().await? {
+            if !entry.file_type().await?.is_file() {
+                continue;
+            }
+
+            let path = entry.path();
+            let extension = path.extension().and_then(|e| e.to_str());
+            if self.extension.is_some() && extension != self.extension.as_deref() {
+                continue;
+            }
+
+            let member = match path.file_stem().and_then(|s| s.to_str()) {
+                Some(stem) => stem.to_uppercase(),
+                None => continue,
+            };
+
+            if self.is_selected(&member) {
+                entries.push((member.into(), path));
+            }
+        }
+
+        entries.sort();
+
+        Ok(entries)
+    }
+
+    async fn member_list(&self) -> Result<MemberList<MemberAttributesBase>> {
+        MemberListBuilder::<MemberList<MemberAttributesName>>::new(self.core.clone(), &self.dataset)
+            .max_items(0)
+            .attributes_base()
+            .build()
+            .await
+    }
+
+    async fn remote_entries(
+        &self,
+        members: &MemberList<MemberAttributesBase>,
+    ) -> Result<Vec<(Arc<str>, PathBuf)>> {
+        let mut local: HashMap<Arc<str>, PathBuf> = match self.local_entries().await {
+            Ok(entries) => entries.into_iter().collect(),
+            Err(Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(err) => return Err(err),
+        };
+
+        Ok(members
+            .items()
+            .iter()
+            .map(|m| m.name())
+            .filter(|m| self.is_selected(m))
+            .map(|m| {
+                let path = local.remove(m).unwrap_or_else(|| self.local_path(m));
+
+                (m.into(), path)
+            })
+            .collect())
+    }
+
+    async fn upload(
+        &self,
+        member: &str,
+        path: &Path,
+        stats: Option<&MemberAttributesBase>,
+    ) -> Result<(DatasetSyncAction, Option<Arc<str>>)> {
+        member.parse::<MemberName>()?;
+
+        let local = tokio::fs::read_to_string(path).await?;
+
+        if let Some(stats) = stats {
+            let modified = tokio::fs::metadata(path).await?.modified()?;
+
+            if is_unchanged_by_stats(stats, &local, modified) {
+                return Ok((
+                    DatasetSyncAction::Unchanged,
+                    self.etags.get(member).cloned(),
+                ));
+            }
+        }
+
+        let remote = match self.read_builder(member).build().await {
+            Ok(read) => Some(read),
+            Err(err) if err.status() == Some(reqwest::StatusCode::NOT_FOUND) => None,
+            Err(err) => return Err(err),
+        };
+
+        let etag = remote.as_ref().and_then(|r| r.etag()).map(Arc::from);
+
+        if let Some(remote) = &remote {
+            if normalize_text(remote.data()) == normalize_text(&local) {
+                return Ok((DatasetSyncAction::Unchanged, etag));
+            }
+        }
+
+        if self.dry_run {
+            return Ok((DatasetSyncAction::Upload, etag));
+        }
+
+        let builder = self.write_builder(member).text(local);
+        let builder = match &etag {
+            Some(etag) => builder.if_match(etag),
+            None => builder,
+        };
+
+        let written = builder.build().await?;
+
+        Ok((DatasetSyncAction::Upload, written.etag().map(Arc::from)))
+    }
+
+    async fn download(
+        &self,
+        member: &str,
+        path: &Path,
+    ) -> Result<(DatasetSyncAction, Option<Arc<str>>)> {
+        let local = match tokio::fs::read_to_string(path).await {
+            Ok(local) => Some(local),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+            Err(err) => return Err(err.into()),
+        };
+
+        let known_etag = self.etags.get(member).filter(|_| local.is_some());
+
+        let (data, etag) = match known_etag {
+            Some(known_etag) => {
+                let read = self
+                    .read_builder(member)
+                    .if_none_match(known_etag)
+                    .build()
+                    .await?;
+
+                match read.data() {
+                    Some(data) => (data.to_string(), read.etag().map(Arc::from)),
+                    None => return Ok((DatasetSyncAction::Unchanged, Some(known_etag.clone()))),
+                }
+            }
+            None => {
+                let read = self.read_builder(member).build().await?;
+
+                (read.data().to_string(), read.etag().map(Arc::from))
+            }
+        };
+
+        if let Some(local) = &local {
+            if normalize_text(local) == normalize_text(&data) {
+                return Ok((DatasetSyncAction::Unchanged, etag));
+            }
+        }
+
+        if !self.dry_run {
+            tokio::fs::create_dir_all(&self.directory).await?;
+            tokio::fs::write(path, data).await?;
+        }
+
+        Ok((DatasetSyncAction::Download, etag))
+    }
+
+    fn read_builder(&self, member: &str) -> DatasetReadBuilder<DatasetRead<Arc<str>>> {
+        DatasetReadBuilder::new(self.core.clone(), &self.dataset)
+            .member(member)
+            .return_etag(true)
+    }
+
+    fn write_builder(&self, member: &str) -> DatasetWriteBuilder<crate::restfiles::Etag> {
+        DatasetWriteBuilder::new(self.core.clone(), &self.dataset).member(member)
+    }
+}
+
+/// The outcome of synchronizing a local directory with a partitioned dataset.
+#[derive(Debug)]
+pub struct DatasetSync {
+    items: Vec<DatasetSyncItem>,
+    dry_run: bool,
+}
+
+impl DatasetSync {
+    pub fn items(&self) -> &[DatasetSyncItem] {
+        &self.items
+    }
+
+    pub fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// The members that were (or, in a dry run, would be) transferred.
+    pub fn changed(&self) -> impl Iterator<Item = &DatasetSyncItem> {
+        self.items.iter().filter(|item| {
+            matches!(
+                item.action,
+                DatasetSyncAction::Upload | DatasetSyncAction::Download
+            )
+        })
+    }
+
+    pub fn failed(&self) -> impl Iterator<Item = &DatasetSyncItem> {
+        self.items
+            .iter()
+            .filter(|item| item.action == DatasetSyncAction::Failed)
+    }
+
+    /// The latest known etag of each member, for use with [`DatasetSyncBuilder::etags`].
+    pub fn etags(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.items
+            .iter()
+            .filter_map(|item| Some((item.member(), item.etag()?)))
+    }
+}
+
+#[derive(Debug)]
+pub struct DatasetSyncItem {
+    member: Arc<str>,
+    path: PathBuf,
+    action: DatasetSyncAction,
+    etag: Option<Arc<str>>,
+    error: Option<Error>,
+}
+
+impl DatasetSyncItem {
+    pub fn member(&self) -> &str {
+        &self.member
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn action(&self) -> DatasetSyncAction {
+        self.action
+    }
+
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    pub fn error(&self) -> Option<&Error> {
+        self.error.as_ref()
+    }
+}
+
+/// Whether the member was changed after the local file and has a record for each of its lines.
+fn is_unchanged_by_stats(stats: &MemberAttributesBase, local: &str, modified: SystemTime) -> bool {
+    let (Some(date), Some(records)) =
+        (stats.modification_date(), stats.current_number_of_records())
+    else {
+        return false;
+    };
+
+    let time = stats
+        .modified_time()
+        .and_then(|t| NaiveTime::parse_from_str(t, "%H:%M").ok())
+        .unwrap_or(NaiveTime::MIN);
+    let seconds = stats
+        .modified_seconds()
+        .and_then(|s| s.trim().parse().ok())
+        .unwrap_or(0);
+    let changed = date.and_time(time) + chrono::Duration::seconds(seconds);
+
+    usize::try_from(records).is_ok_and(|records| records == local.lines().count())
+        && changed >= DateTime::<Local>::from(modified).naive_local()
+}
+
+/// Normalize text for comparison: z/OSMF strips trailing blanks from records
+/// and local files may use CRLF line endings.
+fn normalize_text(text: &str) -> String {
+    let lines: Vec<&str> = text.lines().map(|line| line.trim_end()).collect();
+
+    lines.join("\n").trim_end_matches('\n').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn member_names() {
+        let is_valid = |member: &str| member.parse::<MemberName>().is_ok();
+
+        assert!(is_valid("PAYROLL"));
+        assert!(is_valid("$TEMP01"));
+        assert!(!is_valid("PAYROLL01"));
+        assert!(!is_valid("1PAY"));
+        assert!(!is_valid("PAY-1"));
+        assert!(!is_valid(""));
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!(
+            normalize_text("LINE 1   \r\nLINE 2\r\n\r\n"),
+            normalize_text("LINE 1\nLINE 2\n")
+        );
+        assert_ne!(normalize_text("LINE 1\n"), normalize_text("LINE 2\n"));
+    }
+
+    #[test]
+    fn selection() {
+        let zosmf = get_zosmf();
+
+        let sync = zosmf
+            .datasets()
+            .sync("MY.SRC.PDS", "./src", DatasetSyncDirection::Upload)
+            .include("pay*")
+            .exclude("*TEST")
+            .extension(".cbl");
+
+        assert!(sync.is_selected("PAYROLL"));
+        assert!(!sync.is_selected("PAYTEST"));
+        assert!(!sync.is_selected("BILLING"));
+        assert_eq!(
+            sync.local_path("PAYROLL"),
+            PathBuf::from("./src/PAYROLL.cbl")
+        );
+    }
+
+    #[tokio::test]
+    async fn local_entries() {
+        let directory = std::env::temp_dir().join(format!("z_osmf_sync_{}", std::process::id()));
+        std::fs::create_dir_all(directory.join("nested")).unwrap();
+        std::fs::write(directory.join("payroll.cbl"), "").unwrap();
+        std::fs::write(directory.join("billing.cbl"), "").unwrap();
+        std::fs::write(directory.join("notes.txt"), "").unwrap();
+
+        let zosmf = get_zosmf();
+
+        let entries = zosmf
+            .datasets()
+            .sync("MY.SRC.PDS", &directory, DatasetSyncDirection::Upload)
+            .extension("cbl")
+            .local_entries()
+            .await
+            .unwrap();
+
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                ("BILLING".into(), directory.join("billing.cbl")),
+                ("PAYROLL".into(), directory.join("payroll.cbl")),
+            ]
+        );
+    }
+
+    fn members(items: serde_json::Value) -> MemberList<MemberAttributesBase> {
+        serde_json::from_value(serde_json::json!({
+            "items": items,
+            "json_version": 1,
+            "more_rows": null,
+            "returned_rows": 1,
+            "total_rows": null,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn stats() {
+        let list = members(serde_json::json!([{
+            "member": "PAYROLL",
+            "m4date": "2024/03/15",
+            "mtime": "13:45",
+            "msec": "07",
+            "cnorc": 2,
+        }]));
+        let stats = &list.items()[0];
+        let local_time = |h, m, s| {
+            let time = chrono::NaiveDate::from_ymd_opt(2024, 3, 15)
+                .unwrap()
+                .and_hms_opt(h, m, s)
+                .unwrap()
+                .and_local_timezone(Local)
+                .unwrap();
+
+            SystemTime::from(time)
+        };
+
+        assert!(is_unchanged_by_stats(
+            stats,
+            "A\nB\n",
+            local_time(13, 45, 7)
+        ));
+        assert!(!is_unchanged_by_stats(
+            stats,
+            "A\nB\n",
+            local_time(13, 45, 8)
+        ));
+        assert!(!is_unchanged_by_stats(
+            stats,
+            "A\nB\nC\n",
+            local_time(9, 0, 0)
+        ));
+
+        let no_stats = members(serde_json::json!([{ "member": "PAYROLL" }]));
+        assert!(!is_unchanged_by_stats(
+            &no_stats.items()[0],
+            "A\nB\n",
+            local_time(9, 0, 0)
+        ));
+    }
+
+    #[tokio::test]
+    async fn keeps_local_case() {
+        let directory =
+            std::env::temp_dir().join(format!("z_osmf_sync_case_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("payroll.cbl"), "").unwrap();
+
+        let zosmf = get_zosmf();
+
+        let entries = zosmf
+            .datasets()
+            .sync("MY.SRC.PDS", &directory, DatasetSyncDirection::Download)
+            .extension("cbl")
+            .remote_entries(&members(serde_json::json!([
+                { "member": "BILLING" },
+                { "member": "PAYROLL" },
+            ])))
+            .await
+            .unwrap();
+
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                ("BILLING".into(), directory.join("BILLING.cbl")),
+                ("PAYROLL".into(), directory.join("payroll.cbl")),
+            ]
+        );
+    }
+}
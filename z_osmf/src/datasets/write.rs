@@ -2,15 +2,21 @@ use std::marker::PhantomData;
 use std::sync::Arc;
 
 use bytes::Bytes;
+use serde::{Deserialize, Serialize};
 use z_osmf_macros::Endpoint;
 
 use crate::convert::TryFromResponse;
-use crate::ClientCore;
+use crate::{ClientCore, Error, Result};
 
+use super::list::{DatasetAttributesBase, DatasetAttributesName, DatasetList, DatasetListBuilder};
 use super::{get_member, get_volume, DatasetEnqueue, DatasetMigratedRecall};
 
 #[derive(Clone, Debug, Endpoint)]
-#[endpoint(method = put, path = "/zosmf/restfiles/ds{volume}/{dataset}{member}")]
+#[endpoint(
+    method = put,
+    path = "/zosmf/restfiles/ds{volume}/{dataset}{member}",
+    validate_fn = validate
+)]
 pub struct DatasetWriteBuilder<T>
 where
     T: TryFromResponse,
@@ -31,6 +37,10 @@ where
     encoding: Option<Arc<str>>,
     #[endpoint(skip_builder)]
     crlf_newlines: Option<bool>,
+    #[endpoint(skip_builder)]
+    record_length: Option<i32>,
+    #[endpoint(skip_builder)]
+    overflow: Option<DatasetWriteOverflow>,
     #[endpoint(header = "X-IBM-Migrated-Recall")]
     migrated_recall: Option<DatasetMigratedRecall>,
     #[endpoint(header = "X-IBM-Obtain-ENQ")]
@@ -59,6 +69,23 @@ where
         }
     }
 
+    /// Write text one line per record, checked against [`record_length`](Self::record_length).
+    pub fn lines<I, L>(self, lines: I) -> Self
+    where
+        I: IntoIterator<Item = L>,
+        L: AsRef<str>,
+    {
+        DatasetWriteBuilder {
+            data: Some(Data::Lines(
+                lines
+                    .into_iter()
+                    .map(|line| line.as_ref().trim_end_matches(['\r', '\n']).into())
+                    .collect(),
+            )),
+            ..self
+        }
+    }
+
     pub fn record<B>(self, data: B) -> Self
     where
         B: Into<Bytes>,
@@ -80,11 +107,155 @@ where
     }
 }
 
+/// What to do with lines longer than the record length.
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+pub enum DatasetWriteOverflow {
+    /// Fail with [`Error::RecordOverflow`] before anything is sent.
+    #[default]
+    Error,
+    /// Truncate long lines to the record length, or for [`text`](DatasetWriteBuilder::text),
+    /// leave the host to truncate them.
+    Truncate,
+}
+
+impl<T> DatasetWriteBuilder<T>
+where
+    T: TryFromResponse,
+{
+    /// Look up the record length of the existing dataset, so that text or lines longer
+    /// than a record fail with [`Error::RecordOverflow`] instead of being truncated by the host.
+    ///
+    /// For variable-length record formats, the 4-byte record descriptor word is excluded.
+    pub async fn check_record_length(self) -> Result<Self> {
+        let list = self.list_builder().build().await?;
+
+        let attributes = list
+            .items()
+            .iter()
+            .find(|d| d.name().eq_ignore_ascii_case(&self.dataset))
+            .ok_or_else(|| Error::InvalidValue(format!("dataset not found: {}", self.dataset)))?;
+
+        Ok(DatasetWriteBuilder {
+            record_length: data_length(attributes).or(self.record_length),
+            overflow: Some(DatasetWriteOverflow::Error),
+            ..self
+        })
+    }
+
+    fn list_builder(&self) -> DatasetListBuilder<DatasetList<DatasetAttributesBase>> {
+        let builder = DatasetListBuilder::<DatasetList<DatasetAttributesName>>::new(
+            self.core.clone(),
+            &self.dataset,
+        )
+        .attributes_base();
+
+        match &self.volume {
+            Some(volume) => builder.volume(volume),
+            None => builder,
+        }
+    }
+}
+
+/// The longest line that fits in a record, if the record format has a limit.
+fn data_length(attributes: &DatasetAttributesBase) -> Option<i32> {
+    let record_length = attributes.record_length()?;
+    let record_format = attributes.record_format()?;
+
+    if record_format.is_fixed() {
+        Some(record_length)
+    } else if record_format.is_variable() {
+        Some(record_length - 4)
+    } else {
+        None
+    }
+}
+
 #[derive(Clone, Debug)]
 enum Data {
     Binary(Bytes),
     Record(Bytes),
     Text(String),
+    Lines(Arc<[Arc<str>]>),
+}
+
+fn validate<T>(builder: &DatasetWriteBuilder<T>) -> Result<()>
+where
+    T: TryFromResponse,
+{
+    let lines: Vec<&str> = match &builder.data {
+        Some(Data::Lines(lines)) => {
+            if let Some(number) = lines.iter().position(|line| line.contains('\n')) {
+                return Err(Error::InvalidValue(format!(
+                    "line {} contains an embedded newline",
+                    number + 1
+                )));
+            }
+
+            lines.iter().map(|line| line.as_ref()).collect()
+        }
+        Some(Data::Text(text)) => text.lines().collect(),
+        _ => return Ok(()),
+    };
+
+    let record_length = match builder.record_length {
+        Some(record_length) if record_length < 1 => {
+            return Err(Error::InvalidValue(format!(
+                "record length must be positive: {}",
+                record_length
+            )))
+        }
+        record_length => record_length,
+    };
+
+    match (record_length, builder.overflow.unwrap_or_default()) {
+        (Some(record_length), DatasetWriteOverflow::Error) => {
+            let overflows: Arc<[usize]> = lines
+                .iter()
+                .enumerate()
+                .filter(|(_, line)| line.chars().count() > record_length as usize)
+                .map(|(number, _)| number + 1)
+                .collect();
+
+            if overflows.is_empty() {
+                Ok(())
+            } else {
+                Err(Error::RecordOverflow {
+                    record_length,
+                    lines: overflows,
+                })
+            }
+        }
+        _ => Ok(()),
+    }
+}
+
+fn join_lines<T>(builder: &DatasetWriteBuilder<T>, lines: &[Arc<str>]) -> String
+where
+    T: TryFromResponse,
+{
+    let newline = match builder.crlf_newlines {
+        Some(true) => "\r\n",
+        _ => "\n",
+    };
+
+    let truncate_at = match builder.overflow {
+        Some(DatasetWriteOverflow::Truncate) => builder.record_length,
+        _ => None,
+    };
+
+    lines
+        .iter()
+        .map(|line| match truncate_at {
+            Some(record_length) => line
+                .chars()
+                .take(record_length as usize)
+                .collect::<String>(),
+            None => line.to_string(),
+        })
+        .map(|line| line + newline)
+        .collect()
 }
 
 fn build_data<T>(
@@ -108,22 +279,36 @@ where
         Some(Data::Record(record)) => request_builder
             .header("X-IBM-Data-Type", "record")
             .body(record.clone()),
-        Some(Data::Text(text)) => match (encoding, crlf_newlines) {
-            (Some(encoding), Some(true)) => request_builder.header(
-                "X-IBM-Data-Type",
-                format!("text;fileEncoding={};crlf=true", encoding),
-            ),
-            (Some(encoding), _) => {
-                request_builder.header("X-IBM-Data-Type", format!("text;fileEncoding={}", encoding))
-            }
-            (None, Some(true)) => request_builder.header("X-IBM-Data-Type", "text;crlf=true"),
-            _ => request_builder,
+        Some(Data::Lines(lines)) => {
+            let text = join_lines(builder, lines);
+
+            text_data_type(request_builder, encoding, crlf_newlines).body(text)
+        }
+        Some(Data::Text(text)) => {
+            text_data_type(request_builder, encoding, crlf_newlines).body(text.clone())
         }
-        .body(text.clone()),
         None => request_builder,
     }
 }
 
+fn text_data_type(
+    request_builder: reqwest::RequestBuilder,
+    encoding: &Option<Arc<str>>,
+    crlf_newlines: &Option<bool>,
+) -> reqwest::RequestBuilder {
+    match (encoding, crlf_newlines) {
+        (Some(encoding), Some(true)) => request_builder.header(
+            "X-IBM-Data-Type",
+            format!("text;fileEncoding={};crlf=true", encoding),
+        ),
+        (Some(encoding), _) => {
+            request_builder.header("X-IBM-Data-Type", format!("text;fileEncoding={}", encoding))
+        }
+        (None, Some(true)) => request_builder.header("X-IBM-Data-Type", "text;crlf=true"),
+        _ => request_builder,
+    }
+}
+
 fn build_member<T>(builder: &DatasetWriteBuilder<T>) -> String
 where
     T: TryFromResponse,
@@ -155,6 +340,8 @@ where
 mod tests {
     use crate::tests::*;
 
+    use super::*;
+
     #[test]
     fn example_1() {
         let zosmf = get_zosmf();
@@ -189,4 +376,196 @@ mod tests {
             write_dataset.body().unwrap().as_bytes().unwrap()
         )
     }
+
+    #[test]
+    fn text_options() {
+        let zosmf = get_zosmf();
+
+        let string_data = "line 1\r\nline 2\r\n";
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/ds/JIAHJ.REST.TEST.DATASET")
+            .header("X-IBM-Data-Type", "text;fileEncoding=IBM-037;crlf=true")
+            .body(string_data)
+            .header("X-IBM-Migrated-Recall", "wait")
+            .header("X-IBM-Obtain-ENQ", "EXCLU")
+            .build()
+            .unwrap();
+
+        let write_dataset = zosmf
+            .datasets()
+            .write("JIAHJ.REST.TEST.DATASET")
+            .text(string_data)
+            .encoding("IBM-037")
+            .crlf_newlines(true)
+            .migrated_recall(DatasetMigratedRecall::Wait)
+            .obtain_enq(DatasetEnqueue::Exclu)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", write_dataset)
+        );
+    }
+
+    #[test]
+    fn release_enq() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/ds/JIAHJ.REST.TEST.DATASET")
+            .header("X-IBM-Data-Type", "text;crlf=true")
+            .body("")
+            .header("X-IBM-Session-Ref", "ZOSMFAD-55-aaakaaac")
+            .header("X-IBM-Release-ENQ", "true")
+            .build()
+            .unwrap();
+
+        let write_dataset = zosmf
+            .datasets()
+            .write("JIAHJ.REST.TEST.DATASET")
+            .text("")
+            .crlf_newlines(true)
+            .session_ref("ZOSMFAD-55-aaakaaac")
+            .release_enq(true)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", write_dataset)
+        );
+    }
+
+    #[test]
+    fn lines() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/ds/JIAHJ.REST.TEST.DATASET")
+            .header("X-IBM-Data-Type", "text;crlf=true")
+            .body("LINE 1\r\nLINE 2\r\n")
+            .build()
+            .unwrap();
+
+        let write_dataset = zosmf
+            .datasets()
+            .write("JIAHJ.REST.TEST.DATASET")
+            .lines(["LINE 1\n", "LINE 2"])
+            .crlf_newlines(true)
+            .record_length(80)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", write_dataset)
+        );
+        assert_eq!(
+            manual_request.body().unwrap().as_bytes().unwrap(),
+            write_dataset.body().unwrap().as_bytes().unwrap()
+        );
+    }
+
+    #[test]
+    fn lines_overflow() {
+        let zosmf = get_zosmf();
+
+        let write_dataset = zosmf
+            .datasets()
+            .write("JIAHJ.REST.TEST.DATASET")
+            .lines(["SHORT", "A LONG LINE", "OK", "ANOTHER LONG LINE"])
+            .record_length(8);
+
+        match write_dataset.get_request() {
+            Err(Error::RecordOverflow {
+                record_length,
+                lines,
+            }) => {
+                assert_eq!(record_length, 8);
+                assert_eq!(lines.as_ref(), &[2, 4]);
+            }
+            other => panic!("expected a record overflow, got {:?}", other),
+        }
+
+        let truncated = write_dataset
+            .overflow(DatasetWriteOverflow::Truncate)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            truncated.body().unwrap().as_bytes().unwrap(),
+            b"SHORT\nA LONG L\nOK\nANOTHER \n"
+        );
+
+        let embedded = zosmf
+            .datasets()
+            .write("JIAHJ.REST.TEST.DATASET")
+            .lines(["ONE\nTWO"])
+            .get_request();
+
+        assert!(matches!(embedded, Err(Error::InvalidValue(_))));
+    }
+
+    #[test]
+    fn text_overflow() {
+        let zosmf = get_zosmf();
+
+        let write_dataset = zosmf
+            .datasets()
+            .write("JIAHJ.REST.TEST.DATASET")
+            .text("SHORT\r\nA LONG LINE\r\n")
+            .record_length(8)
+            .get_request();
+
+        assert!(matches!(
+            write_dataset,
+            Err(Error::RecordOverflow { lines, .. }) if lines.as_ref() == [2]
+        ));
+    }
+
+    #[test]
+    fn check_record_length() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/ds")
+            .query(&[("dslevel", "JIAHJ.REST.TEST.DATASET")])
+            .header("X-IBM-Attributes", "base")
+            .build()
+            .unwrap();
+
+        let list_request = zosmf
+            .datasets()
+            .write("JIAHJ.REST.TEST.DATASET")
+            .list_builder()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", list_request)
+        );
+
+        let attributes: Vec<DatasetAttributesBase> = serde_json::from_value(serde_json::json!([
+            {"dsname": "A.FB", "lrecl": "80", "migr": "NO", "recfm": "FB", "vol": "VOL001"},
+            {"dsname": "A.VB", "lrecl": "255", "migr": "NO", "recfm": "VB", "vol": "VOL001"},
+            {"dsname": "A.U", "lrecl": "0", "migr": "NO", "recfm": "U", "vol": "VOL001"},
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            attributes.iter().map(data_length).collect::<Vec<_>>(),
+            vec![Some(80), Some(251), None]
+        );
+    }
 }
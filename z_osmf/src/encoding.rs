@@ -0,0 +1,218 @@
+use bytes::Bytes;
+
+use crate::{Error, Result};
+
+/// An EBCDIC code page, for converting data fetched in binary or record mode.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Codepage {
+    Ibm037,
+    #[default]
+    Ibm1047,
+    /// UK English; the overline at `0xA1` is mapped to the Latin-1 macron.
+    Ibm285,
+}
+
+impl Codepage {
+    /// Convert EBCDIC bytes to a string.
+    pub fn decode(&self, data: &[u8]) -> String {
+        let table = self.table();
+
+        data.iter()
+            .map(|b| char::from(table[*b as usize]))
+            .collect()
+    }
+
+    /// Convert a string to EBCDIC bytes, failing on characters the code page cannot represent.
+    pub fn encode(&self, text: &str) -> Result<Vec<u8>> {
+        let table = self.inverse_table();
+
+        text.chars()
+            .map(|c| {
+                u8::try_from(c)
+                    .map(|latin1| table[latin1 as usize])
+                    .map_err(|_| {
+                        Error::InvalidValue(format!("character {:?} is not in {}", c, self))
+                    })
+            })
+            .collect()
+    }
+
+    /// Convert a string to an EBCDIC payload, e.g. for a binary write.
+    pub fn encode_bytes(&self, text: &str) -> Result<Bytes> {
+        self.encode(text).map(Bytes::from)
+    }
+
+    /// Convert each record of a record mode read to a string.
+    pub fn decode_records(&self, records: &[&[u8]]) -> Vec<String> {
+        records.iter().map(|record| self.decode(record)).collect()
+    }
+
+    fn table(&self) -> &'static [u8; 256] {
+        match self {
+            Codepage::Ibm037 => &IBM_037,
+            Codepage::Ibm1047 => &IBM_1047,
+            Codepage::Ibm285 => &IBM_285,
+        }
+    }
+
+    fn inverse_table(&self) -> &'static [u8; 256] {
+        match self {
+            Codepage::Ibm037 => &IBM_037_INVERSE,
+            Codepage::Ibm1047 => &IBM_1047_INVERSE,
+            Codepage::Ibm285 => &IBM_285_INVERSE,
+        }
+    }
+}
+
+impl std::fmt::Display for Codepage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Codepage::Ibm037 => "IBM-037",
+            Codepage::Ibm1047 => "IBM-1047",
+            Codepage::Ibm285 => "IBM-285",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+impl std::str::FromStr for Codepage {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_uppercase().replace(['-', '_'], "").as_str() {
+            "IBM037" | "CP037" => Ok(Codepage::Ibm037),
+            "IBM1047" | "CP1047" => Ok(Codepage::Ibm1047),
+            "IBM285" | "CP285" => Ok(Codepage::Ibm285),
+            _ => Err(Error::InvalidValue(format!("unsupported code page: {}", s))),
+        }
+    }
+}
+
+/// EBCDIC byte to Latin-1 code point.
+#[rustfmt::skip]
+const IBM_037: [u8; 256] = [
+    0x00, 0x01, 0x02, 0x03, 0x9C, 0x09, 0x86, 0x7F, 0x97, 0x8D, 0x8E, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+    0x10, 0x11, 0x12, 0x13, 0x9D, 0x85, 0x08, 0x87, 0x18, 0x19, 0x92, 0x8F, 0x1C, 0x1D, 0x1E, 0x1F,
+    0x80, 0x81, 0x82, 0x83, 0x84, 0x0A, 0x17, 0x1B, 0x88, 0x89, 0x8A, 0x8B, 0x8C, 0x05, 0x06, 0x07,
+    0x90, 0x91, 0x16, 0x93, 0x94, 0x95, 0x96, 0x04, 0x98, 0x99, 0x9A, 0x9B, 0x14, 0x15, 0x9E, 0x1A,
+    0x20, 0xA0, 0xE2, 0xE4, 0xE0, 0xE1, 0xE3, 0xE5, 0xE7, 0xF1, 0xA2, 0x2E, 0x3C, 0x28, 0x2B, 0x7C,
+    0x26, 0xE9, 0xEA, 0xEB, 0xE8, 0xED, 0xEE, 0xEF, 0xEC, 0xDF, 0x21, 0x24, 0x2A, 0x29, 0x3B, 0xAC,
+    0x2D, 0x2F, 0xC2, 0xC4, 0xC0, 0xC1, 0xC3, 0xC5, 0xC7, 0xD1, 0xA6, 0x2C, 0x25, 0x5F, 0x3E, 0x3F,
+    0xF8, 0xC9, 0xCA, 0xCB, 0xC8, 0xCD, 0xCE, 0xCF, 0xCC, 0x60, 0x3A, 0x23, 0x40, 0x27, 0x3D, 0x22,
+    0xD8, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0xAB, 0xBB, 0xF0, 0xFD, 0xFE, 0xB1,
+    0xB0, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0xAA, 0xBA, 0xE6, 0xB8, 0xC6, 0xA4,
+    0xB5, 0x7E, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0xA1, 0xBF, 0xD0, 0xDD, 0xDE, 0xAE,
+    0x5E, 0xA3, 0xA5, 0xB7, 0xA9, 0xA7, 0xB6, 0xBC, 0xBD, 0xBE, 0x5B, 0x5D, 0xAF, 0xA8, 0xB4, 0xD7,
+    0x7B, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0xAD, 0xF4, 0xF6, 0xF2, 0xF3, 0xF5,
+    0x7D, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0xB9, 0xFB, 0xFC, 0xF9, 0xFA, 0xFF,
+    0x5C, 0xF7, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0xB2, 0xD4, 0xD6, 0xD2, 0xD3, 0xD5,
+    0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0xB3, 0xDB, 0xDC, 0xD9, 0xDA, 0x9F,
+];
+
+const IBM_1047: [u8; 256] = swap(swap(swap(IBM_037, 0x5F, 0xB0), 0xAD, 0xBA), 0xBB, 0xBD);
+
+const IBM_285: [u8; 256] = patch(
+    IBM_037,
+    &[
+        (0x4A, 0x24),
+        (0x5B, 0xA3),
+        (0xA1, 0xAF),
+        (0xB0, 0xA2),
+        (0xB1, 0x5B),
+        (0xBA, 0x5E),
+        (0xBC, 0x7E),
+    ],
+);
+
+const IBM_037_INVERSE: [u8; 256] = invert(&IBM_037);
+const IBM_1047_INVERSE: [u8; 256] = invert(&IBM_1047);
+const IBM_285_INVERSE: [u8; 256] = invert(&IBM_285);
+
+const fn swap(mut table: [u8; 256], a: usize, b: usize) -> [u8; 256] {
+    let tmp = table[a];
+    table[a] = table[b];
+    table[b] = tmp;
+
+    table
+}
+
+const fn patch(mut table: [u8; 256], changes: &[(usize, u8)]) -> [u8; 256] {
+    let mut i = 0;
+    while i < changes.len() {
+        table[changes[i].0] = changes[i].1;
+        i += 1;
+    }
+
+    table
+}
+
+const fn invert(table: &[u8; 256]) -> [u8; 256] {
+    let mut inverse = [0; 256];
+
+    let mut i = 0;
+    while i < 256 {
+        inverse[table[i] as usize] = i as u8;
+        i += 1;
+    }
+
+    inverse
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_are_bijective() {
+        for codepage in [Codepage::Ibm037, Codepage::Ibm1047, Codepage::Ibm285] {
+            let mut seen = [false; 256];
+            for b in codepage.table() {
+                assert!(!seen[*b as usize], "{} maps to {:#04x} twice", codepage, b);
+                seen[*b as usize] = true;
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let text = "HELLO, World! [1047] ^ ¬ 0123456789 $#@";
+
+        for codepage in [Codepage::Ibm037, Codepage::Ibm1047, Codepage::Ibm285] {
+            let ebcdic = codepage.encode(text).unwrap();
+            assert_eq!(codepage.decode(&ebcdic), text);
+        }
+
+        assert_eq!(
+            Codepage::Ibm037.encode("A1 ").unwrap(),
+            vec![0xC1, 0xF1, 0x40]
+        );
+        assert_eq!(Codepage::Ibm1047.encode("[]").unwrap(), vec![0xAD, 0xBD]);
+        assert_eq!(Codepage::Ibm037.encode("[]").unwrap(), vec![0xBA, 0xBB]);
+        assert!(Codepage::Ibm1047.encode("€").is_err());
+        assert_eq!(
+            Codepage::Ibm285.encode("£$[").unwrap(),
+            vec![0x5B, 0x4A, 0xB1]
+        );
+        assert_eq!(Codepage::Ibm285.decode(&[0x5B, 0x4A]), "£$");
+    }
+
+    #[test]
+    fn bytes() {
+        let data = Codepage::Ibm285.encode_bytes("£100").unwrap();
+
+        assert_eq!(data, Bytes::from_static(&[0x5B, 0xF1, 0xF0, 0xF0]));
+        assert_eq!(
+            Codepage::Ibm285.decode_records(&[&data[..2], &data[2..]]),
+            vec!["£1", "00"]
+        );
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("IBM-037".parse::<Codepage>().unwrap(), Codepage::Ibm037);
+        assert_eq!("cp1047".parse::<Codepage>().unwrap(), Codepage::Ibm1047);
+        assert_eq!("IBM-285".parse::<Codepage>().unwrap(), Codepage::Ibm285);
+        assert!("IBM-930".parse::<Codepage>().is_err());
+    }
+}
@@ -9,30 +9,82 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub enum Error {
     #[error("z/OSMF API error response: {0:?}")]
     Api(ApiError),
+    #[error("{path} was changed elsewhere, seen by transaction {transaction_id}")]
+    Conflict {
+        path: String,
+        etag: Option<Arc<str>>,
+        transaction_id: Arc<str>,
+    },
     #[error("data serialization failed: {0}")]
     Fmt(#[from] std::fmt::Error),
     #[error("invalid response format: {0:?}")]
     InvalidFormat(Arc<[Arc<str>]>),
     #[error("invalid value: {0}")]
     InvalidValue(String),
+    #[error("I/O failed: {0}")]
+    Io(#[from] std::io::Error),
     #[error("missing etag")]
     NoEtag,
+    #[error("missing session reference")]
+    NoSessionRef,
     #[error("missing transaction id")]
     NoTransactionId,
+    #[error("no user is logged in")]
+    NoUser,
     #[error("failed to parse int: {0}")]
     NumParseInt(#[from] std::num::ParseIntError),
     #[error("invalid record range: {0}")]
     RecordRange(String),
+    #[error("lines {lines:?} exceed the record length of {record_length}")]
+    RecordOverflow {
+        record_length: i32,
+        lines: Arc<[usize]>,
+    },
     #[error("API call failed: {0}")]
     Reqwest(#[from] reqwest::Error),
+    #[error("timed out after {0:?}")]
+    Timeout(std::time::Duration),
+    #[error("invalid UTF-8 sequence {sequence:02X?} at byte {offset}")]
+    Utf8 { offset: usize, sequence: Arc<[u8]> },
     #[error("poisoned read-write lock: {0}")]
     RwLockPoisonError(String),
     #[error("data deserialization failed: {0}")]
     SerdeDe(#[from] serde::de::value::Error),
+    #[error("JSON serialization failed: {0}")]
+    SerdeJson(#[from] serde_json::Error),
     #[error("header value to string failed: {0}")]
     ReqwestHeaderToString(#[from] reqwest::header::ToStrError),
 }
 
+impl Error {
+    /// The details of a failed z/OS UNIX call, e.g. to tell `EACCES` from `ENOSPC`.
+    pub fn uss(&self) -> Option<&UssError> {
+        match self {
+            Error::Api(api_error) => api_error.uss(),
+            _ => None,
+        }
+    }
+
+    /// The HTTP status code of a failed API call, if any.
+    pub fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Error::Api(api_error) => Some(api_error.status()),
+            Error::Reqwest(err) => err.status(),
+            _ => None,
+        }
+    }
+
+    /// Whether the call failed in transit or because z/OSMF was busy, so retrying may succeed.
+    pub fn is_retryable(&self) -> bool {
+        match self.status() {
+            Some(status) => {
+                status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            }
+            None => matches!(self, Error::Reqwest(_)),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum ApiError {
     Json {
@@ -43,6 +95,8 @@ pub enum ApiError {
         reason: i32,
         message: String,
         details: Option<Vec<String>>,
+        /// The failing z/OS UNIX call, if the message or details describe one.
+        uss: Option<Box<UssError>>,
     },
     Text {
         url: String,
@@ -65,6 +119,123 @@ impl ApiError {
             Self::Text { status, .. } => *status,
         }
     }
+
+    pub fn uss(&self) -> Option<&UssError> {
+        match self {
+            Self::Json { uss, .. } => uss.as_deref(),
+            Self::Text { .. } => None,
+        }
+    }
+}
+
+/// The errno, errno2 and failing call of a z/OS UNIX System Services error.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct UssError {
+    errno: Option<i32>,
+    errno2: Option<u32>,
+    reason: i32,
+    syscall: Option<String>,
+}
+
+impl UssError {
+    /// Parse the `EDC5nnnI` message, `errno2=0x...` value and `call()` name z/OSMF reports,
+    /// or `None` if none of them are present.
+    fn parse(reason: i32, message: &str, details: &[String]) -> Option<Self> {
+        let texts = || std::iter::once(message).chain(details.iter().map(|d| d.as_str()));
+
+        let errno = texts().find_map(|text| {
+            text.split(|c: char| !c.is_ascii_alphanumeric())
+                .find_map(|word| word.strip_prefix("EDC5")?.strip_suffix('I')?.parse().ok())
+        });
+        let errno2 = texts().find_map(|text| {
+            let (_, rest) = text.split_once("errno2=0x")?;
+            let hex: String = rest.chars().take_while(|c| c.is_ascii_hexdigit()).collect();
+
+            u32::from_str_radix(&hex, 16).ok()
+        });
+        let syscall = texts().find_map(|text| {
+            text.split_whitespace()
+                .find_map(|word| word.strip_suffix("()"))
+                .filter(|name| !name.is_empty())
+                .map(String::from)
+        });
+
+        if errno.is_none() && errno2.is_none() && syscall.is_none() {
+            return None;
+        }
+
+        Some(UssError {
+            errno,
+            errno2,
+            reason,
+            syscall,
+        })
+    }
+
+    /// The z/OS errno, e.g. `111` for `EACCES`.
+    pub fn errno(&self) -> Option<i32> {
+        self.errno
+    }
+
+    /// The symbolic name of the errno, e.g. `EACCES`, for the common ones.
+    pub fn errno_name(&self) -> Option<&'static str> {
+        let name = match self.errno? {
+            111 => "EACCES",
+            112 => "EAGAIN",
+            113 => "EBADF",
+            114 => "EBUSY",
+            117 => "EEXIST",
+            119 => "EFBIG",
+            121 => "EINVAL",
+            122 => "EIO",
+            123 => "EISDIR",
+            124 => "EMFILE",
+            126 => "ENAMETOOLONG",
+            129 => "ENOENT",
+            132 => "ENOMEM",
+            133 => "ENOSPC",
+            135 => "ENOTDIR",
+            136 => "ENOTEMPTY",
+            139 => "EPERM",
+            141 => "EROFS",
+            144 => "EXDEV",
+            146 => "ELOOP",
+            _ => return None,
+        };
+
+        Some(name)
+    }
+
+    /// The reason code that identifies where in z/OS UNIX the call failed.
+    pub fn errno2(&self) -> Option<u32> {
+        self.errno2
+    }
+
+    /// The reason code z/OSMF returned with the error.
+    pub fn reason(&self) -> i32 {
+        self.reason
+    }
+
+    /// The name of the call that failed, e.g. `open`.
+    pub fn syscall(&self) -> Option<&str> {
+        self.syscall.as_deref()
+    }
+
+    pub fn is_permission_denied(&self) -> bool {
+        matches!(self.errno_name(), Some("EACCES" | "EPERM"))
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        self.errno_name() == Some("ENOENT")
+    }
+
+    pub fn is_exists(&self) -> bool {
+        self.errno_name() == Some("EEXIST")
+    }
+
+    pub fn is_no_space(&self) -> bool {
+        self.errno_name() == Some("ENOSPC")
+    }
 }
 
 pub trait CheckStatus {
@@ -95,6 +266,9 @@ impl CheckStatus for reqwest::Response {
                     })
                 })?;
 
+                let uss = UssError::parse(reason, &message, details.as_deref().unwrap_or_default())
+                    .map(Box::new);
+
                 return Err(Error::Api(ApiError::Json {
                     url,
                     status,
@@ -103,6 +277,7 @@ impl CheckStatus for reqwest::Response {
                     reason,
                     message,
                     details,
+                    uss,
                 }));
             }
         }
@@ -121,3 +296,52 @@ struct ErrorJson {
     #[serde(default)]
     details: Option<Vec<String>>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uss_error() {
+        let uss = UssError::parse(
+            93651005,
+            "open() error",
+            &["EDC5111I Permission denied. (errno2=0x5B4B0002)".to_string()],
+        )
+        .unwrap();
+
+        assert_eq!(uss.errno(), Some(111));
+        assert_eq!(uss.errno_name(), Some("EACCES"));
+        assert_eq!(uss.errno2(), Some(0x5B4B0002));
+        assert_eq!(uss.reason(), 93651005);
+        assert_eq!(uss.syscall(), Some("open"));
+        assert!(uss.is_permission_denied());
+        assert!(!uss.is_no_space());
+
+        let uss = UssError::parse(
+            0,
+            "write failed",
+            &["EDC5133I No space left on device.".to_string()],
+        )
+        .unwrap();
+        assert!(uss.is_no_space());
+        assert_eq!(uss.syscall(), None);
+
+        assert_eq!(UssError::parse(0, "Data set not found", &[]), None);
+    }
+
+    #[test]
+    fn retryable() {
+        let api_error = |status| {
+            Error::Api(ApiError::Text {
+                url: "https://test.com/zosmf/restjobs/jobs/TESTJOB/JOB00023".into(),
+                status,
+                body: String::new(),
+            })
+        };
+
+        assert!(api_error(reqwest::StatusCode::SERVICE_UNAVAILABLE).is_retryable());
+        assert!(!api_error(reqwest::StatusCode::NOT_FOUND).is_retryable());
+        assert!(!Error::InvalidValue("bad status".into()).is_retryable());
+    }
+}
@@ -1,17 +1,36 @@
+pub mod acl;
 pub mod copy;
 pub mod copy_dataset;
 pub mod create;
+pub mod create_dir;
 pub mod delete;
+pub mod delete_matching;
+pub mod digest;
+pub mod disk_usage;
+pub mod download;
+pub mod edit;
 pub mod extra_attributes;
+pub mod filesystems;
+pub mod handle;
 pub mod link;
 pub mod list;
+pub mod lock;
 pub mod mode;
+pub mod mount;
 pub mod owner;
 pub mod read;
 pub mod rename;
+pub mod resolve_symlink;
+pub mod sync;
 pub mod tags;
+pub mod tail;
 pub mod unlink;
+pub mod unmount;
+pub mod upload;
+pub mod walk;
+pub mod watch;
 pub mod write;
+pub mod zfs;
 
 use std::sync::Arc;
 
@@ -20,24 +39,44 @@ use serde::{Deserialize, Serialize};
 use crate::restfiles::Etag;
 use crate::{ClientCore, Result};
 
+use self::acl::{FileAcl, FileAclGetBuilder, FileAclSetBuilder};
 use self::copy::FileCopyBuilder;
 use self::copy_dataset::FileCopyDatasetBuilder;
 use self::create::FileCreateBuilder;
+use self::create_dir::FileCreateDirBuilder;
 use self::delete::FileDeleteBuilder;
+use self::delete_matching::FileDeleteMatchingBuilder;
+use self::digest::FileDigestBuilder;
+use self::disk_usage::FileDiskUsageBuilder;
+use self::download::FileDownloadBuilder;
+use self::edit::FileEditBuilder;
 use self::extra_attributes::reset::FileExtraAttributesResetBuilder;
 use self::extra_attributes::set::FileExtraAttributesSetBuilder;
 use self::extra_attributes::{FileExtraAttributeList, FileExtraAttributeListBuilder};
+use self::filesystems::{FileSystemList, FileSystemListBuilder};
+use self::handle::FileHandle;
 use self::link::{FileLinkBuilder, FileLinkType};
 use self::list::{FileList, FileListBuilder};
+use self::lock::FileLocks;
 use self::mode::FileChangeModeBuilder;
+use self::mount::FileMountBuilder;
 use self::owner::FileChangeOwnerBuilder;
 use self::read::{FileRead, FileReadBuilder};
 use self::rename::FileRenameBuilder;
+use self::resolve_symlink::FileResolveSymlinkBuilder;
+use self::sync::FileSyncBuilder;
 use self::tags::remove::FileTagsRemoveBuilder;
 use self::tags::set::FileTagsSetBuilder;
 use self::tags::{FileTagList, FileTagListBuilder};
+use self::tail::FileTailBuilder;
 use self::unlink::FileUnlinkBuilder;
+use self::unmount::FileUnmountBuilder;
+use self::upload::FileUploadBuilder;
+use self::walk::FileWalkBuilder;
+use self::watch::FileWatchBuilder;
 use self::write::FileWriteBuilder;
+use self::zfs::create::FileZfsCreateBuilder;
+use self::zfs::delete::FileZfsDeleteBuilder;
 
 #[derive(Clone, Debug)]
 pub struct FilesClient {
@@ -76,6 +115,22 @@ impl FilesClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Apply a typed mode to a directory tree without following symbolic links:
+    /// ```
+    /// # use z_osmf::files::mode::{FileChangeModeLinks, FileMode};
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let mode: FileMode = "rwxr-x---".parse()?;
+    /// let change_mode = zosmf
+    ///     .files()
+    ///     .change_mode("/u/jiahj/app", mode)
+    ///     .links(FileChangeModeLinks::Suppress)
+    ///     .recursive(true)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn change_mode<P, M>(&self, path: P, mode: M) -> FileChangeModeBuilder<String>
     where
         P: std::fmt::Display,
@@ -239,368 +294,1230 @@ impl FilesClient {
 
     /// # Examples
     ///
-    /// Delete a file:
-    /// ```
-    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let delete_file = zosmf
-    ///     .files()
-    ///     .delete("/u/jiahj/text.txt")
-    ///     .build()
-    ///     .await?;
-    /// # Ok(())
-    /// # }
-    /// ```
-    ///
-    /// Delete a directory:
+    /// Create a directory and any missing parents:
     /// ```
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let delete_file = zosmf
+    /// let create_dir = zosmf
     ///     .files()
-    ///     .delete("/u/jiahj/testDir")
+    ///     .create_dir("/u/jiahj/app/logs")
+    ///     .mode("755")
+    ///     .recursive(true)
     ///     .build()
     ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn delete<P>(&self, path: P) -> FileDeleteBuilder<String>
+    pub fn create_dir<P>(&self, path: P) -> FileCreateDirBuilder
     where
         P: std::fmt::Display,
     {
-        FileDeleteBuilder::new(self.core.clone(), path)
+        FileCreateDirBuilder::new(self.core.clone(), path)
     }
 
     /// # Examples
     ///
-    /// Get the extra attributes of a file:
+    /// Allocate a zFS file system on specific volumes:
     /// ```
+    /// # use z_osmf::files::mode::FileMode;
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let file_extra_attributes = zosmf
+    /// let create_zfs = zosmf
     ///     .files()
-    ///     .get_extra_attributes("/u/jiahj/testFile.txt")
+    ///     .create_zfs("OMVS.APP.ZFS", 10)
+    ///     .secondary_cylinders(2)
+    ///     .volumes(&["ZFS001", "ZFS002"])
+    ///     .owner("IBMUSER")
+    ///     .perms(FileMode::new(0o755))
+    ///     .build()
     ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_extra_attributes<P>(&self, path: P) -> Result<FileExtraAttributeList>
-    where
-        P: std::fmt::Display,
-    {
-        FileExtraAttributeListBuilder::new(self.core.clone(), path)
-            .build()
-            .await
-    }
-
-    /// # Examples
     ///
-    /// Link a file or directory:
+    /// Allocate a zFS file system that grows by 50 cylinders at a time as it fills:
     /// ```
-    /// # use z_osmf::files::link::FileLinkType;
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let file_link = zosmf
+    /// let create_zfs = zosmf
     ///     .files()
-    ///     .link(FileLinkType::Symbol, "/u/jiahj/sourceFile.txt", "/u/jiahj/targetFile.txt")
+    ///     .create_zfs("OMVS.LOGS.ZFS", 100)
+    ///     .secondary_cylinders(50)
     ///     .build()
     ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn link<S, T>(
-        &self,
-        link_type: FileLinkType,
-        source_path: S,
-        target_path: T,
-    ) -> FileLinkBuilder<String>
+    pub fn create_zfs<N>(&self, name: N, primary_cylinders: i32) -> FileZfsCreateBuilder<String>
     where
-        S: std::fmt::Display,
-        T: std::fmt::Display,
+        N: std::fmt::Display,
     {
-        FileLinkBuilder::new(self.core.clone(), source_path, target_path, link_type)
+        FileZfsCreateBuilder::new(self.core.clone(), name, primary_cylinders)
     }
 
     /// # Examples
     ///
-    /// List files and directories:
+    /// Delete a file:
     /// ```
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let list_files = zosmf
+    /// let delete_file = zosmf
     ///     .files()
-    ///     .list("/usr")
+    ///     .delete("/u/jiahj/text.txt")
     ///     .build()
     ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
     ///
-    /// List a single file:
+    /// Delete a directory:
     /// ```
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let list_files = zosmf
+    /// let delete_file = zosmf
     ///     .files()
-    ///     .list("/u/ibmuser/myFile.txt")
+    ///     .delete("/u/jiahj/testDir")
     ///     .build()
     ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
     ///
-    /// List files filtering by name:
+    /// Delete a directory and everything in it:
     /// ```
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let list_files = zosmf
+    /// let delete_tree = zosmf
     ///     .files()
-    ///     .list("/usr/include")
-    ///     .name("f*.h")
+    ///     .delete("/u/jiahj/testDir")
+    ///     .recursive(true)
+    ///     .confirm_recursive(true)
     ///     .build()
     ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn list<P>(&self, path: P) -> FileListBuilder<FileList>
+    pub fn delete<P>(&self, path: P) -> FileDeleteBuilder<String>
     where
         P: std::fmt::Display,
     {
-        FileListBuilder::new(self.core.clone(), path)
+        FileDeleteBuilder::new(self.core.clone(), path)
     }
 
     /// # Examples
     ///
-    /// List the tag of a file:
+    /// Preview, then clean up, stale files in a transfer directory:
     /// ```
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let list_tag = zosmf
+    /// let cleanup = zosmf
     ///     .files()
-    ///     .list_tag("/u/jiahj/text.txt")
-    ///     .build()
-    ///     .await?;
+    ///     .delete_matching("/u/exchange")
+    ///     .pattern("*.tmp")
+    ///     .pattern("outbound/*.xml")
+    ///     .older_than(chrono::TimeDelta::days(7));
+    ///
+    /// for path in cleanup.clone().dry_run(true).build().await?.planned() {
+    ///     println!("would delete {}", path.display());
+    /// }
+    ///
+    /// let report = cleanup.build().await?;
     /// # Ok(())
     /// # }
     /// ```
+    pub fn delete_matching<P>(&self, path: P) -> FileDeleteMatchingBuilder
+    where
+        P: AsRef<std::path::Path>,
+    {
+        FileDeleteMatchingBuilder::new(self.core.clone(), path)
+    }
+
+    /// # Examples
     ///
-    /// List the tags of files in a directory:
+    /// Delete an unmounted zFS file system:
     /// ```
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let list_tag = zosmf
+    /// let delete_zfs = zosmf
     ///     .files()
-    ///     .list_tag("/u/jiahj/testDir")
-    ///     .recursive(true)
+    ///     .delete_zfs("OMVS.APP.ZFS")
     ///     .build()
     ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn list_tag<P>(&self, path: P) -> FileTagListBuilder<FileTagList>
+    pub fn delete_zfs<N>(&self, name: N) -> FileZfsDeleteBuilder<String>
     where
-        P: std::fmt::Display,
+        N: std::fmt::Display,
     {
-        FileTagListBuilder::new(self.core.clone(), path)
+        FileZfsDeleteBuilder::new(self.core.clone(), name)
     }
 
     /// # Examples
     ///
-    /// Read a file:
+    /// Verify that an uploaded file arrived intact:
     /// ```
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let read_file = zosmf
+    /// let digest = zosmf
     ///     .files()
-    ///     .read("/etc/inetd.conf")
+    ///     .digest("/u/jiahj/app.tar")
+    ///     .compare_to("./app.tar")
     ///     .build()
     ///     .await?;
+    ///
+    /// anyhow::ensure!(
+    ///     digest.is_verified() == Some(true),
+    ///     "checksum mismatch: {}",
+    ///     digest.sha256_hex()
+    /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub fn read<P>(&self, path: P) -> FileReadBuilder<FileRead<Arc<str>>>
+    pub fn digest<P>(&self, path: P) -> FileDigestBuilder
     where
         P: std::fmt::Display,
     {
-        FileReadBuilder::new(self.core.clone(), path)
+        FileDigestBuilder::new(self.core.clone(), path)
     }
 
     /// # Examples
     ///
-    /// Remove the tag on a file:
+    /// Find the largest directories below a home directory:
     /// ```
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let remove_tag = zosmf
+    /// let usage = zosmf
     ///     .files()
-    ///     .remove_tag("/u/jiahj/test.txt")
+    ///     .disk_usage("/u/ibmuser")
+    ///     .max_depth(2)
     ///     .build()
     ///     .await?;
+    ///
+    /// for entry in usage.largest().take(10) {
+    ///     println!("{:>12} {}", entry.size(), entry.path().display());
+    /// }
     /// # Ok(())
     /// # }
     /// ```
+    pub fn disk_usage<P>(&self, path: P) -> FileDiskUsageBuilder
+    where
+        P: AsRef<std::path::Path>,
+    {
+        FileDiskUsageBuilder::new(self.core.clone(), path)
+    }
+
+    /// # Examples
     ///
-    /// Remove the tag on all files in a directory:
+    /// Stream a large file to disk, logging progress:
     /// ```
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let remove_tag = zosmf
+    /// let download = zosmf
     ///     .files()
-    ///     .remove_tag("/u/jiahj/testDir")
-    ///     .recursive(true)
+    ///     .download("/u/jiahj/app.tar", "./app.tar")
+    ///     .progress(|progress| match progress.total {
+    ///         Some(total) => println!("{}/{} bytes", progress.transferred, total),
+    ///         None => println!("{} bytes", progress.transferred),
+    ///     })
     ///     .build()
     ///     .await?;
+    ///
+    /// println!("{:?} {:?}", download.size(), download.etag());
     /// # Ok(())
     /// # }
     /// ```
-    pub fn remove_tag<P>(&self, path: P) -> FileTagsRemoveBuilder<String>
+    pub fn download<P, L>(&self, path: P, local_path: L) -> FileDownloadBuilder
     where
         P: std::fmt::Display,
+        L: AsRef<std::path::Path>,
     {
-        FileTagsRemoveBuilder::new(self.core.clone(), path)
+        FileDownloadBuilder::new(self.core.clone(), path, local_path)
     }
 
     /// # Examples
     ///
-    /// Rename (move) a file:
+    /// Safely change a setting in a configuration file:
     /// ```
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let file_rename = zosmf
+    /// let edit = zosmf
     ///     .files()
-    ///     .rename("/u/jiahj/test.txt", "/u/jiahj/test2.txt")
-    ///     .build()
+    ///     .edit("/etc/app.conf")
+    ///     .build(|text| text.replace("debug=false", "debug=true"))
     ///     .await?;
+    ///
+    /// if edit.is_none() {
+    ///     println!("already enabled");
+    /// }
     /// # Ok(())
     /// # }
     /// ```
+    pub fn edit<P>(&self, path: P) -> FileEditBuilder
+    where
+        P: std::fmt::Display,
+    {
+        FileEditBuilder::new(self.core.clone(), path)
+    }
+
+    /// # Examples
     ///
-    /// Rename (move) a file and overwrite the target, if it exists:
+    /// Work with one file, giving its path and encoding once:
     /// ```
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let file_rename = zosmf
-    ///     .files()
-    ///     .rename("/u/jiahj/test.txt", "/u/jiahj/test2.txt")
-    ///     .overwrite(true)
+    /// let config = zosmf.files().file("/u/jiahj/app.conf").encoding("IBM-1047");
+    ///
+    /// let attributes = config.stat().await?;
+    /// let read = config.read().build().await?;
+    /// let etag = config
+    ///     .write()
+    ///     .text(read.data().replace("debug=false", "debug=true"))
     ///     .build()
     ///     .await?;
+    /// config.chmod("640").build().await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn rename<F, T>(&self, from_path: F, to_path: T) -> FileRenameBuilder<String>
+    pub fn file<P>(&self, path: P) -> FileHandle
     where
-        F: std::fmt::Display,
-        T: std::fmt::Display,
+        P: std::fmt::Display,
     {
-        FileRenameBuilder::new(self.core.clone(), from_path, to_path)
+        FileHandle::new(self.core.clone(), path)
     }
 
     /// # Examples
     ///
-    /// Remove extra attributes from a file:
+    /// Get the access control list of a directory:
     /// ```
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let file_reset_extra_attributes = zosmf
-    ///     .files()
-    ///     .reset_extra_attributes("/u/jiahj/testFile.txt")
-    ///     .apf_authorized(true)
-    ///     .shared_library(true)
-    ///     .build()
-    ///     .await?;
+    /// let acl = zosmf.files().get_acl("/u/jiahj/app").build().await?;
+    /// for entry in acl.entries().iter() {
+    ///     println!("{}", entry);
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub fn reset_extra_attributes<P>(&self, path: P) -> FileExtraAttributesResetBuilder<String>
+    pub fn get_acl<P>(&self, path: P) -> FileAclGetBuilder<FileAcl>
     where
         P: std::fmt::Display,
     {
-        FileExtraAttributesResetBuilder::new(self.core.clone(), path)
+        FileAclGetBuilder::new(self.core.clone(), path)
     }
 
     /// # Examples
     ///
-    /// Add extra attributes to a file:
+    /// Get the extra attributes of a file:
     /// ```
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let file_set_extra_attributes = zosmf
+    /// let file_extra_attributes = zosmf
     ///     .files()
-    ///     .set_extra_attributes("/u/jiahj/testFile.txt")
-    ///     .program_controlled(true)
-    ///     .shared_address_space(true)
-    ///     .build()
+    ///     .get_extra_attributes("/u/jiahj/testFile.txt")
     ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn set_extra_attributes<P>(&self, path: P) -> FileExtraAttributesSetBuilder<String>
+    pub async fn get_extra_attributes<P>(&self, path: P) -> Result<FileExtraAttributeList>
     where
         P: std::fmt::Display,
     {
-        FileExtraAttributesSetBuilder::new(self.core.clone(), path)
+        FileExtraAttributeListBuilder::new(self.core.clone(), path)
+            .build()
+            .await
     }
 
     /// # Examples
     ///
-    /// Set the tag on a file:
+    /// Create a hard link to a file:
     /// ```
-    /// # use z_osmf::files::tags::FileTagType;
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let set_tag = zosmf
+    /// let hard_link = zosmf
     ///     .files()
-    ///     .set_tag("/u/jiahj/test.txt")
-    ///     .tag_type(FileTagType::Text)
-    ///     .code_set("IBM-1047")
+    ///     .hard_link("/u/jiahj/sourceFile.txt", "/u/jiahj/hardLink.txt")
     ///     .build()
     ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
+    pub fn hard_link<S, T>(&self, source_path: S, target_path: T) -> FileLinkBuilder<String>
+    where
+        S: std::fmt::Display,
+        T: std::fmt::Display,
+    {
+        FileLinkBuilder::new(self.core.clone(), source_path, target_path)
+    }
+
+    /// # Examples
     ///
-    /// Set the tag on all files in a directory:
+    /// Link a file or directory:
     /// ```
-    /// # use z_osmf::files::tags::FileTagType;
+    /// # use z_osmf::files::link::FileLinkType;
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let set_tag = zosmf
+    /// let file_link = zosmf
     ///     .files()
-    ///     .set_tag("/u/jiahj/testDir")
-    ///     .tag_type(FileTagType::Text)
-    ///     .code_set("IBM-1047")
-    ///     .recursive(true)
+    ///     .link(FileLinkType::Symbol, "/u/jiahj/sourceFile.txt", "/u/jiahj/targetFile.txt")
     ///     .build()
     ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn set_tag<P>(&self, path: P) -> FileTagsSetBuilder<String>
+    pub fn link<S, T>(
+        &self,
+        link_type: FileLinkType,
+        source_path: S,
+        target_path: T,
+    ) -> FileLinkBuilder<String>
     where
-        P: std::fmt::Display,
+        S: std::fmt::Display,
+        T: std::fmt::Display,
     {
-        FileTagsSetBuilder::new(self.core.clone(), path)
+        FileLinkBuilder::new(self.core.clone(), source_path, target_path).link_type(link_type)
     }
 
     /// # Examples
     ///
-    /// Unlink a file or directory:
+    /// List files and directories:
     /// ```
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// let file_unlink = zosmf
+    /// let list_files = zosmf
     ///     .files()
-    ///     .unlink("/u/jiahj/targetFile.txt")
+    ///     .list("/usr")
+    ///     .build()
     ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn unlink<P>(&self, path: P) -> Result<String>
-    where
-        P: std::fmt::Display,
-    {
-        FileUnlinkBuilder::new(self.core.clone(), path)
-            .build()
-            .await
-    }
-
-    /// # Examples
     ///
-    /// Write to a file:
+    /// List a single file:
     /// ```
     /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
-    /// # let text_data = "";
-    /// let write_file = zosmf
+    /// let list_files = zosmf
     ///     .files()
-    ///     .write("/etc/inetd.conf")
-    ///     .text(text_data)
+    ///     .list("/u/ibmuser/myFile.txt")
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// List files filtering by name:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let list_files = zosmf
+    ///     .files()
+    ///     .list("/usr/include")
+    ///     .name("f*.h")
     ///     .build()
     ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// List a directory with the tag of each file, to choose how to download it:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let list_files = zosmf
+    ///     .files()
+    ///     .list("/u/ibmuser/src")
+    ///     .build_with_tags()
+    ///     .await?;
+    ///
+    /// for item in list_files.items().iter() {
+    ///     let text = item.tag().is_some_and(|tag| tag.is_text());
+    ///     println!("{} text={}", item.name(), text);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// List log files that have not been modified for 30 days:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let stale_logs = zosmf
+    ///     .files()
+    ///     .list("/u/ibmuser/logs")
+    ///     .name("*.log")
+    ///     .modified_older_than(chrono::TimeDelta::days(30))
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// List a large directory in full, even past the default limit of 1000 entries:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let list = zosmf
+    ///     .files()
+    ///     .list("/u/ibmuser/spool")
+    ///     .build_complete()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// List logs between 1 MiB and 2 GiB that have not changed in a month:
+    /// ```
+    /// # use chrono::TimeDelta;
+    /// # use z_osmf::files::list::{FileFilter, FileSize};
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let list = zosmf
+    ///     .files()
+    ///     .list("/u/ibmuser/logs")
+    ///     .size(FileFilter::Between(FileSize::Megabytes(1), FileSize::Gigabytes(2)))
+    ///     .modified_older_than(TimeDelta::days(30))
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Find the newest log in a directory:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let list = zosmf.files().list("/u/ibmuser/logs").build().await?;
+    ///
+    /// let newest = list
+    ///     .sorted_by_mtime()
+    ///     .filter(|item| item.is_file())
+    ///     .last();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list<P>(&self, path: P) -> FileListBuilder<FileList>
+    where
+        P: std::fmt::Display,
+    {
+        FileListBuilder::new(self.core.clone(), path)
+    }
+
+    /// # Examples
+    ///
+    /// Report free space in the mounted zFS aggregates of an application:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let filesystems = zosmf
+    ///     .files()
+    ///     .list_filesystems()
+    ///     .name("OMVS.APP.*")
+    ///     .build()
+    ///     .await?;
+    ///
+    /// for filesystem in filesystems.items().iter().filter(|fs| fs.is_zfs()) {
+    ///     println!("{}: {:?} bytes free", filesystem.name(), filesystem.free_bytes());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_filesystems(&self) -> FileSystemListBuilder<FileSystemList> {
+        FileSystemListBuilder::new(self.core.clone())
+    }
+
+    /// # Examples
+    ///
+    /// List the tag of a file:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let list_tag = zosmf
+    ///     .files()
+    ///     .list_tag("/u/jiahj/text.txt")
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// List the tags of files in a directory:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let list_tag = zosmf
+    ///     .files()
+    ///     .list_tag("/u/jiahj/testDir")
+    ///     .recursive(true)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_tag<P>(&self, path: P) -> FileTagListBuilder<FileTagList>
+    where
+        P: std::fmt::Display,
+    {
+        FileTagListBuilder::new(self.core.clone(), path)
+    }
+
+    /// # Examples
+    ///
+    /// Let several tasks change the same configuration file one at a time:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let locks = zosmf.files().locks();
+    ///
+    /// let tasks = ["a", "b"].map(|name| {
+    ///     let locks = locks.clone();
+    ///
+    ///     tokio::spawn(async move {
+    ///         let mut guard = locks.lock("/etc/app.conf").await?;
+    ///
+    ///         guard
+    ///             .edit(|text| format!("{}worker={}\n", text, name))
+    ///             .await
+    ///     })
+    /// });
+    ///
+    /// for task in tasks {
+    ///     task.await??;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn locks(&self) -> FileLocks {
+        FileLocks::new(self.core.clone())
+    }
+
+    /// # Examples
+    ///
+    /// Mount a zFS file system read-only:
+    /// ```
+    /// # use z_osmf::files::mount::FileMountMode;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let mount = zosmf
+    ///     .files()
+    ///     .mount("OMVS.APP.ZFS", "/u/jiahj/app")
+    ///     .mode(FileMountMode::ReadOnly)
+    ///     .setuid(false)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mount<F, M>(&self, filesystem: F, mount_point: M) -> FileMountBuilder<String>
+    where
+        F: std::fmt::Display,
+        M: std::fmt::Display,
+    {
+        FileMountBuilder::new(self.core.clone(), filesystem, mount_point)
+    }
+
+    /// # Examples
+    ///
+    /// Read a file:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let read_file = zosmf
+    ///     .files()
+    ///     .read("/etc/inetd.conf")
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Read the last 100 lines of a log:
+    /// ```
+    /// # use std::num::NonZeroU32;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let tail = zosmf
+    ///     .files()
+    ///     .read("/u/jiahj/app.log")
+    ///     .last_records(NonZeroU32::new(100).unwrap())
+    ///     .await?
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Find the first error in a log, with line numbers:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let search = zosmf
+    ///     .files()
+    ///     .read("/u/jiahj/app.log")
+    ///     .search("ERROR")
+    ///     .search_max_return(50)
+    ///     .build_search()
+    ///     .await?;
+    ///
+    /// for line in search.matches() {
+    ///     println!("{:?}: {}", line.number(), line.text());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Fall back to the raw bytes when a file is not valid UTF-8:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let read = zosmf.files().read("/u/jiahj/notes.txt");
+    ///
+    /// match read.clone().build_strict().await {
+    ///     Ok(read) => println!("{}", read.data()),
+    ///     Err(z_osmf::Error::Utf8 { offset, .. }) => {
+    ///         let raw = read.build_raw().await?;
+    ///         println!("invalid at byte {}, tagged {:?}", offset, raw.ccsid());
+    ///     }
+    ///     Err(err) => return Err(err.into()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read<P>(&self, path: P) -> FileReadBuilder<FileRead<Arc<str>>>
+    where
+        P: std::fmt::Display,
+    {
+        FileReadBuilder::new(self.core.clone(), path)
+    }
+
+    /// # Examples
+    ///
+    /// Remove the tag on a file:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let remove_tag = zosmf
+    ///     .files()
+    ///     .remove_tag("/u/jiahj/test.txt")
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Remove the tag on all files in a directory:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let remove_tag = zosmf
+    ///     .files()
+    ///     .remove_tag("/u/jiahj/testDir")
+    ///     .recursive(true)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn remove_tag<P>(&self, path: P) -> FileTagsRemoveBuilder<String>
+    where
+        P: std::fmt::Display,
+    {
+        FileTagsRemoveBuilder::new(self.core.clone(), path)
+    }
+
+    /// # Examples
+    ///
+    /// Rename (move) a file:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let file_rename = zosmf
+    ///     .files()
+    ///     .rename("/u/jiahj/test.txt", "/u/jiahj/test2.txt")
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Rename (move) a file and overwrite the target, if it exists:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let file_rename = zosmf
+    ///     .files()
+    ///     .rename("/u/jiahj/test.txt", "/u/jiahj/test2.txt")
+    ///     .overwrite(true)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Deploy a new version of a file by staging it beside the target and moving it into place,
+    /// so readers never see a partially written file:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// # let config = "";
+    /// zosmf
+    ///     .files()
+    ///     .write("/u/jiahj/app/app.conf.new")
+    ///     .text(config)
+    ///     .build()
+    ///     .await?;
+    /// zosmf
+    ///     .files()
+    ///     .rename("/u/jiahj/app/app.conf.new", "/u/jiahj/app/app.conf")
+    ///     .overwrite(true)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rename<F, T>(&self, from_path: F, to_path: T) -> FileRenameBuilder<String>
+    where
+        F: std::fmt::Display,
+        T: std::fmt::Display,
+    {
+        FileRenameBuilder::new(self.core.clone(), from_path, to_path)
+    }
+
+    /// # Examples
+    ///
+    /// Remove extra attributes from a file:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let file_reset_extra_attributes = zosmf
+    ///     .files()
+    ///     .reset_extra_attributes("/u/jiahj/testFile.txt")
+    ///     .apf_authorized(true)
+    ///     .shared_library(true)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reset_extra_attributes<P>(&self, path: P) -> FileExtraAttributesResetBuilder<String>
+    where
+        P: std::fmt::Display,
+    {
+        FileExtraAttributesResetBuilder::new(self.core.clone(), path)
+    }
+
+    /// # Examples
+    ///
+    /// Find where a release symlink points and whether it dangles:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let resolved = zosmf
+    ///     .files()
+    ///     .resolve_symlink("/u/jiahj/app/current")
+    ///     .build()
+    ///     .await?;
+    ///
+    /// if !resolved.exists() {
+    ///     println!("{} is missing", resolved.target());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn resolve_symlink<P>(&self, path: P) -> FileResolveSymlinkBuilder
+    where
+        P: std::fmt::Display,
+    {
+        FileResolveSymlinkBuilder::new(self.core.clone(), path)
+    }
+
+    /// # Examples
+    ///
+    /// Grant a user access to a directory and to the files later created in it:
+    /// ```
+    /// # use z_osmf::files::acl::{FileAclEntry, FileAclType};
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let entry = FileAclEntry::user("IBMUSER", "r-x")?;
+    /// let set_acl = zosmf
+    ///     .files()
+    ///     .set_acl("/u/jiahj/app")
+    ///     .modify([entry.clone(), entry.with_type(FileAclType::FileDefault)])
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_acl<P>(&self, path: P) -> FileAclSetBuilder<String>
+    where
+        P: std::fmt::Display,
+    {
+        FileAclSetBuilder::new(self.core.clone(), path)
+    }
+
+    /// # Examples
+    ///
+    /// Add extra attributes to a file:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let file_set_extra_attributes = zosmf
+    ///     .files()
+    ///     .set_extra_attributes("/u/jiahj/testFile.txt")
+    ///     .program_controlled(true)
+    ///     .shared_address_space(true)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Mark a deployed executable as APF-authorized and program controlled:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// zosmf
+    ///     .files()
+    ///     .set_extra_attributes("/u/jiahj/bin/authpgm")
+    ///     .apf_authorized(true)
+    ///     .program_controlled(true)
+    ///     .build()
+    ///     .await?;
+    /// let extra_attributes = zosmf
+    ///     .files()
+    ///     .get_extra_attributes("/u/jiahj/bin/authpgm")
+    ///     .await?;
+    /// assert!(extra_attributes.apf_authorized());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_extra_attributes<P>(&self, path: P) -> FileExtraAttributesSetBuilder<String>
+    where
+        P: std::fmt::Display,
+    {
+        FileExtraAttributesSetBuilder::new(self.core.clone(), path)
+    }
+
+    /// # Examples
+    ///
+    /// Set the tag on a file:
+    /// ```
+    /// # use z_osmf::files::tags::FileTagType;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let set_tag = zosmf
+    ///     .files()
+    ///     .set_tag("/u/jiahj/test.txt")
+    ///     .tag_type(FileTagType::Text)
+    ///     .code_set("IBM-1047")
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Set the tag on all files in a directory:
+    /// ```
+    /// # use z_osmf::files::tags::FileTagType;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let set_tag = zosmf
+    ///     .files()
+    ///     .set_tag("/u/jiahj/testDir")
+    ///     .tag_type(FileTagType::Text)
+    ///     .code_set("IBM-1047")
+    ///     .recursive(true)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_tag<P>(&self, path: P) -> FileTagsSetBuilder<String>
+    where
+        P: std::fmt::Display,
+    {
+        FileTagsSetBuilder::new(self.core.clone(), path)
+    }
+
+    /// # Examples
+    ///
+    /// Point a symbolic link at a new release directory, replacing the existing link:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let symlink = zosmf
+    ///     .files()
+    ///     .symlink("/u/jiahj/app-1.2", "/u/jiahj/app")
+    ///     .force(true)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn symlink<S, T>(&self, source_path: S, target_path: T) -> FileLinkBuilder<String>
+    where
+        S: std::fmt::Display,
+        T: std::fmt::Display,
+    {
+        FileLinkBuilder::new(self.core.clone(), source_path, target_path)
+            .link_type(FileLinkType::Symbol)
+    }
+
+    /// # Examples
+    ///
+    /// Deploy only the files that changed since the last deployment, removing stale ones:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let sync = zosmf
+    ///     .files()
+    ///     .sync("./dist", "/u/jiahj/app")
+    ///     .text("*.js")
+    ///     .encoding("IBM-1047")
+    ///     .delete(true)
+    ///     .build()
+    ///     .await?;
+    ///
+    /// for item in sync.items() {
+    ///     println!("{:?} {}", item.action(), item.path());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Preview fetching a remote configuration tree:
+    /// ```
+    /// # use z_osmf::files::sync::FileSyncDirection;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let sync = zosmf
+    ///     .files()
+    ///     .sync("./config", "/etc/app")
+    ///     .direction(FileSyncDirection::Download)
+    ///     .default_type(z_osmf::files::FileDataType::Text)
+    ///     .dry_run(true)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sync<D, P>(&self, directory: D, path: P) -> FileSyncBuilder
+    where
+        D: AsRef<std::path::Path>,
+        P: std::fmt::Display,
+    {
+        FileSyncBuilder::new(self.core.clone(), directory, path)
+    }
+
+    /// # Examples
+    ///
+    /// Follow a log file like `tail -f`:
+    /// ```
+    /// # use std::time::Duration;
+    /// # use futures::TryStreamExt;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let lines = zosmf
+    ///     .files()
+    ///     .tail("/u/jiahj/app.log")
+    ///     .follow(Duration::from_secs(5));
+    /// futures::pin_mut!(lines);
+    ///
+    /// while let Some(line) = lines.try_next().await? {
+    ///     println!("{}", line);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn tail<P>(&self, path: P) -> FileTailBuilder
+    where
+        P: std::fmt::Display,
+    {
+        FileTailBuilder::new(self.core.clone(), path)
+    }
+
+    /// # Examples
+    ///
+    /// Unlink a file or directory:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let file_unlink = zosmf
+    ///     .files()
+    ///     .unlink("/u/jiahj/targetFile.txt")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unlink<P>(&self, path: P) -> Result<String>
+    where
+        P: std::fmt::Display,
+    {
+        FileUnlinkBuilder::new(self.core.clone(), path)
+            .build()
+            .await
+    }
+
+    /// # Examples
+    ///
+    /// Unmount a file system once the activity on it has finished:
+    /// ```
+    /// # use z_osmf::files::unmount::FileUnmountMode;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let unmount = zosmf
+    ///     .files()
+    ///     .unmount("OMVS.APP.ZFS")
+    ///     .mode(FileUnmountMode::Drain)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn unmount<F>(&self, filesystem: F) -> FileUnmountBuilder<String>
+    where
+        F: std::fmt::Display,
+    {
+        FileUnmountBuilder::new(self.core.clone(), filesystem)
+    }
+
+    /// # Examples
+    ///
+    /// Deploy a Node.js application tree, converting sources to EBCDIC:
+    /// ```
+    /// # use z_osmf::files::FileDataType;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let upload = zosmf
+    ///     .files()
+    ///     .upload("./dist", "/u/jiahj/app")
+    ///     .text("*.js")
+    ///     .text("*.json")
+    ///     .default_type(FileDataType::Binary)
+    ///     .mode("755")
+    ///     .tag("IBM-1047")
+    ///     .build()
+    ///     .await?;
+    ///
+    /// for item in upload.failed() {
+    ///     eprintln!("{}: {:?}", item.path(), item.error());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Deploy a new release with the modes, owners and tags of the current one:
+    /// ```
+    /// # use z_osmf::files::upload::FileUploadAttributes;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let upload = zosmf
+    ///     .files()
+    ///     .upload("./dist", "/u/jiahj/app-2.0")
+    ///     .mirror("/u/jiahj/app-1.0")
+    ///     .manifest([("bin/start.sh", FileUploadAttributes::new().mode("755"))])
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn upload<D, P>(&self, directory: D, path: P) -> FileUploadBuilder
+    where
+        D: AsRef<std::path::Path>,
+        P: std::fmt::Display,
+    {
+        FileUploadBuilder::new(self.core.clone(), directory, path)
+    }
+
+    /// # Examples
+    ///
+    /// Find large files anywhere below a home directory:
+    /// ```
+    /// # use futures::TryStreamExt;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let walk = zosmf
+    ///     .files()
+    ///     .walk("/u/ibmuser")
+    ///     .max_depth(10)
+    ///     .build();
+    /// futures::pin_mut!(walk);
+    ///
+    /// while let Some((path, attributes)) = walk.try_next().await? {
+    ///     if attributes.size().unwrap_or(0) > 100_000_000 {
+    ///         println!("{}", path.display());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn walk<P>(&self, path: P) -> FileWalkBuilder
+    where
+        P: AsRef<std::path::Path>,
+    {
+        FileWalkBuilder::new(self.core.clone(), path)
+    }
+
+    /// # Examples
+    ///
+    /// React to files arriving in an exchange directory:
+    /// ```
+    /// # use std::time::Duration;
+    /// # use futures::TryStreamExt;
+    /// # use z_osmf::files::watch::FileWatchEventKind;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let watch = zosmf
+    ///     .files()
+    ///     .watch("/u/exchange/in")
+    ///     .name("*.xml")
+    ///     .interval(Duration::from_secs(10))
+    ///     .build();
+    /// futures::pin_mut!(watch);
+    ///
+    /// while let Some(event) = watch.try_next().await? {
+    ///     if event.kind() == FileWatchEventKind::Created {
+    ///         println!("new file: {}", event.name());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch<P>(&self, path: P) -> FileWatchBuilder
+    where
+        P: std::fmt::Display,
+    {
+        FileWatchBuilder::new(self.core.clone(), path)
+    }
+
+    /// # Examples
+    ///
+    /// Write to a file:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// # let text_data = "";
+    /// let write_file = zosmf
+    ///     .files()
+    ///     .write("/etc/inetd.conf")
+    ///     .text(text_data)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Create a new file, or replace an existing one, with binary data:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let write_binary = zosmf
+    ///     .files()
+    ///     .write("/u/jiahj/newFile.bin")
+    ///     .binary(vec![0xC1, 0xC2, 0xC3])
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Upload a large file from disk without reading it into memory:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let file = tokio::fs::File::open("app.tar").await?;
+    /// let length = file.metadata().await?.len();
+    ///
+    /// let write_file = zosmf
+    ///     .files()
+    ///     .write("/u/jiahj/app.tar")
+    ///     .reader(file, Some(length))
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Write a file only if it does not exist yet, since creating it fails if it does:
+    /// ```
+    /// # use z_osmf::files::create::FileCreateType;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// zosmf
+    ///     .files()
+    ///     .create("/etc/app.conf")
+    ///     .file_type(FileCreateType::File)
+    ///     .build()
+    ///     .await?;
+    ///
+    /// let write_file = zosmf
+    ///     .files()
+    ///     .write("/etc/app.conf")
+    ///     .text("debug=false")
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Update a file only if it has not changed since it was read:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let read_file = zosmf.files().read("/u/jiahj/app.conf").build().await?;
+    /// let updated = read_file.data().replace("debug=false", "debug=true");
+    ///
+    /// let mut write_file = zosmf
+    ///     .files()
+    ///     .write("/u/jiahj/app.conf")
+    ///     .text(updated)
+    ///     .encoding("IBM-1047");
+    /// if let Some(etag) = read_file.etag() {
+    ///     write_file = write_file.if_match(etag);
+    /// }
+    /// let etag = write_file.build().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn write<P>(&self, path: P) -> FileWriteBuilder<Etag>
     where
         P: std::fmt::Display,
@@ -0,0 +1,494 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::{Endpoint, Getters};
+
+use crate::convert::TryFromResponse;
+use crate::restfiles::get_transaction_id;
+use crate::{ClientCore, Error, Result};
+
+/// Which access control list of a file or directory to operate on.
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+pub enum FileAclType {
+    /// The ACL that controls access to the file or directory itself.
+    #[default]
+    #[serde(rename = "access")]
+    Access,
+    /// The default ACL a directory gives to files created in it.
+    #[serde(rename = "fdefault")]
+    FileDefault,
+    /// The default ACL a directory gives to directories created in it.
+    #[serde(rename = "ddefault")]
+    DirectoryDefault,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileAclEntryKind {
+    User,
+    Group,
+    Other,
+}
+
+/// A single ACL entry, e.g. `user:IBMUSER:rwx` or `fdefault:group:SYS1:r-x`.
+///
+/// Entries without a qualifier (`user::rwx`) describe the owner, owning group and others.
+#[derive(Clone, Debug, Eq, Getters, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FileAclEntry {
+    #[getter(copy)]
+    acl_type: FileAclType,
+    #[getter(copy)]
+    kind: FileAclEntryKind,
+    qualifier: Option<Arc<str>>,
+    #[getter(copy)]
+    read: bool,
+    #[getter(copy)]
+    write: bool,
+    #[getter(copy)]
+    execute: bool,
+}
+
+impl FileAclEntry {
+    /// An access entry for a user, identified by user ID or UID.
+    pub fn user<U>(user: U, permissions: &str) -> Result<Self>
+    where
+        U: std::fmt::Display,
+    {
+        format!("user:{}:{}", user, permissions).parse()
+    }
+
+    /// An access entry for a group, identified by group name or GID.
+    pub fn group<G>(group: G, permissions: &str) -> Result<Self>
+    where
+        G: std::fmt::Display,
+    {
+        format!("group:{}:{}", group, permissions).parse()
+    }
+
+    /// The same entry in another ACL, e.g. to make it a default for new files.
+    pub fn with_type(self, acl_type: FileAclType) -> Self {
+        FileAclEntry { acl_type, ..self }
+    }
+}
+
+impl std::fmt::Display for FileAclEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let prefix = match self.acl_type {
+            FileAclType::Access => "",
+            FileAclType::FileDefault => "fdefault:",
+            FileAclType::DirectoryDefault => "default:",
+        };
+        let kind = match self.kind {
+            FileAclEntryKind::User => "user",
+            FileAclEntryKind::Group => "group",
+            FileAclEntryKind::Other => "other",
+        };
+        let flag = |set: bool, c: char| if set { c } else { '-' };
+
+        write!(
+            f,
+            "{}{}:{}:{}{}{}",
+            prefix,
+            kind,
+            self.qualifier.as_deref().unwrap_or(""),
+            flag(self.read, 'r'),
+            flag(self.write, 'w'),
+            flag(self.execute, 'x')
+        )
+    }
+}
+
+impl std::str::FromStr for FileAclEntry {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || Error::InvalidValue(format!("invalid ACL entry: {}", s));
+
+        let mut parts: Vec<&str> = s.trim().split(':').collect();
+
+        let acl_type = match parts.first() {
+            Some(&"fdefault") | Some(&"f") => FileAclType::FileDefault,
+            Some(&"default") | Some(&"ddefault") | Some(&"d") => FileAclType::DirectoryDefault,
+            _ => FileAclType::Access,
+        };
+        if acl_type != FileAclType::Access {
+            parts.remove(0);
+        }
+
+        let [kind, qualifier, permissions] = parts[..] else {
+            return Err(invalid());
+        };
+
+        let kind = match kind {
+            "user" | "u" => FileAclEntryKind::User,
+            "group" | "g" => FileAclEntryKind::Group,
+            "other" | "o" => FileAclEntryKind::Other,
+            _ => return Err(invalid()),
+        };
+
+        let [r, w, x] = permissions.as_bytes() else {
+            return Err(invalid());
+        };
+        let flag = |actual: u8, expected: u8| match actual {
+            b'-' => Ok(false),
+            a if a == expected => Ok(true),
+            _ => Err(invalid()),
+        };
+
+        Ok(FileAclEntry {
+            acl_type,
+            kind,
+            qualifier: (!qualifier.is_empty()).then(|| qualifier.into()),
+            read: flag(*r, b'r')?,
+            write: flag(*w, b'w')?,
+            execute: flag(*x, b'x')?,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for FileAclEntry {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for FileAclEntry {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, PartialEq, Serialize)]
+pub struct FileAcl {
+    owner: Option<Arc<str>>,
+    group: Option<Arc<str>>,
+    entries: Arc<[FileAclEntry]>,
+    transaction_id: Arc<str>,
+}
+
+impl FileAcl {
+    fn from_lines(lines: &[Arc<str>], transaction_id: Arc<str>) -> Result<Self> {
+        let mut owner = None;
+        let mut group = None;
+        let mut entries = Vec::new();
+
+        for line in lines.iter().map(|line| line.trim()) {
+            if let Some(header) = line.strip_prefix('#') {
+                match header.split_once(':') {
+                    Some(("owner", value)) => owner = Some(value.trim().into()),
+                    Some(("group", value)) => group = Some(value.trim().into()),
+                    _ => {}
+                }
+            } else if !line.is_empty() {
+                entries.push(line.parse()?);
+            }
+        }
+
+        Ok(FileAcl {
+            owner,
+            group,
+            entries: entries.into(),
+            transaction_id,
+        })
+    }
+}
+
+impl TryFromResponse for FileAcl {
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        let transaction_id = get_transaction_id(&value)?;
+
+        let json: ResponseJson = value.json().await?;
+
+        FileAcl::from_lines(&json.stdout, transaction_id)
+    }
+}
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = put, path = "/zosmf/restfiles/fs{path}")]
+pub struct FileAclGetBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    path: Arc<str>,
+    #[endpoint(builder_fn = build_get_body)]
+    acl_type: Option<FileAclType>,
+    /// Only return the entries that apply to this user.
+    #[endpoint(skip_builder)]
+    user: Option<Arc<str>>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Serialize)]
+struct GetRequestJson<'a> {
+    request: &'static str,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    acl_type: Option<FileAclType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<&'a str>,
+}
+
+fn build_get_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &FileAclGetBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    request_builder.json(&GetRequestJson {
+        request: "getfacl",
+        acl_type: builder.acl_type,
+        user: builder.user.as_deref(),
+    })
+}
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(
+    method = put,
+    path = "/zosmf/restfiles/fs{path}",
+    validate_fn = validate_set
+)]
+pub struct FileAclSetBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    path: Arc<str>,
+    #[endpoint(skip_setter, builder_fn = build_set_body)]
+    set: Option<Arc<[FileAclEntry]>>,
+    #[endpoint(skip_setter, skip_builder)]
+    modify: Option<Arc<[FileAclEntry]>>,
+    #[endpoint(skip_setter, skip_builder)]
+    delete: Option<Arc<[FileAclEntry]>>,
+    /// Remove every extended entry of an ACL.
+    #[endpoint(skip_builder)]
+    delete_type: Option<FileAclType>,
+
+    target_type: PhantomData<T>,
+}
+
+impl<T> FileAclSetBuilder<T>
+where
+    T: TryFromResponse,
+{
+    /// Replace the ACL with these entries.
+    pub fn set<I>(mut self, entries: I) -> Self
+    where
+        I: IntoIterator<Item = FileAclEntry>,
+    {
+        self.set = Some(entries.into_iter().collect());
+
+        self
+    }
+
+    /// Add these entries, or change the permissions of matching ones.
+    pub fn modify<I>(mut self, entries: I) -> Self
+    where
+        I: IntoIterator<Item = FileAclEntry>,
+    {
+        self.modify = Some(entries.into_iter().collect());
+
+        self
+    }
+
+    /// Remove the entries matching these; their permissions are ignored.
+    pub fn delete<I>(mut self, entries: I) -> Self
+    where
+        I: IntoIterator<Item = FileAclEntry>,
+    {
+        self.delete = Some(entries.into_iter().collect());
+
+        self
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct SetRequestJson {
+    request: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    set: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    modify: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delete: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delete_type: Option<FileAclType>,
+}
+
+fn build_set_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &FileAclSetBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    let join = |entries: &Option<Arc<[FileAclEntry]>>| {
+        entries.as_ref().map(|entries| {
+            entries
+                .iter()
+                .map(|entry| entry.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+    };
+
+    request_builder.json(&SetRequestJson {
+        request: "setfacl",
+        set: join(&builder.set),
+        modify: join(&builder.modify),
+        delete: join(&builder.delete),
+        delete_type: builder.delete_type,
+    })
+}
+
+fn validate_set<T>(builder: &FileAclSetBuilder<T>) -> Result<()>
+where
+    T: TryFromResponse,
+{
+    if builder.set.is_some() && (builder.modify.is_some() || builder.delete.is_some()) {
+        return Err(Error::InvalidValue(
+            "set replaces the whole ACL and cannot be combined with modify or delete".to_string(),
+        ));
+    }
+
+    if builder.set.is_none()
+        && builder.modify.is_none()
+        && builder.delete.is_none()
+        && builder.delete_type.is_none()
+    {
+        return Err(Error::InvalidValue(
+            "one of set, modify, delete or delete_type is required".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[derive(Deserialize)]
+struct ResponseJson {
+    stdout: Arc<[Arc<str>]>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn parse_entries() {
+        let entry: FileAclEntry = "user:IBMUSER:rwx".parse().unwrap();
+        assert_eq!(entry.kind(), FileAclEntryKind::User);
+        assert_eq!(entry.qualifier(), Some("IBMUSER"));
+        assert!(entry.read() && entry.write() && entry.execute());
+
+        let entry: FileAclEntry = "fdefault:group:SYS1:r-x".parse().unwrap();
+        assert_eq!(entry.acl_type(), FileAclType::FileDefault);
+        assert_eq!(entry.to_string(), "fdefault:group:SYS1:r-x");
+
+        let entry: FileAclEntry = "d:o::r--".parse().unwrap();
+        assert_eq!(entry.to_string(), "default:other::r--");
+
+        assert!("user:IBMUSER:rwz".parse::<FileAclEntry>().is_err());
+        assert!("mask::rwx".parse::<FileAclEntry>().is_err());
+    }
+
+    #[test]
+    fn parse_acl() {
+        let lines: Vec<Arc<str>> = vec![
+            "#file:  /u/jiahj/app".into(),
+            "#owner: JIAHJ".into(),
+            "#group: SYS1".into(),
+            "user::rwx".into(),
+            "group::r-x".into(),
+            "other::---".into(),
+            "user:IBMUSER:r-x".into(),
+        ];
+
+        let acl = FileAcl::from_lines(&lines, "TXID0001".into()).unwrap();
+
+        assert_eq!(acl.owner(), Some("JIAHJ"));
+        assert_eq!(acl.group(), Some("SYS1"));
+        assert_eq!(acl.entries().len(), 4);
+        assert_eq!(acl.entries()[3].qualifier(), Some("IBMUSER"));
+    }
+
+    #[test]
+    fn get_request() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .files()
+            .get_acl("/u/jiahj/app")
+            .acl_type(FileAclType::DirectoryDefault)
+            .user("IBMUSER")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(request.method(), reqwest::Method::PUT);
+        assert_eq!(
+            urlencoding::decode(request.url().as_str()).unwrap(),
+            "https://test.com/zosmf/restfiles/fs/u/jiahj/app"
+        );
+        assert_eq!(
+            request.json(),
+            Some(serde_json::json!({
+                "request": "getfacl",
+                "type": "ddefault",
+                "user": "IBMUSER"
+            }))
+        );
+    }
+
+    #[test]
+    fn set_request() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .files()
+            .set_acl("/u/jiahj/app")
+            .modify([
+                FileAclEntry::user("IBMUSER", "rwx").unwrap(),
+                FileAclEntry::group("SYS1", "r-x")
+                    .unwrap()
+                    .with_type(FileAclType::FileDefault),
+            ])
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            request.json(),
+            Some(serde_json::json!({
+                "request": "setfacl",
+                "modify": "user:IBMUSER:rwx,fdefault:group:SYS1:r-x"
+            }))
+        );
+
+        let invalid = zosmf
+            .files()
+            .set_acl("/u/jiahj/app")
+            .set([FileAclEntry::user("IBMUSER", "rwx").unwrap()])
+            .delete([FileAclEntry::group("SYS1", "---").unwrap()])
+            .get_request();
+
+        assert!(matches!(invalid, Err(Error::InvalidValue(_))));
+        assert!(zosmf.files().set_acl("/u/jiahj/app").get_request().is_err());
+    }
+}
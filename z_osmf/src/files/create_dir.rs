@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use crate::error::ApiError;
+use crate::{ClientCore, Error, Result};
+
+use super::create::{FileCreateBuilder, FileCreateType};
+
+#[derive(Clone, Debug)]
+pub struct FileCreateDirBuilder {
+    core: ClientCore,
+
+    path: Arc<str>,
+    mode: Option<Arc<str>>,
+    recursive: bool,
+}
+
+impl FileCreateDirBuilder {
+    pub(crate) fn new<P>(core: ClientCore, path: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        FileCreateDirBuilder {
+            core,
+            path: path.to_string().into(),
+            mode: None,
+            recursive: false,
+        }
+    }
+
+    /// The permissions of the new directory, e.g. `755` or `rwxr-xr-x`.
+    ///
+    /// Intermediate directories created with [`recursive`](Self::recursive) get the same mode.
+    pub fn mode<M>(self, value: M) -> Self
+    where
+        M: std::fmt::Display,
+    {
+        FileCreateDirBuilder {
+            mode: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// Create any missing parent directories, like `mkdir -p`.
+    pub fn recursive(self, value: bool) -> Self {
+        FileCreateDirBuilder {
+            recursive: value,
+            ..self
+        }
+    }
+
+    pub async fn build(self) -> Result<String> {
+        if self.recursive {
+            for parent in parents(&self.path) {
+                match self.create_builder(parent).build().await {
+                    Err(err) if !is_exists_error(&err) => return Err(err),
+                    _ => {}
+                }
+            }
+        }
+
+        self.create_builder(&self.path).build().await
+    }
+
+    fn create_builder(&self, path: &str) -> FileCreateBuilder<String> {
+        let builder =
+            FileCreateBuilder::new(self.core.clone(), path).file_type(FileCreateType::Directory);
+
+        match &self.mode {
+            Some(mode) => builder.mode(mode),
+            None => builder,
+        }
+    }
+}
+
+/// The ancestors of an absolute path below the root, outermost first.
+fn parents(path: &str) -> impl Iterator<Item = &str> {
+    let path = path.trim_end_matches('/');
+
+    path.match_indices('/')
+        .map(|(i, _)| &path[..i])
+        .filter(|parent| !parent.is_empty() && !parent.ends_with('/'))
+}
+
+pub(super) fn is_exists_error(err: &Error) -> bool {
+    let Error::Api(api_error) = err else {
+        return false;
+    };
+
+    match api_error {
+        ApiError::Json {
+            message, details, ..
+        } => std::iter::once(message)
+            .chain(details.iter().flatten())
+            .any(|m| m.to_uppercase().contains("EXISTS")),
+        ApiError::Text { body, .. } => body.to_uppercase().contains("EXISTS"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn create_request() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .post("https://test.com/zosmf/restfiles/fs/u/jiahj/newdir")
+            .json(&serde_json::json!({
+                "type": "directory",
+                "mode": "755"
+            }))
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .create_dir("/u/jiahj/newdir")
+            .mode("755")
+            .create_builder("/u/jiahj/newdir")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(manual_request.method(), request.method());
+        assert_eq!(
+            urlencoding::decode(manual_request.url().as_str()).unwrap(),
+            urlencoding::decode(request.url().as_str()).unwrap()
+        );
+        assert_eq!(manual_request.json(), request.json());
+    }
+
+    #[test]
+    fn parent_paths() {
+        assert_eq!(
+            parents("/u/jiahj/a/b/").collect::<Vec<_>>(),
+            vec!["/u", "/u/jiahj", "/u/jiahj/a"]
+        );
+        assert_eq!(parents("/u").count(), 0);
+    }
+
+    #[test]
+    fn exists_error() {
+        let err = Error::Api(ApiError::Json {
+            url: "https://test.com/zosmf/restfiles/fs/u".to_string(),
+            status: reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+            category: 1,
+            return_code: 8,
+            reason: 0,
+            message: "mkdir failed".to_string(),
+            details: Some(vec!["EDC5117I File exists.".to_string()]),
+            uss: None,
+        });
+
+        assert!(is_exists_error(&err));
+        assert!(!is_exists_error(&Error::NoEtag));
+    }
+}
@@ -4,10 +4,14 @@ use std::sync::Arc;
 use z_osmf_macros::Endpoint;
 
 use crate::convert::TryFromResponse;
-use crate::ClientCore;
+use crate::{ClientCore, Error, Result};
 
 #[derive(Clone, Debug, Endpoint)]
-#[endpoint(method = delete, path = "/zosmf/restfiles/fs{path}")]
+#[endpoint(
+    method = delete,
+    path = "/zosmf/restfiles/fs{path}",
+    validate_fn = validate
+)]
 pub struct FileDeleteBuilder<T>
 where
     T: TryFromResponse,
@@ -18,6 +22,9 @@ where
     path: Arc<str>,
     #[endpoint(builder_fn = build_recursive)]
     recursive: Option<bool>,
+    /// Must be set alongside `recursive`, so a directory tree is never removed by accident.
+    #[endpoint(skip_builder)]
+    confirm_recursive: Option<bool>,
 
     target_type: PhantomData<T>,
 }
@@ -35,10 +42,36 @@ where
     }
 }
 
+fn validate<T>(builder: &FileDeleteBuilder<T>) -> Result<()>
+where
+    T: TryFromResponse,
+{
+    if builder.recursive != Some(true) {
+        return Ok(());
+    }
+
+    if builder.confirm_recursive != Some(true) {
+        return Err(Error::InvalidValue(format!(
+            "recursive delete of {} requires confirm_recursive",
+            builder.path
+        )));
+    }
+
+    if builder.path.trim_end_matches('/').is_empty() {
+        return Err(Error::InvalidValue(
+            "refusing to recursively delete the root directory".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use crate::tests::*;
 
+    use super::*;
+
     #[test]
     fn example_1() {
         let zosmf = get_zosmf();
@@ -78,6 +111,7 @@ mod tests {
             .files()
             .delete("/u/jiahj/testDir")
             .recursive(true)
+            .confirm_recursive(true)
             .get_request()
             .unwrap();
 
@@ -86,4 +120,26 @@ mod tests {
             format!("{:?}", delete_file)
         )
     }
+
+    #[test]
+    fn recursive_requires_confirmation() {
+        let zosmf = get_zosmf();
+
+        let unconfirmed = zosmf
+            .files()
+            .delete("/u/jiahj/testDir")
+            .recursive(true)
+            .get_request();
+
+        assert!(matches!(unconfirmed, Err(Error::InvalidValue(_))));
+
+        let root = zosmf
+            .files()
+            .delete("/")
+            .recursive(true)
+            .confirm_recursive(true)
+            .get_request();
+
+        assert!(matches!(root, Err(Error::InvalidValue(_))));
+    }
 }
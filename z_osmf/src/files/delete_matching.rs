@@ -0,0 +1,309 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use chrono::{FixedOffset, TimeDelta, Utc};
+use futures::{StreamExt, TryStreamExt};
+
+use crate::utils::wildcard_match;
+use crate::{ClientCore, Error, Result};
+
+use super::delete::FileDeleteBuilder;
+use super::list::FileAttributes;
+use super::walk::FileWalkBuilder;
+
+#[derive(Clone, Debug)]
+pub struct FileDeleteMatchingBuilder {
+    core: ClientCore,
+
+    path: PathBuf,
+    patterns: Vec<Arc<str>>,
+    older_than: Option<TimeDelta>,
+    max_depth: Option<u32>,
+    server_offset: Option<FixedOffset>,
+    dry_run: bool,
+    concurrency: usize,
+}
+
+impl FileDeleteMatchingBuilder {
+    pub(crate) fn new<P>(core: ClientCore, path: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        FileDeleteMatchingBuilder {
+            core,
+            path: path.as_ref().to_path_buf(),
+            patterns: Vec::new(),
+            older_than: None,
+            max_depth: None,
+            server_offset: None,
+            dry_run: false,
+            concurrency: 4,
+        }
+    }
+
+    /// Delete files whose path below the starting directory matches a wildcard pattern,
+    /// e.g. `*.tmp` or `outbound/*.xml`. `*` also matches `/`.
+    ///
+    /// May be called more than once; a file is deleted if it matches any pattern.
+    pub fn pattern<P>(self, value: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        let mut new = self;
+        new.patterns.push(value.to_string().into());
+
+        new
+    }
+
+    /// Only delete files last modified more than `age` ago.
+    pub fn older_than(self, age: TimeDelta) -> Self {
+        FileDeleteMatchingBuilder {
+            older_than: Some(age),
+            ..self
+        }
+    }
+
+    /// How many levels below the starting directory to search, `1` for its entries only.
+    pub fn max_depth(self, value: u32) -> Self {
+        FileDeleteMatchingBuilder {
+            max_depth: Some(value),
+            ..self
+        }
+    }
+
+    /// The offset of the server's local time from UTC, so [`older_than`](Self::older_than)
+    /// compares modification times correctly when z/OSMF lists them without one.
+    pub fn server_offset(self, value: FixedOffset) -> Self {
+        FileDeleteMatchingBuilder {
+            server_offset: Some(value),
+            ..self
+        }
+    }
+
+    /// Report which files would be deleted without deleting anything.
+    pub fn dry_run(self, value: bool) -> Self {
+        FileDeleteMatchingBuilder {
+            dry_run: value,
+            ..self
+        }
+    }
+
+    /// The maximum number of delete requests in flight at once.
+    pub fn concurrency(self, value: usize) -> Self {
+        FileDeleteMatchingBuilder {
+            concurrency: value.max(1),
+            ..self
+        }
+    }
+
+    /// Only regular files are deleted; directories and symbolic links are left in place.
+    pub async fn build(self) -> Result<FileDeleteReport> {
+        if self.patterns.is_empty() {
+            return Err(Error::InvalidValue(
+                "at least one pattern is required".to_string(),
+            ));
+        }
+
+        let builder = &self;
+
+        let paths: Vec<PathBuf> = self
+            .walk_builder()
+            .build()
+            .try_filter_map(|(path, attributes)| async move {
+                Ok(builder.is_selected(&path, &attributes).then_some(path))
+            })
+            .try_collect()
+            .await?;
+
+        let items = futures::stream::iter(paths)
+            .map(|path| async move {
+                let result = match builder.dry_run {
+                    true => Ok(None),
+                    false => builder
+                        .delete_builder(&path)
+                        .build()
+                        .await
+                        .map(|txid| Some(txid.into())),
+                };
+
+                FileDeleteOutcome { path, result }
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+
+        Ok(FileDeleteReport {
+            items,
+            dry_run: self.dry_run,
+        })
+    }
+
+    fn walk_builder(&self) -> FileWalkBuilder {
+        let builder = FileWalkBuilder::new(self.core.clone(), &self.path);
+        let builder = match self.max_depth {
+            Some(max_depth) => builder.max_depth(max_depth),
+            None => builder,
+        };
+
+        match self.server_offset {
+            Some(offset) => builder.server_offset(offset),
+            None => builder,
+        }
+    }
+
+    fn delete_builder(&self, path: &Path) -> FileDeleteBuilder<String> {
+        FileDeleteBuilder::new(self.core.clone(), path.to_string_lossy())
+    }
+
+    fn is_selected(&self, path: &Path, attributes: &FileAttributes) -> bool {
+        let relative = path
+            .strip_prefix(&self.path)
+            .unwrap_or(path)
+            .to_string_lossy();
+
+        let old_enough = match (self.older_than, attributes.mtime_utc()) {
+            (None, _) => true,
+            (Some(age), Some(mtime)) => mtime < Utc::now() - age,
+            (Some(_), None) => false,
+        };
+
+        attributes.is_file()
+            && old_enough
+            && self
+                .patterns
+                .iter()
+                .any(|pattern| wildcard_match(pattern, &relative))
+    }
+}
+
+#[derive(Debug)]
+pub struct FileDeleteOutcome {
+    path: PathBuf,
+    result: Result<Option<Arc<str>>>,
+}
+
+impl FileDeleteOutcome {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The transaction id of the delete request, `None` in dry-run mode,
+    /// or the error that caused it to fail.
+    pub fn result(&self) -> std::result::Result<Option<&str>, &Error> {
+        self.result.as_ref().map(|txid| txid.as_deref())
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        matches!(self.result, Ok(Some(_)))
+    }
+}
+
+#[derive(Debug)]
+pub struct FileDeleteReport {
+    items: Vec<FileDeleteOutcome>,
+    dry_run: bool,
+}
+
+impl FileDeleteReport {
+    pub fn items(&self) -> &[FileDeleteOutcome] {
+        &self.items
+    }
+
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// The files that were, or in dry-run mode would be, deleted.
+    pub fn planned(&self) -> impl Iterator<Item = &Path> {
+        self.items
+            .iter()
+            .filter(|item| item.result.is_ok())
+            .map(|item| item.path())
+    }
+
+    pub fn deleted(&self) -> impl Iterator<Item = &Path> {
+        self.items
+            .iter()
+            .filter(|item| item.is_deleted())
+            .map(|item| item.path())
+    }
+
+    pub fn failed(&self) -> impl Iterator<Item = (&Path, &Error)> {
+        self.items
+            .iter()
+            .filter_map(|item| item.result().err().map(|err| (item.path(), err)))
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.items.iter().all(|item| item.result.is_ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    fn attributes(mode: &str, mtime: &str) -> FileAttributes {
+        serde_json::from_value(serde_json::json!({
+            "name": "file",
+            "mode": mode,
+            "mtime": mtime
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn selection() {
+        let zosmf = get_zosmf();
+
+        let delete = zosmf
+            .files()
+            .delete_matching("/u/exchange")
+            .pattern("*.tmp")
+            .pattern("outbound/*.xml")
+            .older_than(TimeDelta::days(7));
+
+        let old = attributes("-rw-r--r--", "2020-01-01T00:00:00");
+        let new = attributes("-rw-r--r--", "2999-01-01T00:00:00");
+        let dir = attributes("drwxr-xr-x", "2020-01-01T00:00:00");
+
+        assert!(delete.is_selected(Path::new("/u/exchange/a/b.tmp"), &old));
+        assert!(delete.is_selected(Path::new("/u/exchange/outbound/c.xml"), &old));
+        assert!(!delete.is_selected(Path::new("/u/exchange/inbound/c.xml"), &old));
+        assert!(!delete.is_selected(Path::new("/u/exchange/a/b.tmp"), &new));
+        assert!(!delete.is_selected(Path::new("/u/exchange/a.tmp"), &dir));
+    }
+
+    #[tokio::test]
+    async fn requires_pattern() {
+        let zosmf = get_zosmf();
+
+        let result = zosmf.files().delete_matching("/u/exchange").build().await;
+
+        assert!(matches!(result, Err(Error::InvalidValue(_))));
+    }
+
+    #[test]
+    fn report() {
+        let report = FileDeleteReport {
+            items: vec![
+                FileDeleteOutcome {
+                    path: "/u/exchange/a.tmp".into(),
+                    result: Ok(Some("TXID0001".into())),
+                },
+                FileDeleteOutcome {
+                    path: "/u/exchange/b.tmp".into(),
+                    result: Err(Error::NoEtag),
+                },
+            ],
+            dry_run: false,
+        };
+
+        assert_eq!(report.deleted().count(), 1);
+        assert_eq!(report.planned().count(), 1);
+        assert_eq!(report.failed().count(), 1);
+        assert!(!report.is_success());
+    }
+}
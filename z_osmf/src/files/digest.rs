@@ -0,0 +1,217 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use bytes::Bytes;
+use futures::StreamExt;
+use sha2::{Digest, Sha256};
+use tokio::io::AsyncReadExt;
+
+use crate::error::CheckStatus;
+use crate::restfiles::{get_etag, get_transaction_id};
+use crate::{ClientCore, Result};
+
+use super::read::{FileRead, FileReadBuilder};
+
+#[derive(Clone, Debug)]
+pub struct FileDigestBuilder {
+    core: ClientCore,
+
+    path: Arc<str>,
+    local_path: Option<PathBuf>,
+}
+
+impl FileDigestBuilder {
+    pub(crate) fn new<P>(core: ClientCore, path: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        FileDigestBuilder {
+            core,
+            path: path.to_string().into(),
+            local_path: None,
+        }
+    }
+
+    /// Also hash a local file and compare it with the remote one.
+    pub fn compare_to<L>(self, local_path: L) -> Self
+    where
+        L: AsRef<Path>,
+    {
+        FileDigestBuilder {
+            local_path: Some(local_path.as_ref().to_path_buf()),
+            ..self
+        }
+    }
+
+    /// Stream the file in binary mode and compute its SHA-256 without buffering it in memory.
+    pub async fn build(self) -> Result<FileDigest> {
+        let request = self.get_request()?;
+        let response = self
+            .core
+            .client
+            .execute(request)
+            .await?
+            .check_status()
+            .await?;
+
+        let etag = get_etag(&response)?;
+        let transaction_id = get_transaction_id(&response)?;
+
+        let mut hasher = Sha256::new();
+        let mut size = 0;
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            let chunk: Bytes = chunk?;
+            hasher.update(&chunk);
+            size += chunk.len() as u64;
+        }
+
+        let local_sha256 = match &self.local_path {
+            Some(local_path) => Some(sha256_local(local_path).await?),
+            None => None,
+        };
+
+        Ok(FileDigest {
+            size,
+            sha256: hasher.finalize().into(),
+            local_sha256,
+            etag,
+            transaction_id,
+        })
+    }
+
+    fn get_request(&self) -> Result<reqwest::Request> {
+        let builder: FileReadBuilder<FileRead<Bytes>> =
+            FileReadBuilder::new(self.core.clone(), &self.path);
+
+        builder.binary().get_request()
+    }
+}
+
+/// The SHA-256 of a file's bytes as stored on z/OS.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FileDigest {
+    size: u64,
+    sha256: [u8; 32],
+    local_sha256: Option<[u8; 32]>,
+    etag: Option<Arc<str>>,
+    transaction_id: Arc<str>,
+}
+
+impl FileDigest {
+    /// The number of bytes hashed.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn sha256(&self) -> &[u8; 32] {
+        &self.sha256
+    }
+
+    /// The SHA-256 as lowercase hex, as printed by `sha256sum`.
+    pub fn sha256_hex(&self) -> String {
+        to_hex(&self.sha256)
+    }
+
+    /// The SHA-256 of the local file, if one was given with
+    /// [`compare_to`](FileDigestBuilder::compare_to).
+    pub fn local_sha256(&self) -> Option<&[u8; 32]> {
+        self.local_sha256.as_ref()
+    }
+
+    /// Whether the local file has the same contents, or `None` if there was nothing to compare.
+    pub fn is_verified(&self) -> Option<bool> {
+        self.local_sha256.map(|local| local == self.sha256)
+    }
+
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    pub fn transaction_id(&self) -> &str {
+        &self.transaction_id
+    }
+}
+
+async fn sha256_local(path: &Path) -> Result<[u8; 32]> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    Ok(hasher.finalize().into())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn request() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .files()
+            .digest("/u/jiahj/app.tar")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(request.method(), reqwest::Method::GET);
+        assert_eq!(request.headers()["X-IBM-Data-Type"], "binary");
+    }
+
+    #[tokio::test]
+    async fn local() {
+        let path = std::env::temp_dir().join(format!("z_osmf_digest_{}", std::process::id()));
+        tokio::fs::write(&path, "abc").await.unwrap();
+
+        let sha256 = sha256_local(&path).await.unwrap();
+        tokio::fs::remove_file(&path).await.unwrap();
+
+        assert_eq!(
+            to_hex(&sha256),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn verified() {
+        let digest = FileDigest {
+            size: 3,
+            sha256: [1; 32],
+            local_sha256: None,
+            etag: None,
+            transaction_id: "TXID0001".into(),
+        };
+
+        assert_eq!(digest.is_verified(), None);
+        assert_eq!(
+            FileDigest {
+                local_sha256: Some([1; 32]),
+                ..digest.clone()
+            }
+            .is_verified(),
+            Some(true)
+        );
+        assert_eq!(
+            FileDigest {
+                local_sha256: Some([2; 32]),
+                ..digest
+            }
+            .is_verified(),
+            Some(false)
+        );
+    }
+}
@@ -0,0 +1,298 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use futures::StreamExt;
+
+use crate::{ClientCore, Error, Result};
+
+use super::list::{FileAttributes, FileListBuilder};
+
+#[derive(Clone, Debug)]
+pub struct FileDiskUsageBuilder {
+    core: ClientCore,
+
+    path: PathBuf,
+    max_depth: Option<u32>,
+    concurrency: usize,
+}
+
+impl FileDiskUsageBuilder {
+    pub(crate) fn new<P>(core: ClientCore, path: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        FileDiskUsageBuilder {
+            core,
+            path: path.as_ref().to_path_buf(),
+            max_depth: None,
+            concurrency: 4,
+        }
+    }
+
+    /// How many levels of subdirectories to report, `1` for the starting directory's own.
+    ///
+    /// Deeper directories are still walked and counted in their parents' totals.
+    pub fn max_depth(self, value: u32) -> Self {
+        FileDiskUsageBuilder {
+            max_depth: Some(value),
+            ..self
+        }
+    }
+
+    /// The maximum number of directories listed at once.
+    pub fn concurrency(self, value: usize) -> Self {
+        FileDiskUsageBuilder {
+            concurrency: value.max(1),
+            ..self
+        }
+    }
+
+    /// Walk the tree one level at a time and total the sizes of the regular files in it.
+    ///
+    /// Symbolic links are not followed. Fails if the starting directory cannot be listed;
+    /// subdirectories that cannot be listed are recorded in the report and left out of the totals.
+    pub async fn build(self) -> Result<FileDiskUsage> {
+        let builder = &self;
+
+        let mut usage = BTreeMap::new();
+        let mut failed = Vec::new();
+        let mut pending = vec![self.path.clone()];
+
+        while !pending.is_empty() {
+            let listed: Vec<_> = futures::stream::iter(std::mem::take(&mut pending))
+                .map(|directory| async move {
+                    let list =
+                        FileListBuilder::new(builder.core.clone(), directory.to_string_lossy())
+                            .lstat(true)
+                            .build_complete()
+                            .await;
+
+                    (directory, list)
+                })
+                .buffer_unordered(self.concurrency)
+                .collect()
+                .await;
+
+            for (directory, list) in listed {
+                match list {
+                    Ok(list) => {
+                        pending.extend(tally(&mut usage, &directory, list.items()));
+                    }
+                    Err(err) if directory == self.path => return Err(err),
+                    Err(err) => failed.push((directory, err)),
+                }
+            }
+        }
+
+        Ok(FileDiskUsage {
+            entries: roll_up(&self.path, usage, self.max_depth),
+            failed,
+        })
+    }
+}
+
+/// Add the regular files in a listing to the usage of `directory`,
+/// returning the subdirectories still to be listed.
+fn tally(
+    usage: &mut BTreeMap<PathBuf, (u64, u64)>,
+    directory: &Path,
+    items: &[FileAttributes],
+) -> Vec<PathBuf> {
+    let (size, files) = usage.entry(directory.to_path_buf()).or_default();
+    let mut subdirectories = Vec::new();
+
+    for item in items {
+        if matches!(item.name(), "." | "..") {
+            continue;
+        }
+
+        if item.is_dir() {
+            subdirectories.push(directory.join(item.name()));
+        } else if item.is_file() {
+            *size += item.size().map_or(0, |size| size.max(0) as u64);
+            *files += 1;
+        }
+    }
+
+    subdirectories
+}
+
+/// Add the usage of each directory to all of its ancestors below `root`.
+fn roll_up(
+    root: &Path,
+    usage: BTreeMap<PathBuf, (u64, u64)>,
+    max_depth: Option<u32>,
+) -> Vec<FileDiskUsageEntry> {
+    let depth = |path: &Path| {
+        path.strip_prefix(root)
+            .map_or(0, |relative| relative.components().count() as u32)
+    };
+
+    let mut totals = usage.clone();
+    for (path, (size, files)) in usage {
+        for ancestor in path.ancestors().skip(1) {
+            if !ancestor.starts_with(root) {
+                break;
+            }
+
+            if let Some(total) = totals.get_mut(ancestor) {
+                total.0 += size;
+                total.1 += files;
+            }
+        }
+    }
+
+    totals
+        .into_iter()
+        .filter(|(path, _)| max_depth.is_none_or(|max_depth| depth(path) <= max_depth))
+        .map(|(path, (size, files))| FileDiskUsageEntry {
+            depth: depth(&path),
+            path,
+            size,
+            files,
+        })
+        .collect()
+}
+
+/// The sizes of a directory tree, by directory.
+#[derive(Debug)]
+pub struct FileDiskUsage {
+    entries: Vec<FileDiskUsageEntry>,
+    failed: Vec<(PathBuf, Error)>,
+}
+
+impl FileDiskUsage {
+    /// Every reported directory, sorted by path, starting with the walked directory itself.
+    pub fn entries(&self) -> &[FileDiskUsageEntry] {
+        &self.entries
+    }
+
+    /// The usage of the whole tree.
+    pub fn total(&self) -> &FileDiskUsageEntry {
+        &self.entries[0]
+    }
+
+    pub fn get<P>(&self, path: P) -> Option<&FileDiskUsageEntry>
+    where
+        P: AsRef<Path>,
+    {
+        self.entries
+            .iter()
+            .find(|entry| entry.path == path.as_ref())
+    }
+
+    /// The reported directories, largest first.
+    pub fn largest(&self) -> impl Iterator<Item = &FileDiskUsageEntry> {
+        let mut entries: Vec<_> = self.entries.iter().collect();
+        entries.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.path.cmp(&b.path)));
+
+        entries.into_iter()
+    }
+
+    /// The subdirectories that could not be listed.
+    pub fn failed(&self) -> impl Iterator<Item = (&Path, &Error)> {
+        self.failed.iter().map(|(path, err)| (path.as_path(), err))
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FileDiskUsageEntry {
+    path: PathBuf,
+    depth: u32,
+    size: u64,
+    files: u64,
+}
+
+impl FileDiskUsageEntry {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// How many levels below the walked directory, `0` for the directory itself.
+    pub fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    /// The total size in bytes of the regular files in this directory and below.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// The number of regular files in this directory and below.
+    pub fn files(&self) -> u64 {
+        self.files
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attributes(name: &str, mode: &str, size: i32) -> FileAttributes {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "mode": mode,
+            "size": size
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn summary() {
+        let root = Path::new("/u/ibmuser");
+        let mut usage = BTreeMap::new();
+
+        let pending = tally(
+            &mut usage,
+            root,
+            &[
+                attributes(".", "drwxr-xr-x", 8192),
+                attributes(".profile", "-rw-r--r--", 100),
+                attributes("logs", "drwxr-xr-x", 8192),
+                attributes("bin", "lrwxrwxrwx", 12),
+            ],
+        );
+        assert_eq!(pending, vec![root.join("logs")]);
+
+        let pending = tally(
+            &mut usage,
+            &root.join("logs"),
+            &[
+                attributes("a.log", "-rw-r--r--", 1000),
+                attributes("old", "drwxr-xr-x", 8192),
+            ],
+        );
+        assert_eq!(pending, vec![root.join("logs/old")]);
+
+        tally(
+            &mut usage,
+            &root.join("logs/old"),
+            &[
+                attributes("b.log", "-rw-r--r--", 5000),
+                attributes("c.log", "-rw-r--r--", 7000),
+            ],
+        );
+
+        let report = FileDiskUsage {
+            entries: roll_up(root, usage.clone(), None),
+            failed: Vec::new(),
+        };
+
+        assert_eq!(report.total().size(), 13100);
+        assert_eq!(report.total().files(), 4);
+        assert_eq!(report.get("/u/ibmuser/logs").unwrap().size(), 13000);
+        assert_eq!(report.get("/u/ibmuser/logs/old").unwrap().depth(), 2);
+        assert_eq!(
+            report.largest().map(|e| e.size()).collect::<Vec<_>>(),
+            vec![13100, 13000, 12000]
+        );
+
+        let entries = roll_up(root, usage, Some(1));
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].size(), 13100);
+    }
+}
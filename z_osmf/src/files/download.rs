@@ -0,0 +1,313 @@
+use std::num::NonZeroU32;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use bytes::Bytes;
+use futures::StreamExt;
+use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+
+use crate::error::CheckStatus;
+use crate::restfiles::{get_etag, get_transaction_id};
+use crate::{ClientCore, Error, Result};
+
+use super::read::{FileRead, FileReadBuilder, RecordRange};
+use super::FileDataType;
+
+/// Reported after each chunk of a download is written.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FileDownloadProgress {
+    pub transferred: u64,
+    /// The size of the response body, when z/OSMF sends a `Content-Length`.
+    pub total: Option<u64>,
+}
+
+type ProgressFn = Arc<dyn Fn(FileDownloadProgress) + Send + Sync>;
+
+#[derive(Clone)]
+pub struct FileDownloadBuilder {
+    core: ClientCore,
+
+    path: Arc<str>,
+    local_path: PathBuf,
+    data_type: FileDataType,
+    encoding: Option<Arc<str>>,
+    progress: Option<ProgressFn>,
+    retries: u32,
+}
+
+impl std::fmt::Debug for FileDownloadBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileDownloadBuilder")
+            .field("path", &self.path)
+            .field("local_path", &self.local_path)
+            .field("data_type", &self.data_type)
+            .field("encoding", &self.encoding)
+            .field("progress", &self.progress.is_some())
+            .field("retries", &self.retries)
+            .finish()
+    }
+}
+
+impl FileDownloadBuilder {
+    pub(crate) fn new<P, L>(core: ClientCore, path: P, local_path: L) -> Self
+    where
+        P: std::fmt::Display,
+        L: AsRef<Path>,
+    {
+        FileDownloadBuilder {
+            core,
+            path: path.to_string().into(),
+            local_path: local_path.as_ref().to_path_buf(),
+            data_type: FileDataType::Binary,
+            encoding: None,
+            progress: None,
+            retries: 0,
+        }
+    }
+
+    /// Transfer the file byte for byte (the default) or convert it to text.
+    pub fn data_type(self, value: FileDataType) -> Self {
+        FileDownloadBuilder {
+            data_type: value,
+            ..self
+        }
+    }
+
+    /// The EBCDIC code page of the file, e.g. `IBM-1047`. Implies text mode.
+    pub fn encoding<E>(self, value: E) -> Self
+    where
+        E: std::fmt::Display,
+    {
+        FileDownloadBuilder {
+            data_type: FileDataType::Text,
+            encoding: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// Called after each chunk is written to the local file.
+    pub fn progress<F>(self, callback: F) -> Self
+    where
+        F: Fn(FileDownloadProgress) + Send + Sync + 'static,
+    {
+        FileDownloadBuilder {
+            progress: Some(Arc::new(callback)),
+            ..self
+        }
+    }
+
+    /// How many times to retry after the connection fails, none by default.
+    ///
+    /// Binary downloads resume from the last byte received; text downloads start over,
+    /// since z/OSMF counts text ranges in records rather than bytes.
+    pub fn retries(self, value: u32) -> Self {
+        FileDownloadBuilder {
+            retries: value,
+            ..self
+        }
+    }
+
+    pub async fn build(self) -> Result<FileDownload> {
+        let mut file = tokio::fs::File::create(&self.local_path).await?;
+        let mut state = DownloadState::default();
+        let mut retries = 0;
+
+        loop {
+            match self.transfer(&mut file, &mut state).await {
+                Ok(()) => break,
+                Err(err) if is_transient(&err) && retries < self.retries => {
+                    retries += 1;
+
+                    if self.data_type == FileDataType::Text {
+                        file.set_len(0).await?;
+                        file.rewind().await?;
+                        state.transferred = 0;
+                    }
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        file.flush().await?;
+
+        Ok(FileDownload {
+            path: self.local_path,
+            size: state.transferred,
+            etag: state.etag,
+            transaction_id: state.transaction_id.unwrap_or_default(),
+        })
+    }
+
+    async fn transfer(&self, file: &mut tokio::fs::File, state: &mut DownloadState) -> Result<()> {
+        let request = match state.transferred {
+            0 => self.get_request()?,
+            offset => self.get_resume_request(offset)?,
+        };
+        let response = self
+            .core
+            .client
+            .execute(request)
+            .await?
+            .check_status()
+            .await?;
+
+        let etag = get_etag(&response)?;
+        if state.transferred > 0 && etag.is_some() && etag != state.etag {
+            return Err(Error::InvalidValue(format!(
+                "{} changed while it was downloading",
+                self.path
+            )));
+        }
+
+        if state.transaction_id.is_none() {
+            state.transaction_id = Some(get_transaction_id(&response)?);
+            state.etag = etag;
+            state.total = response.content_length();
+        }
+
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            let chunk: Bytes = chunk?;
+            file.write_all(&chunk).await?;
+            state.transferred += chunk.len() as u64;
+
+            if let Some(progress) = &self.progress {
+                progress(FileDownloadProgress {
+                    transferred: state.transferred,
+                    total: state.total,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_resume_request(&self, offset: u64) -> Result<reqwest::Request> {
+        let start = u32::try_from(offset)
+            .map_err(|_| Error::InvalidValue(format!("cannot resume {} past 4 GiB", self.path)))?;
+        let builder: FileReadBuilder<FileRead<Bytes>> =
+            FileReadBuilder::new(self.core.clone(), &self.path);
+
+        builder
+            .record_range(RecordRange::StartCount(start, NonZeroU32::MAX))
+            .binary()
+            .get_request()
+    }
+
+    fn get_request(&self) -> Result<reqwest::Request> {
+        let builder: FileReadBuilder<FileRead<Bytes>> =
+            FileReadBuilder::new(self.core.clone(), &self.path);
+        let builder = match &self.encoding {
+            Some(encoding) => builder.encoding(encoding),
+            None => builder,
+        };
+
+        match self.data_type {
+            FileDataType::Binary => builder.binary().get_request(),
+            FileDataType::Text => builder.text().get_request(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct DownloadState {
+    transferred: u64,
+    total: Option<u64>,
+    etag: Option<Arc<str>>,
+    transaction_id: Option<Arc<str>>,
+}
+
+/// Whether a request failed in transit, rather than being rejected by z/OSMF.
+pub(super) fn is_transient(err: &Error) -> bool {
+    matches!(err, Error::Reqwest(err) if err.status().is_none())
+}
+
+/// The result of downloading a file to a local path.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FileDownload {
+    path: PathBuf,
+    size: u64,
+    etag: Option<Arc<str>>,
+    transaction_id: Arc<str>,
+}
+
+impl FileDownload {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The number of bytes written to [`path`](Self::path).
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    pub fn transaction_id(&self) -> &str {
+        &self.transaction_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    #[test]
+    fn binary_request() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .files()
+            .download("/u/jiahj/app.tar", "./app.tar")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(request.method(), reqwest::Method::GET);
+        assert_eq!(
+            urlencoding::decode(request.url().as_str()).unwrap(),
+            "https://test.com/zosmf/restfiles/fs/u/jiahj/app.tar"
+        );
+        assert_eq!(request.headers()["X-IBM-Data-Type"], "binary");
+    }
+
+    #[test]
+    fn text_request() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .files()
+            .download("/u/jiahj/app.log", "./app.log")
+            .encoding("IBM-1047")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            request.headers()["X-IBM-Data-Type"],
+            "text;fileEncoding=IBM-1047"
+        );
+    }
+
+    #[test]
+    fn resume_request() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .files()
+            .download("/u/jiahj/app.tar", "./app.tar")
+            .get_resume_request(1024)
+            .unwrap();
+
+        assert_eq!(request.headers()["X-IBM-Data-Type"], "binary");
+        assert_eq!(
+            request.headers()["X-IBM-Record-Range"],
+            format!("1024,{}", u32::MAX)
+        );
+
+        assert!(zosmf
+            .files()
+            .download("/u/jiahj/app.tar", "./app.tar")
+            .get_resume_request(u64::from(u32::MAX) + 1)
+            .is_err());
+    }
+}
@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use crate::restfiles::Etag;
+use crate::{ClientCore, Error, Result};
+
+use super::read::{FileRead, FileReadBuilder};
+use super::write::FileWriteBuilder;
+
+#[derive(Clone, Debug)]
+pub struct FileEditBuilder {
+    core: ClientCore,
+
+    path: Arc<str>,
+    encoding: Option<Arc<str>>,
+    attempts: usize,
+}
+
+impl FileEditBuilder {
+    pub(crate) fn new<P>(core: ClientCore, path: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        FileEditBuilder {
+            core,
+            path: path.to_string().into(),
+            encoding: None,
+            attempts: 3,
+        }
+    }
+
+    /// The EBCDIC code page of the file, e.g. `IBM-1047`.
+    pub fn encoding<E>(self, value: E) -> Self
+    where
+        E: std::fmt::Display,
+    {
+        FileEditBuilder {
+            encoding: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// How many times to read and apply the edit when the file changes before it is written.
+    pub fn attempts(self, value: usize) -> Self {
+        FileEditBuilder {
+            attempts: value.max(1),
+            ..self
+        }
+    }
+
+    /// Read the file, apply `edit` to its text and write the result back with `If-Match`.
+    ///
+    /// If the file was changed by someone else in the meantime, the edit is retried on the
+    /// new contents. Returns `None` without writing when `edit` leaves the text unchanged,
+    /// and fails with [`Error::NoEtag`] rather than writing unconditionally when the read
+    /// returns no etag.
+    pub async fn build<F>(self, mut edit: F) -> Result<Option<Etag>>
+    where
+        F: FnMut(&str) -> String,
+    {
+        let mut attempt = 1;
+        loop {
+            let read = self.read_builder().build().await?;
+
+            let text = edit(read.data());
+            if text == read.data() {
+                return Ok(None);
+            }
+
+            let etag = read.etag().ok_or(Error::NoEtag)?;
+            let builder = self.write_builder().text(text).if_match(etag);
+
+            match builder.build().await {
+                Err(err) if is_precondition_failed(&err) && attempt < self.attempts => {
+                    attempt += 1;
+                }
+                result => return result.map(Some),
+            }
+        }
+    }
+
+    fn read_builder(&self) -> FileReadBuilder<FileRead<Arc<str>>> {
+        let builder = FileReadBuilder::new(self.core.clone(), &self.path);
+
+        match &self.encoding {
+            Some(encoding) => builder.encoding(encoding),
+            None => builder,
+        }
+    }
+
+    fn write_builder(&self) -> FileWriteBuilder<Etag> {
+        let builder = FileWriteBuilder::new(self.core.clone(), &self.path);
+
+        match &self.encoding {
+            Some(encoding) => builder.encoding(encoding),
+            None => builder,
+        }
+    }
+}
+
+pub(super) fn is_precondition_failed(err: &Error) -> bool {
+    err.status() == Some(reqwest::StatusCode::PRECONDITION_FAILED)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ApiError;
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn requests() {
+        let zosmf = get_zosmf();
+
+        let edit = zosmf.files().edit("/etc/app.conf").encoding("IBM-1047");
+
+        let request = edit.read_builder().get_request().unwrap();
+        assert_eq!(request.method(), reqwest::Method::GET);
+        assert_eq!(
+            request.headers()["X-IBM-Data-Type"],
+            "text;fileEncoding=IBM-1047"
+        );
+
+        let request = edit
+            .write_builder()
+            .text("debug=true")
+            .if_match("B5C6454F783590AA8EC15BD88E29EA63")
+            .get_request()
+            .unwrap();
+        assert_eq!(request.method(), reqwest::Method::PUT);
+        assert_eq!(
+            request.headers()["If-Match"],
+            "B5C6454F783590AA8EC15BD88E29EA63"
+        );
+        assert_eq!(
+            request.headers()["X-IBM-Data-Type"],
+            "text;fileEncoding=IBM-1047"
+        );
+    }
+
+    #[test]
+    fn precondition_failed() {
+        let err = Error::Api(ApiError::Text {
+            url: "https://test.com/zosmf/restfiles/fs/etc/app.conf".to_string(),
+            status: reqwest::StatusCode::PRECONDITION_FAILED,
+            body: String::new(),
+        });
+
+        assert!(is_precondition_failed(&err));
+        assert!(!is_precondition_failed(&Error::NoEtag));
+    }
+}
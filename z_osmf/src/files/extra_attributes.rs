@@ -76,6 +76,25 @@ where
     })
 }
 
+/// The `extattr` flag letters for the selected attributes.
+fn get_flags(
+    apf_authorized: Option<bool>,
+    shared_library: Option<bool>,
+    program_controlled: Option<bool>,
+    shared_address_space: Option<bool>,
+) -> String {
+    [
+        (apf_authorized, 'a'),
+        (shared_library, 'l'),
+        (program_controlled, 'p'),
+        (shared_address_space, 's'),
+    ]
+    .into_iter()
+    .filter(|(selected, _)| *selected == Some(true))
+    .map(|(_, flag)| flag)
+    .collect()
+}
+
 #[derive(Serialize)]
 struct RequestJson {
     request: &'static str,
@@ -4,12 +4,16 @@ use std::sync::Arc;
 use z_osmf_macros::Endpoint;
 
 use crate::convert::TryFromResponse;
-use crate::ClientCore;
+use crate::{ClientCore, Error, Result};
 
-use super::RequestJson;
+use super::{get_flags, RequestJson};
 
 #[derive(Clone, Debug, Endpoint)]
-#[endpoint(method = put, path = "/zosmf/restfiles/fs{path}")]
+#[endpoint(
+    method = put,
+    path = "/zosmf/restfiles/fs{path}",
+    validate_fn = validate
+)]
 pub struct FileExtraAttributesResetBuilder<T>
 where
     T: TryFromResponse,
@@ -37,21 +41,15 @@ fn build_body<T>(
 where
     T: TryFromResponse,
 {
-    let mut reset = Vec::new();
-
-    if builder.apf_authorized == Some(true) {
-        reset.push('a');
-    }
-    if builder.shared_library == Some(true) {
-        reset.push('l');
-    }
-    if builder.program_controlled == Some(true) {
-        reset.push('p');
-    }
-    if builder.shared_address_space == Some(true) {
-        reset.push('s');
-    }
-    let reset = Some(reset.into_iter().collect::<String>().into());
+    let reset = Some(
+        get_flags(
+            builder.apf_authorized,
+            builder.shared_library,
+            builder.program_controlled,
+            builder.shared_address_space,
+        )
+        .into(),
+    );
 
     request_builder.json(&RequestJson {
         request: "extattr",
@@ -59,3 +57,23 @@ where
         reset,
     })
 }
+
+fn validate<T>(builder: &FileExtraAttributesResetBuilder<T>) -> Result<()>
+where
+    T: TryFromResponse,
+{
+    let flags = get_flags(
+        builder.apf_authorized,
+        builder.shared_library,
+        builder.program_controlled,
+        builder.shared_address_space,
+    );
+
+    if flags.is_empty() {
+        return Err(Error::InvalidValue(
+            "at least one extra attribute is required".to_string(),
+        ));
+    }
+
+    Ok(())
+}
@@ -4,12 +4,16 @@ use std::sync::Arc;
 use z_osmf_macros::Endpoint;
 
 use crate::convert::TryFromResponse;
-use crate::ClientCore;
+use crate::{ClientCore, Error, Result};
 
-use super::RequestJson;
+use super::{get_flags, RequestJson};
 
 #[derive(Clone, Debug, Endpoint)]
-#[endpoint(method = put, path = "/zosmf/restfiles/fs{path}")]
+#[endpoint(
+    method = put,
+    path = "/zosmf/restfiles/fs{path}",
+    validate_fn = validate
+)]
 pub struct FileExtraAttributesSetBuilder<T>
 where
     T: TryFromResponse,
@@ -37,21 +41,15 @@ fn build_body<T>(
 where
     T: TryFromResponse,
 {
-    let mut set = Vec::new();
-
-    if builder.apf_authorized == Some(true) {
-        set.push('a');
-    }
-    if builder.shared_library == Some(true) {
-        set.push('l');
-    }
-    if builder.program_controlled == Some(true) {
-        set.push('p');
-    }
-    if builder.shared_address_space == Some(true) {
-        set.push('s');
-    }
-    let set = Some(set.into_iter().collect::<String>().into());
+    let set = Some(
+        get_flags(
+            builder.apf_authorized,
+            builder.shared_library,
+            builder.program_controlled,
+            builder.shared_address_space,
+        )
+        .into(),
+    );
 
     request_builder.json(&RequestJson {
         request: "extattr",
@@ -59,3 +57,72 @@ where
         reset: None,
     })
 }
+
+fn validate<T>(builder: &FileExtraAttributesSetBuilder<T>) -> Result<()>
+where
+    T: TryFromResponse,
+{
+    let flags = get_flags(
+        builder.apf_authorized,
+        builder.shared_library,
+        builder.program_controlled,
+        builder.shared_address_space,
+    );
+
+    if flags.is_empty() {
+        return Err(Error::InvalidValue(
+            "at least one extra attribute is required".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn set_request() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .files()
+            .set_extra_attributes("/u/jiahj/bin/authpgm")
+            .apf_authorized(true)
+            .program_controlled(true)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            urlencoding::decode(request.url().as_str()).unwrap(),
+            "https://test.com/zosmf/restfiles/fs/u/jiahj/bin/authpgm"
+        );
+        assert_eq!(
+            request.json(),
+            Some(serde_json::json!({"request": "extattr", "set": "ap"}))
+        );
+    }
+
+    #[test]
+    fn requires_attribute() {
+        let zosmf = get_zosmf();
+
+        let set = zosmf
+            .files()
+            .set_extra_attributes("/u/jiahj/bin/authpgm")
+            .apf_authorized(false)
+            .get_request();
+
+        assert!(matches!(set, Err(Error::InvalidValue(_))));
+
+        let reset = zosmf
+            .files()
+            .reset_extra_attributes("/u/jiahj/bin/authpgm")
+            .get_request();
+
+        assert!(matches!(reset, Err(Error::InvalidValue(_))));
+    }
+}
@@ -0,0 +1,187 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::{Endpoint, Getters};
+
+use crate::convert::TryFromResponse;
+use crate::restfiles::get_transaction_id;
+use crate::{ClientCore, Result};
+
+/// A mounted z/OS UNIX file system, such as a zFS aggregate.
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct FileSystemInfo {
+    name: Arc<str>,
+    #[serde(default)]
+    mountpoint: Option<Arc<str>>,
+    /// The file system type, e.g. `ZFS` or `TFS`.
+    #[serde(default)]
+    fstname: Option<Arc<str>>,
+    #[serde(default)]
+    status: Option<Arc<str>>,
+    /// Mount options, e.g. `rdwr`, `rdonly`, `acl`.
+    #[serde(default)]
+    mode: Arc<[Arc<str>]>,
+    #[getter(copy)]
+    #[serde(default)]
+    dev: Option<i64>,
+    #[getter(copy)]
+    #[serde(default)]
+    bsize: Option<i64>,
+    #[getter(copy)]
+    #[serde(default)]
+    bavail: Option<i64>,
+    #[getter(copy)]
+    #[serde(default)]
+    blocks: Option<i64>,
+    /// The system that owns the mount in a sysplex.
+    #[serde(default)]
+    sysname: Option<Arc<str>>,
+}
+
+impl FileSystemInfo {
+    /// Total capacity in bytes.
+    pub fn size_bytes(&self) -> Option<i64> {
+        Some(self.blocks? * self.bsize?)
+    }
+
+    /// Space available to unprivileged users, in bytes.
+    pub fn free_bytes(&self) -> Option<i64> {
+        Some(self.bavail? * self.bsize?)
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status
+            .as_deref()
+            .is_some_and(|status| status.eq_ignore_ascii_case("active"))
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.mode.iter().any(|mode| &**mode == "rdonly")
+    }
+
+    pub fn is_zfs(&self) -> bool {
+        self.fstname
+            .as_deref()
+            .is_some_and(|fstname| fstname.eq_ignore_ascii_case("zfs"))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct FileSystemList {
+    items: Arc<[FileSystemInfo]>,
+    #[getter(copy)]
+    returned_rows: i32,
+    #[getter(copy)]
+    total_rows: i32,
+    #[getter(copy)]
+    json_version: i32,
+    transaction_id: Arc<str>,
+}
+
+impl TryFromResponse for FileSystemList {
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        let transaction_id = get_transaction_id(&value)?;
+
+        let ResponseJson {
+            items,
+            returned_rows,
+            total_rows,
+            json_version,
+        } = value.json().await?;
+
+        Ok(FileSystemList {
+            items,
+            returned_rows,
+            total_rows,
+            json_version,
+            transaction_id,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = get, path = "/zosmf/restfiles/mfs")]
+pub struct FileSystemListBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    /// Only list the file system containing this path.
+    #[endpoint(query = "path")]
+    path: Option<Arc<str>>,
+    /// Only list file systems whose data set name matches, e.g. `OMVS.APP.*`.
+    #[endpoint(query = "fsname")]
+    name: Option<Arc<str>>,
+    #[endpoint(header = "X-IBM-Max-Items")]
+    max_items: Option<i32>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResponseJson {
+    items: Arc<[FileSystemInfo]>,
+    returned_rows: i32,
+    total_rows: i32,
+    #[serde(rename = "JSONversion")]
+    json_version: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn example_1() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/mfs")
+            .query(&[("fsname", "OMVS.APP.*")])
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .list_filesystems()
+            .name("OMVS.APP.*")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
+    #[test]
+    fn attributes() {
+        let filesystem: FileSystemInfo = serde_json::from_value(serde_json::json!({
+            "name": "OMVS.APP.ZFS",
+            "mountpoint": "/u/app",
+            "fstname": "ZFS",
+            "status": "active",
+            "mode": ["acl", "rdonly", "synchonly"],
+            "dev": 42,
+            "fstype": 1,
+            "bsize": 1024,
+            "bavail": 300,
+            "blocks": 1000,
+            "sysname": "SY1",
+            "readibc": 0,
+            "writeibc": 0,
+            "diribc": 0
+        }))
+        .unwrap();
+
+        assert_eq!(filesystem.size_bytes(), Some(1_024_000));
+        assert_eq!(filesystem.free_bytes(), Some(307_200));
+        assert!(filesystem.is_active());
+        assert!(filesystem.is_read_only());
+        assert!(filesystem.is_zfs());
+    }
+}
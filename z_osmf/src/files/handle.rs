@@ -0,0 +1,169 @@
+use std::sync::Arc;
+
+use crate::restfiles::Etag;
+use crate::{ClientCore, Error, Result};
+
+use super::delete::FileDeleteBuilder;
+use super::edit::FileEditBuilder;
+use super::list::{FileAttributes, FileList, FileListBuilder};
+use super::mode::FileChangeModeBuilder;
+use super::read::{FileRead, FileReadBuilder};
+use super::write::FileWriteBuilder;
+
+/// A single file, so its path and encoding are given once for every operation on it.
+#[derive(Clone, Debug)]
+pub struct FileHandle {
+    core: ClientCore,
+
+    path: Arc<str>,
+    encoding: Option<Arc<str>>,
+}
+
+impl FileHandle {
+    pub(crate) fn new<P>(core: ClientCore, path: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        FileHandle {
+            core,
+            path: path.to_string().into(),
+            encoding: None,
+        }
+    }
+
+    /// The EBCDIC code page used when reading and writing the file as text, e.g. `IBM-1047`.
+    pub fn encoding<E>(self, value: E) -> Self
+    where
+        E: std::fmt::Display,
+    {
+        FileHandle {
+            encoding: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn chmod<M>(&self, mode: M) -> FileChangeModeBuilder<String>
+    where
+        M: std::fmt::Display,
+    {
+        FileChangeModeBuilder::new(self.core.clone(), &self.path, mode)
+    }
+
+    pub fn delete(&self) -> FileDeleteBuilder<String> {
+        FileDeleteBuilder::new(self.core.clone(), &self.path)
+    }
+
+    pub fn edit(&self) -> FileEditBuilder {
+        let builder = FileEditBuilder::new(self.core.clone(), &self.path);
+
+        match &self.encoding {
+            Some(encoding) => builder.encoding(encoding),
+            None => builder,
+        }
+    }
+
+    pub fn read(&self) -> FileReadBuilder<FileRead<Arc<str>>> {
+        let builder = FileReadBuilder::new(self.core.clone(), &self.path);
+
+        match &self.encoding {
+            Some(encoding) => builder.encoding(encoding),
+            None => builder,
+        }
+    }
+
+    /// Look up the attributes of the file itself, without following a symbolic link.
+    pub async fn stat(&self) -> Result<FileAttributes> {
+        let list = self.list_builder().build().await?;
+
+        list.items()
+            .first()
+            .cloned()
+            .ok_or_else(|| Error::InvalidValue(format!("no attributes returned for {}", self.path)))
+    }
+
+    pub fn write(&self) -> FileWriteBuilder<Etag> {
+        let builder = FileWriteBuilder::new(self.core.clone(), &self.path);
+
+        match &self.encoding {
+            Some(encoding) => builder.encoding(encoding),
+            None => builder,
+        }
+    }
+
+    fn list_builder(&self) -> FileListBuilder<FileList> {
+        FileListBuilder::new(self.core.clone(), &self.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    #[test]
+    fn read() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .files()
+            .read("/u/jiahj/app.conf")
+            .encoding("IBM-1047")
+            .get_request()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .file("/u/jiahj/app.conf")
+            .encoding("IBM-1047")
+            .read()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
+    #[test]
+    fn write() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .files()
+            .write("/u/jiahj/app.conf")
+            .encoding("IBM-1047")
+            .text("port=8080\n")
+            .get_request()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .file("/u/jiahj/app.conf")
+            .encoding("IBM-1047")
+            .write()
+            .text("port=8080\n")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
+    #[test]
+    fn chmod() {
+        let zosmf = get_zosmf();
+
+        let file = zosmf.files().file("/u/jiahj/app.conf");
+
+        let manual_request = zosmf
+            .files()
+            .change_mode("/u/jiahj/app.conf", "640")
+            .get_request()
+            .unwrap();
+
+        let request = file.chmod("640").get_request().unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+}
@@ -19,8 +19,9 @@ where
     source_path: Arc<str>,
     #[endpoint(path)]
     target_path: Arc<str>,
+    /// Without a link type, a hard link is created.
     #[endpoint(skip_builder)]
-    link_type: FileLinkType,
+    link_type: Option<FileLinkType>,
     #[endpoint(skip_builder)]
     recursive: Option<bool>,
     #[endpoint(skip_builder)]
@@ -40,8 +41,8 @@ pub enum FileLinkType {
 struct RequestJson<'a> {
     request: &'static str,
     from: &'a str,
-    #[serde(rename = "type")]
-    link_type: FileLinkType,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    link_type: Option<FileLinkType>,
     recursive: bool,
     force: bool,
 }
@@ -70,6 +71,54 @@ mod tests {
 
     use super::FileLinkType;
 
+    #[test]
+    fn hard_link() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .files()
+            .hard_link("/u/jiahj/sourceFile.txt", "/u/jiahj/hardLink.txt")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            urlencoding::decode(request.url().as_str()).unwrap(),
+            "https://test.com/zosmf/restfiles/fs/u/jiahj/hardLink.txt"
+        );
+        assert_eq!(
+            request.json(),
+            Some(serde_json::json!({
+                "request": "link",
+                "from": "/u/jiahj/sourceFile.txt",
+                "recursive": false,
+                "force": false
+            }))
+        );
+    }
+
+    #[test]
+    fn symlink() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .files()
+            .symlink("/u/jiahj/app-1.2", "/u/jiahj/app")
+            .force(true)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            request.json(),
+            Some(serde_json::json!({
+                "request": "link",
+                "from": "/u/jiahj/app-1.2",
+                "type": "symbol",
+                "recursive": false,
+                "force": true
+            }))
+        );
+    }
+
     #[test]
     fn maximal() {
         let zosmf = get_zosmf();
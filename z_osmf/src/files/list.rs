@@ -1,14 +1,20 @@
+use std::collections::BTreeSet;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
-use chrono::NaiveDateTime;
+use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeDelta, Utc};
+use futures::{StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
 use z_osmf_macros::{Endpoint, Getters};
 
 use crate::convert::TryFromResponse;
 use crate::restfiles::get_transaction_id;
+use crate::utils::wildcard_match;
 use crate::{ClientCore, Result};
 
+use super::mode::FileMode;
+use super::tags::{FileTag, FileTagList, FileTagListBuilder};
+
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct FileAttributes {
     name: Arc<str>,
@@ -22,10 +28,122 @@ pub struct FileAttributes {
     #[getter(copy)]
     gid: Option<i32>,
     group: Option<Arc<str>>,
-    #[getter(copy)]
-    mtime: Option<NaiveDateTime>,
+    #[getter(skip)]
+    mtime: Option<FileMtime>,
     #[serde(default)]
     target: Option<Arc<str>>,
+    /// Only looked up by [`FileListBuilder::build_with_tags`].
+    #[getter(skip)]
+    #[serde(default)]
+    tag: Option<FileTag>,
+}
+
+impl FileAttributes {
+    /// The file tag, if the list was built with [`FileListBuilder::build_with_tags`].
+    pub fn tag(&self) -> Option<&FileTag> {
+        self.tag.as_ref()
+    }
+
+    /// The modification time as listed, in the server's local time.
+    pub fn mtime(&self) -> Option<NaiveDateTime> {
+        self.mtime.map(|mtime| mtime.local)
+    }
+
+    /// The UTC offset of [`mtime`](Self::mtime), if z/OSMF reported one
+    /// or a server offset was applied with [`with_server_offset`](Self::with_server_offset).
+    pub fn mtime_offset(&self) -> Option<FixedOffset> {
+        FixedOffset::east_opt(self.mtime?.offset?)
+    }
+
+    /// The modification time with its UTC offset, if the offset is known.
+    pub fn mtime_fixed(&self) -> Option<DateTime<FixedOffset>> {
+        self.mtime()?
+            .and_local_timezone(self.mtime_offset()?)
+            .single()
+    }
+
+    /// The modification time in UTC. If the offset is not known, the listed time is taken as UTC.
+    pub fn mtime_utc(&self) -> Option<DateTime<Utc>> {
+        match self.mtime_fixed() {
+            Some(mtime) => Some(mtime.to_utc()),
+            None => self.mtime().map(|mtime| mtime.and_utc()),
+        }
+    }
+
+    /// Interpret the listed modification time as local to a server `offset` from UTC,
+    /// unless z/OSMF reported an offset itself.
+    pub fn with_server_offset(self, offset: FixedOffset) -> Self {
+        let mtime = self.mtime.map(|mtime| FileMtime {
+            offset: mtime.offset.or(Some(offset.local_minus_utc())),
+            ..mtime
+        });
+
+        FileAttributes { mtime, ..self }
+    }
+
+    /// The parsed [`mode`](Self::mode), including the file type.
+    pub fn file_mode(&self) -> Option<FileMode> {
+        self.mode.as_deref()?.parse().ok()
+    }
+
+    pub fn is_file(&self) -> bool {
+        self.file_mode().is_some_and(|mode| mode.is_file())
+    }
+
+    pub fn is_dir(&self) -> bool {
+        self.file_mode().is_some_and(|mode| mode.is_dir())
+    }
+
+    fn is_dot_entry(&self) -> bool {
+        matches!(&*self.name, "." | "..")
+    }
+}
+
+/// A listed modification time, with the offset from UTC in seconds if known.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct FileMtime {
+    local: NaiveDateTime,
+    offset: Option<i32>,
+}
+
+impl<'de> Deserialize<'de> for FileMtime {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+
+        if let Ok(mtime) = DateTime::parse_from_rfc3339(&s) {
+            return Ok(FileMtime {
+                local: mtime.naive_local(),
+                offset: Some(mtime.offset().local_minus_utc()),
+            });
+        }
+
+        let local = s.parse().map_err(serde::de::Error::custom)?;
+
+        Ok(FileMtime {
+            local,
+            offset: None,
+        })
+    }
+}
+
+impl Serialize for FileMtime {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let fixed = self
+            .offset
+            .and_then(FixedOffset::east_opt)
+            .and_then(|offset| self.local.and_local_timezone(offset).single());
+
+        match fixed {
+            Some(mtime) => mtime.serialize(serializer),
+            None => self.local.serialize(serializer),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -37,6 +155,46 @@ where
     LessThan(T),
     EqualTo(T),
     GreaterThan(T),
+    /// Greater than the first value and less than the second, sent as two query values.
+    Between(T, T),
+}
+
+impl<T> FileFilter<T>
+where
+    T: std::fmt::Display + std::str::FromStr,
+    <T as std::str::FromStr>::Err: std::fmt::Display,
+{
+    /// The query values for this filter, two for [`Between`](Self::Between) and one otherwise.
+    fn query_values(&self) -> Vec<String> {
+        match self {
+            FileFilter::LessThan(f) => vec![format!("-{}", f)],
+            FileFilter::EqualTo(f) => vec![format!("{}", f)],
+            FileFilter::GreaterThan(f) => vec![format!("+{}", f)],
+            FileFilter::Between(min, max) => vec![format!("+{}", min), format!("-{}", max)],
+        }
+    }
+
+    fn from_query_value(s: &str) -> std::result::Result<Self, <T as std::str::FromStr>::Err> {
+        let v = match s {
+            s if s.starts_with('+') => FileFilter::GreaterThan(T::from_str(&s[1..])?),
+            s if s.starts_with('-') => FileFilter::LessThan(T::from_str(&s[1..])?),
+            s => FileFilter::EqualTo(T::from_str(s)?),
+        };
+
+        Ok(v)
+    }
+}
+
+impl From<FileSize> for FileFilter<FileSize> {
+    fn from(value: FileSize) -> Self {
+        FileFilter::EqualTo(value)
+    }
+}
+
+impl From<u64> for FileFilter<FileSize> {
+    fn from(value: u64) -> Self {
+        FileFilter::EqualTo(value.into())
+    }
 }
 
 impl<'de, T> Deserialize<'de> for FileFilter<T>
@@ -48,19 +206,28 @@ where
     where
         D: serde::Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer)?;
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Values {
+            One(String),
+            Two(String, String),
+        }
 
-        let v = match s {
-            s if s.starts_with('+') => FileFilter::GreaterThan(
-                T::from_str(s.trim_start_matches('+')).map_err(serde::de::Error::custom)?,
-            ),
-            s if s.starts_with('-') => FileFilter::LessThan(
-                T::from_str(s.trim_start_matches('-')).map_err(serde::de::Error::custom)?,
-            ),
-            s => FileFilter::EqualTo(T::from_str(&s).map_err(serde::de::Error::custom)?),
-        };
+        let parse = |s: &str| FileFilter::from_query_value(s).map_err(serde::de::Error::custom);
 
-        Ok(v)
+        match Values::deserialize(deserializer)? {
+            Values::One(s) => parse(&s),
+            Values::Two(a, b) => match (parse(&a)?, parse(&b)?) {
+                (FileFilter::GreaterThan(min), FileFilter::LessThan(max))
+                | (FileFilter::LessThan(max), FileFilter::GreaterThan(min)) => {
+                    Ok(FileFilter::Between(min, max))
+                }
+                _ => Err(serde::de::Error::custom(format!(
+                    "invalid filter range: {}, {}",
+                    a, b
+                ))),
+            },
+        }
     }
 }
 
@@ -73,13 +240,10 @@ where
     where
         S: serde::Serializer,
     {
-        let s = match self {
-            FileFilter::EqualTo(f) => format!("{}", f),
-            FileFilter::GreaterThan(f) => format!("+{}", f),
-            FileFilter::LessThan(f) => format!("-{}", f),
-        };
-
-        serializer.serialize_str(&s)
+        match self.query_values().as_slice() {
+            [value] => serializer.serialize_str(value),
+            values => values.serialize(serializer),
+        }
     }
 }
 
@@ -95,6 +259,73 @@ pub struct FileList {
     transaction_id: Arc<str>,
 }
 
+impl FileList {
+    /// Whether the server stopped at the list `limit` before returning every matching entry.
+    pub fn is_truncated(&self) -> bool {
+        self.returned_rows < self.total_rows
+    }
+
+    /// Interpret every listed modification time as local to a server `offset` from UTC,
+    /// see [`FileAttributes::with_server_offset`].
+    pub fn with_server_offset(self, offset: FixedOffset) -> Self {
+        let items = self
+            .items
+            .iter()
+            .map(|item| item.clone().with_server_offset(offset))
+            .collect();
+
+        FileList { items, ..self }
+    }
+
+    /// Subdirectories, leaving out the `.` and `..` entries.
+    pub fn directories(&self) -> impl Iterator<Item = &FileAttributes> {
+        self.items
+            .iter()
+            .filter(|item| item.is_dir() && !item.is_dot_entry())
+    }
+
+    /// Regular files only.
+    pub fn files(&self) -> impl Iterator<Item = &FileAttributes> {
+        self.items.iter().filter(|item| item.is_file())
+    }
+
+    /// Entries whose name matches a wildcard pattern such as `*.log`, leaving out `.` and `..`.
+    pub fn files_matching<'a>(
+        &'a self,
+        pattern: &'a str,
+    ) -> impl Iterator<Item = &'a FileAttributes> + 'a {
+        self.items
+            .iter()
+            .filter(move |item| !item.is_dot_entry() && wildcard_match(pattern, &item.name))
+    }
+
+    /// Entries from oldest to newest modification time, then by name.
+    pub fn sorted_by_mtime(&self) -> impl Iterator<Item = &FileAttributes> {
+        self.sorted_by_key(|item| (item.mtime_utc(), item.name.clone()))
+    }
+
+    /// Entries by name, as `ls` would list them.
+    pub fn sorted_by_name(&self) -> impl Iterator<Item = &FileAttributes> {
+        self.sorted_by_key(|item| item.name.clone())
+    }
+
+    /// Entries from smallest to largest, then by name.
+    pub fn sorted_by_size(&self) -> impl Iterator<Item = &FileAttributes> {
+        self.sorted_by_key(|item| (item.size, item.name.clone()))
+    }
+
+    fn sorted_by_key<K, F>(&self, key: F) -> impl Iterator<Item = &FileAttributes>
+    where
+        K: Ord,
+        F: FnMut(&&FileAttributes) -> K,
+    {
+        let mut items: Vec<&FileAttributes> = self.items.iter().collect();
+        items.sort_by_key(key);
+
+        items.into_iter()
+    }
+}
+
 impl TryFromResponse for FileList {
     async fn try_from_response(value: reqwest::Response) -> Result<Self> {
         let transaction_id = get_transaction_id(&value)?;
@@ -130,16 +361,18 @@ where
     lstat: Option<bool>,
     #[endpoint(query = "group")]
     group: Option<Arc<str>>,
-    #[endpoint(query = "mtime")]
+    #[endpoint(builder_fn = build_modified_days)]
     modified_days: Option<FileFilter<u32>>,
     #[endpoint(query = "name")]
     name: Option<Arc<str>>,
-    #[endpoint(query = "size")]
+    #[endpoint(builder_fn = build_size)]
     size: Option<FileFilter<FileSize>>,
+    /// Match an exact mode, e.g. a [`FileMode`] or `755`.
     #[endpoint(query = "perm")]
     permissions: Option<Arc<str>>,
-    #[endpoint(query = "type")]
-    file_type: Option<FileType>,
+    /// Match any of a set of file types, e.g. `[FileType::File, FileType::SymbolicLink]`.
+    #[endpoint(builder_fn = build_file_type)]
+    file_type: Option<FileTypes>,
     #[endpoint(query = "user")]
     user: Option<Arc<str>>,
     #[endpoint(query = "depth")]
@@ -154,15 +387,168 @@ where
     target_type: PhantomData<T>,
 }
 
+impl<T> FileListBuilder<T>
+where
+    T: TryFromResponse,
+{
+    /// Leave out entries of a file type, e.g. directories.
+    ///
+    /// Narrows the [`file_type`](Self::file_type) set, or every type if none was set.
+    pub fn exclude_type(self, value: FileType) -> Self {
+        let file_type = self.file_type.clone().unwrap_or_else(FileTypes::all);
+
+        FileListBuilder {
+            file_type: Some(file_type.without(value)),
+            ..self
+        }
+    }
+
+    /// Only list files modified within `age`, rounded up to whole days.
+    pub fn modified_within(self, age: TimeDelta) -> Self {
+        self.modified_days(FileFilter::LessThan(days_ceil(age)))
+    }
+
+    /// Only list files last modified more than `age` ago, rounded down to whole days.
+    pub fn modified_older_than(self, age: TimeDelta) -> Self {
+        self.modified_days(FileFilter::GreaterThan(days_floor(age)))
+    }
+
+    /// Only list files modified since `time`, rounded to whole days like [`modified_within`](Self::modified_within).
+    pub fn modified_since<Tz>(self, time: DateTime<Tz>) -> Self
+    where
+        Tz: chrono::TimeZone,
+    {
+        self.modified_within(Utc::now().signed_duration_since(time))
+    }
+
+    /// Only list files last modified before `time`, rounded to whole days like
+    /// [`modified_older_than`](Self::modified_older_than).
+    pub fn modified_before<Tz>(self, time: DateTime<Tz>) -> Self
+    where
+        Tz: chrono::TimeZone,
+    {
+        self.modified_older_than(Utc::now().signed_duration_since(time))
+    }
+}
+
+fn days_floor(age: TimeDelta) -> u32 {
+    age.num_days().clamp(0, u32::MAX as i64) as u32
+}
+
+fn days_ceil(age: TimeDelta) -> u32 {
+    let days = days_floor(age);
+
+    if age > TimeDelta::days(days as i64) {
+        days.saturating_add(1)
+    } else {
+        days
+    }
+}
+
+impl FileListBuilder<FileList> {
+    /// List the files, and if the result was truncated at the list limit, list them again with
+    /// the limit raised to the total number of entries.
+    ///
+    /// z/OSMF has no way to continue a truncated list, so the second request fetches every entry.
+    pub async fn build_complete(self) -> Result<FileList> {
+        let list = self.clone().build().await?;
+        if !list.is_truncated() {
+            return Ok(list);
+        }
+
+        FileListBuilder {
+            limit: Some(list.total_rows),
+            ..self
+        }
+        .build()
+        .await
+    }
+
+    /// List the files, then look up the tag of each regular file in the list.
+    ///
+    /// This makes one extra request per file, at most four at a time.
+    pub async fn build_with_tags(self) -> Result<FileList> {
+        let core = self.core.clone();
+        let path = self.path.clone();
+
+        let mut list = self.build().await?;
+
+        let items: Vec<FileAttributes> = futures::stream::iter(list.items.iter().cloned())
+            .map(|mut item| {
+                let core = core.clone();
+                let item_path = entry_path(&path, &item.name);
+
+                async move {
+                    if item.is_file() {
+                        let tags: FileTagList =
+                            FileTagListBuilder::new(core, item_path).build().await?;
+                        item.tag = tags.tags().first().cloned();
+                    }
+
+                    Ok::<_, crate::Error>(item)
+                }
+            })
+            .buffered(4)
+            .try_collect()
+            .await?;
+
+        list.items = items.into();
+
+        Ok(list)
+    }
+}
+
+/// The absolute path of an entry listed below `path`.
+fn entry_path(path: &str, name: &str) -> String {
+    if name.starts_with('/') {
+        name.to_string()
+    } else {
+        format!("{}/{}", path.trim_end_matches('/'), name)
+    }
+}
+
 // TODO: impl serde?
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum FileSize {
-    Bytes(u32),
+    Bytes(u64),
     Kilobytes(u32),
     Megabytes(u32),
     Gigabytes(u32),
 }
 
+impl FileSize {
+    /// The size in bytes, e.g. to compare with [`FileAttributes::size`].
+    pub fn bytes(&self) -> u64 {
+        match *self {
+            FileSize::Bytes(s) => s,
+            FileSize::Kilobytes(s) => u64::from(s) << 10,
+            FileSize::Megabytes(s) => u64::from(s) << 20,
+            FileSize::Gigabytes(s) => u64::from(s) << 30,
+        }
+    }
+}
+
+/// Uses the largest unit that represents the size exactly, e.g. `2048` is `2K`.
+impl From<u64> for FileSize {
+    fn from(value: u64) -> Self {
+        let exact = |shift: u32| {
+            (value != 0 && value.trailing_zeros() >= shift)
+                .then(|| u32::try_from(value >> shift).ok())
+                .flatten()
+        };
+
+        if let Some(s) = exact(30) {
+            FileSize::Gigabytes(s)
+        } else if let Some(s) = exact(20) {
+            FileSize::Megabytes(s)
+        } else if let Some(s) = exact(10) {
+            FileSize::Kilobytes(s)
+        } else {
+            FileSize::Bytes(value)
+        }
+    }
+}
+
 impl std::fmt::Display for FileSize {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -178,11 +564,16 @@ impl std::str::FromStr for FileSize {
     type Err = std::num::ParseIntError;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        let v = match s {
-            s if s.ends_with('K') => FileSize::Kilobytes(u32::from_str(s.trim_end_matches('K'))?),
-            s if s.ends_with('M') => FileSize::Megabytes(u32::from_str(s.trim_end_matches('M'))?),
-            s if s.ends_with('G') => FileSize::Gigabytes(u32::from_str(s.trim_end_matches('G'))?),
-            s => FileSize::Bytes(u32::from_str(s)?),
+        let (digits, unit) = match s.char_indices().last() {
+            Some((i, c)) if c.is_ascii_alphabetic() => (&s[..i], Some(c.to_ascii_uppercase())),
+            _ => (s, None),
+        };
+
+        let v = match unit {
+            Some('K') => FileSize::Kilobytes(u32::from_str(digits)?),
+            Some('M') => FileSize::Megabytes(u32::from_str(digits)?),
+            Some('G') => FileSize::Gigabytes(u32::from_str(digits)?),
+            _ => FileSize::Bytes(u64::from_str(s)?),
         };
 
         Ok(v)
@@ -219,6 +610,63 @@ pub enum FileType {
     SymbolicLink,
 }
 
+impl FileType {
+    const ALL: [FileType; 6] = [
+        FileType::CharacterSpecialFile,
+        FileType::Directory,
+        FileType::FIFO,
+        FileType::File,
+        FileType::Socket,
+        FileType::SymbolicLink,
+    ];
+}
+
+/// A set of file types to list, sent as one `type` query value each.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FileTypes(BTreeSet<FileType>);
+
+impl FileTypes {
+    pub fn all() -> Self {
+        FileType::ALL.into()
+    }
+
+    pub fn contains(&self, value: FileType) -> bool {
+        self.0.contains(&value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = FileType> + '_ {
+        self.0.iter().copied()
+    }
+
+    pub fn without(self, value: FileType) -> Self {
+        let mut new = self;
+        new.0.remove(&value);
+
+        new
+    }
+}
+
+impl From<FileType> for FileTypes {
+    fn from(value: FileType) -> Self {
+        FileTypes(BTreeSet::from([value]))
+    }
+}
+
+impl<const N: usize> From<[FileType; N]> for FileTypes {
+    fn from(value: [FileType; N]) -> Self {
+        FileTypes(value.into())
+    }
+}
+
+impl FromIterator<FileType> for FileTypes {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = FileType>,
+    {
+        FileTypes(iter.into_iter().collect())
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct ResponseJson {
@@ -242,12 +690,229 @@ where
     }
 }
 
+fn build_file_type<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &FileListBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    match &builder.file_type {
+        Some(file_type) => request_builder.query(
+            &file_type
+                .iter()
+                .map(|file_type| ("type", file_type))
+                .collect::<Vec<_>>(),
+        ),
+        None => request_builder,
+    }
+}
+
+fn build_modified_days<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &FileListBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    build_filter(request_builder, "mtime", builder.modified_days.as_ref())
+}
+
+fn build_size<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &FileListBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    build_filter(request_builder, "size", builder.size.as_ref())
+}
+
+fn build_filter<F>(
+    request_builder: reqwest::RequestBuilder,
+    key: &str,
+    filter: Option<&FileFilter<F>>,
+) -> reqwest::RequestBuilder
+where
+    F: std::fmt::Display + std::str::FromStr,
+    <F as std::str::FromStr>::Err: std::fmt::Display,
+{
+    match filter {
+        Some(filter) => request_builder.query(
+            &filter
+                .query_values()
+                .iter()
+                .map(|value| (key, value))
+                .collect::<Vec<_>>(),
+        ),
+        None => request_builder,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::tests::get_zosmf;
 
     use super::*;
 
+    #[test]
+    fn modified_filters() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .files()
+            .list("/u/ibmuser/logs")
+            .modified_within(TimeDelta::hours(36))
+            .get_request()
+            .unwrap();
+        assert_eq!(
+            request.url().query(),
+            Some("path=%2Fu%2Fibmuser%2Flogs&mtime=-2")
+        );
+
+        let request = zosmf
+            .files()
+            .list("/u/ibmuser/logs")
+            .modified_older_than(TimeDelta::days(30))
+            .get_request()
+            .unwrap();
+        assert_eq!(
+            request.url().query(),
+            Some("path=%2Fu%2Fibmuser%2Flogs&mtime=%2B30")
+        );
+
+        let request = zosmf
+            .files()
+            .list("/u/ibmuser/logs")
+            .modified_since(Utc::now() - TimeDelta::days(7) + TimeDelta::hours(1))
+            .get_request()
+            .unwrap();
+        assert_eq!(
+            request.url().query(),
+            Some("path=%2Fu%2Fibmuser%2Flogs&mtime=-7")
+        );
+
+        assert_eq!(days_ceil(TimeDelta::days(-1)), 0);
+        assert_eq!(days_ceil(TimeDelta::days(3)), 3);
+    }
+
+    #[test]
+    fn attributes_tag() {
+        let attributes: FileAttributes = serde_json::from_value(serde_json::json!({
+            "name": "app.js",
+            "mode": "-rw-r--r--",
+            "size": 10,
+            "uid": 0,
+            "gid": 1,
+            "group": "OMVSGRP",
+            "mtime": "2024-01-01T00:00:00"
+        }))
+        .unwrap();
+
+        assert!(attributes.is_file());
+        assert!(attributes.file_mode().unwrap().user_can_write());
+        assert!(attributes.tag().is_none());
+        assert_eq!(entry_path("/u/ibmuser/", "app.js"), "/u/ibmuser/app.js");
+        assert_eq!(entry_path("/u/ibmuser", "/u/ibmuser"), "/u/ibmuser");
+    }
+
+    #[test]
+    fn mtime_offsets() {
+        let attributes = |mtime: &str| -> FileAttributes {
+            serde_json::from_value(serde_json::json!({
+                "name": "app.log",
+                "mtime": mtime
+            }))
+            .unwrap()
+        };
+        let est = FixedOffset::west_opt(5 * 3600).unwrap();
+        let utc = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+
+        let naive = attributes("2024-03-10T01:30:00");
+        assert_eq!(naive.mtime_offset(), None);
+        assert_eq!(naive.mtime_fixed(), None);
+        assert_eq!(naive.mtime_utc(), Some(utc("2024-03-10T01:30:00Z")));
+
+        let local = naive.clone().with_server_offset(est);
+        assert_eq!(local.mtime(), Some("2024-03-10T01:30:00".parse().unwrap()));
+        assert_eq!(local.mtime_offset(), Some(est));
+        assert_eq!(local.mtime_utc(), Some(utc("2024-03-10T06:30:00Z")));
+        assert_eq!(
+            serde_json::to_value(&local).unwrap()["mtime"],
+            "2024-03-10T01:30:00-05:00"
+        );
+
+        let reported = attributes("2024-03-10T03:30:00-04:00").with_server_offset(est);
+        assert_eq!(reported.mtime_offset(), FixedOffset::west_opt(4 * 3600));
+        assert_eq!(reported.mtime_utc(), Some(utc("2024-03-10T07:30:00Z")));
+
+        assert_eq!(
+            serde_json::to_value(&naive).unwrap()["mtime"],
+            "2024-03-10T01:30:00"
+        );
+    }
+
+    #[test]
+    fn views() {
+        let list: FileList = serde_json::from_value(serde_json::json!({
+            "items": [
+                {"name": ".", "mode": "drwxr-xr-x", "mtime": "2024-01-01T00:00:00"},
+                {"name": "b.log", "mode": "-rw-r--r--", "size": 20, "mtime": "2024-03-01T00:00:00"},
+                {"name": "a.log", "mode": "-rw-r--r--", "size": 30, "mtime": "2024-02-01T00:00:00"},
+                {"name": "old", "mode": "drwxr-xr-x", "size": 10, "mtime": "2023-01-01T00:00:00"}
+            ],
+            "returned_rows": 4,
+            "total_rows": 4,
+            "json_version": 1,
+            "transaction_id": "TXID0001"
+        }))
+        .unwrap();
+
+        let names = |items: Vec<&FileAttributes>| {
+            items
+                .into_iter()
+                .map(|item| item.name().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(list.directories().collect()), ["old"]);
+        assert_eq!(names(list.files().collect()), ["b.log", "a.log"]);
+        assert_eq!(
+            names(list.files_matching("*.log").collect()),
+            ["b.log", "a.log"]
+        );
+        assert_eq!(
+            names(list.sorted_by_mtime().collect()),
+            ["old", ".", "a.log", "b.log"]
+        );
+        assert_eq!(
+            names(list.sorted_by_name().collect()),
+            [".", "a.log", "b.log", "old"]
+        );
+        assert_eq!(
+            names(list.sorted_by_size().collect()),
+            [".", "old", "b.log", "a.log"]
+        );
+    }
+
+    #[test]
+    fn truncated() {
+        let list = FileList {
+            items: Arc::new([]),
+            returned_rows: 1000,
+            total_rows: 1250,
+            json_version: 1,
+            transaction_id: "TXID0001".into(),
+        };
+        assert!(list.is_truncated());
+
+        let list = FileList {
+            total_rows: 1000,
+            ..list
+        };
+        assert!(!list.is_truncated());
+    }
+
     #[test]
     fn example_1() {
         let zosmf = get_zosmf();
@@ -308,6 +973,87 @@ mod tests {
         assert_eq!(format!("{:?}", manual_request), format!("{:?}", list_files))
     }
 
+    #[test]
+    fn size_filters() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .files()
+            .list("/u/ibmuser/logs")
+            .size(FileFilter::Between(FileSize::Megabytes(1), 1536u64.into()))
+            .get_request()
+            .unwrap();
+        assert_eq!(
+            request.url().query(),
+            Some("path=%2Fu%2Fibmuser%2Flogs&size=%2B1M&size=-1536")
+        );
+
+        let request = zosmf
+            .files()
+            .list("/u/ibmuser/logs")
+            .size(4096u64)
+            .get_request()
+            .unwrap();
+        assert_eq!(
+            request.url().query(),
+            Some("path=%2Fu%2Fibmuser%2Flogs&size=4K")
+        );
+
+        assert_eq!(FileSize::from(0), FileSize::Bytes(0));
+        assert_eq!(FileSize::from(3 << 30), FileSize::Gigabytes(3));
+        assert_eq!(
+            FileSize::from(5_000_000_000),
+            FileSize::Bytes(5_000_000_000)
+        );
+        assert_eq!(FileSize::Megabytes(2).bytes(), 2 << 20);
+
+        for s in ["0", "5000000000", "10K", "7M", "2G"] {
+            assert_eq!(s.parse::<FileSize>().unwrap().to_string(), s);
+        }
+        assert_eq!("10k".parse::<FileSize>().unwrap(), FileSize::Kilobytes(10));
+        assert!("10X".parse::<FileSize>().is_err());
+
+        let filter: FileFilter<FileSize> = serde_json::from_str(r#"["-2G", "+1M"]"#).unwrap();
+        assert_eq!(
+            filter,
+            FileFilter::Between(FileSize::Megabytes(1), FileSize::Gigabytes(2))
+        );
+        assert_eq!(serde_json::to_string(&filter).unwrap(), r#"["+1M","-2G"]"#);
+        assert!(serde_json::from_str::<FileFilter<FileSize>>(r#"["+1M", "+2G"]"#).is_err());
+    }
+
+    #[test]
+    fn type_filters() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .files()
+            .list("/u/ibmuser")
+            .file_type([FileType::File, FileType::SymbolicLink])
+            .get_request()
+            .unwrap();
+        assert_eq!(
+            request.url().query(),
+            Some("path=%2Fu%2Fibmuser&type=f&type=l")
+        );
+
+        let request = zosmf
+            .files()
+            .list("/u/ibmuser")
+            .exclude_type(FileType::Directory)
+            .exclude_type(FileType::Socket)
+            .get_request()
+            .unwrap();
+        assert_eq!(
+            request.url().query(),
+            Some("path=%2Fu%2Fibmuser&type=c&type=p&type=f&type=l")
+        );
+
+        let types: FileTypes = [FileType::File, FileType::FIFO].into_iter().collect();
+        assert!(types.contains(FileType::FIFO));
+        assert!(!types.without(FileType::FIFO).contains(FileType::FIFO));
+    }
+
     #[test]
     fn maximal_request() {
         let zosmf = get_zosmf();
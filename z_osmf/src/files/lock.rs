@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use futures::lock::{Mutex, OwnedMutexGuard};
+
+use crate::restfiles::Etag;
+use crate::{ClientCore, Error, Result};
+
+use super::edit::is_precondition_failed;
+use super::read::{FileRead, FileReadBuilder};
+use super::write::FileWriteBuilder;
+
+type LockMap = HashMap<Arc<str>, Arc<Mutex<Option<Arc<str>>>>>;
+
+/// A map of advisory locks on USS paths, shared by cloning it.
+///
+/// Only edits made through the same map, or its clones, wait for each other.
+/// Changes made anywhere else are detected by their etag and reported as [`Error::Conflict`].
+/// A path is dropped from the map, along with the etag the group last saw, once no guard
+/// holds or waits for its lock.
+#[derive(Clone, Debug)]
+pub struct FileLocks {
+    core: ClientCore,
+
+    encoding: Option<Arc<str>>,
+    locks: Arc<std::sync::Mutex<LockMap>>,
+}
+
+impl FileLocks {
+    pub(crate) fn new(core: ClientCore) -> Self {
+        FileLocks {
+            core,
+            encoding: None,
+            locks: Arc::new(std::sync::Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// The EBCDIC code page of the locked files, e.g. `IBM-1047`.
+    pub fn encoding<E>(self, value: E) -> Self
+    where
+        E: std::fmt::Display,
+    {
+        FileLocks {
+            encoding: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// Wait until no one else in the group holds the lock on `path`, then take it.
+    ///
+    /// The lock is released when the guard is dropped.
+    pub async fn lock<P>(&self, path: P) -> Result<FileLockGuard>
+    where
+        P: std::fmt::Display,
+    {
+        let path: Arc<str> = path.to_string().into();
+
+        let lock = self
+            .locks
+            .lock()
+            .map_err(|err| Error::RwLockPoisonError(err.to_string()))?
+            .entry(path.clone())
+            .or_default()
+            .clone();
+
+        Ok(FileLockGuard {
+            core: self.core.clone(),
+            path,
+            encoding: self.encoding.clone(),
+            locks: self.locks.clone(),
+            lock: lock.clone(),
+            etag: lock.lock_owned().await,
+        })
+    }
+}
+
+/// Exclusive access to a path within a [`FileLocks`] group.
+#[derive(Debug)]
+pub struct FileLockGuard {
+    core: ClientCore,
+
+    path: Arc<str>,
+    encoding: Option<Arc<str>>,
+    locks: Arc<std::sync::Mutex<LockMap>>,
+    lock: Arc<Mutex<Option<Arc<str>>>>,
+    etag: OwnedMutexGuard<Option<Arc<str>>>,
+}
+
+impl FileLockGuard {
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The etag of the file as last read or written by the group, if it has been edited.
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    /// Accept changes made outside the group, so the next edit applies to the current file.
+    pub fn reset(&mut self) {
+        *self.etag = None;
+    }
+
+    /// Read the file, apply `edit` to its text and write the result back with `If-Match`.
+    ///
+    /// Fails with [`Error::Conflict`] if the file no longer has the etag the group last saw,
+    /// or is changed between the read and the write, and with [`Error::NoEtag`] rather than
+    /// writing unconditionally when the read returns no etag. Returns `None` without writing
+    /// when `edit` leaves the text unchanged.
+    pub async fn edit<F>(&mut self, edit: F) -> Result<Option<Etag>>
+    where
+        F: FnOnce(&str) -> String,
+    {
+        let read = self.read_builder().build().await?;
+        self.check(&read)?;
+
+        let text = edit(read.data());
+        if text == read.data() {
+            *self.etag = read.etag().map(Arc::from);
+
+            return Ok(None);
+        }
+
+        let etag = read.etag().ok_or(Error::NoEtag)?;
+        let builder = self.write_builder().text(text).if_match(etag);
+
+        match builder.build().await {
+            Ok(written) => {
+                *self.etag = written.etag().map(Arc::from);
+
+                Ok(Some(written))
+            }
+            Err(err) if is_precondition_failed(&err) => {
+                let read = self.read_builder().build().await?;
+
+                Err(self.conflict(&read))
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    fn check(&self, read: &FileRead<Arc<str>>) -> Result<()> {
+        match self.etag.as_deref() {
+            Some(etag) if read.etag() != Some(etag) => Err(self.conflict(read)),
+            _ => Ok(()),
+        }
+    }
+
+    fn conflict(&self, read: &FileRead<Arc<str>>) -> Error {
+        Error::Conflict {
+            path: self.path.to_string(),
+            etag: read.etag().map(Arc::from),
+            transaction_id: read.transaction_id().into(),
+        }
+    }
+
+    fn read_builder(&self) -> FileReadBuilder<FileRead<Arc<str>>> {
+        let builder = FileReadBuilder::new(self.core.clone(), &self.path);
+
+        match &self.encoding {
+            Some(encoding) => builder.encoding(encoding),
+            None => builder,
+        }
+    }
+
+    fn write_builder(&self) -> FileWriteBuilder<Etag> {
+        let builder = FileWriteBuilder::new(self.core.clone(), &self.path);
+
+        match &self.encoding {
+            Some(encoding) => builder.encoding(encoding),
+            None => builder,
+        }
+    }
+}
+
+impl Drop for FileLockGuard {
+    fn drop(&mut self) {
+        let Ok(mut locks) = self.locks.lock() else {
+            return;
+        };
+
+        // the map, this guard and its mutex guard hold the only references unless someone waits
+        if Arc::strong_count(&self.lock) == 3 {
+            locks.remove(&self.path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::FutureExt;
+
+    use crate::tests::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn serializes() {
+        let zosmf = get_zosmf();
+
+        let locks = zosmf.files().locks();
+        let other = locks.clone();
+
+        let guard = locks.lock("/etc/app.conf").await.unwrap();
+        assert!(other.lock("/etc/app.conf").now_or_never().is_none());
+        assert!(other.lock("/etc/other.conf").now_or_never().is_some());
+
+        drop(guard);
+        assert!(other.lock("/etc/app.conf").now_or_never().is_some());
+        assert!(locks.locks.lock().unwrap().is_empty());
+
+        let first = locks.lock("/etc/app.conf").await.unwrap();
+        let mut waiting = Box::pin(other.lock("/etc/app.conf"));
+        assert!((&mut waiting).now_or_never().is_none());
+
+        drop(first);
+        assert_eq!(locks.locks.lock().unwrap().len(), 1);
+
+        drop(waiting.await.unwrap());
+        assert!(locks.locks.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn conflict() {
+        let zosmf = get_zosmf();
+
+        let read = |etag: &str| -> FileRead<Arc<str>> {
+            serde_json::from_value(serde_json::json!({
+                "data": "debug=false",
+                "etag": etag,
+                "transaction_id": "TXID0002"
+            }))
+            .unwrap()
+        };
+
+        let mut guard = zosmf.files().locks().lock("/etc/app.conf").await.unwrap();
+        assert!(guard.check(&read("E2")).is_ok());
+
+        *guard.etag = Some("E1".into());
+        assert!(guard.check(&read("E1")).is_ok());
+        assert!(matches!(
+            guard.check(&read("E2")),
+            Err(Error::Conflict { transaction_id, .. }) if &*transaction_id == "TXID0002"
+        ));
+
+        guard.reset();
+        assert!(guard.check(&read("E2")).is_ok());
+    }
+}
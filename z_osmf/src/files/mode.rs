@@ -5,7 +5,9 @@ use serde::{Deserialize, Serialize};
 use z_osmf_macros::Endpoint;
 
 use crate::convert::TryFromResponse;
-use crate::ClientCore;
+use crate::{ClientCore, Error, Result};
+
+use super::list::FileType;
 
 #[derive(Clone, Debug, Endpoint)]
 #[endpoint(method = put, path = "/zosmf/restfiles/fs{path}")]
@@ -34,6 +36,158 @@ pub enum FileChangeModeLinks {
     Suppress,
 }
 
+/// Permission bits for a file, displayed as an octal mode such as `755`.
+///
+/// Parses from octal (`755`, `0644`, `4755`) or symbolic (`rwxr-xr-x`, `-rwsr-xr-x`) notation.
+/// The file type of a ten character symbolic mode, as listed in [`FileAttributes::mode`],
+/// is kept for [`file_type`](Self::file_type) but does not affect the octal form.
+///
+/// [`FileAttributes::mode`]: super::list::FileAttributes::mode
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FileMode {
+    bits: u16,
+    file_type: Option<FileType>,
+}
+
+impl FileMode {
+    pub const fn new(bits: u16) -> Self {
+        FileMode {
+            bits: bits & 0o7777,
+            file_type: None,
+        }
+    }
+
+    pub fn bits(&self) -> u16 {
+        self.bits
+    }
+
+    /// The file type, if parsed from a ten character symbolic mode.
+    pub fn file_type(&self) -> Option<FileType> {
+        self.file_type
+    }
+
+    pub fn is_dir(&self) -> bool {
+        self.file_type == Some(FileType::Directory)
+    }
+
+    pub fn is_file(&self) -> bool {
+        self.file_type == Some(FileType::File)
+    }
+
+    pub fn is_symlink(&self) -> bool {
+        self.file_type == Some(FileType::SymbolicLink)
+    }
+
+    pub fn user_can_read(&self) -> bool {
+        self.has(0o400)
+    }
+
+    pub fn user_can_write(&self) -> bool {
+        self.has(0o200)
+    }
+
+    pub fn user_can_execute(&self) -> bool {
+        self.has(0o100)
+    }
+
+    pub fn group_can_read(&self) -> bool {
+        self.has(0o040)
+    }
+
+    pub fn group_can_write(&self) -> bool {
+        self.has(0o020)
+    }
+
+    pub fn group_can_execute(&self) -> bool {
+        self.has(0o010)
+    }
+
+    pub fn other_can_read(&self) -> bool {
+        self.has(0o004)
+    }
+
+    pub fn other_can_write(&self) -> bool {
+        self.has(0o002)
+    }
+
+    pub fn other_can_execute(&self) -> bool {
+        self.has(0o001)
+    }
+
+    fn has(&self, bit: u16) -> bool {
+        self.bits & bit != 0
+    }
+
+    fn from_symbolic(s: &str) -> Option<Self> {
+        let mut chars = s.chars();
+        let file_type = match s.chars().count() {
+            10 => file_type(chars.next()?),
+            _ => None,
+        };
+        let chars: Vec<char> = chars.collect();
+        if chars.len() != 9 {
+            return None;
+        }
+
+        let mut bits = 0;
+        for (i, c) in chars.iter().enumerate() {
+            let shift = 8 - i as u16;
+            let special = 0o4000 >> (i / 3);
+            bits |= match (i % 3, c) {
+                (_, '-') => 0,
+                (0, 'r') | (1, 'w') | (2, 'x') => 1 << shift,
+                (2, 's') if i < 6 => special | 1 << shift,
+                (2, 'S') if i < 6 => special,
+                (2, 't') if i == 8 => 0o1000 | 1,
+                (2, 'T') if i == 8 => 0o1000,
+                _ => return None,
+            };
+        }
+
+        Some(FileMode { bits, file_type })
+    }
+}
+
+impl std::fmt::Display for FileMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:03o}", self.bits)
+    }
+}
+
+fn file_type(c: char) -> Option<FileType> {
+    match c {
+        '-' => Some(FileType::File),
+        'c' => Some(FileType::CharacterSpecialFile),
+        'd' => Some(FileType::Directory),
+        'l' => Some(FileType::SymbolicLink),
+        'p' => Some(FileType::FIFO),
+        's' => Some(FileType::Socket),
+        _ => None,
+    }
+}
+
+impl From<u16> for FileMode {
+    fn from(value: u16) -> Self {
+        FileMode::new(value)
+    }
+}
+
+impl std::str::FromStr for FileMode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let octal = (1..=4).contains(&s.len()) && s.chars().all(|c| ('0'..='7').contains(&c));
+
+        let mode = if octal {
+            u16::from_str_radix(s, 8).ok().map(FileMode::new)
+        } else {
+            FileMode::from_symbolic(s)
+        };
+
+        mode.ok_or_else(|| Error::InvalidValue(format!("invalid file mode: {}", s)))
+    }
+}
+
 #[derive(Serialize)]
 struct RequestJson<'a> {
     request: &'static str,
@@ -124,4 +278,51 @@ mod tests {
         assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
         assert_eq!(manual_request.json(), request.json());
     }
+
+    #[test]
+    fn file_mode() {
+        assert_eq!("755".parse::<FileMode>().unwrap(), FileMode::new(0o755));
+        assert_eq!("0644".parse::<FileMode>().unwrap().to_string(), "644");
+        assert_eq!("rwxr-xr-x".parse::<FileMode>().unwrap().bits(), 0o755);
+        assert_eq!(
+            "-rwsr-xr-x".parse::<FileMode>().unwrap().to_string(),
+            "4755"
+        );
+        assert_eq!(
+            "drwxrwxrwt".parse::<FileMode>().unwrap().to_string(),
+            "1777"
+        );
+        assert_eq!(FileMode::from(0o7).to_string(), "007");
+        assert!("rwxr-xr-q".parse::<FileMode>().is_err());
+        assert!("789".parse::<FileMode>().is_err());
+        assert_eq!("érwxr-xr-x".parse::<FileMode>().unwrap().bits(), 0o755);
+        assert!("rwxr-xr-xé".parse::<FileMode>().is_err());
+
+        let mode: FileMode = "drwxr-x---".parse().unwrap();
+        assert!(mode.is_dir());
+        assert!(mode.user_can_write());
+        assert!(mode.group_can_execute());
+        assert!(!mode.group_can_write());
+        assert!(!mode.other_can_read());
+        assert_eq!(mode.to_string(), "750");
+        assert_eq!(mode.file_type(), Some(FileType::Directory));
+        assert_eq!("rwxr-x---".parse::<FileMode>().unwrap().file_type(), None);
+
+        let request = get_zosmf()
+            .files()
+            .change_mode("/u/jiahj/bin", FileMode::new(0o750))
+            .links(FileChangeModeLinks::Follow)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            request.json(),
+            Some(serde_json::json!({
+                "request": "chmod",
+                "mode": "750",
+                "links": "follow",
+                "recursive": false
+            }))
+        );
+    }
 }
@@ -0,0 +1,127 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = put, path = "/zosmf/restfiles/mfs/{filesystem}")]
+pub struct FileMountBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    filesystem: Arc<str>,
+    #[endpoint(builder_fn = build_body)]
+    mount_point: Arc<str>,
+    /// The file system type, `zFS` unless set.
+    #[endpoint(skip_builder)]
+    fs_type: Option<Arc<str>>,
+    #[endpoint(skip_builder)]
+    mode: Option<FileMountMode>,
+    #[endpoint(skip_builder)]
+    setuid: Option<bool>,
+
+    target_type: PhantomData<T>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum FileMountMode {
+    #[serde(rename = "rdonly")]
+    ReadOnly,
+    #[serde(rename = "rdwr")]
+    ReadWrite,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct RequestJson<'a> {
+    action: &'static str,
+    mount_point: &'a str,
+    fs_type: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<FileMountMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    setuid: Option<&'static str>,
+}
+
+fn build_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &FileMountBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    request_builder.json(&RequestJson {
+        action: "mount",
+        mount_point: &builder.mount_point,
+        fs_type: builder.fs_type.as_deref().unwrap_or("zFS"),
+        mode: builder.mode,
+        setuid: builder
+            .setuid
+            .map(|setuid| if setuid { "setuid" } else { "nosetuid" }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn maximal_request() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/mfs/OMVS.APP.ZFS")
+            .json(&serde_json::json!({
+                "action": "mount",
+                "mount-point": "/u/jiahj/app",
+                "fs-type": "HFS",
+                "mode": "rdonly",
+                "setuid": "nosetuid"
+            }))
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .mount("OMVS.APP.ZFS", "/u/jiahj/app")
+            .fs_type("HFS")
+            .mode(FileMountMode::ReadOnly)
+            .setuid(false)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+
+    #[test]
+    fn minimal_request() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .files()
+            .mount("OMVS.APP.ZFS", "/u/jiahj/app")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            request.json(),
+            Some(serde_json::json!({
+                "action": "mount",
+                "mount-point": "/u/jiahj/app",
+                "fs-type": "zFS"
+            }))
+        );
+    }
+}
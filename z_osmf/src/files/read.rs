@@ -1,4 +1,6 @@
 use std::marker::PhantomData;
+use std::num::NonZeroU32;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use bytes::Bytes;
@@ -8,10 +10,14 @@ use z_osmf_macros::{Endpoint, Getters};
 
 use crate::convert::TryFromResponse;
 use crate::restfiles::{get_etag, get_transaction_id};
+use crate::utils::{count_records, decode_utf8};
 use crate::{ClientCore, Result};
 
+use super::tags::{FileTag, FileTagList, FileTagListBuilder};
 use super::FileDataType;
 
+pub use crate::utils::RecordRange;
+
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct FileRead<T> {
     #[getter(skip)]
@@ -24,6 +30,11 @@ impl FileRead<Arc<str>> {
     pub fn data(&self) -> &str {
         &self.data
     }
+
+    /// The lines of a text read, split on `\n` or `\r\n`.
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        self.data.lines()
+    }
 }
 
 impl TryFromResponse for FileRead<Arc<str>> {
@@ -64,6 +75,11 @@ impl FileRead<Option<Arc<str>>> {
     pub fn data(&self) -> Option<&str> {
         self.data.as_deref()
     }
+
+    /// The lines of a text read, or `None` if the data was not modified.
+    pub fn lines(&self) -> Option<std::str::Lines<'_>> {
+        self.data.as_deref().map(str::lines)
+    }
 }
 
 impl TryFromResponse for FileRead<Option<Arc<str>>> {
@@ -108,6 +124,70 @@ impl TryFromResponse for FileRead<Option<Bytes>> {
     }
 }
 
+/// The bytes of a file as stored, with the tag that says how to decode them.
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct FileReadRaw {
+    #[getter(skip)]
+    data: Bytes,
+    #[getter(skip)]
+    tag: Option<FileTag>,
+    etag: Option<Arc<str>>,
+    transaction_id: Arc<str>,
+}
+
+impl FileReadRaw {
+    pub fn data(&self) -> &Bytes {
+        &self.data
+    }
+
+    /// The file tag, if the file has one.
+    pub fn tag(&self) -> Option<&FileTag> {
+        self.tag.as_ref()
+    }
+
+    /// The CCSID the file is tagged with, e.g. `1047`.
+    pub fn ccsid(&self) -> Option<u16> {
+        self.tag.as_ref()?.ccsid()
+    }
+}
+
+/// The lines returned by a file content search.
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct FileSearch {
+    lines: Arc<[FileSearchLine]>,
+    /// Whether more lines may follow, because `maxreturnsize` lines (100 by default) were returned.
+    #[getter(copy)]
+    truncated: bool,
+    etag: Option<Arc<str>>,
+    transaction_id: Arc<str>,
+}
+
+impl FileSearch {
+    /// Whether the search found anything.
+    pub fn is_match(&self) -> bool {
+        !self.lines.is_empty()
+    }
+
+    pub fn matches(&self) -> impl Iterator<Item = &FileSearchLine> {
+        self.lines.iter().filter(|line| line.is_match)
+    }
+
+    /// The number of returned lines that match.
+    pub fn total_matches(&self) -> usize {
+        self.matches().count()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct FileSearchLine {
+    /// The 1-based line number, if z/OSMF reported where the returned lines start.
+    #[getter(copy)]
+    number: Option<u32>,
+    text: Arc<str>,
+    #[getter(copy)]
+    is_match: bool,
+}
+
 #[derive(Clone, Debug, Endpoint)]
 #[endpoint(method = get, path = "/zosmf/restfiles/fs{path}")]
 pub struct FileReadBuilder<T>
@@ -132,10 +212,101 @@ where
     encoding: Option<Arc<str>>,
     #[endpoint(header = "If-None-Match", skip_setter)]
     etag: Option<Arc<str>>,
+    /// Lines of a text file, or bytes of a binary file, to read.
+    #[endpoint(header = "X-IBM-Record-Range")]
+    record_range: Option<RecordRange>,
 
     target_type: PhantomData<T>,
 }
 
+impl<T> FileReadBuilder<T>
+where
+    T: TryFromResponse,
+{
+    /// Read only the first `count` lines, or bytes in binary mode, e.g. to sniff the file type.
+    pub fn first_records(self, count: NonZeroU32) -> Self {
+        FileReadBuilder {
+            record_range: Some(RecordRange::StartCount(0, count)),
+            ..self
+        }
+    }
+
+    /// Read only the last `count` lines, or bytes in binary mode, e.g. the tail of a log.
+    ///
+    /// The total line count is found by probing for single lines first,
+    /// which takes a number of requests logarithmic in the size of the file.
+    pub async fn last_records(self, count: NonZeroU32) -> Result<Self> {
+        let total = count_records(|record| self.has_record(record)).await?;
+
+        Ok(FileReadBuilder {
+            record_range: Some(RecordRange::last(count, total.into())),
+            ..self
+        })
+    }
+
+    async fn has_record(&self, record: u32) -> Result<bool> {
+        let read = self.probe_builder(record).build().await?;
+
+        Ok(!read.data().is_empty())
+    }
+
+    fn probe_builder(&self, record: u32) -> FileReadBuilder<FileRead<Bytes>> {
+        FileReadBuilder {
+            core: self.core.clone(),
+            path: self.path.clone(),
+            search: None,
+            regex_search: None,
+            search_case_sensitive: None,
+            search_max_return: None,
+            data_type: self.data_type,
+            encoding: self.encoding.clone(),
+            etag: None,
+            record_range: Some(RecordRange::StartCount(record, NonZeroU32::MIN)),
+            target_type: PhantomData,
+        }
+    }
+
+    /// Run the [`search`](Self::search) or [`regex_search`](Self::regex_search) and parse the
+    /// lines z/OSMF returns, starting with the first match.
+    ///
+    /// Which of the returned lines match is only known for plain searches;
+    /// for a regular expression, only the first line is marked as a match.
+    pub async fn build_search(self) -> Result<FileSearch> {
+        let response = self.get_response().await?;
+
+        let (etag, transaction_id) = get_headers(&response)?;
+        let record_range = response
+            .headers()
+            .get("X-IBM-Record-Range")
+            .map(|v| v.to_str())
+            .transpose()?
+            .map(RecordRange::from_str)
+            .transpose()?;
+        let start = match record_range {
+            Some(RecordRange::StartCount(start, _)) => Some(start),
+            Some(RecordRange::StartEnd(start, _)) => start,
+            None => None,
+        };
+
+        let data = response.text().await?;
+        let lines = search_lines(
+            &data,
+            start,
+            self.search.as_deref(),
+            self.search_case_sensitive == Some(true),
+        );
+        let max_return = self.search_max_return.unwrap_or(DEFAULT_MAX_RETURN);
+        let truncated = lines.len() >= max_return.max(0) as usize;
+
+        Ok(FileSearch {
+            lines: lines.into(),
+            truncated,
+            etag,
+            transaction_id,
+        })
+    }
+}
+
 impl<U> FileReadBuilder<FileRead<U>>
 where
     FileRead<U>: TryFromResponse,
@@ -152,6 +323,7 @@ where
             data_type: Some(FileDataType::Binary),
             encoding: self.encoding,
             etag: self.etag,
+            record_range: self.record_range,
             target_type: PhantomData,
         }
     }
@@ -167,10 +339,29 @@ where
             data_type: Some(FileDataType::Text),
             encoding: self.encoding,
             etag: self.etag,
+            record_range: self.record_range,
             target_type: PhantomData,
         }
     }
 
+    /// Read the file byte for byte, then look up its tag, so it can be decoded by the caller.
+    ///
+    /// The tag is looked up with a second request.
+    pub async fn build_raw(self) -> Result<FileReadRaw> {
+        let core = self.core.clone();
+        let path = self.path.clone();
+
+        let read = self.binary().build().await?;
+        let tags: FileTagList = FileTagListBuilder::new(core, path).build().await?;
+
+        Ok(FileReadRaw {
+            data: read.data,
+            tag: tags.tags().first().cloned(),
+            etag: read.etag,
+            transaction_id: read.transaction_id,
+        })
+    }
+
     pub fn if_none_match<E>(self, etag: E) -> FileReadBuilder<FileRead<Option<U>>>
     where
         E: std::fmt::Display,
@@ -185,11 +376,45 @@ where
             data_type: self.data_type,
             encoding: self.encoding,
             etag: Some(etag.to_string().into()),
+            record_range: self.record_range,
             target_type: PhantomData,
         }
     }
 }
 
+impl FileReadBuilder<FileRead<Arc<str>>> {
+    /// Read the text, failing with [`Error::Utf8`](crate::Error::Utf8) at the first invalid byte sequence,
+    /// e.g. when the file is tagged with the wrong code set.
+    pub async fn build_strict(self) -> Result<FileRead<Arc<str>>> {
+        let response = self.get_response().await?;
+
+        let (etag, transaction_id) = get_headers(&response)?;
+        let data = decode_utf8(&response.bytes().await?)?.into();
+
+        Ok(FileRead {
+            data,
+            etag,
+            transaction_id,
+        })
+    }
+
+    /// Read the text, replacing invalid byte sequences with `U+FFFD`.
+    ///
+    /// Unlike [`build`](Self::build), this ignores any charset in the response `Content-Type`.
+    pub async fn build_lossy(self) -> Result<FileRead<Arc<str>>> {
+        let response = self.get_response().await?;
+
+        let (etag, transaction_id) = get_headers(&response)?;
+        let data = String::from_utf8_lossy(&response.bytes().await?).into();
+
+        Ok(FileRead {
+            data,
+            etag,
+            transaction_id,
+        })
+    }
+}
+
 impl<U> FileReadBuilder<FileRead<Option<U>>>
 where
     FileRead<Option<U>>: TryFromResponse,
@@ -205,6 +430,7 @@ where
             data_type: Some(FileDataType::Binary),
             encoding: self.encoding,
             etag: self.etag,
+            record_range: self.record_range,
             target_type: PhantomData,
         }
     }
@@ -220,6 +446,7 @@ where
             data_type: Some(FileDataType::Text),
             encoding: self.encoding,
             etag: self.etag,
+            record_range: self.record_range,
             target_type: PhantomData,
         }
     }
@@ -252,6 +479,9 @@ where
     }
 }
 
+/// The number of lines z/OSMF returns from a search without `maxreturnsize`.
+const DEFAULT_MAX_RETURN: i32 = 100;
+
 fn build_search_case_sensitive<T>(
     request_builder: reqwest::RequestBuilder,
     builder: &FileReadBuilder<T>,
@@ -265,14 +495,47 @@ where
     }
 }
 
+fn search_lines(
+    data: &str,
+    start: Option<u32>,
+    search: Option<&str>,
+    case_sensitive: bool,
+) -> Vec<FileSearchLine> {
+    let search = search.map(|search| match case_sensitive {
+        true => search.to_string(),
+        false => search.to_lowercase(),
+    });
+
+    data.lines()
+        .enumerate()
+        .map(|(i, text)| {
+            let is_match = match &search {
+                Some(search) if case_sensitive => text.contains(search.as_str()),
+                Some(search) => text.to_lowercase().contains(search.as_str()),
+                None => i == 0,
+            };
+
+            FileSearchLine {
+                number: start.map(|start| start + i as u32 + 1),
+                text: text.into(),
+                is_match,
+            }
+        })
+        .collect()
+}
+
 fn get_headers(response: &reqwest::Response) -> Result<(Option<Arc<str>>, Arc<str>)> {
     Ok((get_etag(response)?, get_transaction_id(response)?))
 }
 
 #[cfg(test)]
 mod tests {
+    use std::num::NonZeroU32;
+
     use crate::tests::*;
 
+    use super::*;
+
     #[test]
     fn data_type() {
         let zosmf = get_zosmf();
@@ -295,6 +558,92 @@ mod tests {
         assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
     }
 
+    #[test]
+    fn record_range() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/fs/u/jiahj/app.log")
+            .header("X-IBM-Data-Type", "text")
+            .header("X-IBM-Record-Range", "100,1")
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .read("/u/jiahj/app.log")
+            .text()
+            .probe_builder(100)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(manual_request.headers(), request.headers());
+
+        let request = zosmf
+            .files()
+            .read("/u/jiahj/app.bin")
+            .binary()
+            .first_records(NonZeroU32::new(4096).unwrap())
+            .get_request()
+            .unwrap();
+
+        assert_eq!(request.headers()["X-IBM-Record-Range"], "0,4096");
+    }
+
+    #[test]
+    fn lines() {
+        let read = FileRead {
+            data: Arc::<str>::from("line 1\r\n\nline 3"),
+            etag: None,
+            transaction_id: "".into(),
+        };
+
+        assert_eq!(
+            read.lines().collect::<Vec<_>>(),
+            vec!["line 1", "", "line 3"]
+        );
+
+        let not_modified = FileRead {
+            data: None::<Arc<str>>,
+            etag: Some("E1".into()),
+            transaction_id: "".into(),
+        };
+
+        assert!(not_modified.lines().is_none());
+    }
+
+    #[test]
+    fn search_results() {
+        let lines = search_lines(
+            "ERROR: disk full\nretrying\nerror: disk full\n",
+            Some(41),
+            Some("ERROR"),
+            false,
+        );
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].number(), Some(42));
+        assert_eq!(lines[1].text(), "retrying");
+        assert_eq!(
+            lines.iter().map(|l| l.is_match()).collect::<Vec<_>>(),
+            vec![true, false, true]
+        );
+
+        let lines = search_lines(
+            "ERROR: disk full\nerror: disk full",
+            None,
+            Some("ERROR"),
+            true,
+        );
+        assert_eq!(lines[0].number(), None);
+        assert!(!lines[1].is_match());
+
+        let lines = search_lines("a1\na2", None, None, false);
+        assert!(lines[0].is_match() && !lines[1].is_match());
+    }
+
     #[test]
     fn etag() {
         let zosmf = get_zosmf();
@@ -84,4 +84,29 @@ mod tests {
 
         assert_eq!(manual_request.json(), request.json());
     }
+
+    #[test]
+    fn overwrite_true() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .files()
+            .rename("/u/jiahj/app.conf.new", "/u/jiahj/app.conf")
+            .overwrite(true)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            urlencoding::decode(request.url().as_str()).unwrap(),
+            "https://test.com/zosmf/restfiles/fs/u/jiahj/app.conf"
+        );
+        assert_eq!(
+            request.json(),
+            Some(serde_json::json!({
+                "request": "move",
+                "from": "/u/jiahj/app.conf.new",
+                "overwrite": true
+            }))
+        );
+    }
 }
@@ -0,0 +1,198 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::{ClientCore, Error, Result};
+
+use super::list::{FileAttributes, FileList, FileListBuilder};
+
+#[derive(Clone, Debug)]
+pub struct FileResolveSymlinkBuilder {
+    core: ClientCore,
+
+    path: Arc<str>,
+    max_depth: usize,
+}
+
+impl FileResolveSymlinkBuilder {
+    pub(crate) fn new<P>(core: ClientCore, path: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        FileResolveSymlinkBuilder {
+            core,
+            path: path.to_string().into(),
+            max_depth: 16,
+        }
+    }
+
+    /// The most symbolic links to follow before giving up, 16 by default.
+    pub fn max_depth(self, value: usize) -> Self {
+        FileResolveSymlinkBuilder {
+            max_depth: value,
+            ..self
+        }
+    }
+
+    /// Follow the chain of symbolic links starting at the path, one `lstat` list per link.
+    ///
+    /// Relative targets are resolved against the directory of the link, without looking at
+    /// symbolic links in the directories along the way. Fails if a link is seen twice or the
+    /// chain is longer than [`max_depth`](Self::max_depth).
+    pub async fn build(self) -> Result<FileSymlinkResolution> {
+        let mut chain: Vec<Arc<str>> = Vec::new();
+        let mut seen = HashSet::new();
+        let mut current = self.path.clone();
+
+        loop {
+            if !seen.insert(current.clone()) {
+                return Err(Error::InvalidValue(format!(
+                    "symbolic link loop at {}",
+                    current
+                )));
+            }
+
+            let attributes = match self.list_builder(&current).build().await {
+                Ok(list) => list.items().first().cloned(),
+                Err(err) if err.status() == Some(reqwest::StatusCode::NOT_FOUND) => None,
+                Err(err) => return Err(err),
+            };
+
+            let target = match &attributes {
+                Some(attributes) if is_symlink(attributes) => attributes.target(),
+                _ => None,
+            };
+
+            let Some(target) = target else {
+                return Ok(FileSymlinkResolution {
+                    target: current,
+                    chain: chain.into(),
+                    attributes,
+                });
+            };
+
+            if chain.len() >= self.max_depth {
+                return Err(Error::InvalidValue(format!(
+                    "more than {} symbolic links from {}",
+                    self.max_depth, self.path
+                )));
+            }
+
+            let next = join_target(&current, target).into();
+            chain.push(current);
+            current = next;
+        }
+    }
+
+    fn list_builder(&self, path: &str) -> FileListBuilder<FileList> {
+        FileListBuilder::new(self.core.clone(), path).lstat(true)
+    }
+}
+
+/// Where a chain of symbolic links ends.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FileSymlinkResolution {
+    target: Arc<str>,
+    chain: Arc<[Arc<str>]>,
+    attributes: Option<FileAttributes>,
+}
+
+impl FileSymlinkResolution {
+    /// The final path, which is not itself a symbolic link.
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// The symbolic links followed to reach the target, starting with the original path.
+    pub fn chain(&self) -> &[Arc<str>] {
+        &self.chain
+    }
+
+    pub fn attributes(&self) -> Option<&FileAttributes> {
+        self.attributes.as_ref()
+    }
+
+    /// Whether the target exists, `false` for a dangling link.
+    pub fn exists(&self) -> bool {
+        self.attributes.is_some()
+    }
+}
+
+fn is_symlink(attributes: &FileAttributes) -> bool {
+    attributes.file_mode().is_some_and(|mode| mode.is_symlink())
+}
+
+/// Resolve a link `target` against the directory containing `link`, normalizing `.` and `..`.
+fn join_target(link: &str, target: &str) -> String {
+    let base = match target.starts_with('/') {
+        true => String::new(),
+        false => match link.rfind('/') {
+            Some(index) => link[..index].to_string(),
+            None => String::new(),
+        },
+    };
+
+    let mut parts: Vec<&str> = Vec::new();
+    for part in base.split('/').chain(target.split('/')) {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            part => parts.push(part),
+        }
+    }
+
+    format!("/{}", parts.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn join() {
+        assert_eq!(
+            join_target("/usr/lib/libz.so", "libz.so.1"),
+            "/usr/lib/libz.so.1"
+        );
+        assert_eq!(
+            join_target("/u/app/current", "../releases/2.1"),
+            "/u/releases/2.1"
+        );
+        assert_eq!(
+            join_target("/u/app/current", "/opt/app/./2.1/"),
+            "/opt/app/2.1"
+        );
+        assert_eq!(join_target("/bin", "../../.."), "/");
+    }
+
+    #[test]
+    fn request() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .files()
+            .resolve_symlink("/u/app/current")
+            .list_builder("/u/app/current")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(request.url().query(), Some("path=%2Fu%2Fapp%2Fcurrent"));
+        assert_eq!(request.headers()["X-IBM-Lstat"], "true");
+    }
+
+    #[test]
+    fn symlink() {
+        let attributes: FileAttributes = serde_json::from_value(serde_json::json!({
+            "name": "/u/app/current",
+            "mode": "lrwxrwxrwx",
+            "target": "releases/2.1"
+        }))
+        .unwrap();
+
+        assert!(is_symlink(&attributes));
+        assert_eq!(attributes.target(), Some("releases/2.1"));
+    }
+}
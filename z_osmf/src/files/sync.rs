@@ -0,0 +1,563 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Timelike, Utc};
+use futures::{StreamExt, TryStreamExt};
+use reqwest::StatusCode;
+
+use crate::{ClientCore, Error, Result};
+
+use super::delete::FileDeleteBuilder;
+use super::download::FileDownloadBuilder;
+use super::list::FileAttributes;
+use super::upload::{classify, walk, FileUploadBuilder};
+use super::walk::FileWalkBuilder;
+use super::FileDataType;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum FileSyncDirection {
+    /// Make the remote tree match the local directory.
+    Upload,
+    /// Make the local directory match the remote tree.
+    Download,
+}
+
+#[derive(Clone, Debug)]
+pub struct FileSyncBuilder {
+    core: ClientCore,
+
+    directory: PathBuf,
+    path: Arc<str>,
+    direction: FileSyncDirection,
+    rules: Vec<(Arc<str>, FileDataType)>,
+    default_type: FileDataType,
+    encoding: Option<Arc<str>>,
+    server_offset: Option<FixedOffset>,
+    delete: bool,
+    dry_run: bool,
+    concurrency: usize,
+}
+
+impl FileSyncBuilder {
+    pub(crate) fn new<D, P>(core: ClientCore, directory: D, path: P) -> Self
+    where
+        D: AsRef<Path>,
+        P: std::fmt::Display,
+    {
+        FileSyncBuilder {
+            core,
+            directory: directory.as_ref().to_path_buf(),
+            path: path.to_string().trim_end_matches('/').into(),
+            direction: FileSyncDirection::Upload,
+            rules: Vec::new(),
+            default_type: FileDataType::Binary,
+            encoding: None,
+            server_offset: None,
+            delete: false,
+            dry_run: false,
+            concurrency: 4,
+        }
+    }
+
+    /// Which side is copied to the other, [`Upload`](FileSyncDirection::Upload) by default.
+    pub fn direction(self, value: FileSyncDirection) -> Self {
+        FileSyncBuilder {
+            direction: value,
+            ..self
+        }
+    }
+
+    /// Transfer files whose name matches a wildcard pattern, e.g. `*.js`, as text.
+    ///
+    /// Rules are checked in the order they are added and the first match wins.
+    pub fn text<P>(self, pattern: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        self.rule(pattern, FileDataType::Text)
+    }
+
+    /// Transfer files whose name matches a wildcard pattern, e.g. `*.jar`, byte for byte.
+    pub fn binary<P>(self, pattern: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        self.rule(pattern, FileDataType::Binary)
+    }
+
+    /// How to transfer files that match no rule, binary unless set.
+    pub fn default_type(self, value: FileDataType) -> Self {
+        FileSyncBuilder {
+            default_type: value,
+            ..self
+        }
+    }
+
+    /// The EBCDIC code page of text files on z/OS, e.g. `IBM-1047`.
+    pub fn encoding<E>(self, value: E) -> Self
+    where
+        E: std::fmt::Display,
+    {
+        FileSyncBuilder {
+            encoding: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// The offset of the server's local time from UTC, for remote modification times
+    /// listed without one.
+    pub fn server_offset(self, value: FixedOffset) -> Self {
+        FileSyncBuilder {
+            server_offset: Some(value),
+            ..self
+        }
+    }
+
+    /// Delete files on the receiving side that do not exist on the sending side.
+    ///
+    /// Only regular files are deleted; extraneous directories are left in place.
+    pub fn delete(self, value: bool) -> Self {
+        FileSyncBuilder {
+            delete: value,
+            ..self
+        }
+    }
+
+    /// Report what would change without transferring or deleting anything.
+    pub fn dry_run(self, value: bool) -> Self {
+        FileSyncBuilder {
+            dry_run: value,
+            ..self
+        }
+    }
+
+    /// The maximum number of files transferred or deleted at once.
+    pub fn concurrency(self, value: usize) -> Self {
+        FileSyncBuilder {
+            concurrency: value.max(1),
+            ..self
+        }
+    }
+
+    /// Compare both trees, then transfer the files that are missing or differ.
+    ///
+    /// A file differs if the sending side was modified more recently or, for binary files,
+    /// the sizes do not match; text sizes change with the encoding and are not compared.
+    ///
+    /// Fails if either tree cannot be read. Errors for individual files are recorded in the report.
+    pub async fn build(self) -> Result<FileSync> {
+        let (directories, local) = self.local_files().await?;
+        let (remote_directories, remote) = self.remote_files().await?;
+
+        let (changes, unchanged) = self.plan(&local, &remote);
+
+        if !self.dry_run && self.direction == FileSyncDirection::Upload {
+            let upload = self.upload_builder();
+
+            if remote_directories.is_none() {
+                upload.create_dir(&self.path, true).await?;
+            }
+            for directory in directories.iter() {
+                let exists = remote_directories
+                    .as_ref()
+                    .is_some_and(|remote| remote.contains(directory));
+                if !exists {
+                    upload
+                        .create_dir(&self.remote_path(directory), false)
+                        .await?;
+                }
+            }
+        }
+
+        let builder = &self;
+
+        let items = futures::stream::iter(changes)
+            .map(|(path, action)| async move {
+                let result = match builder.dry_run {
+                    true => Ok(None),
+                    false => builder.apply(&path, action).await,
+                };
+
+                FileSyncChange {
+                    path: path.into(),
+                    action,
+                    result,
+                }
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+
+        Ok(FileSync {
+            direction: self.direction,
+            items,
+            unchanged,
+            dry_run: self.dry_run,
+        })
+    }
+
+    fn rule<P>(self, pattern: P, data_type: FileDataType) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        let mut new = self;
+        new.rules.push((pattern.to_string().into(), data_type));
+
+        new
+    }
+
+    fn data_type(&self, relative: &str) -> FileDataType {
+        classify(&self.rules, self.default_type, relative)
+    }
+
+    fn remote_path(&self, relative: &str) -> String {
+        format!("{}/{}", self.path, relative)
+    }
+
+    fn upload_builder(&self) -> FileUploadBuilder {
+        let builder = FileUploadBuilder::new(self.core.clone(), &self.directory, &self.path);
+
+        match &self.encoding {
+            Some(encoding) => builder.encoding(encoding),
+            None => builder,
+        }
+    }
+
+    /// The local directories and files, or nothing if a download target does not exist yet.
+    async fn local_files(&self) -> Result<(Vec<String>, BTreeMap<String, FileSyncState>)> {
+        let (directories, files) = match walk(&self.directory).await {
+            Err(Error::Io(err))
+                if err.kind() == std::io::ErrorKind::NotFound
+                    && self.direction == FileSyncDirection::Download =>
+            {
+                return Ok((Vec::new(), BTreeMap::new()))
+            }
+            result => result?,
+        };
+
+        let mut states = BTreeMap::new();
+        for (relative, local_path) in files {
+            let metadata = tokio::fs::metadata(&local_path).await?;
+            let mtime = metadata
+                .modified()
+                .ok()
+                .map(|mtime| DateTime::<Utc>::from(mtime).naive_utc());
+
+            states.insert(
+                relative,
+                FileSyncState {
+                    size: metadata.len(),
+                    mtime,
+                },
+            );
+        }
+
+        Ok((directories, states))
+    }
+
+    /// The remote directories and files, with no directories if an upload target does not exist yet.
+    async fn remote_files(
+        &self,
+    ) -> Result<(Option<BTreeSet<String>>, BTreeMap<String, FileSyncState>)> {
+        let root = Path::new(&*self.path);
+
+        let walk = FileWalkBuilder::new(self.core.clone(), root);
+        let walk = match self.server_offset {
+            Some(offset) => walk.server_offset(offset),
+            None => walk,
+        };
+
+        let entries: Vec<(PathBuf, FileAttributes)> = match walk.build().try_collect().await {
+            Err(err)
+                if err.status() == Some(StatusCode::NOT_FOUND)
+                    && self.direction == FileSyncDirection::Upload =>
+            {
+                return Ok((None, BTreeMap::new()))
+            }
+            result => result?,
+        };
+
+        let mut directories = BTreeSet::new();
+        let mut states = BTreeMap::new();
+        for (path, attributes) in entries {
+            let Some(relative) = path.strip_prefix(root).ok().and_then(|r| r.to_str()) else {
+                continue;
+            };
+
+            if attributes.is_dir() {
+                directories.insert(relative.to_string());
+            } else if attributes.is_file() {
+                states.insert(
+                    relative.to_string(),
+                    FileSyncState {
+                        size: attributes.size().map_or(0, |size| size.max(0) as u64),
+                        mtime: attributes.mtime_utc().map(|mtime| mtime.naive_utc()),
+                    },
+                );
+            }
+        }
+
+        Ok((Some(directories), states))
+    }
+
+    /// The changes needed to make the receiving side match, and the number of unchanged files.
+    fn plan(
+        &self,
+        local: &BTreeMap<String, FileSyncState>,
+        remote: &BTreeMap<String, FileSyncState>,
+    ) -> (Vec<(String, FileSyncAction)>, usize) {
+        let (source, target) = match self.direction {
+            FileSyncDirection::Upload => (local, remote),
+            FileSyncDirection::Download => (remote, local),
+        };
+
+        let mut changes = Vec::new();
+        let mut unchanged = 0;
+
+        for (relative, state) in source {
+            match target.get(relative) {
+                None => changes.push((relative.clone(), FileSyncAction::Create)),
+                Some(existing) if self.differs(relative, state, existing) => {
+                    changes.push((relative.clone(), FileSyncAction::Update))
+                }
+                Some(_) => unchanged += 1,
+            }
+        }
+
+        if self.delete {
+            changes.extend(
+                target
+                    .keys()
+                    .filter(|relative| !source.contains_key(*relative))
+                    .map(|relative| (relative.clone(), FileSyncAction::Delete)),
+            );
+        }
+
+        (changes, unchanged)
+    }
+
+    fn differs(&self, relative: &str, source: &FileSyncState, target: &FileSyncState) -> bool {
+        let seconds = |mtime: Option<NaiveDateTime>| mtime.and_then(|m| m.with_nanosecond(0));
+
+        let size_differs =
+            self.data_type(relative) == FileDataType::Binary && source.size != target.size;
+
+        size_differs || seconds(source.mtime) > seconds(target.mtime)
+    }
+
+    /// Transfer or delete one file, returning the etag of a transferred file.
+    async fn apply(&self, relative: &str, action: FileSyncAction) -> Result<Option<Arc<str>>> {
+        let local_path = self.directory.join(relative);
+        let path = self.remote_path(relative);
+        let data_type = self.data_type(relative);
+
+        match (self.direction, action) {
+            (FileSyncDirection::Upload, FileSyncAction::Delete) => {
+                FileDeleteBuilder::<String>::new(self.core.clone(), path)
+                    .build()
+                    .await?;
+
+                Ok(None)
+            }
+            (FileSyncDirection::Upload, _) => {
+                let (_, etag) = self
+                    .upload_builder()
+                    .write(&local_path, &path, data_type)
+                    .await?;
+
+                Ok(etag.etag().map(Arc::from))
+            }
+            (FileSyncDirection::Download, FileSyncAction::Delete) => {
+                tokio::fs::remove_file(&local_path).await?;
+
+                Ok(None)
+            }
+            (FileSyncDirection::Download, _) => {
+                if let Some(parent) = local_path.parent() {
+                    tokio::fs::create_dir_all(parent).await?;
+                }
+
+                let builder = FileDownloadBuilder::new(self.core.clone(), &path, &local_path)
+                    .data_type(data_type);
+                let builder = match (&self.encoding, data_type) {
+                    (Some(encoding), FileDataType::Text) => builder.encoding(encoding),
+                    _ => builder,
+                };
+
+                Ok(builder.build().await?.etag().map(Arc::from))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct FileSyncState {
+    size: u64,
+    mtime: Option<NaiveDateTime>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum FileSyncAction {
+    /// The file only exists on the sending side.
+    Create,
+    /// The file exists on both sides but differs.
+    Update,
+    /// The file only exists on the receiving side.
+    Delete,
+}
+
+#[derive(Debug)]
+pub struct FileSyncChange {
+    path: Arc<str>,
+    action: FileSyncAction,
+    result: Result<Option<Arc<str>>>,
+}
+
+impl FileSyncChange {
+    /// The path relative to both the local directory and the remote tree.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn action(&self) -> FileSyncAction {
+        self.action
+    }
+
+    /// The etag of the transferred file, `None` for deletes and in dry-run mode,
+    /// or the error that caused the change to fail.
+    pub fn result(&self) -> std::result::Result<Option<&str>, &Error> {
+        self.result.as_ref().map(|etag| etag.as_deref())
+    }
+}
+
+/// The report of a directory sync.
+#[derive(Debug)]
+pub struct FileSync {
+    direction: FileSyncDirection,
+    items: Vec<FileSyncChange>,
+    unchanged: usize,
+    dry_run: bool,
+}
+
+impl FileSync {
+    pub fn direction(&self) -> FileSyncDirection {
+        self.direction
+    }
+
+    pub fn items(&self) -> &[FileSyncChange] {
+        &self.items
+    }
+
+    /// The number of files that already matched.
+    pub fn unchanged(&self) -> usize {
+        self.unchanged
+    }
+
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// The files that were, or in dry-run mode would be, changed by `action`.
+    pub fn changed(&self, action: FileSyncAction) -> impl Iterator<Item = &str> {
+        self.items
+            .iter()
+            .filter(move |item| item.action == action && item.result.is_ok())
+            .map(|item| item.path())
+    }
+
+    pub fn failed(&self) -> impl Iterator<Item = &FileSyncChange> {
+        self.items.iter().filter(|item| item.result.is_err())
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.failed().next().is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    fn state(size: u64, mtime: &str) -> FileSyncState {
+        FileSyncState {
+            size,
+            mtime: Some(mtime.parse().unwrap()),
+        }
+    }
+
+    #[test]
+    fn plan() {
+        let zosmf = get_zosmf();
+
+        let local = BTreeMap::from([
+            ("app.jar".to_string(), state(100, "2024-03-01T00:00:00.5")),
+            ("index.js".to_string(), state(10, "2024-03-02T00:00:00")),
+            ("new.js".to_string(), state(10, "2024-03-02T00:00:00")),
+            ("lib/util.js".to_string(), state(10, "2024-03-01T00:00:00")),
+        ]);
+        let remote = BTreeMap::from([
+            ("app.jar".to_string(), state(120, "2024-03-01T00:00:00")),
+            ("index.js".to_string(), state(12, "2024-03-01T00:00:00")),
+            ("lib/util.js".to_string(), state(12, "2024-03-01T00:00:00")),
+            ("old.js".to_string(), state(10, "2024-03-01T00:00:00")),
+        ]);
+
+        let sync = zosmf.files().sync("./app", "/u/jiahj/app").text("*.js");
+
+        let (changes, unchanged) = sync.plan(&local, &remote);
+        assert_eq!(
+            changes,
+            vec![
+                ("app.jar".to_string(), FileSyncAction::Update),
+                ("index.js".to_string(), FileSyncAction::Update),
+                ("new.js".to_string(), FileSyncAction::Create),
+            ]
+        );
+        assert_eq!(unchanged, 1);
+
+        let sync = sync.direction(FileSyncDirection::Download).delete(true);
+
+        let (changes, unchanged) = sync.plan(&local, &remote);
+        assert_eq!(
+            changes,
+            vec![
+                ("app.jar".to_string(), FileSyncAction::Update),
+                ("old.js".to_string(), FileSyncAction::Create),
+                ("new.js".to_string(), FileSyncAction::Delete),
+            ]
+        );
+        assert_eq!(unchanged, 2);
+    }
+
+    #[test]
+    fn report() {
+        let report = FileSync {
+            direction: FileSyncDirection::Upload,
+            items: vec![
+                FileSyncChange {
+                    path: "index.js".into(),
+                    action: FileSyncAction::Update,
+                    result: Ok(Some("E1".into())),
+                },
+                FileSyncChange {
+                    path: "old.js".into(),
+                    action: FileSyncAction::Delete,
+                    result: Err(Error::NoEtag),
+                },
+            ],
+            unchanged: 3,
+            dry_run: false,
+        };
+
+        assert_eq!(
+            report.changed(FileSyncAction::Update).collect::<Vec<_>>(),
+            vec!["index.js"]
+        );
+        assert_eq!(report.changed(FileSyncAction::Delete).count(), 0);
+        assert_eq!(report.failed().count(), 1);
+        assert!(!report.is_success());
+    }
+}
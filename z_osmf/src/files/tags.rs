@@ -22,6 +22,24 @@ pub struct FileTag {
     path: Arc<str>,
 }
 
+impl FileTag {
+    /// The CCSID of the tagged code set, e.g. `819` for `ISO8859-1`.
+    ///
+    /// Only `IBM-nnn`, `ISO8859-1` and `UTF-8` code set names are recognized.
+    pub fn ccsid(&self) -> Option<u16> {
+        match self.code_set.as_deref()? {
+            "ISO8859-1" => Some(819),
+            "UTF-8" => Some(1208),
+            code_set => code_set.strip_prefix("IBM-")?.parse().ok(),
+        }
+    }
+
+    /// Whether the file should be transferred as text, i.e. it is tagged text or has the text flag set.
+    pub fn is_text(&self) -> bool {
+        self.text_flag || self.tag_type == Some(FileTagType::Text)
+    }
+}
+
 impl std::str::FromStr for FileTag {
     type Err = Error;
 
@@ -181,6 +199,20 @@ mod tests {
         assert!(FileTag::from_str("some nonsense").is_err());
     }
 
+    #[test]
+    fn file_tag_ccsid() {
+        let tag = FileTag::from_str("t IBM-1047    T=on  /tmp/file").unwrap();
+        assert_eq!(tag.ccsid(), Some(1047));
+        assert!(tag.is_text());
+
+        let tag = FileTag::from_str("m ISO8859-1   T=off /tmp/file").unwrap();
+        assert_eq!(tag.ccsid(), Some(819));
+        assert!(!tag.is_text());
+
+        let tag = FileTag::from_str("b untagged    T=off /tmp/file").unwrap();
+        assert_eq!(tag.ccsid(), None);
+    }
+
     #[test]
     fn maximal_request() {
         let zosmf = get_zosmf();
@@ -0,0 +1,229 @@
+use std::collections::VecDeque;
+use std::num::NonZeroU32;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures::Stream;
+
+use crate::encoding::Codepage;
+use crate::{ClientCore, Result};
+
+use super::list::{FileList, FileListBuilder};
+use super::read::{FileRead, FileReadBuilder, RecordRange};
+
+#[derive(Clone, Debug)]
+pub struct FileTailBuilder {
+    core: ClientCore,
+
+    path: Arc<str>,
+    codepage: Option<Codepage>,
+    from_start: bool,
+    max_errors: u32,
+}
+
+impl FileTailBuilder {
+    pub(crate) fn new<P>(core: ClientCore, path: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        FileTailBuilder {
+            core,
+            path: path.to_string().into(),
+            codepage: None,
+            from_start: false,
+            max_errors: 5,
+        }
+    }
+
+    /// Decode the file from EBCDIC; otherwise it is read as UTF-8, e.g. for files tagged `ISO8859-1`.
+    pub fn codepage(self, value: Codepage) -> Self {
+        FileTailBuilder {
+            codepage: Some(value),
+            ..self
+        }
+    }
+
+    /// Yield the lines already in the file before following it.
+    pub fn from_start(self, value: bool) -> Self {
+        FileTailBuilder {
+            from_start: value,
+            ..self
+        }
+    }
+
+    /// The number of polls in a row that may fail in transit or with a server error
+    /// before the stream ends, 5 unless set.
+    pub fn max_errors(self, value: u32) -> Self {
+        FileTailBuilder {
+            max_errors: value,
+            ..self
+        }
+    }
+
+    /// Poll the size of the file every `interval` and yield each complete line appended to it.
+    ///
+    /// Only new bytes are fetched. If the file shrinks, e.g. because it was rotated,
+    /// it is followed again from the start.
+    ///
+    /// A failed poll is yielded as an error. The stream ends after an error that polling
+    /// again cannot fix, e.g. a 404 once the file is deleted, or after
+    /// [`max_errors`](Self::max_errors) failures in a row.
+    pub fn follow(self, interval: Duration) -> impl Stream<Item = Result<String>> {
+        let state = TailState {
+            offset: None,
+            pending: Vec::new(),
+            ready: VecDeque::new(),
+            polled: false,
+            errors: 0,
+            done: false,
+        };
+
+        futures::stream::unfold((self, state), move |(builder, mut state)| async move {
+            loop {
+                if let Some(line) = state.ready.pop_front() {
+                    return Some((Ok(line), (builder, state)));
+                }
+
+                if state.done {
+                    return None;
+                }
+
+                if state.polled {
+                    tokio::time::sleep(interval).await;
+                }
+                state.polled = true;
+
+                match builder.poll(&mut state).await {
+                    Ok(()) => state.errors = 0,
+                    Err(err) => {
+                        state.errors += 1;
+                        state.done = !err.is_retryable() || state.errors >= builder.max_errors;
+
+                        return Some((Err(err), (builder, state)));
+                    }
+                }
+            }
+        })
+    }
+
+    async fn poll(&self, state: &mut TailState) -> Result<()> {
+        let list = self.list_builder().build().await?;
+        let size = list
+            .items()
+            .first()
+            .and_then(|item| item.size())
+            .unwrap_or(0)
+            .max(0) as u64;
+
+        let offset = match state.offset {
+            Some(offset) if offset <= size => offset,
+            Some(_) => {
+                state.pending.clear();
+                0
+            }
+            None if self.from_start => 0,
+            None => size,
+        };
+        state.offset = Some(offset);
+
+        let count = (size - offset).min(u32::MAX as u64) as u32;
+        let Some(count) = NonZeroU32::new(count) else {
+            return Ok(());
+        };
+
+        let read = self.read_builder(offset, count).build().await?;
+        state.offset = Some(offset + read.data().len() as u64);
+        state.pending.extend_from_slice(read.data());
+        state
+            .ready
+            .extend(split_lines(&mut state.pending, self.codepage));
+
+        Ok(())
+    }
+
+    fn list_builder(&self) -> FileListBuilder<FileList> {
+        FileListBuilder::new(self.core.clone(), &self.path)
+    }
+
+    fn read_builder(&self, offset: u64, count: NonZeroU32) -> FileReadBuilder<FileRead<Bytes>> {
+        let start = offset.min(u32::MAX as u64) as u32;
+
+        FileReadBuilder::<FileRead<Arc<str>>>::new(self.core.clone(), &self.path)
+            .binary()
+            .record_range(RecordRange::StartCount(start, count))
+    }
+}
+
+struct TailState {
+    offset: Option<u64>,
+    pending: Vec<u8>,
+    ready: VecDeque<String>,
+    polled: bool,
+    errors: u32,
+    done: bool,
+}
+
+/// Remove and decode the complete lines at the start of `pending`.
+fn split_lines(pending: &mut Vec<u8>, codepage: Option<Codepage>) -> Vec<String> {
+    // EBCDIC text uses NL (0x15), though LF (0x25) also appears
+    let is_newline = |b: &u8| match codepage {
+        Some(_) => *b == 0x15 || *b == 0x25,
+        None => *b == b'\n',
+    };
+
+    let Some(end) = pending.iter().rposition(is_newline) else {
+        return Vec::new();
+    };
+    let complete: Vec<u8> = pending.drain(..=end).collect();
+
+    complete[..end]
+        .split(is_newline)
+        .map(|line| match codepage {
+            Some(codepage) => codepage.decode(line),
+            None => String::from_utf8_lossy(line)
+                .trim_end_matches('\r')
+                .to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn read_request() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .files()
+            .tail("/u/jiahj/app.log")
+            .read_builder(1024, NonZeroU32::new(512).unwrap())
+            .get_request()
+            .unwrap();
+
+        assert_eq!(request.headers()["X-IBM-Data-Type"], "binary");
+        assert_eq!(request.headers()["X-IBM-Record-Range"], "1024,512");
+    }
+
+    #[test]
+    fn lines() {
+        let mut pending = b"first\r\nsecond\nthi".to_vec();
+        assert_eq!(split_lines(&mut pending, None), vec!["first", "second"]);
+        assert_eq!(pending, b"thi");
+
+        pending.extend_from_slice(b"rd\n");
+        assert_eq!(split_lines(&mut pending, None), vec!["third"]);
+        assert!(pending.is_empty());
+
+        let mut pending = vec![0xC1, 0x15, 0xC2, 0x25, 0xC3];
+        assert_eq!(
+            split_lines(&mut pending, Some(Codepage::Ibm1047)),
+            vec!["A", "B"]
+        );
+        assert_eq!(pending, vec![0xC3]);
+    }
+}
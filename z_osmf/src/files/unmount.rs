@@ -0,0 +1,108 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = put, path = "/zosmf/restfiles/mfs/{filesystem}")]
+pub struct FileUnmountBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    filesystem: Arc<str>,
+    #[endpoint(builder_fn = build_body)]
+    mode: Option<FileUnmountMode>,
+
+    target_type: PhantomData<T>,
+}
+
+/// How to treat activity on the file system when unmounting it.
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum FileUnmountMode {
+    /// Fail if any files are open or the file system has mounts below it.
+    #[default]
+    Normal,
+    /// Wait until all activity on the file system has finished.
+    Drain,
+    /// Unmount at once, failing any requests that use open files.
+    Immediate,
+    /// Unmount even if an immediate unmount failed; may lose data.
+    Force,
+}
+
+#[derive(Serialize)]
+struct RequestJson {
+    action: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<FileUnmountMode>,
+}
+
+fn build_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &FileUnmountBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    request_builder.json(&RequestJson {
+        action: "unmount",
+        mode: builder.mode,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn example_1() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/mfs/OMVS.APP.ZFS")
+            .json(&serde_json::json!({
+                "action": "unmount"
+            }))
+            .build()
+            .unwrap();
+
+        let request = zosmf.files().unmount("OMVS.APP.ZFS").get_request().unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+
+    #[test]
+    fn drain() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .files()
+            .unmount("OMVS.APP.ZFS")
+            .mode(FileUnmountMode::Drain)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            request.json(),
+            Some(serde_json::json!({
+                "action": "unmount",
+                "mode": "drain"
+            }))
+        );
+    }
+}
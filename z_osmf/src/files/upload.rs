@@ -0,0 +1,772 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use futures::{StreamExt, TryStreamExt};
+
+use crate::restfiles::Etag;
+use crate::utils::wildcard_match;
+use crate::{ClientCore, Error, Result};
+
+use super::create_dir::{is_exists_error, FileCreateDirBuilder};
+use super::download::is_transient;
+use super::list::FileAttributes;
+use super::mode::FileChangeModeBuilder;
+use super::owner::FileChangeOwnerBuilder;
+use super::tags::set::FileTagsSetBuilder;
+use super::tags::{FileTagList, FileTagListBuilder, FileTagType};
+use super::walk::FileWalkBuilder;
+use super::write::FileWriteBuilder;
+use super::FileDataType;
+
+#[derive(Clone, Debug)]
+pub struct FileUploadBuilder {
+    core: ClientCore,
+
+    directory: PathBuf,
+    path: Arc<str>,
+    rules: Vec<(Arc<str>, FileDataType)>,
+    default_type: FileDataType,
+    encoding: Option<Arc<str>>,
+    mode: Option<Arc<str>>,
+    directory_mode: Option<Arc<str>>,
+    tag: Option<Arc<str>>,
+    manifest: HashMap<Arc<str>, FileUploadAttributes>,
+    mirror: Option<Arc<str>>,
+    concurrency: usize,
+    retries: u32,
+}
+
+impl FileUploadBuilder {
+    pub(crate) fn new<D, P>(core: ClientCore, directory: D, path: P) -> Self
+    where
+        D: AsRef<Path>,
+        P: std::fmt::Display,
+    {
+        FileUploadBuilder {
+            core,
+            directory: directory.as_ref().to_path_buf(),
+            path: path.to_string().trim_end_matches('/').into(),
+            rules: Vec::new(),
+            default_type: FileDataType::Binary,
+            encoding: None,
+            mode: None,
+            directory_mode: None,
+            tag: None,
+            manifest: HashMap::new(),
+            mirror: None,
+            concurrency: 4,
+            retries: 0,
+        }
+    }
+
+    /// Upload files whose name matches a wildcard pattern, e.g. `*.js`, as text.
+    ///
+    /// Rules are checked in the order they are added and the first match wins.
+    pub fn text<P>(self, pattern: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        self.rule(pattern, FileDataType::Text)
+    }
+
+    /// Upload files whose name matches a wildcard pattern, e.g. `*.jar`, byte for byte.
+    pub fn binary<P>(self, pattern: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        self.rule(pattern, FileDataType::Binary)
+    }
+
+    /// How to upload files that match no rule, binary unless set.
+    pub fn default_type(self, value: FileDataType) -> Self {
+        FileUploadBuilder {
+            default_type: value,
+            ..self
+        }
+    }
+
+    /// The EBCDIC code page text files are converted to, e.g. `IBM-1047`.
+    pub fn encoding<E>(self, value: E) -> Self
+    where
+        E: std::fmt::Display,
+    {
+        FileUploadBuilder {
+            encoding: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// The permissions applied to each uploaded file, e.g. `644` or `rwxr-xr-x`.
+    pub fn mode<M>(self, value: M) -> Self
+    where
+        M: std::fmt::Display,
+    {
+        FileUploadBuilder {
+            mode: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// The permissions of directories created by the upload.
+    pub fn directory_mode<M>(self, value: M) -> Self
+    where
+        M: std::fmt::Display,
+    {
+        FileUploadBuilder {
+            directory_mode: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// Tag text files as text in this code set, e.g. `ISO8859-1`, and binary files as binary.
+    pub fn tag<C>(self, code_set: C) -> Self
+    where
+        C: std::fmt::Display,
+    {
+        FileUploadBuilder {
+            tag: Some(code_set.to_string().into()),
+            ..self
+        }
+    }
+
+    /// The attributes of individual files, keyed by their path relative to the local directory.
+    ///
+    /// Attributes set here take precedence over a [`mirror`](Self::mirror),
+    /// which in turn takes precedence over [`mode`](Self::mode) and [`tag`](Self::tag).
+    pub fn manifest<I, R>(self, entries: I) -> Self
+    where
+        I: IntoIterator<Item = (R, FileUploadAttributes)>,
+        R: std::fmt::Display,
+    {
+        let mut new = self;
+        new.manifest.extend(
+            entries
+                .into_iter()
+                .map(|(relative, attributes)| (relative.to_string().into(), attributes)),
+        );
+
+        new
+    }
+
+    /// Give each uploaded file the mode, owner and tag of the file at the same relative path
+    /// below this remote directory, e.g. the previous release of an application.
+    ///
+    /// The tree is walked before anything is uploaded; files it does not contain are left alone.
+    pub fn mirror<P>(self, path: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        FileUploadBuilder {
+            mirror: Some(path.to_string().trim_end_matches('/').into()),
+            ..self
+        }
+    }
+
+    /// The maximum number of files transferred at once.
+    pub fn concurrency(self, value: usize) -> Self {
+        FileUploadBuilder {
+            concurrency: value.max(1),
+            ..self
+        }
+    }
+
+    /// How many times to write a file again after the connection fails, none by default.
+    ///
+    /// z/OSMF cannot append to a file, so each retry uploads the whole file.
+    pub fn retries(self, value: u32) -> Self {
+        FileUploadBuilder {
+            retries: value,
+            ..self
+        }
+    }
+
+    /// Create the directory tree, then upload the files in it.
+    ///
+    /// Fails if the local directory cannot be read or a remote directory cannot be created.
+    /// Errors uploading individual files are recorded in the report.
+    pub async fn build(self) -> Result<FileUpload> {
+        let (directories, files) = walk(&self.directory).await?;
+
+        let mirrored = match &self.mirror {
+            Some(source) => self.mirrored(source, &directories, &files).await?,
+            None => HashMap::new(),
+        };
+        let mirrored = &mirrored;
+
+        self.create_dir(&self.path, true).await?;
+        for directory in directories.iter() {
+            self.create_dir(&self.remote_path(directory), false).await?;
+        }
+
+        let builder = &self;
+
+        let items = futures::stream::iter(files)
+            .map(|(relative, local_path)| async move {
+                let path = builder.remote_path(&relative);
+                let data_type = builder.data_type(&relative);
+                let attributes = builder.attributes(&relative, data_type, mirrored);
+
+                let (size, etag, error) = match builder
+                    .upload(&local_path, &path, data_type, &attributes)
+                    .await
+                {
+                    Ok((size, etag)) => (size, etag, None),
+                    Err(err) => (0, None, Some(err)),
+                };
+
+                FileUploadItem {
+                    local_path,
+                    path: path.into(),
+                    data_type,
+                    size,
+                    etag,
+                    error,
+                }
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+
+        Ok(FileUpload {
+            directories: directories.len(),
+            items,
+        })
+    }
+
+    fn rule<P>(self, pattern: P, data_type: FileDataType) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        let mut new = self;
+        new.rules.push((pattern.to_string().into(), data_type));
+
+        new
+    }
+
+    fn data_type(&self, relative: &str) -> FileDataType {
+        classify(&self.rules, self.default_type, relative)
+    }
+
+    /// The attributes of a file, merged from the manifest, the mirrored tree and the defaults.
+    fn attributes(
+        &self,
+        relative: &str,
+        data_type: FileDataType,
+        mirrored: &HashMap<String, FileUploadAttributes>,
+    ) -> FileUploadAttributes {
+        let defaults = FileUploadAttributes {
+            mode: self.mode.clone(),
+            tag_type: self.tag.as_ref().map(|_| match data_type {
+                FileDataType::Binary => FileTagType::Binary,
+                FileDataType::Text => FileTagType::Text,
+            }),
+            code_set: self.tag.clone().filter(|_| data_type == FileDataType::Text),
+            ..Default::default()
+        };
+
+        self.manifest
+            .get(relative)
+            .cloned()
+            .unwrap_or_default()
+            .or(mirrored.get(relative).cloned().unwrap_or_default())
+            .or(defaults)
+    }
+
+    /// The attributes of the files below `source` that will be uploaded, by relative path.
+    ///
+    /// Only the directories that also exist locally are listed.
+    async fn mirrored(
+        &self,
+        source: &str,
+        directories: &[String],
+        files: &[(String, PathBuf)],
+    ) -> Result<HashMap<String, FileUploadAttributes>> {
+        let wanted: Arc<HashSet<String>> = Arc::new(
+            directories
+                .iter()
+                .cloned()
+                .chain(files.iter().map(|(relative, _)| relative.clone()))
+                .collect(),
+        );
+        let root = PathBuf::from(source);
+        let relative = move |path: &Path| {
+            path.strip_prefix(&root)
+                .ok()
+                .and_then(|path| path.to_str())
+                .map(String::from)
+        };
+
+        let entries: Vec<(PathBuf, FileAttributes)> = {
+            let wanted = wanted.clone();
+            let relative = relative.clone();
+
+            FileWalkBuilder::new(self.core.clone(), source)
+                .filter(move |path, _| relative(path).is_some_and(|r| wanted.contains(&r)))
+                .build()
+                .try_collect()
+                .await?
+        };
+
+        futures::stream::iter(entries.into_iter().filter_map(|(path, attributes)| {
+            attributes
+                .is_file()
+                .then(|| relative(&path))
+                .flatten()
+                .map(|relative| (relative, path, attributes))
+        }))
+        .map(|(relative, path, attributes)| async move {
+            let tags: FileTagList =
+                FileTagListBuilder::new(self.core.clone(), path.to_string_lossy())
+                    .build()
+                    .await?;
+
+            let mut attributes = FileUploadAttributes::from(&attributes);
+            if let Some(tag) = tags.tags().first() {
+                attributes.tag_type = tag.tag_type();
+                attributes.code_set = tag.code_set().map(Arc::from);
+            }
+
+            Ok::<_, Error>((relative, attributes))
+        })
+        .buffered(self.concurrency)
+        .try_collect()
+        .await
+    }
+
+    fn remote_path(&self, relative: &str) -> String {
+        format!("{}/{}", self.path, relative)
+    }
+
+    pub(super) async fn create_dir(&self, path: &str, recursive: bool) -> Result<()> {
+        let builder = FileCreateDirBuilder::new(self.core.clone(), path).recursive(recursive);
+        let builder = match &self.directory_mode {
+            Some(mode) => builder.mode(mode),
+            None => builder,
+        };
+
+        match builder.build().await {
+            Err(err) if !is_exists_error(&err) => Err(err),
+            _ => Ok(()),
+        }
+    }
+
+    async fn upload(
+        &self,
+        local_path: &Path,
+        path: &str,
+        data_type: FileDataType,
+        attributes: &FileUploadAttributes,
+    ) -> Result<(u64, Option<Arc<str>>)> {
+        let mut retries = 0;
+        let (size, written) = loop {
+            match self.write(local_path, path, data_type).await {
+                Err(err) if is_transient(&err) && retries < self.retries => retries += 1,
+                result => break result?,
+            }
+        };
+
+        self.apply(path, attributes).await?;
+
+        Ok((size, written.etag().map(Arc::from)))
+    }
+
+    /// Change the mode, owner and tag of an uploaded file, all at once.
+    async fn apply(&self, path: &str, attributes: &FileUploadAttributes) -> Result<()> {
+        let mode = async {
+            if let Some(mode) = &attributes.mode {
+                FileChangeModeBuilder::<String>::new(self.core.clone(), path, mode)
+                    .build()
+                    .await?;
+            }
+
+            Ok::<_, Error>(())
+        };
+
+        let owner = async {
+            if let Some(owner) = &attributes.owner {
+                let builder = FileChangeOwnerBuilder::<String>::new(self.core.clone(), path, owner);
+                let builder = match &attributes.group {
+                    Some(group) => builder.group(group),
+                    None => builder,
+                };
+
+                builder.build().await?;
+            }
+
+            Ok(())
+        };
+
+        let tag = async {
+            if attributes.tag_type.is_some() || attributes.code_set.is_some() {
+                let builder = FileTagsSetBuilder::<String>::new(self.core.clone(), path);
+                let builder = match attributes.tag_type {
+                    Some(tag_type) => builder.tag_type(tag_type),
+                    None => builder,
+                };
+                let builder = match &attributes.code_set {
+                    Some(code_set) => builder.code_set(code_set),
+                    None => builder,
+                };
+
+                builder.build().await?;
+            }
+
+            Ok(())
+        };
+
+        futures::try_join!(mode, owner, tag)?;
+
+        Ok(())
+    }
+
+    pub(super) async fn write(
+        &self,
+        local_path: &Path,
+        path: &str,
+        data_type: FileDataType,
+    ) -> Result<(u64, Etag)> {
+        let builder: FileWriteBuilder<Etag> = FileWriteBuilder::new(self.core.clone(), path);
+
+        let (builder, size) = match data_type {
+            FileDataType::Binary => {
+                let file = tokio::fs::File::open(local_path).await?;
+                let size = file.metadata().await?.len();
+
+                (builder.reader(file, Some(size)), size)
+            }
+            FileDataType::Text => {
+                let text = tokio::fs::read_to_string(local_path).await?;
+                let size = text.len() as u64;
+                let builder = builder.text(text);
+
+                match &self.encoding {
+                    Some(encoding) => (builder.encoding(encoding), size),
+                    None => (builder, size),
+                }
+            }
+        };
+
+        Ok((size, builder.build().await?))
+    }
+}
+
+/// The mode, owner and tag given to an uploaded file.
+///
+/// The group is only changed along with the owner.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FileUploadAttributes {
+    mode: Option<Arc<str>>,
+    owner: Option<Arc<str>>,
+    group: Option<Arc<str>>,
+    tag_type: Option<FileTagType>,
+    code_set: Option<Arc<str>>,
+}
+
+impl FileUploadAttributes {
+    pub fn new() -> Self {
+        FileUploadAttributes::default()
+    }
+
+    /// The permissions of the file, e.g. `644` or `rwxr-xr-x`.
+    pub fn mode<M>(self, value: M) -> Self
+    where
+        M: std::fmt::Display,
+    {
+        FileUploadAttributes {
+            mode: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    pub fn owner<O>(self, value: O) -> Self
+    where
+        O: std::fmt::Display,
+    {
+        FileUploadAttributes {
+            owner: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    pub fn group<G>(self, value: G) -> Self
+    where
+        G: std::fmt::Display,
+    {
+        FileUploadAttributes {
+            group: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    pub fn tag_type(self, value: FileTagType) -> Self {
+        FileUploadAttributes {
+            tag_type: Some(value),
+            ..self
+        }
+    }
+
+    pub fn code_set<C>(self, value: C) -> Self
+    where
+        C: std::fmt::Display,
+    {
+        FileUploadAttributes {
+            code_set: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// Fill the attributes not set here from `other`, keeping the tag type and code set together.
+    fn or(self, other: FileUploadAttributes) -> Self {
+        let has_tag = self.tag_type.is_some() || self.code_set.is_some();
+        let has_owner = self.owner.is_some();
+
+        FileUploadAttributes {
+            mode: self.mode.or(other.mode),
+            owner: self.owner.or(other.owner),
+            group: if has_owner {
+                self.group
+            } else {
+                self.group.or(other.group)
+            },
+            tag_type: if has_tag {
+                self.tag_type
+            } else {
+                other.tag_type
+            },
+            code_set: if has_tag {
+                self.code_set
+            } else {
+                other.code_set
+            },
+        }
+    }
+}
+
+impl From<&FileAttributes> for FileUploadAttributes {
+    fn from(value: &FileAttributes) -> Self {
+        FileUploadAttributes {
+            mode: value.file_mode().map(|mode| mode.to_string().into()),
+            owner: value.user().map(Arc::from),
+            group: value.group().map(Arc::from),
+            tag_type: value.tag().and_then(|tag| tag.tag_type()),
+            code_set: value.tag().and_then(|tag| tag.code_set()).map(Arc::from),
+        }
+    }
+}
+
+/// How to transfer a file, by the first rule whose pattern matches its name.
+pub(super) fn classify(
+    rules: &[(Arc<str>, FileDataType)],
+    default_type: FileDataType,
+    relative: &str,
+) -> FileDataType {
+    let name = relative.rsplit('/').next().unwrap_or(relative);
+
+    rules
+        .iter()
+        .find(|(pattern, _)| wildcard_match(pattern, name))
+        .map(|(_, data_type)| *data_type)
+        .unwrap_or(default_type)
+}
+
+/// The relative paths of the directories and files below `root`, parents first.
+///
+/// Symbolic links and other special files are skipped.
+pub(super) async fn walk(root: &Path) -> Result<(Vec<String>, Vec<(String, PathBuf)>)> {
+    let mut directories = Vec::new();
+    let mut files = Vec::new();
+    let mut pending = vec![(String::new(), root.to_path_buf())];
+
+    while let Some((prefix, directory)) = pending.pop() {
+        let mut read_dir = tokio::fs::read_dir(&directory).await?;
+        let mut entries = Vec::new();
+        while let Some(entry) = read_dir.next_entry().await? {
+            entries.push(entry);
+        }
+        entries.sort_by_key(|entry| entry.file_name());
+
+        for entry in entries.into_iter().rev() {
+            let name = entry
+                .file_name()
+                .to_str()
+                .map(String::from)
+                .ok_or_else(|| {
+                    Error::InvalidValue(format!("invalid file name: {:?}", entry.path()))
+                })?;
+            let relative = format!("{}{}", prefix, name);
+            let file_type = entry.file_type().await?;
+
+            if file_type.is_dir() {
+                directories.push(relative.clone());
+                pending.push((format!("{}/", relative), entry.path()));
+            } else if file_type.is_file() {
+                files.push((relative, entry.path()));
+            }
+        }
+    }
+
+    directories.sort_by_key(|d| d.matches('/').count());
+    files.sort();
+
+    Ok((directories, files))
+}
+
+/// The report of a directory upload.
+#[derive(Debug)]
+pub struct FileUpload {
+    directories: usize,
+    items: Vec<FileUploadItem>,
+}
+
+impl FileUpload {
+    /// The number of directories below the root that were created or already existed.
+    pub fn directories(&self) -> usize {
+        self.directories
+    }
+
+    pub fn items(&self) -> &[FileUploadItem] {
+        &self.items
+    }
+
+    pub fn failed(&self) -> impl Iterator<Item = &FileUploadItem> {
+        self.items.iter().filter(|item| item.error.is_some())
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.failed().next().is_none()
+    }
+}
+
+#[derive(Debug)]
+pub struct FileUploadItem {
+    local_path: PathBuf,
+    path: Arc<str>,
+    data_type: FileDataType,
+    size: u64,
+    etag: Option<Arc<str>>,
+    error: Option<Error>,
+}
+
+impl FileUploadItem {
+    pub fn local_path(&self) -> &Path {
+        &self.local_path
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn data_type(&self) -> FileDataType {
+        self.data_type
+    }
+
+    /// The number of bytes read from [`local_path`](Self::local_path).
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    pub fn error(&self) -> Option<&Error> {
+        self.error.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn classify() {
+        let zosmf = get_zosmf();
+
+        let upload = zosmf
+            .files()
+            .upload("./app", "/u/jiahj/app/")
+            .binary("*.min.js")
+            .text("*.js")
+            .text("*.json")
+            .default_type(FileDataType::Binary);
+
+        assert_eq!(upload.data_type("lib/index.js"), FileDataType::Text);
+        assert_eq!(upload.data_type("lib/vendor.min.js"), FileDataType::Binary);
+        assert_eq!(upload.data_type("package.json"), FileDataType::Text);
+        assert_eq!(upload.data_type("img/logo.png"), FileDataType::Binary);
+        assert_eq!(
+            upload.remote_path("lib/index.js"),
+            "/u/jiahj/app/lib/index.js"
+        );
+    }
+
+    #[test]
+    fn merge_attributes() {
+        let zosmf = get_zosmf();
+
+        let upload = zosmf
+            .files()
+            .upload("./app", "/u/jiahj/app")
+            .text("*.sh")
+            .mode("644")
+            .tag("IBM-1047")
+            .manifest([(
+                "bin/start.sh",
+                FileUploadAttributes::new().mode("755").group("appgrp"),
+            )]);
+        let mirrored = HashMap::from([(
+            "bin/start.sh".to_string(),
+            FileUploadAttributes::new()
+                .mode("700")
+                .owner("appusr")
+                .group("sys1")
+                .tag_type(FileTagType::Text)
+                .code_set("ISO8859-1"),
+        )]);
+
+        assert_eq!(
+            upload.attributes("bin/start.sh", FileDataType::Text, &mirrored),
+            FileUploadAttributes::new()
+                .mode("755")
+                .owner("appusr")
+                .group("appgrp")
+                .tag_type(FileTagType::Text)
+                .code_set("ISO8859-1")
+        );
+        assert_eq!(
+            upload.attributes("lib/app.jar", FileDataType::Binary, &mirrored),
+            FileUploadAttributes::new()
+                .mode("644")
+                .tag_type(FileTagType::Binary)
+        );
+    }
+
+    #[tokio::test]
+    async fn walk_tree() {
+        let root = std::env::temp_dir().join(format!("z_osmf_upload_{}", std::process::id()));
+        tokio::fs::create_dir_all(root.join("lib/util"))
+            .await
+            .unwrap();
+        tokio::fs::write(root.join("package.json"), "{}")
+            .await
+            .unwrap();
+        tokio::fs::write(root.join("lib/index.js"), "")
+            .await
+            .unwrap();
+        tokio::fs::write(root.join("lib/util/fmt.js"), "")
+            .await
+            .unwrap();
+
+        let (directories, files) = walk(&root).await.unwrap();
+        tokio::fs::remove_dir_all(&root).await.unwrap();
+
+        assert_eq!(directories, vec!["lib", "lib/util"]);
+        assert_eq!(
+            files.iter().map(|(r, _)| r.as_str()).collect::<Vec<_>>(),
+            vec!["lib/index.js", "lib/util/fmt.js", "package.json"]
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use chrono::FixedOffset;
+use futures::Stream;
+
+use crate::{ClientCore, Result};
+
+use super::list::{FileAttributes, FileList, FileListBuilder};
+
+type FilterFn = Arc<dyn Fn(&Path, &FileAttributes) -> bool + Send + Sync>;
+
+#[derive(Clone)]
+pub struct FileWalkBuilder {
+    core: ClientCore,
+
+    path: PathBuf,
+    max_depth: Option<u32>,
+    follow_symlinks: bool,
+    limit: Option<i32>,
+    server_offset: Option<FixedOffset>,
+    filter: Option<FilterFn>,
+}
+
+impl std::fmt::Debug for FileWalkBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileWalkBuilder")
+            .field("path", &self.path)
+            .field("max_depth", &self.max_depth)
+            .field("follow_symlinks", &self.follow_symlinks)
+            .field("limit", &self.limit)
+            .field("server_offset", &self.server_offset)
+            .field("filter", &self.filter.is_some())
+            .finish()
+    }
+}
+
+impl FileWalkBuilder {
+    pub(crate) fn new<P>(core: ClientCore, path: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        FileWalkBuilder {
+            core,
+            path: path.as_ref().to_path_buf(),
+            max_depth: None,
+            follow_symlinks: false,
+            limit: None,
+            server_offset: None,
+            filter: None,
+        }
+    }
+
+    /// How many levels below the starting directory to visit, `1` for its entries only.
+    pub fn max_depth(self, value: u32) -> Self {
+        FileWalkBuilder {
+            max_depth: Some(value),
+            ..self
+        }
+    }
+
+    /// Descend into symbolic links to directories.
+    ///
+    /// By default links are reported as links and not followed, which also avoids cycles.
+    pub fn follow_symlinks(self, value: bool) -> Self {
+        FileWalkBuilder {
+            follow_symlinks: value,
+            ..self
+        }
+    }
+
+    /// The maximum number of entries returned for each directory.
+    pub fn limit(self, value: i32) -> Self {
+        FileWalkBuilder {
+            limit: Some(value),
+            ..self
+        }
+    }
+
+    /// The offset of the server's local time from UTC, for modification times listed without one.
+    ///
+    /// See [`FileAttributes::with_server_offset`].
+    pub fn server_offset(self, value: FixedOffset) -> Self {
+        FileWalkBuilder {
+            server_offset: Some(value),
+            ..self
+        }
+    }
+
+    /// Only yield entries for which `filter` returns `true`.
+    ///
+    /// Directories that are filtered out are not descended into.
+    pub fn filter<F>(self, filter: F) -> Self
+    where
+        F: Fn(&Path, &FileAttributes) -> bool + Send + Sync + 'static,
+    {
+        FileWalkBuilder {
+            filter: Some(Arc::new(filter)),
+            ..self
+        }
+    }
+
+    /// Walk the tree breadth first, listing each directory only when the stream reaches it.
+    ///
+    /// A directory that cannot be listed yields an error and the walk carries on.
+    pub fn build(self) -> impl Stream<Item = Result<(PathBuf, FileAttributes)>> {
+        let pending = VecDeque::from([(self.path.clone(), 0)]);
+
+        futures::stream::unfold(
+            (self, pending, VecDeque::new()),
+            |(builder, mut pending, mut ready)| async move {
+                loop {
+                    if let Some(entry) = ready.pop_front() {
+                        return Some((Ok(entry), (builder, pending, ready)));
+                    }
+
+                    let (directory, depth) = pending.pop_front()?;
+
+                    let list = match builder.list_builder(&directory).build().await {
+                        Ok(list) => list,
+                        Err(err) => return Some((Err(err), (builder, pending, ready))),
+                    };
+
+                    let list = match builder.server_offset {
+                        Some(offset) => list.with_server_offset(offset),
+                        None => list,
+                    };
+
+                    for attributes in list.items().iter() {
+                        if matches!(attributes.name(), "." | "..") {
+                            continue;
+                        }
+
+                        let path = directory.join(attributes.name());
+                        if !builder.is_selected(&path, attributes) {
+                            continue;
+                        }
+
+                        if is_directory(attributes) && builder.descends_below(depth + 1) {
+                            pending.push_back((path.clone(), depth + 1));
+                        }
+                        ready.push_back((path, attributes.clone()));
+                    }
+                }
+            },
+        )
+    }
+
+    fn list_builder(&self, path: &Path) -> FileListBuilder<FileList> {
+        let builder = FileListBuilder::new(self.core.clone(), path.to_string_lossy())
+            .lstat(!self.follow_symlinks);
+
+        match self.limit {
+            Some(limit) => builder.limit(limit),
+            None => builder,
+        }
+    }
+
+    fn is_selected(&self, path: &Path, attributes: &FileAttributes) -> bool {
+        self.filter
+            .as_ref()
+            .is_none_or(|filter| filter(path, attributes))
+    }
+
+    fn descends_below(&self, depth: u32) -> bool {
+        self.max_depth.is_none_or(|max_depth| depth < max_depth)
+    }
+}
+
+fn is_directory(attributes: &FileAttributes) -> bool {
+    attributes.mode().is_some_and(|mode| mode.starts_with('d'))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn list_request() {
+        let zosmf = get_zosmf();
+
+        let walk = zosmf.files().walk("/u/ibmuser").limit(500);
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/fs")
+            .query(&[("path", "/u/ibmuser/src"), ("limit", "500")])
+            .header("X-IBM-Lstat", "true")
+            .build()
+            .unwrap();
+
+        let request = walk
+            .list_builder(Path::new("/u/ibmuser/src"))
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
+    #[test]
+    fn depth() {
+        let zosmf = get_zosmf();
+
+        let walk = zosmf.files().walk("/u/ibmuser");
+        assert!(walk.descends_below(100));
+
+        let walk = walk.max_depth(2);
+        assert!(walk.descends_below(1));
+        assert!(!walk.descends_below(2));
+    }
+}
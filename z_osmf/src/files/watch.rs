@@ -0,0 +1,305 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::Stream;
+
+use crate::{ClientCore, Result};
+
+use super::list::{FileAttributes, FileList, FileListBuilder};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum FileWatchEventKind {
+    Created,
+    Modified,
+    Deleted,
+}
+
+#[derive(Clone, Debug)]
+pub struct FileWatchEvent {
+    kind: FileWatchEventKind,
+    name: Arc<str>,
+    attributes: FileAttributes,
+}
+
+impl FileWatchEvent {
+    pub fn kind(&self) -> FileWatchEventKind {
+        self.kind
+    }
+
+    /// The name of the file, relative to the watched path.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The attributes of the file, or its last known attributes if it was deleted.
+    pub fn attributes(&self) -> &FileAttributes {
+        &self.attributes
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FileWatchBuilder {
+    core: ClientCore,
+
+    path: Arc<str>,
+    name: Option<Arc<str>>,
+    interval: Duration,
+    max_interval: Duration,
+    include_existing: bool,
+    max_errors: u32,
+}
+
+impl FileWatchBuilder {
+    pub(crate) fn new<P>(core: ClientCore, path: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        FileWatchBuilder {
+            core,
+            path: path.to_string().into(),
+            name: None,
+            interval: Duration::from_secs(30),
+            max_interval: Duration::from_secs(300),
+            include_existing: false,
+            max_errors: 5,
+        }
+    }
+
+    /// Only watch files whose name matches a pattern, e.g. `*.xml`.
+    pub fn name<N>(self, value: N) -> Self
+    where
+        N: std::fmt::Display,
+    {
+        FileWatchBuilder {
+            name: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// The time between polls, 30 seconds unless set.
+    pub fn interval(self, value: Duration) -> Self {
+        FileWatchBuilder {
+            interval: value,
+            ..self
+        }
+    }
+
+    /// The longest time between polls while listing the path keeps failing, 5 minutes unless set.
+    pub fn max_interval(self, value: Duration) -> Self {
+        FileWatchBuilder {
+            max_interval: value,
+            ..self
+        }
+    }
+
+    /// Report the files already present on the first poll as created.
+    pub fn include_existing(self, value: bool) -> Self {
+        FileWatchBuilder {
+            include_existing: value,
+            ..self
+        }
+    }
+
+    /// The number of polls in a row that may fail in transit or with a server error
+    /// before the stream ends, 5 unless set.
+    pub fn max_errors(self, value: u32) -> Self {
+        FileWatchBuilder {
+            max_errors: value,
+            ..self
+        }
+    }
+
+    /// Poll the path, yielding an event for each file created, modified or deleted.
+    ///
+    /// Changes are detected by comparing the size and modification time of each file.
+    /// A failed poll yields an error and doubles the interval, up to the maximum,
+    /// until a poll succeeds again. The stream ends after an error that polling again
+    /// cannot fix, e.g. a 404 once the path is deleted, or after
+    /// [`max_errors`](Self::max_errors) failures in a row.
+    pub fn build(self) -> impl Stream<Item = Result<FileWatchEvent>> {
+        let state = WatchState {
+            delay: None,
+            snapshot: None,
+            ready: VecDeque::new(),
+            errors: 0,
+            done: false,
+        };
+
+        futures::stream::unfold((self, state), |(builder, mut state)| async move {
+            loop {
+                if let Some(event) = state.ready.pop_front() {
+                    return Some((Ok(event), (builder, state)));
+                }
+
+                if state.done {
+                    return None;
+                }
+
+                if let Some(delay) = state.delay {
+                    tokio::time::sleep(delay).await;
+                }
+
+                let list = match builder.list_builder().build().await {
+                    Ok(list) => list,
+                    Err(err) => {
+                        state.errors += 1;
+                        state.done = !err.is_retryable() || state.errors >= builder.max_errors;
+                        state.delay = Some(match state.delay {
+                            Some(delay) => (delay * 2).min(builder.max_interval),
+                            None => builder.interval,
+                        });
+
+                        return Some((Err(err), (builder, state)));
+                    }
+                };
+                state.errors = 0;
+                state.delay = Some(builder.interval);
+
+                let snapshot = snapshot(&list);
+                let previous = match state.snapshot.take() {
+                    Some(previous) => previous,
+                    None if builder.include_existing => BTreeMap::new(),
+                    None => snapshot.clone(),
+                };
+
+                state.ready.extend(diff(&previous, &snapshot));
+                state.snapshot = Some(snapshot);
+            }
+        })
+    }
+
+    fn list_builder(&self) -> FileListBuilder<FileList> {
+        let builder = FileListBuilder::new(self.core.clone(), &self.path);
+
+        match &self.name {
+            Some(name) => builder.name(name),
+            None => builder,
+        }
+    }
+}
+
+struct WatchState {
+    delay: Option<Duration>,
+    snapshot: Option<BTreeMap<Arc<str>, FileAttributes>>,
+    ready: VecDeque<FileWatchEvent>,
+    errors: u32,
+    done: bool,
+}
+
+fn snapshot(list: &FileList) -> BTreeMap<Arc<str>, FileAttributes> {
+    list.items()
+        .iter()
+        .filter(|item| !matches!(item.name(), "." | ".."))
+        .map(|item| (item.name().into(), item.clone()))
+        .collect()
+}
+
+fn diff(
+    previous: &BTreeMap<Arc<str>, FileAttributes>,
+    current: &BTreeMap<Arc<str>, FileAttributes>,
+) -> Vec<FileWatchEvent> {
+    let mut events: Vec<FileWatchEvent> = current
+        .iter()
+        .filter_map(|(name, attributes)| {
+            let kind = match previous.get(name) {
+                None => FileWatchEventKind::Created,
+                Some(old)
+                    if old.mtime() != attributes.mtime() || old.size() != attributes.size() =>
+                {
+                    FileWatchEventKind::Modified
+                }
+                Some(_) => return None,
+            };
+
+            Some(FileWatchEvent {
+                kind,
+                name: name.clone(),
+                attributes: attributes.clone(),
+            })
+        })
+        .collect();
+
+    events.extend(
+        previous
+            .iter()
+            .filter(|(name, _)| !current.contains_key(*name))
+            .map(|(name, attributes)| FileWatchEvent {
+                kind: FileWatchEventKind::Deleted,
+                name: name.clone(),
+                attributes: attributes.clone(),
+            }),
+    );
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    fn attributes(name: &str, size: i32, mtime: &str) -> (Arc<str>, FileAttributes) {
+        let attributes = serde_json::from_value(serde_json::json!({
+            "name": name,
+            "mode": "-rw-r--r--",
+            "size": size,
+            "mtime": mtime
+        }))
+        .unwrap();
+
+        (name.into(), attributes)
+    }
+
+    #[test]
+    fn changes() {
+        let previous = BTreeMap::from([
+            attributes("a.xml", 10, "2024-01-01T00:00:00"),
+            attributes("b.xml", 10, "2024-01-01T00:00:00"),
+            attributes("c.xml", 10, "2024-01-01T00:00:00"),
+        ]);
+        let current = BTreeMap::from([
+            attributes("a.xml", 10, "2024-01-01T00:00:00"),
+            attributes("b.xml", 20, "2024-01-02T00:00:00"),
+            attributes("d.xml", 5, "2024-01-02T00:00:00"),
+        ]);
+
+        let events: Vec<_> = diff(&previous, &current)
+            .iter()
+            .map(|event| (event.kind(), event.name().to_string()))
+            .collect();
+
+        assert_eq!(
+            events,
+            vec![
+                (FileWatchEventKind::Modified, "b.xml".to_string()),
+                (FileWatchEventKind::Created, "d.xml".to_string()),
+                (FileWatchEventKind::Deleted, "c.xml".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn list_request() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restfiles/fs")
+            .query(&[("path", "/u/exchange/in"), ("name", "*.xml")])
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .watch("/u/exchange/in")
+            .name("*.xml")
+            .list_builder()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+}
@@ -1,11 +1,14 @@
 use std::marker::PhantomData;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use bytes::Bytes;
+use futures::TryStream;
+use tokio::io::AsyncRead;
+use tokio_util::io::ReaderStream;
 use z_osmf_macros::Endpoint;
 
 use crate::convert::TryFromResponse;
-use crate::ClientCore;
+use crate::{ClientCore, Error, Result};
 
 #[derive(Clone, Debug, Endpoint)]
 #[endpoint(method = put, path = "/zosmf/restfiles/fs{path}")]
@@ -20,7 +23,7 @@ where
 
     #[endpoint(skip_builder)]
     crlf_newlines: Option<bool>,
-    #[endpoint(skip_setter, builder_fn = build_data)]
+    #[endpoint(skip_setter, try_builder_fn = build_data)]
     data: Option<Data>,
     #[endpoint(skip_builder)]
     encoding: Option<Arc<str>>,
@@ -43,6 +46,36 @@ where
         self
     }
 
+    /// Upload binary data from a stream of chunks without buffering it in memory.
+    ///
+    /// With a `content_length` the request is sent with a `Content-Length` header,
+    /// otherwise it uses chunked transfer encoding.
+    /// A streamed body can only be sent once, so clones of this builder share it,
+    /// and building the request again fails instead of sending an empty file.
+    pub fn stream<S>(mut self, stream: S, content_length: Option<u64>) -> Self
+    where
+        S: TryStream + Send + 'static,
+        S::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+        Bytes: From<S::Ok>,
+    {
+        self.data = Some(Data::Stream(
+            Arc::new(Mutex::new(Some(reqwest::Body::wrap_stream(stream)))),
+            content_length,
+        ));
+
+        self
+    }
+
+    /// Upload binary data from a reader, such as a [`tokio::fs::File`], without buffering it in memory.
+    ///
+    /// See [`stream`](Self::stream) for how `content_length` is used.
+    pub fn reader<R>(self, reader: R, content_length: Option<u64>) -> Self
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        self.stream(ReaderStream::new(reader), content_length)
+    }
+
     pub fn text<B>(mut self, data: B) -> Self
     where
         B: std::fmt::Display,
@@ -56,7 +89,7 @@ where
 fn build_data<T>(
     request_builder: reqwest::RequestBuilder,
     builder: &FileWriteBuilder<T>,
-) -> reqwest::RequestBuilder
+) -> Result<reqwest::RequestBuilder>
 where
     T: TryFromResponse,
 {
@@ -67,7 +100,7 @@ where
         ..
     } = builder;
 
-    match data {
+    Ok(match data {
         Some(Data::Binary(binary)) => request_builder
             .body(binary.clone())
             .header("X-IBM-Data-Type", "binary"),
@@ -83,13 +116,33 @@ where
             _ => request_builder,
         }
         .body(text.to_string()),
+        Some(Data::Stream(body, content_length)) => {
+            let request_builder = match content_length {
+                Some(length) => request_builder.header(reqwest::header::CONTENT_LENGTH, *length),
+                None => request_builder,
+            }
+            .header("X-IBM-Data-Type", "binary");
+
+            let body = body
+                .lock()
+                .map_err(|err| {
+                    Error::InvalidValue(format!("the streamed body is unavailable: {}", err))
+                })?
+                .take()
+                .ok_or_else(|| {
+                    Error::InvalidValue("the streamed body has already been consumed".into())
+                })?;
+
+            request_builder.body(body)
+        }
         _ => request_builder,
-    }
+    })
 }
 
 #[derive(Clone, Debug)]
 enum Data {
     Binary(Bytes),
+    Stream(Arc<Mutex<Option<reqwest::Body>>>, Option<u64>),
     Text(Arc<str>),
 }
 
@@ -98,6 +151,7 @@ mod tests {
     use bytes::Bytes;
 
     use crate::tests::*;
+    use crate::Error;
 
     #[test]
     fn binary() {
@@ -121,6 +175,46 @@ mod tests {
         assert_eq!(format!("{:?}", manual_request), format!("{:?}", request))
     }
 
+    #[test]
+    fn stream() {
+        let zosmf = get_zosmf();
+
+        let chunks: Vec<Result<Bytes, std::io::Error>> =
+            vec![Ok(Bytes::from("some ")), Ok(Bytes::from("data"))];
+
+        let builder = zosmf
+            .files()
+            .write("/u/jiahj/app.tar")
+            .stream(futures::stream::iter(chunks), Some(9));
+
+        let request = builder.get_request().unwrap();
+
+        assert_eq!(request.headers()["Content-Length"], "9");
+        assert_eq!(request.headers()["X-IBM-Data-Type"], "binary");
+        assert!(request.body().unwrap().as_bytes().is_none());
+
+        assert!(matches!(
+            builder.get_request(),
+            Err(Error::InvalidValue(message)) if message.contains("already been consumed")
+        ));
+    }
+
+    #[test]
+    fn reader() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .files()
+            .write("/u/jiahj/app.tar")
+            .reader(&b"some data"[..], None)
+            .get_request()
+            .unwrap();
+
+        assert!(request.headers().get("Content-Length").is_none());
+        assert_eq!(request.headers()["X-IBM-Data-Type"], "binary");
+        assert!(request.body().is_some());
+    }
+
     #[test]
     fn encoding() {
         let zosmf = get_zosmf();
@@ -145,6 +239,41 @@ mod tests {
         assert_eq!(format!("{:?}", manual_request), format!("{:?}", request))
     }
 
+    #[test]
+    fn if_match() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restfiles/fs/u/jiahj/app.conf")
+            .header("If-Match", "B5C6454F783590AA8EC15BD88E29EA63")
+            .header("x-ibm-data-type", "text;fileEncoding=IBM-1047")
+            .body("debug=true")
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .write("/u/jiahj/app.conf")
+            .text("debug=true")
+            .encoding("IBM-1047")
+            .if_match("B5C6454F783590AA8EC15BD88E29EA63")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(manual_request.method(), request.method());
+        assert_eq!(
+            urlencoding::decode(manual_request.url().as_str()).unwrap(),
+            urlencoding::decode(request.url().as_str()).unwrap()
+        );
+        assert_eq!(manual_request.headers(), request.headers());
+        assert_eq!(
+            manual_request.body().unwrap().as_bytes(),
+            request.body().unwrap().as_bytes()
+        );
+    }
+
     #[test]
     fn example_1() {
         let zosmf = get_zosmf();
@@ -0,0 +1,191 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use serde::Serialize;
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::files::mode::FileMode;
+use crate::ClientCore;
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = post, path = "/zosmf/restfiles/mfs/zfs/{name}")]
+pub struct FileZfsCreateBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    name: Arc<str>,
+    /// The primary allocation in cylinders.
+    #[endpoint(builder_fn = build_body)]
+    primary_cylinders: i32,
+    /// The secondary allocation in cylinders.
+    ///
+    /// z/OSMF has no request to grow or alter an existing aggregate. A zFS file system with a
+    /// secondary allocation extends itself as it fills when it is mounted with `aggrgrow`
+    /// in effect, which is the default unless `IOEFSPRM` sets `aggrgrow=off`.
+    #[endpoint(skip_builder)]
+    secondary_cylinders: Option<i32>,
+    #[endpoint(skip_setter, skip_builder)]
+    volumes: Option<Vec<Arc<str>>>,
+    #[endpoint(skip_builder)]
+    owner: Option<Arc<str>>,
+    #[endpoint(skip_builder)]
+    group: Option<Arc<str>>,
+    /// The permissions of the root directory of the new file system.
+    #[endpoint(skip_builder)]
+    perms: Option<FileMode>,
+    #[endpoint(skip_builder)]
+    storage_class: Option<Arc<str>>,
+    #[endpoint(skip_builder)]
+    management_class: Option<Arc<str>>,
+    #[endpoint(skip_builder)]
+    data_class: Option<Arc<str>>,
+    /// Seconds to wait for the request to complete.
+    #[endpoint(skip_builder)]
+    timeout: Option<i32>,
+
+    target_type: PhantomData<T>,
+}
+
+impl<T> FileZfsCreateBuilder<T>
+where
+    T: TryFromResponse,
+{
+    pub fn volume<V>(self, value: V) -> Self
+    where
+        V: std::fmt::Display,
+    {
+        let mut new = self;
+        new.volumes
+            .get_or_insert_with(Vec::new)
+            .push(value.to_string().into());
+
+        new
+    }
+
+    pub fn volumes<V>(self, value: &[V]) -> Self
+    where
+        V: std::fmt::Display,
+    {
+        let mut new = self;
+        new.volumes
+            .get_or_insert_with(Vec::new)
+            .extend(value.iter().map(|v| v.to_string().into()));
+
+        new
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestJson<'a> {
+    cyls_pri: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cyls_sec: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    volumes: Option<&'a [Arc<str>]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    perms: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    storage_class: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    management_class: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data_class: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timeout: Option<i32>,
+}
+
+fn build_body<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &FileZfsCreateBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    request_builder.json(&RequestJson {
+        cyls_pri: builder.primary_cylinders,
+        cyls_sec: builder.secondary_cylinders,
+        volumes: builder.volumes.as_deref(),
+        owner: builder.owner.as_deref(),
+        group: builder.group.as_deref(),
+        // z/OSMF takes the octal digits of the mode as a JSON number, e.g. 755.
+        perms: builder
+            .perms
+            .map(|perms| perms.to_string().parse().unwrap_or_default()),
+        storage_class: builder.storage_class.as_deref(),
+        management_class: builder.management_class.as_deref(),
+        data_class: builder.data_class.as_deref(),
+        timeout: builder.timeout,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn maximal_request() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .post("https://test.com/zosmf/restfiles/mfs/zfs/OMVS.APP.ZFS")
+            .json(&serde_json::json!({
+                "cylsPri": 10,
+                "cylsSec": 2,
+                "volumes": ["ZFS001", "ZFS002"],
+                "owner": "IBMUSER",
+                "group": "OMVSGRP",
+                "perms": 755,
+                "storageClass": "SCZFS",
+                "managementClass": "MCZFS",
+                "dataClass": "DCZFS",
+                "timeout": 30
+            }))
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .create_zfs("OMVS.APP.ZFS", 10)
+            .secondary_cylinders(2)
+            .volume("ZFS001")
+            .volumes(&["ZFS002"])
+            .owner("IBMUSER")
+            .group("OMVSGRP")
+            .perms(FileMode::new(0o755))
+            .storage_class("SCZFS")
+            .management_class("MCZFS")
+            .data_class("DCZFS")
+            .timeout(30)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+        assert_eq!(manual_request.json(), request.json());
+    }
+
+    #[test]
+    fn minimal_request() {
+        let zosmf = get_zosmf();
+
+        let request = zosmf
+            .files()
+            .create_zfs("OMVS.APP.ZFS", 10)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(request.json(), Some(serde_json::json!({ "cylsPri": 10 })));
+    }
+}
@@ -0,0 +1,46 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use z_osmf_macros::Endpoint;
+
+use crate::convert::TryFromResponse;
+use crate::ClientCore;
+
+#[derive(Clone, Debug, Endpoint)]
+#[endpoint(method = delete, path = "/zosmf/restfiles/mfs/zfs/{name}")]
+pub struct FileZfsDeleteBuilder<T>
+where
+    T: TryFromResponse,
+{
+    core: Arc<ClientCore>,
+
+    #[endpoint(path)]
+    name: Arc<str>,
+
+    target_type: PhantomData<T>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    #[test]
+    fn example_1() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .delete("https://test.com/zosmf/restfiles/mfs/zfs/OMVS.APP.ZFS")
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .files()
+            .delete_zfs("OMVS.APP.ZFS")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+}
@@ -1,10 +1,19 @@
+pub mod archive;
 pub mod class;
+pub mod cleanup;
+pub mod download;
+pub mod explain;
 pub mod feedback;
 pub mod files;
+pub mod flow;
 pub mod list;
+pub mod monitor;
+pub mod output;
 pub mod purge;
+pub mod resubmit;
 pub mod status;
 pub mod submit;
+pub mod wait;
 
 use std::sync::Arc;
 
@@ -14,14 +23,23 @@ use z_osmf_macros::Getters;
 use crate::convert::TryFromResponse;
 use crate::{ClientCore, Error, Result};
 
+use self::archive::JobArchiveBuilder;
 use self::class::JobChangeClassBuilder;
+use self::cleanup::JobCleanupBuilder;
+use self::download::JobOutputDownloadBuilder;
 use self::feedback::{JobFeedback, JobFeedbackBuilder};
+use self::files::dd_name::JobFileByDdNameBuilder;
 use self::files::read::{JobFileId, JobFileRead, JobFileReadBuilder};
 use self::files::{JobFileList, JobFileListBuilder};
+use self::flow::JobFlowBuilder;
 use self::list::{JobList, JobListBuilder};
+use self::monitor::JobMonitorBuilder;
+use self::output::JobOutputBuilder;
 use self::purge::JobPurgeBuilder;
+use self::resubmit::JobResubmitBuilder;
 use self::status::JobStatusBuilder;
 use self::submit::{JobSource, JobSubmitBuilder};
+use self::wait::JobWaitBuilder;
 
 #[derive(Clone, Debug)]
 pub struct JobsClient {
@@ -34,6 +52,30 @@ impl JobsClient {
         JobsClient { core }
     }
 
+    /// Collect the status, step data, JCL and spool files of a job into one bundle.
+    ///
+    /// # Examples
+    ///
+    /// Save job TESTJOBJ with ID JOB00023 as a directory and as a zip file:
+    /// ```
+    /// # use z_osmf::jobs::JobIdentifier;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let identifier = JobIdentifier::NameId("TESTJOBJ".to_string(), "JOB00023".to_string());
+    ///
+    /// let archive = zosmf.jobs().archive(identifier).build().await?;
+    ///
+    /// archive.write_to_dir("incidents/TESTJOBJ.JOB00023").await?;
+    /// tokio::fs::write("incidents/TESTJOBJ.JOB00023.zip", archive.to_zip()?).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn archive<I>(&self, identifier: I) -> JobArchiveBuilder
+    where
+        I: Into<JobIdentifier>,
+    {
+        JobArchiveBuilder::new(self.core.clone(), identifier)
+    }
+
     /// # Examples
     ///
     /// Cancel job TESTJOB2 with ID JOB0084:
@@ -104,6 +146,73 @@ impl JobsClient {
         JobChangeClassBuilder::new(self.core.clone(), identifier, class)
     }
 
+    /// # Examples
+    ///
+    /// Save the output and JCL of job TESTJOBJ with ID JOB00023 to a local directory:
+    /// ```
+    /// # use z_osmf::jobs::JobIdentifier;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let identifier = JobIdentifier::NameId("TESTJOBJ".to_string(), "JOB00023".to_string());
+    ///
+    /// let download = zosmf
+    ///     .jobs()
+    ///     .download_output(identifier, "incidents/TESTJOBJ.JOB00023")
+    ///     .encoding("IBM-037")
+    ///     .build()
+    ///     .await?;
+    ///
+    /// for (job_file, path) in download.files() {
+    ///     println!("{} -> {}", job_file.label(), path.display());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn download_output<I, D>(&self, identifier: I, directory: D) -> JobOutputDownloadBuilder
+    where
+        I: Into<JobIdentifier>,
+        D: AsRef<std::path::Path>,
+    {
+        JobOutputDownloadBuilder::new(self.core.clone(), identifier, directory)
+    }
+
+    /// Run several jobs, each one submitted once the jobs it depends on have succeeded.
+    ///
+    /// # Examples
+    ///
+    /// Load the results of two extracts, then report on them, accepting warnings:
+    /// ```
+    /// # use z_osmf::jobs::submit::JobSource;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let jobs = zosmf.jobs();
+    ///
+    /// let report = jobs
+    ///     .flow()
+    ///     .job("EXTRACT1", jobs.submit(JobSource::member("PROD.JCL", "EXTRACT1")?))
+    ///     .job("EXTRACT2", jobs.submit(JobSource::member("PROD.JCL", "EXTRACT2")?))
+    ///     .job_after(
+    ///         "LOAD",
+    ///         jobs.submit(JobSource::member("PROD.JCL", "LOAD")?),
+    ///         ["EXTRACT1", "EXTRACT2"],
+    ///     )
+    ///     .job_after(
+    ///         "REPORT",
+    ///         jobs.submit(JobSource::member("PROD.JCL", "REPORT")?),
+    ///         ["LOAD"],
+    ///     )
+    ///     .max_cc(4)
+    ///     .build()
+    ///     .await?;
+    ///
+    /// for (name, outcome) in report.unsuccessful() {
+    ///     println!("{} did not succeed: {:?}", name, outcome);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn flow(&self) -> JobFlowBuilder {
+        JobFlowBuilder::new(self.core.clone())
+    }
+
     /// # Examples
     ///
     /// Hold job TESTJOBW with ID JOB0023:
@@ -147,6 +256,31 @@ impl JobsClient {
         JobListBuilder::new(self.core.clone())
     }
 
+    /// # Examples
+    ///
+    /// List the active jobs owned by the logged in user:
+    /// ```
+    /// # use z_osmf::jobs::list::JobListStatus;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// zosmf.login("USERNAME", "PASSWORD").await?;
+    ///
+    /// let job_list = zosmf
+    ///     .jobs()
+    ///     .list_my_jobs()?
+    ///     .status(JobListStatus::Active)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Fails with [`Error::NoUser`] unless a user has logged in with [`ZOsmf::login`](crate::ZOsmf::login).
+    pub fn list_my_jobs(&self) -> Result<JobListBuilder<JobList<JobAttributes>>> {
+        let user = self.core.user()?.ok_or(Error::NoUser)?;
+
+        Ok(self.list().owner(user))
+    }
+
     /// # Examples
     ///
     /// List the spool files for job TESTJOB1 with ID JOB00023:
@@ -170,6 +304,113 @@ impl JobsClient {
         JobFileListBuilder::new(self.core.clone(), identifier)
     }
 
+    /// # Examples
+    ///
+    /// Follow a job through the queues until it finishes:
+    /// ```
+    /// # use futures::TryStreamExt;
+    /// # use z_osmf::jobs::monitor::JobMonitorEventKind;
+    /// # use z_osmf::jobs::JobIdentifier;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let identifier = JobIdentifier::NameId("TESTJOBJ".to_string(), "JOB00023".to_string());
+    ///
+    /// let monitor = zosmf.jobs().monitor(identifier).build();
+    /// futures::pin_mut!(monitor);
+    ///
+    /// while let Some(event) = monitor.try_next().await? {
+    ///     let attributes = event.attributes();
+    ///     match event.kind() {
+    ///         JobMonitorEventKind::Completed => {
+    ///             println!("finished: {:?}", attributes.completion_code())
+    ///         }
+    ///         _ => println!("{:?}: {}", attributes.status(), attributes.phase_name()),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn monitor<I>(&self, identifier: I) -> JobMonitorBuilder
+    where
+        I: Into<JobIdentifier>,
+    {
+        JobMonitorBuilder::new(self.core.clone(), identifier)
+    }
+
+    /// # Examples
+    ///
+    /// Read all of the output for job TESTJOBJ with ID JOB00023:
+    /// ```
+    /// # use z_osmf::jobs::JobIdentifier;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let identifier = JobIdentifier::NameId("TESTJOBJ".to_string(), "JOB00023".to_string());
+    ///
+    /// let job_output = zosmf.jobs().output(identifier).build().await?;
+    ///
+    /// println!("{}", job_output.concatenated());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Read the SYSPRINT output of step STEP1:
+    /// ```
+    /// # use z_osmf::jobs::JobIdentifier;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let identifier = JobIdentifier::NameId("TESTJOBJ".to_string(), "JOB00023".to_string());
+    ///
+    /// let job_output = zosmf
+    ///     .jobs()
+    ///     .output(identifier)
+    ///     .dd_names(["SYSPRINT"])
+    ///     .step_names(["STEP1"])
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn output<I>(&self, identifier: I) -> JobOutputBuilder
+    where
+        I: Into<JobIdentifier>,
+    {
+        JobOutputBuilder::new(self.core.clone(), identifier)
+    }
+
+    /// Purge the output of jobs that finished executing longer ago than `age`.
+    ///
+    /// # Examples
+    ///
+    /// See which of the nightly jobs are over a week old, then purge them:
+    /// ```
+    /// # use std::time::Duration;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let week = Duration::from_secs(7 * 24 * 60 * 60);
+    ///
+    /// let dry_run = zosmf
+    ///     .jobs()
+    ///     .purge_older_than(week)
+    ///     .owner("IBMUSER")
+    ///     .prefix("NIGHTLY*")
+    ///     .dry_run(true)
+    ///     .build()
+    ///     .await?;
+    /// println!("{} jobs to purge", dry_run.selected().len());
+    ///
+    /// let cleanup = zosmf
+    ///     .jobs()
+    ///     .purge_older_than(week)
+    ///     .owner("IBMUSER")
+    ///     .prefix("NIGHTLY*")
+    ///     .build()
+    ///     .await?;
+    /// for (job, err) in cleanup.failed() {
+    ///     println!("{}({}) was not purged: {}", job.name(), job.id(), err);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn purge_older_than(&self, age: std::time::Duration) -> JobCleanupBuilder {
+        JobCleanupBuilder::new(self.core.clone(), age)
+    }
+
     /// # Examples
     ///
     /// Read file 1 for job TESTJOBJ with ID JOB00023:
@@ -206,6 +447,49 @@ impl JobsClient {
     /// # }
     /// ```
     ///
+    /// Read the last 50 records of the SYSPRINT of job TESTJOBJ with ID JOB00023:
+    /// ```
+    /// # use std::num::NonZeroU32;
+    /// # use z_osmf::jobs::JobIdentifier;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let identifier = JobIdentifier::NameId("TESTJOBJ".to_string(), "JOB00023".to_string());
+    ///
+    /// let job_files = zosmf.jobs().list_files(identifier.clone()).build().await?;
+    /// if let Some(sysprint) = job_files.find_dd("SYSPRINT") {
+    ///     let tail = zosmf
+    ///         .jobs()
+    ///         .read_file(identifier, sysprint.id())
+    ///         .record_range(sysprint.last_records(NonZeroU32::new(50).unwrap()))
+    ///         .build()
+    ///         .await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Read file 8 for job TESTJOBJ with ID JOB00023 as IBM-939, reporting any bytes that are
+    /// still not valid UTF-8:
+    /// ```
+    /// # use z_osmf::jobs::files::read::JobFileId;
+    /// # use z_osmf::jobs::JobIdentifier;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let identifier = JobIdentifier::NameId("TESTJOBJ".to_string(), "JOB00023".to_string());
+    ///
+    /// let job_file = zosmf
+    ///     .jobs()
+    ///     .read_file(identifier, JobFileId::Id(8))
+    ///     .encoding("IBM-939")
+    ///     .build_raw()
+    ///     .await?;
+    ///
+    /// for invalid in job_file.invalid_sequences() {
+    ///     println!("{:02X?} at byte {}", invalid.sequence(), invalid.offset());
+    /// }
+    /// println!("{}", job_file.text_lossy());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
     /// Read the JCL for job TESTJOBJ with ID JOB00060:
     /// ```
     /// # use z_osmf::jobs::files::read::JobFileId;
@@ -233,6 +517,30 @@ impl JobsClient {
         JobFileReadBuilder::new(self.core.clone(), identifier, file_id)
     }
 
+    /// # Examples
+    ///
+    /// Read the SYSPRINT output of step STEP1 for job TESTJOBJ with ID JOB00023:
+    /// ```
+    /// # use z_osmf::jobs::JobIdentifier;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let identifier = JobIdentifier::NameId("TESTJOBJ".to_string(), "JOB00023".to_string());
+    ///
+    /// let job_file = zosmf
+    ///     .jobs()
+    ///     .read_file_by_dd_name(identifier, "STEP1.SYSPRINT")
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_file_by_dd_name<I, D>(&self, identifier: I, dd_name: D) -> JobFileByDdNameBuilder
+    where
+        I: Into<JobIdentifier>,
+        D: std::fmt::Display,
+    {
+        JobFileByDdNameBuilder::new(self.core.clone(), identifier, dd_name)
+    }
+
     /// # Examples
     ///
     /// Release job TESTJOBW with ID JOB0023:
@@ -280,6 +588,33 @@ impl JobsClient {
         JobStatusBuilder::new(self.core.clone(), identifier)
     }
 
+    /// Read the JCL of a job and submit it again as a new job.
+    ///
+    /// # Examples
+    ///
+    /// Rerun job TESTJOBJ with ID JOB00023 under a new name and with a different symbol value:
+    /// ```
+    /// # use z_osmf::jobs::JobIdentifier;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let identifier = JobIdentifier::NameId("TESTJOBJ".to_string(), "JOB00023".to_string());
+    ///
+    /// let job_data = zosmf
+    ///     .jobs()
+    ///     .resubmit(identifier)
+    ///     .job_name("TESTJOBR")
+    ///     .symbol("HLQ", "IBMUSER.RERUN")
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn resubmit<I>(&self, identifier: I) -> JobResubmitBuilder
+    where
+        I: Into<JobIdentifier>,
+    {
+        JobResubmitBuilder::new(self.core.clone(), identifier)
+    }
+
     /// # Examples
     ///
     /// Submit a job from text:
@@ -307,6 +642,88 @@ impl JobsClient {
     {
         JobSubmitBuilder::new(self.core.clone(), source)
     }
+
+    /// Read JCL from a local file and prepare it for submission.
+    ///
+    /// Line endings are normalized and trailing blanks removed. JCL with records of
+    /// at most 80 characters is submitted as fixed 80 byte records, anything longer as
+    /// variable length records.
+    ///
+    /// # Examples
+    ///
+    /// Submit the JCL in `jobs/iefbr14.jcl`:
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let job_data = zosmf
+    ///     .jobs()
+    ///     .submit_file("jobs/iefbr14.jcl")
+    ///     .await?
+    ///     .message_class('A')
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn submit_file<P>(&self, path: P) -> Result<JobSubmitBuilder<JobAttributes>>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        JobSubmitBuilder::from_file(self.core.clone(), path).await
+    }
+
+    /// # Examples
+    ///
+    /// Submit a job and wait for it to finish:
+    /// ```
+    /// # use std::time::Duration;
+    /// # use z_osmf::jobs::submit::JobSource;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let job_data = zosmf
+    ///     .jobs()
+    ///     .submit(JobSource::member("IBMUSER.JCL", "IEFBR14")?)
+    ///     .build()
+    ///     .await?;
+    ///
+    /// let job_data = zosmf
+    ///     .jobs()
+    ///     .wait_for_output(&job_data)
+    ///     .timeout(Duration::from_secs(300))
+    ///     .build()
+    ///     .await?;
+    ///
+    /// println!("{:?}", job_data.return_code());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_for_output<I>(&self, identifier: I) -> JobWaitBuilder
+    where
+        I: Into<JobIdentifier>,
+    {
+        JobWaitBuilder::new(self.core.clone(), identifier, JobStatus::Output)
+    }
+
+    /// # Examples
+    ///
+    /// Wait for job TESTJOB2 with ID JOB0084 to start running:
+    /// ```
+    /// # use z_osmf::jobs::{JobIdentifier, JobStatus};
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let identifier = JobIdentifier::NameId("TESTJOB2".into(), "JOB0084".into());
+    ///
+    /// let job_data = zosmf
+    ///     .jobs()
+    ///     .wait_for_status(identifier, JobStatus::Active)
+    ///     .build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_for_status<I>(&self, identifier: I, status: JobStatus) -> JobWaitBuilder
+    where
+        I: Into<JobIdentifier>,
+    {
+        JobWaitBuilder::new(self.core.clone(), identifier, status)
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
@@ -339,6 +756,11 @@ impl JobAttributes {
     pub fn identifier(&self) -> JobIdentifier {
         self.into()
     }
+
+    /// The parsed return code, if the job has finished.
+    pub fn completion_code(&self) -> Option<CompletionCode> {
+        self.return_code.as_deref().map(CompletionCode::from)
+    }
 }
 
 impl TryFromResponse for JobAttributes {
@@ -359,9 +781,43 @@ pub struct JobAttributesExec {
     #[serde(default)]
     exec_submitted: Option<Arc<str>>,
     #[serde(default)]
+    exec_started: Option<Arc<str>>,
+    #[serde(default)]
     exec_ended: Option<Arc<str>>,
 }
 
+impl JobAttributesExec {
+    /// When the job was submitted.
+    pub fn submitted(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.exec_submitted.as_deref().and_then(parse_timestamp)
+    }
+
+    /// When the job started executing, if it has.
+    pub fn started(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.exec_started.as_deref().and_then(parse_timestamp)
+    }
+
+    /// When the job finished executing, if it has.
+    pub fn ended(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.exec_ended.as_deref().and_then(parse_timestamp)
+    }
+
+    /// How long the job executed for, once it has finished.
+    pub fn duration(&self) -> Option<chrono::Duration> {
+        Some(self.ended()? - self.started()?)
+    }
+}
+
+/// Parse an execution timestamp, e.g. `2024-02-27T15:33:36.470Z`, assuming UTC if no offset is given.
+fn parse_timestamp(value: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    match chrono::DateTime::parse_from_rfc3339(value) {
+        Ok(timestamp) => Some(timestamp.to_utc()),
+        Err(_) => chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f")
+            .ok()
+            .map(|timestamp| timestamp.and_utc()),
+    }
+}
+
 impl std::ops::Deref for JobAttributesExec {
     type Target = JobAttributes;
 
@@ -392,6 +848,12 @@ impl std::ops::Deref for JobAttributesExecStep {
     }
 }
 
+impl JobSteps for JobAttributesExecStep {
+    fn steps(&self) -> &[JobStepData] {
+        &self.step_data
+    }
+}
+
 impl TryFromResponse for JobAttributesExecStep {
     async fn try_from_response(value: reqwest::Response) -> Result<Self> {
         Ok(value.json().await?)
@@ -414,33 +876,189 @@ impl std::ops::Deref for JobAttributesStep {
     }
 }
 
+impl JobSteps for JobAttributesStep {
+    fn steps(&self) -> &[JobStepData] {
+        &self.step_data
+    }
+}
+
 impl TryFromResponse for JobAttributesStep {
     async fn try_from_response(value: reqwest::Response) -> Result<Self> {
         Ok(value.json().await?)
     }
 }
 
+/// How a job ended, parsed from return codes like `CC 0004` or `ABEND S0C4`.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum CompletionCode {
+    /// The highest condition code of the job's steps.
+    ConditionCode(u32),
+    /// A system abend code, e.g. `0x0C4` for `S0C4`.
+    SystemAbend(u32),
+    /// A user abend code, e.g. `100` for `U0100`.
+    UserAbend(u32),
+    JclError,
+    Canceled,
+    SecurityError,
+    ConversionAbend,
+    SystemFailure,
+    Other(Arc<str>),
+}
+
+impl CompletionCode {
+    pub fn condition_code(&self) -> Option<u32> {
+        match self {
+            CompletionCode::ConditionCode(code) => Some(*code),
+            _ => None,
+        }
+    }
+
+    pub fn system_abend(&self) -> Option<u32> {
+        match self {
+            CompletionCode::SystemAbend(code) => Some(*code),
+            _ => None,
+        }
+    }
+
+    pub fn user_abend(&self) -> Option<u32> {
+        match self {
+            CompletionCode::UserAbend(code) => Some(*code),
+            _ => None,
+        }
+    }
+
+    pub fn is_abend(&self) -> bool {
+        matches!(
+            self,
+            CompletionCode::SystemAbend(_) | CompletionCode::UserAbend(_)
+        )
+    }
+
+    /// Whether the job ran to completion with a condition code of at most `max_cc`.
+    pub fn is_success(&self, max_cc: u32) -> bool {
+        self.condition_code().is_some_and(|code| code <= max_cc)
+    }
+}
+
+impl From<&str> for CompletionCode {
+    fn from(value: &str) -> Self {
+        let words: Vec<&str> = value.split_whitespace().collect();
+
+        let parsed = match words[..] {
+            ["CC", code] => code.parse().ok().map(CompletionCode::ConditionCode),
+            ["ABEND", code] => match code.split_at_checked(1) {
+                Some(("S", code)) => u32::from_str_radix(code, 16)
+                    .ok()
+                    .map(CompletionCode::SystemAbend),
+                Some(("U", code)) => code.parse().ok().map(CompletionCode::UserAbend),
+                _ => None,
+            },
+            ["JCL", "ERROR"] => Some(CompletionCode::JclError),
+            ["CANCELED"] => Some(CompletionCode::Canceled),
+            ["SEC", "ERROR"] => Some(CompletionCode::SecurityError),
+            ["CONV", "ABEND"] => Some(CompletionCode::ConversionAbend),
+            ["SYS", "FAIL"] => Some(CompletionCode::SystemFailure),
+            _ => None,
+        };
+
+        parsed.unwrap_or_else(|| CompletionCode::Other(value.into()))
+    }
+}
+
+impl From<String> for CompletionCode {
+    fn from(value: String) -> Self {
+        value.as_str().into()
+    }
+}
+
+impl From<CompletionCode> for String {
+    fn from(value: CompletionCode) -> Self {
+        value.to_string()
+    }
+}
+
+impl std::fmt::Display for CompletionCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompletionCode::ConditionCode(code) => write!(f, "CC {:04}", code),
+            CompletionCode::SystemAbend(code) => write!(f, "ABEND S{:03X}", code),
+            CompletionCode::UserAbend(code) => write!(f, "ABEND U{:04}", code),
+            CompletionCode::JclError => write!(f, "JCL ERROR"),
+            CompletionCode::Canceled => write!(f, "CANCELED"),
+            CompletionCode::SecurityError => write!(f, "SEC ERROR"),
+            CompletionCode::ConversionAbend => write!(f, "CONV ABEND"),
+            CompletionCode::SystemFailure => write!(f, "SYS FAIL"),
+            CompletionCode::Other(value) => write!(f, "{}", value),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum JobIdentifier {
     Correlator(String),
     NameId(String, String),
 }
 
+/// Parses `JOBNAME/JOBID`, `JOBNAME(JOBID)` or a job correlator.
+///
+/// Job names and IDs are uppercased and must be valid JES names, e.g. `TESTJOB(JOB00123)`.
 impl std::str::FromStr for JobIdentifier {
     type Err = Error;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        match s.split('/').collect::<Vec<_>>()[..] {
-            [name, id] => Ok(JobIdentifier::NameId(name.to_string(), id.to_string())),
-            [correlator] => Ok(JobIdentifier::Correlator(correlator.to_string())),
-            _ => Err(Error::InvalidValue(format!(
-                "invalid job identifier: {}",
-                s
-            ))),
+        let invalid = || Error::InvalidValue(format!("invalid job identifier: {}", s));
+
+        let s = s.trim();
+        let name_id = match s.split_once('/') {
+            Some(name_id) => Some(name_id),
+            None => s.strip_suffix(')').and_then(|s| s.split_once('(')),
+        };
+
+        match name_id {
+            Some((name, id)) => {
+                let (name, id) = (name.to_uppercase(), id.to_uppercase());
+                if !is_valid_job_name(&name) || !is_valid_job_id(&id) {
+                    return Err(invalid());
+                }
+
+                Ok(JobIdentifier::NameId(name, id))
+            }
+            None if !s.is_empty()
+                && !s.contains(|c: char| c.is_whitespace() || "()".contains(c)) =>
+            {
+                Ok(JobIdentifier::Correlator(s.to_string()))
+            }
+            None => Err(invalid()),
         }
     }
 }
 
+/// 1 to 8 letters, digits or national characters, not starting with a digit.
+fn is_valid_job_name(name: &str) -> bool {
+    (1..=8).contains(&name.len())
+        && name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_uppercase() || "#$@".contains(c))
+        && name
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || "#$@".contains(c))
+}
+
+/// `JOB`, `STC` or `TSU` and 5 digits, or `J`, `S` or `T` and 7 digits.
+fn is_valid_job_id(id: &str) -> bool {
+    let digits = match id.get(..3) {
+        Some("JOB" | "STC" | "TSU") => &id[3..],
+        _ => match id.get(..1) {
+            Some("J" | "S" | "T") => &id[1..],
+            _ => return false,
+        },
+    };
+
+    id.len() == 8 && digits.chars().all(|c| c.is_ascii_digit())
+}
+
 impl std::fmt::Display for JobIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -456,6 +1074,36 @@ impl From<&JobAttributes> for JobIdentifier {
     }
 }
 
+impl From<JobAttributes> for JobIdentifier {
+    fn from(value: JobAttributes) -> Self {
+        (&value).into()
+    }
+}
+
+impl From<&JobAttributesExec> for JobIdentifier {
+    fn from(value: &JobAttributesExec) -> Self {
+        (&**value).into()
+    }
+}
+
+impl From<&JobAttributesStep> for JobIdentifier {
+    fn from(value: &JobAttributesStep) -> Self {
+        (&**value).into()
+    }
+}
+
+impl From<&JobAttributesExecStep> for JobIdentifier {
+    fn from(value: &JobAttributesExecStep) -> Self {
+        (&**value).into()
+    }
+}
+
+impl From<&JobIdentifier> for JobIdentifier {
+    fn from(value: &JobIdentifier) -> Self {
+        value.clone()
+    }
+}
+
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum JobStatus {
@@ -493,6 +1141,106 @@ pub struct JobStepData {
     abend_reason_code: Option<Arc<str>>,
 }
 
+impl JobStepData {
+    /// The parsed completion code, if the step has finished.
+    pub fn completion(&self) -> Option<CompletionCode> {
+        self.completion_code.as_deref().map(CompletionCode::from)
+    }
+
+    fn is_step(&self, name: &str) -> bool {
+        match name.split_once('.') {
+            Some((step_name, proc_step_name)) => {
+                self.step_name.eq_ignore_ascii_case(step_name)
+                    && self.proc_step_name.eq_ignore_ascii_case(proc_step_name)
+            }
+            None => self.step_name.eq_ignore_ascii_case(name),
+        }
+    }
+}
+
+/// Condition codes rank by value, below every abend and error. Other completions,
+/// such as flushed steps, rank lowest.
+fn severity(code: &CompletionCode) -> (u8, u32) {
+    match code {
+        CompletionCode::Other(_) => (0, 0),
+        CompletionCode::ConditionCode(code) => (1, *code),
+        _ => (2, 0),
+    }
+}
+
+fn is_failure(code: &CompletionCode, max_cc: u32) -> bool {
+    match code {
+        CompletionCode::Other(_) => false,
+        CompletionCode::ConditionCode(code) => *code > max_cc,
+        _ => true,
+    }
+}
+
+/// Lookups over the steps of job attributes fetched with step data.
+pub trait JobSteps {
+    fn steps(&self) -> &[JobStepData];
+
+    /// The step named `STEP` or `STEP.PROCSTEP`, ignoring case.
+    fn find_step(&self, name: &str) -> Option<&JobStepData> {
+        self.steps().iter().find(|step| step.is_step(name))
+    }
+
+    /// The steps that abended, failed or ended with a condition code above `max_cc`.
+    fn failed_steps(&self, max_cc: u32) -> impl Iterator<Item = &JobStepData> {
+        self.steps().iter().filter(move |step| {
+            step.completion()
+                .is_some_and(|code| is_failure(&code, max_cc))
+        })
+    }
+
+    /// The worst completion of any step, where an abend or error outranks any condition code.
+    fn max_completion_code(&self) -> Option<CompletionCode> {
+        self.steps()
+            .iter()
+            .filter_map(|step| step.completion())
+            .max_by_key(severity)
+    }
+
+    /// A table of the steps, one line each, for display.
+    fn step_summary(&self) -> JobStepSummary<'_> {
+        JobStepSummary(self.steps())
+    }
+}
+
+/// The steps of a job as a table with a header line.
+#[derive(Clone, Copy, Debug)]
+pub struct JobStepSummary<'a>(&'a [JobStepData]);
+
+impl std::fmt::Display for JobStepSummary<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:>4}  {:<8}  {:<8}  {:<8}  COMPLETION",
+            "STEP", "STEPNAME", "PROCSTEP", "PROGRAM"
+        )?;
+
+        for step in self.0 {
+            let completion = match (step.completion(), step.active) {
+                (Some(code), _) => code.to_string(),
+                (None, true) => "ACTIVE".to_string(),
+                (None, false) => String::new(),
+            };
+
+            writeln!(
+                f,
+                "{:>4}  {:<8}  {:<8}  {:<8}  {}",
+                step.step_number,
+                step.step_name,
+                step.proc_step_name,
+                step.program_name,
+                completion
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum JobType {
@@ -510,8 +1258,172 @@ fn get_subsystem(value: &Option<Arc<str>>) -> String {
 
 #[cfg(test)]
 mod tests {
+    use crate::tests::*;
+
     use super::*;
 
+    #[test]
+    fn parse_identifier() {
+        let expected = JobIdentifier::NameId("TESTJOB".to_string(), "JOB00123".to_string());
+
+        assert_eq!(
+            "TESTJOB/JOB00123".parse::<JobIdentifier>().unwrap(),
+            expected
+        );
+        assert_eq!(
+            "testjob(job00123)".parse::<JobIdentifier>().unwrap(),
+            expected
+        );
+        assert_eq!(
+            "IBMUSER(J0012345)".parse::<JobIdentifier>().unwrap(),
+            JobIdentifier::NameId("IBMUSER".to_string(), "J0012345".to_string())
+        );
+        assert_eq!(
+            "J0000023SY1.....D5E0A2F1.......:"
+                .parse::<JobIdentifier>()
+                .unwrap(),
+            JobIdentifier::Correlator("J0000023SY1.....D5E0A2F1.......:".to_string())
+        );
+
+        assert!("TOOLONGJOB/JOB00123".parse::<JobIdentifier>().is_err());
+        assert!("TESTJOB/JOB123".parse::<JobIdentifier>().is_err());
+        assert!("TESTJOB(ABC00123)".parse::<JobIdentifier>().is_err());
+        assert!("TESTJOB(JOB00123".parse::<JobIdentifier>().is_err());
+        assert!("".parse::<JobIdentifier>().is_err());
+    }
+
+    #[test]
+    fn exec_timestamps() {
+        let attributes: JobAttributesExec = job_attributes(
+            "OUTPUT",
+            Some("CC 0000"),
+            serde_json::json!({
+                "exec-submitted": "2024-02-27T15:33:30.120Z",
+                "exec-started": "2024-02-27T15:33:31.000Z",
+                "exec-ended": "2024-02-27T15:35:01.500Z"
+            }),
+        );
+
+        assert_eq!(attributes.exec_started(), Some("2024-02-27T15:33:31.000Z"));
+        assert_eq!(
+            attributes.submitted(),
+            "2024-02-27T15:33:30.120Z".parse().ok()
+        );
+        assert_eq!(
+            attributes.duration(),
+            Some(chrono::Duration::milliseconds(90_500))
+        );
+        assert_eq!(
+            parse_timestamp("2024-02-27T15:33:31"),
+            "2024-02-27T15:33:31Z".parse().ok()
+        );
+        assert_eq!(parse_timestamp("yesterday"), None);
+    }
+
+    #[test]
+    fn identifier_from_attributes() {
+        let attributes: JobAttributesExec =
+            job_attributes("OUTPUT", Some("CC 0000"), serde_json::json!({}));
+        let expected = JobIdentifier::NameId("TESTJOB".to_string(), "JOB00023".to_string());
+
+        assert_eq!(JobIdentifier::from(&attributes), expected);
+        assert_eq!(JobIdentifier::from(&*attributes), expected);
+        assert_eq!(JobIdentifier::from((*attributes).clone()), expected);
+        assert_eq!(JobIdentifier::from(&expected), expected);
+    }
+
+    #[test]
+    fn step_queries() {
+        let step = |number: i32, name: &str, proc_step: &str, completion: Option<&str>| {
+            serde_json::json!({
+                "active": false,
+                "step-number": number,
+                "program-name": "IEFBR14",
+                "step-name": name,
+                "proc-step-name": proc_step,
+                "completion": completion
+            })
+        };
+        let attributes: JobAttributesStep = job_attributes(
+            "OUTPUT",
+            Some("ABEND S0C4"),
+            serde_json::json!({
+                "step-data": [
+                    step(1, "STEP010", "", Some("CC 0004")),
+                    step(2, "STEP020", "COMPILE", Some("ABEND S0C4")),
+                    step(3, "STEP030", "", Some("FLUSH")),
+                    step(4, "STEP040", "", None)
+                ]
+            }),
+        );
+
+        assert_eq!(attributes.find_step("step010").unwrap().step_number(), 1);
+        assert_eq!(
+            attributes
+                .find_step("STEP020.COMPILE")
+                .unwrap()
+                .step_number(),
+            2
+        );
+        assert!(attributes.find_step("STEP020.LINK").is_none());
+
+        let failed = |max_cc| {
+            attributes
+                .failed_steps(max_cc)
+                .map(|step| step.step_number())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(failed(0), vec![1, 2]);
+        assert_eq!(failed(4), vec![2]);
+
+        assert_eq!(
+            attributes.max_completion_code(),
+            Some(CompletionCode::SystemAbend(0x0C4))
+        );
+
+        assert_eq!(
+            attributes.step_summary().to_string(),
+            "STEP  STEPNAME  PROCSTEP  PROGRAM   COMPLETION\n\
+             \x20  1  STEP010             IEFBR14   CC 0004\n\
+             \x20  2  STEP020   COMPILE   IEFBR14   ABEND S0C4\n\
+             \x20  3  STEP030             IEFBR14   FLUSH\n\
+             \x20  4  STEP040             IEFBR14   \n"
+        );
+    }
+
+    #[test]
+    fn completion_codes() {
+        let cc = CompletionCode::from("CC 0004");
+        assert_eq!(cc.condition_code(), Some(4));
+        assert!(cc.is_success(4));
+        assert!(!cc.is_success(0));
+
+        let abend = CompletionCode::from("ABEND S0C4");
+        assert_eq!(abend.system_abend(), Some(0x0C4));
+        assert!(abend.is_abend());
+        assert!(!abend.is_success(u32::MAX));
+        assert_eq!(abend.to_string(), "ABEND S0C4");
+
+        assert_eq!(CompletionCode::from("ABEND U0100").user_abend(), Some(100));
+        assert_eq!(CompletionCode::from("JCL ERROR"), CompletionCode::JclError);
+        assert_eq!(CompletionCode::from("CANCELED"), CompletionCode::Canceled);
+        assert_eq!(
+            CompletionCode::from("ABEND SXYZ"),
+            CompletionCode::Other("ABEND SXYZ".into())
+        );
+    }
+
+    #[test]
+    fn list_my_jobs() {
+        let zosmf = get_zosmf();
+        assert!(matches!(zosmf.jobs().list_my_jobs(), Err(Error::NoUser)));
+
+        zosmf.set_user(Some("IBMUSER".into())).unwrap();
+
+        let job_list = zosmf.jobs().list_my_jobs().unwrap().get_request().unwrap();
+        assert_eq!(job_list.url().query(), Some("owner=IBMUSER"));
+    }
+
     #[test]
     fn display_job_identifier() {
         assert_eq!(
@@ -0,0 +1,334 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use chrono::{DateTime, Datelike, Timelike, Utc};
+
+use crate::{ClientCore, Error, Result};
+
+use super::download::file_name;
+use super::files::read::{JobFileId, JobFileRead, JobFileReadBuilder};
+use super::output::{JobOutput, JobOutputBuilder};
+use super::status::JobStatusBuilder;
+use super::{JobAttributes, JobAttributesExecStep, JobIdentifier};
+
+#[derive(Clone, Debug)]
+pub struct JobArchiveBuilder {
+    core: ClientCore,
+
+    identifier: JobIdentifier,
+    subsystem: Option<Arc<str>>,
+    encoding: Option<Arc<str>>,
+    concurrency: usize,
+}
+
+impl JobArchiveBuilder {
+    pub(crate) fn new<I>(core: ClientCore, identifier: I) -> Self
+    where
+        I: Into<JobIdentifier>,
+    {
+        JobArchiveBuilder {
+            core,
+            identifier: identifier.into(),
+            subsystem: None,
+            encoding: None,
+            concurrency: 4,
+        }
+    }
+
+    pub fn subsystem<S>(self, value: S) -> Self
+    where
+        S: std::fmt::Display,
+    {
+        JobArchiveBuilder {
+            subsystem: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// The EBCDIC code page of the JCL and spool files, e.g. `IBM-037`.
+    pub fn encoding<E>(self, value: E) -> Self
+    where
+        E: std::fmt::Display,
+    {
+        JobArchiveBuilder {
+            encoding: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// The maximum number of spool files read at once.
+    pub fn concurrency(self, value: usize) -> Self {
+        JobArchiveBuilder {
+            concurrency: value.max(1),
+            ..self
+        }
+    }
+
+    /// Read the job's status with execution and step data, its JCL and every spool file.
+    pub async fn build(self) -> Result<JobArchive> {
+        let attributes = self.status_builder().build().await?;
+        let jcl = self.jcl_builder().build().await?;
+        let output = self.output_builder().build().await?;
+
+        Ok(JobArchive {
+            attributes,
+            jcl: jcl.data().into(),
+            output,
+            created: Utc::now(),
+        })
+    }
+
+    fn status_builder(&self) -> JobStatusBuilder<JobAttributesExecStep> {
+        let builder =
+            JobStatusBuilder::<JobAttributes>::new(self.core.clone(), self.identifier.clone());
+        let builder = match &self.subsystem {
+            Some(subsystem) => builder.subsystem(subsystem),
+            None => builder,
+        };
+
+        builder.exec_data().step_data()
+    }
+
+    fn jcl_builder(&self) -> JobFileReadBuilder<JobFileRead<Arc<str>>> {
+        let builder =
+            JobFileReadBuilder::new(self.core.clone(), self.identifier.clone(), JobFileId::Jcl);
+        let builder = match &self.subsystem {
+            Some(subsystem) => builder.subsystem(subsystem),
+            None => builder,
+        };
+
+        match &self.encoding {
+            Some(encoding) => builder.encoding(encoding),
+            None => builder,
+        }
+    }
+
+    fn output_builder(&self) -> JobOutputBuilder {
+        let builder = JobOutputBuilder::new(self.core.clone(), self.identifier.clone())
+            .concurrency(self.concurrency);
+        let builder = match &self.subsystem {
+            Some(subsystem) => builder.subsystem(subsystem),
+            None => builder,
+        };
+
+        match &self.encoding {
+            Some(encoding) => builder.encoding(encoding),
+            None => builder,
+        }
+    }
+}
+
+/// Everything known about a job, ready to attach to a problem report.
+///
+/// The bundle holds `status.json`, `steps.json`, `JCL.txt` and a
+/// `spool/STEP.DD.id.txt` file for each spool file.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct JobArchive {
+    attributes: JobAttributesExecStep,
+    jcl: Arc<str>,
+    output: JobOutput,
+    created: DateTime<Utc>,
+}
+
+impl JobArchive {
+    /// The job's status, with execution and step data.
+    pub fn attributes(&self) -> &JobAttributesExecStep {
+        &self.attributes
+    }
+
+    pub fn jcl(&self) -> &str {
+        &self.jcl
+    }
+
+    pub fn output(&self) -> &JobOutput {
+        &self.output
+    }
+
+    /// Write the bundle to a directory, creating it if needed.
+    pub async fn write_to_dir<P>(&self, directory: P) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let directory = directory.as_ref();
+
+        for (name, data) in self.entries()? {
+            let path = directory.join(name);
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            tokio::fs::write(path, data).await?;
+        }
+
+        Ok(())
+    }
+
+    /// The bundle as an uncompressed zip file.
+    pub fn to_zip(&self) -> Result<Bytes> {
+        zip(&self.entries()?, self.created).map(Bytes::from)
+    }
+
+    fn entries(&self) -> Result<Vec<(String, Vec<u8>)>> {
+        let mut entries = vec![
+            (
+                "status.json".to_string(),
+                serde_json::to_vec_pretty(&self.attributes)?,
+            ),
+            (
+                "steps.json".to_string(),
+                serde_json::to_vec_pretty(self.attributes.step_data())?,
+            ),
+            ("JCL.txt".to_string(), self.jcl.as_bytes().to_vec()),
+        ];
+        entries.extend(self.output.files().iter().map(|file| {
+            (
+                format!("spool/{}", file_name(file.file())),
+                file.data().as_bytes().to_vec(),
+            )
+        }));
+
+        Ok(entries)
+    }
+}
+
+/// Write a zip file that stores each entry without compression.
+fn zip(entries: &[(String, Vec<u8>)], modified: DateTime<Utc>) -> Result<Vec<u8>> {
+    fn u32_size(value: usize) -> Result<u32> {
+        u32::try_from(value).map_err(|_| Error::InvalidValue("archive exceeds 4 GiB".into()))
+    }
+
+    let (time, date) = dos_date_time(modified);
+
+    let mut local = Vec::new();
+    let mut central = Vec::new();
+    for (name, data) in entries {
+        let offset = u32_size(local.len())?;
+        let size = u32_size(data.len())?;
+        let crc = crc32(data);
+
+        // version 2.0, names in UTF-8, stored
+        let mut header = Vec::new();
+        header.extend_from_slice(&20u16.to_le_bytes());
+        header.extend_from_slice(&0x0800u16.to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes());
+        header.extend_from_slice(&time.to_le_bytes());
+        header.extend_from_slice(&date.to_le_bytes());
+        header.extend_from_slice(&crc.to_le_bytes());
+        header.extend_from_slice(&size.to_le_bytes());
+        header.extend_from_slice(&size.to_le_bytes());
+        header.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes());
+
+        local.extend_from_slice(&0x04034b50u32.to_le_bytes());
+        local.extend_from_slice(&header);
+        local.extend_from_slice(name.as_bytes());
+        local.extend_from_slice(data);
+
+        central.extend_from_slice(&0x02014b50u32.to_le_bytes());
+        central.extend_from_slice(&20u16.to_le_bytes());
+        central.extend_from_slice(&header);
+        // comment length, disk number, internal and external attributes
+        central.extend_from_slice(&[0; 10]);
+        central.extend_from_slice(&offset.to_le_bytes());
+        central.extend_from_slice(name.as_bytes());
+    }
+
+    let count = u16::try_from(entries.len())
+        .map_err(|_| Error::InvalidValue("archive has too many files".into()))?;
+    let central_offset = u32_size(local.len())?;
+    let central_size = u32_size(central.len())?;
+
+    let mut zip = local;
+    zip.append(&mut central);
+    zip.extend_from_slice(&0x06054b50u32.to_le_bytes());
+    zip.extend_from_slice(&[0; 4]);
+    zip.extend_from_slice(&count.to_le_bytes());
+    zip.extend_from_slice(&count.to_le_bytes());
+    zip.extend_from_slice(&central_size.to_le_bytes());
+    zip.extend_from_slice(&central_offset.to_le_bytes());
+    zip.extend_from_slice(&0u16.to_le_bytes());
+
+    Ok(zip)
+}
+
+/// The MS-DOS time and date fields, which count years from 1980 and seconds in pairs.
+fn dos_date_time(value: DateTime<Utc>) -> (u16, u16) {
+    let year = value.year().clamp(1980, 2107) as u16 - 1980;
+    let time = (value.hour() << 11 | value.minute() << 5 | (value.second() / 2)) as u16;
+    let date = year << 9 | (value.month() << 5 | value.day()) as u16;
+
+    (time, date)
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0xEDB88320,
+                _ => crc >> 1,
+            };
+        }
+    }
+
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn status_request() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restjobs/jobs/J0000023SY1.....D5E0A2F1")
+            .query(&[("exec-data", "Y")])
+            .query(&[("step-data", "Y")])
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .jobs()
+            .archive(JobIdentifier::Correlator("J0000023SY1.....D5E0A2F1".into()))
+            .status_builder()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+
+    #[test]
+    fn zip_file() {
+        assert_eq!(crc32(b"123456789"), 0xCBF43926);
+
+        let modified = "2024-02-27T15:33:36Z".parse().unwrap();
+        assert_eq!(dos_date_time(modified), (0x7C32, 0x585B));
+
+        let entries = vec![
+            ("JCL.txt".to_string(), b"//TESTJOB JOB\n".to_vec()),
+            ("spool/JES2.JESMSGLG.2.txt".to_string(), Vec::new()),
+        ];
+        let zip = zip(&entries, modified).unwrap();
+
+        assert_eq!(zip[..4], 0x04034b50u32.to_le_bytes());
+        assert_eq!(zip[30..37], *b"JCL.txt");
+        assert_eq!(zip[37..51], *b"//TESTJOB JOB\n");
+
+        let end = &zip[zip.len() - 22..];
+        assert_eq!(end[..4], 0x06054b50u32.to_le_bytes());
+        assert_eq!(end[10..12], 2u16.to_le_bytes());
+
+        let central_offset = u32::from_le_bytes(end[16..20].try_into().unwrap()) as usize;
+        assert_eq!(
+            zip[central_offset..central_offset + 4],
+            0x02014b50u32.to_le_bytes()
+        );
+    }
+}
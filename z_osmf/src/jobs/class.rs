@@ -8,6 +8,7 @@ use crate::convert::TryFromResponse;
 use crate::jobs::JobIdentifier;
 use crate::ClientCore;
 
+use super::feedback::{JobAsynchronousResponse, JobFeedback};
 use super::get_subsystem;
 
 #[derive(Clone, Debug, Endpoint)]
@@ -34,7 +35,8 @@ impl<T> JobChangeClassBuilder<T>
 where
     T: TryFromResponse,
 {
-    pub fn asynchronous(self) -> JobChangeClassBuilder<()> {
+    /// Send the request with version 1.0, returning as soon as z/OSMF accepts it.
+    pub fn asynchronous(self) -> JobChangeClassBuilder<JobAsynchronousResponse> {
         JobChangeClassBuilder {
             core: self.core,
             class: self.class,
@@ -44,6 +46,18 @@ where
             target_type: PhantomData,
         }
     }
+
+    /// Send the request with version 2.0, the default, waiting for it to complete.
+    pub fn synchronous(self) -> JobChangeClassBuilder<JobFeedback> {
+        JobChangeClassBuilder {
+            core: self.core,
+            class: self.class,
+            subsystem: self.subsystem,
+            identifier: self.identifier,
+            asynchronous: Some(false),
+            target_type: PhantomData,
+        }
+    }
 }
 
 #[derive(Clone, Serialize)]
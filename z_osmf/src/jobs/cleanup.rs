@@ -0,0 +1,282 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+
+use crate::{ClientCore, Error, Result};
+
+use super::feedback::JobFeedback;
+use super::list::{JobList, JobListBuilder, JobListStatus};
+use super::purge::JobPurgeBuilder;
+use super::{JobAttributes, JobAttributesExec};
+
+#[derive(Clone, Debug)]
+pub struct JobCleanupBuilder {
+    core: ClientCore,
+
+    older_than: Duration,
+    subsystem: Option<Arc<str>>,
+    owner: Option<Arc<str>>,
+    prefix: Option<Arc<str>>,
+    status: JobListStatus,
+    max_jobs: Option<i32>,
+    concurrency: usize,
+    dry_run: bool,
+}
+
+impl JobCleanupBuilder {
+    pub(crate) fn new(core: ClientCore, older_than: Duration) -> Self {
+        JobCleanupBuilder {
+            core,
+            older_than,
+            subsystem: None,
+            owner: None,
+            prefix: None,
+            status: JobListStatus::Output,
+            max_jobs: None,
+            concurrency: 4,
+            dry_run: false,
+        }
+    }
+
+    pub fn subsystem<S>(self, value: S) -> Self
+    where
+        S: std::fmt::Display,
+    {
+        JobCleanupBuilder {
+            subsystem: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// Only purge jobs owned by this user, which z/OSMF defaults to the caller.
+    pub fn owner<O>(self, value: O) -> Self
+    where
+        O: std::fmt::Display,
+    {
+        JobCleanupBuilder {
+            owner: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// Only purge jobs whose name matches this prefix, e.g. `NIGHTLY*`.
+    pub fn prefix<P>(self, value: P) -> Self
+    where
+        P: std::fmt::Display,
+    {
+        JobCleanupBuilder {
+            prefix: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// Which jobs to consider, only those on the output queue unless set.
+    ///
+    /// Whatever the status, a job is only purged once it has finished executing.
+    pub fn status(self, value: JobListStatus) -> Self {
+        JobCleanupBuilder {
+            status: value,
+            ..self
+        }
+    }
+
+    /// The maximum number of jobs to list, which z/OSMF defaults to 1000.
+    pub fn max_jobs(self, value: i32) -> Self {
+        JobCleanupBuilder {
+            max_jobs: Some(value),
+            ..self
+        }
+    }
+
+    /// The maximum number of jobs purged at once.
+    pub fn concurrency(self, value: usize) -> Self {
+        JobCleanupBuilder {
+            concurrency: value.max(1),
+            ..self
+        }
+    }
+
+    /// Select the jobs to purge without purging them.
+    pub fn dry_run(self, value: bool) -> Self {
+        JobCleanupBuilder {
+            dry_run: value,
+            ..self
+        }
+    }
+
+    /// List the jobs, select those that finished longer ago than the age limit and purge them.
+    ///
+    /// Only listing the jobs fails the build; a job that cannot be purged is reported as failed.
+    pub async fn build(self) -> Result<JobCleanup> {
+        let builder = &self;
+        let jobs = self.list_builder().build().await?;
+
+        let cutoff = Utc::now()
+            - chrono::Duration::from_std(self.older_than)
+                .map_err(|err| Error::InvalidValue(err.to_string()))?;
+        let selected: Vec<JobAttributesExec> = jobs
+            .items()
+            .iter()
+            .filter(|job| is_expired(job, cutoff))
+            .cloned()
+            .collect();
+
+        if self.dry_run {
+            return Ok(JobCleanup {
+                selected,
+                purged: Vec::new(),
+                failed: Vec::new(),
+                dry_run: true,
+            });
+        }
+
+        let results: Vec<(JobAttributesExec, Result<JobFeedback>)> =
+            futures::stream::iter(selected.iter().cloned())
+                .map(|job| async move {
+                    let result = builder.purge_builder(&job).build().await;
+
+                    (job, result)
+                })
+                .buffer_unordered(self.concurrency)
+                .collect()
+                .await;
+
+        let mut purged = Vec::new();
+        let mut failed = Vec::new();
+        for (job, result) in results {
+            match result {
+                Ok(feedback) if feedback.is_success() => purged.push(job),
+                Ok(feedback) => failed.push((
+                    job,
+                    Arc::new(Error::InvalidValue(format!("purge {}", feedback))),
+                )),
+                Err(err) => failed.push((job, Arc::new(err))),
+            }
+        }
+
+        Ok(JobCleanup {
+            selected,
+            purged,
+            failed,
+            dry_run: false,
+        })
+    }
+
+    fn list_builder(&self) -> JobListBuilder<JobList<JobAttributesExec>> {
+        let builder = JobListBuilder::<JobList<JobAttributes>>::new(self.core.clone())
+            .exec_data()
+            .status(self.status);
+        let builder = match &self.subsystem {
+            Some(subsystem) => builder.subsystem(subsystem),
+            None => builder,
+        };
+        let builder = match &self.owner {
+            Some(owner) => builder.owner(owner),
+            None => builder,
+        };
+        let builder = match &self.prefix {
+            Some(prefix) => builder.prefix(prefix),
+            None => builder,
+        };
+
+        match self.max_jobs {
+            Some(max_jobs) => builder.max_jobs(max_jobs),
+            None => builder,
+        }
+    }
+
+    fn purge_builder(&self, job: &JobAttributes) -> JobPurgeBuilder<JobFeedback> {
+        let builder = JobPurgeBuilder::new(self.core.clone(), job);
+
+        match &self.subsystem {
+            Some(subsystem) => builder.subsystem(subsystem),
+            None => builder,
+        }
+    }
+}
+
+/// Whether the job finished executing before `cutoff`.
+fn is_expired(job: &JobAttributesExec, cutoff: DateTime<Utc>) -> bool {
+    job.ended().is_some_and(|ended| ended < cutoff)
+}
+
+/// The jobs selected for purging and what became of them.
+#[derive(Clone, Debug)]
+pub struct JobCleanup {
+    selected: Vec<JobAttributesExec>,
+    purged: Vec<JobAttributesExec>,
+    failed: Vec<(JobAttributesExec, Arc<Error>)>,
+    dry_run: bool,
+}
+
+impl JobCleanup {
+    /// Every job old enough to purge, whether or not it was purged.
+    pub fn selected(&self) -> &[JobAttributesExec] {
+        &self.selected
+    }
+
+    pub fn purged(&self) -> &[JobAttributesExec] {
+        &self.purged
+    }
+
+    pub fn failed(&self) -> &[(JobAttributesExec, Arc<Error>)] {
+        &self.failed
+    }
+
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    fn job(ended: Option<&str>) -> JobAttributesExec {
+        job_attributes(
+            "OUTPUT",
+            Some("CC 0000"),
+            serde_json::json!({ "jobname": "NIGHTLY1", "exec-ended": ended }),
+        )
+    }
+
+    #[test]
+    fn expired() {
+        let cutoff = "2024-03-01T00:00:00Z".parse().unwrap();
+
+        assert!(is_expired(&job(Some("2024-02-27T15:33:36.470Z")), cutoff));
+        assert!(is_expired(&job(Some("2024-02-27T15:33:36.470")), cutoff));
+        assert!(!is_expired(&job(Some("2024-03-02T08:00:00.000Z")), cutoff));
+        assert!(!is_expired(&job(None), cutoff));
+    }
+
+    #[test]
+    fn list_request() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restjobs/jobs")
+            .query(&[("owner", "IBMUSER"), ("prefix", "NIGHTLY*")])
+            .query(&[("exec-data", "Y")])
+            .query(&[("status", "output")])
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .jobs()
+            .purge_older_than(Duration::from_secs(7 * 24 * 60 * 60))
+            .owner("IBMUSER")
+            .prefix("NIGHTLY*")
+            .list_builder()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+}
@@ -0,0 +1,235 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use futures::{StreamExt, TryStreamExt};
+use serde::Serialize;
+
+use crate::{ClientCore, Error, Result};
+
+use super::files::read::{JobFileId, JobFileRead, JobFileReadBuilder};
+use super::files::{JobFile, JobFileList, JobFileListBuilder};
+use super::JobIdentifier;
+
+const MANIFEST_FILE_NAME: &str = "manifest.json";
+const JCL_FILE_NAME: &str = "JCL.txt";
+
+#[derive(Clone, Debug)]
+pub struct JobOutputDownloadBuilder {
+    core: ClientCore,
+
+    identifier: JobIdentifier,
+    directory: PathBuf,
+    subsystem: Option<Arc<str>>,
+    encoding: Option<Arc<str>>,
+    jcl: bool,
+    concurrency: usize,
+}
+
+impl JobOutputDownloadBuilder {
+    pub(crate) fn new<I, D>(core: ClientCore, identifier: I, directory: D) -> Self
+    where
+        I: Into<JobIdentifier>,
+        D: AsRef<Path>,
+    {
+        JobOutputDownloadBuilder {
+            core,
+            identifier: identifier.into(),
+            directory: directory.as_ref().to_path_buf(),
+            subsystem: None,
+            encoding: None,
+            jcl: true,
+            concurrency: 4,
+        }
+    }
+
+    pub fn subsystem<S>(self, value: S) -> Self
+    where
+        S: std::fmt::Display,
+    {
+        JobOutputDownloadBuilder {
+            subsystem: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// The EBCDIC code page of the spool files, e.g. `IBM-037`.
+    pub fn encoding<E>(self, value: E) -> Self
+    where
+        E: std::fmt::Display,
+    {
+        JobOutputDownloadBuilder {
+            encoding: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// Whether to save the job's JCL as `JCL.txt`, which is the default.
+    pub fn jcl(self, value: bool) -> Self {
+        JobOutputDownloadBuilder { jcl: value, ..self }
+    }
+
+    /// The maximum number of spool files downloaded at once.
+    pub fn concurrency(self, value: usize) -> Self {
+        JobOutputDownloadBuilder {
+            concurrency: value.max(1),
+            ..self
+        }
+    }
+
+    /// Save every spool file as `STEP.DD.id.txt` in the directory, creating it if needed,
+    /// along with a `manifest.json` describing the files.
+    pub async fn build(self) -> Result<JobOutputDownload> {
+        let builder = &self;
+
+        tokio::fs::create_dir_all(&self.directory).await?;
+
+        let job_files = self.list_builder().build().await?;
+
+        let files: Vec<(JobFile, PathBuf)> =
+            futures::stream::iter(job_files.items().iter().cloned())
+                .map(|file| async move {
+                    let path = builder.directory.join(file_name(&file));
+                    builder.save(JobFileId::Id(file.id()), &path).await?;
+
+                    Ok::<_, Error>((file, path))
+                })
+                .buffered(self.concurrency)
+                .try_collect()
+                .await?;
+
+        let jcl = match self.jcl {
+            true => {
+                let path = self.directory.join(JCL_FILE_NAME);
+                self.save(JobFileId::Jcl, &path).await?;
+
+                Some(path)
+            }
+            false => None,
+        };
+
+        let manifest = self.directory.join(MANIFEST_FILE_NAME);
+        tokio::fs::write(&manifest, manifest_json(&self.identifier, &files)?).await?;
+
+        Ok(JobOutputDownload {
+            directory: self.directory,
+            files,
+            jcl,
+            manifest,
+        })
+    }
+
+    async fn save(&self, id: JobFileId, path: &Path) -> Result<()> {
+        let read = self.read_builder(id).build().await?;
+        tokio::fs::write(path, read.data()).await?;
+
+        Ok(())
+    }
+
+    fn list_builder(&self) -> JobFileListBuilder<JobFileList> {
+        let builder = JobFileListBuilder::new(self.core.clone(), self.identifier.clone());
+
+        match &self.subsystem {
+            Some(subsystem) => builder.subsystem(subsystem),
+            None => builder,
+        }
+    }
+
+    fn read_builder(&self, id: JobFileId) -> JobFileReadBuilder<JobFileRead<Arc<str>>> {
+        let builder = JobFileReadBuilder::new(self.core.clone(), self.identifier.clone(), id);
+        let builder = match &self.subsystem {
+            Some(subsystem) => builder.subsystem(subsystem),
+            None => builder,
+        };
+
+        match &self.encoding {
+            Some(encoding) => builder.encoding(encoding),
+            None => builder,
+        }
+    }
+}
+
+pub(super) fn file_name(file: &JobFile) -> String {
+    format!("{}.{}.txt", file.label(), file.id())
+}
+
+#[derive(Serialize)]
+struct Manifest<'a> {
+    job: String,
+    files: Vec<ManifestEntry<'a>>,
+}
+
+#[derive(Serialize)]
+struct ManifestEntry<'a> {
+    #[serde(flatten)]
+    file: &'a JobFile,
+    path: String,
+}
+
+fn manifest_json(identifier: &JobIdentifier, files: &[(JobFile, PathBuf)]) -> Result<String> {
+    let manifest = Manifest {
+        job: identifier.to_string(),
+        files: files
+            .iter()
+            .map(|(file, path)| ManifestEntry {
+                file,
+                path: path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+            })
+            .collect(),
+    };
+
+    Ok(serde_json::to_string_pretty(&manifest)?)
+}
+
+/// The local copies of a job's spool files.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct JobOutputDownload {
+    directory: PathBuf,
+    files: Vec<(JobFile, PathBuf)>,
+    jcl: Option<PathBuf>,
+    manifest: PathBuf,
+}
+
+impl JobOutputDownload {
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Each spool file with the path it was saved to, in spool order.
+    pub fn files(&self) -> impl Iterator<Item = (&JobFile, &Path)> {
+        self.files.iter().map(|(file, path)| (file, path.as_path()))
+    }
+
+    pub fn jcl(&self) -> Option<&Path> {
+        self.jcl.as_deref()
+    }
+
+    pub fn manifest(&self) -> &Path {
+        &self.manifest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn manifest() {
+        let file = job_file(102, "STEP1", "SYSPRINT", serde_json::json!({}));
+        assert_eq!(file_name(&file), "STEP1.SYSPRINT.102.txt");
+
+        let identifier = JobIdentifier::NameId("TESTJOB".to_string(), "JOB00023".to_string());
+        let path = Path::new("/tmp/TESTJOB").join(file_name(&file));
+        let json: serde_json::Value =
+            serde_json::from_str(&manifest_json(&identifier, &[(file, path)]).unwrap()).unwrap();
+
+        assert_eq!(json["job"], "TESTJOB/JOB00023");
+        assert_eq!(json["files"][0]["ddname"], "SYSPRINT");
+        assert_eq!(json["files"][0]["recfm"], "FBA");
+        assert_eq!(json["files"][0]["path"], "STEP1.SYSPRINT.102.txt");
+    }
+}
@@ -0,0 +1,135 @@
+//! Short explanations of common abend codes and JCL error messages.
+//!
+//! The tables only cover the failures most often seen in batch jobs;
+//! see the z/OS MVS System Codes and Messages manuals for the rest.
+
+use super::CompletionCode;
+
+const SYSTEM_ABENDS: &[(u32, &str)] = &[
+    (0x013, "a dataset could not be opened, often because of conflicting DCB attributes or a missing member"),
+    (0x047, "an unauthorized program issued a restricted supervisor call"),
+    (0x0C1, "operation exception: the program ran an invalid instruction, often after a branch to a bad address or a missing DD statement"),
+    (0x0C4, "protection exception: the program used storage it may not access, often through a bad pointer or a subscript out of range"),
+    (0x0C5, "addressing exception: the program used an address outside of the storage available to it"),
+    (0x0C7, "data exception: a numeric field did not hold valid packed decimal data, often because it was never initialized"),
+    (0x0C9, "fixed point divide exception: the program divided by zero"),
+    (0x0CB, "decimal divide exception: the program divided a packed decimal field by zero"),
+    (0x106, "a program could not be loaded, often because the region was too small"),
+    (0x122, "the operator canceled the job and requested a dump"),
+    (0x213, "a dataset could not be opened because it was not found on the volume"),
+    (0x222, "the operator or a user canceled the job"),
+    (0x322, "the job or step used more processor time than its TIME limit"),
+    (0x522, "the job or step waited longer than the installation's wait time limit"),
+    (0x722, "the job wrote more lines of output than its LINES limit"),
+    (0x804, "the region was too small for the storage the program requested"),
+    (0x806, "a program was not found in the STEPLIB, JOBLIB or system libraries"),
+    (0x80A, "the region was too small for the storage the program requested"),
+    (0x878, "the region was too small for the storage the program requested"),
+    (0x913, "the user is not authorized to open a dataset"),
+    (0xB37, "a dataset ran out of space after using all of its secondary extents"),
+    (0xD37, "a dataset ran out of space and has no secondary allocation"),
+    (0xE37, "a dataset ran out of space on its volumes, or a PDS directory is full"),
+];
+
+const MESSAGES: &[(&str, &str)] = &[
+    ("CSV003I", "a program was not found in the libraries searched"),
+    ("ICH408I", "the user is not authorized to a resource"),
+    ("IEC030I", "a dataset ran out of space after using all of its secondary extents (B37)"),
+    ("IEC031I", "a dataset ran out of space and has no secondary allocation (D37)"),
+    ("IEC032I", "a dataset ran out of space on its volumes (E37)"),
+    ("IEC141I", "a dataset could not be opened (013)"),
+    ("IEF212I", "a dataset named on a DD statement was not found"),
+    ("IEF253I", "a dataset with the same name already exists on the volume"),
+    ("IEF272I", "a step was not executed, because of an earlier error or its COND parameter"),
+    ("IEF450I", "a step ended abnormally"),
+    ("IEF452I", "the job was not run because of a JCL error"),
+    ("IEF453I", "the job failed because of a JCL error"),
+    ("IEFC452I", "the job was not run because of a JCL error"),
+    ("IEFC605I", "a statement has an operation the converter does not recognize, often a misspelled EXEC or DD"),
+    ("IEFC607I", "the job has no EXEC statements"),
+    ("IEFC612I", "a cataloged procedure was not found in the procedure libraries"),
+    ("IEFC621I", "a statement ended with a comma but the next statement is not a continuation"),
+    ("IEFC622I", "a statement has unbalanced parentheses"),
+    ("IEFC630I", "a statement has a keyword the converter does not recognize"),
+    ("IGD17101I", "a dataset was not created because the name is already cataloged"),
+];
+
+/// A short explanation of a system abend code, e.g. `0x0C4` for `S0C4`.
+pub fn system_abend(code: u32) -> Option<&'static str> {
+    SYSTEM_ABENDS
+        .iter()
+        .find(|(abend, _)| *abend == code)
+        .map(|(_, explanation)| *explanation)
+}
+
+/// A short explanation of a message, e.g. `IEF212I`.
+pub fn message(id: &str) -> Option<&'static str> {
+    MESSAGES
+        .iter()
+        .find(|(message, _)| message.eq_ignore_ascii_case(id))
+        .map(|(_, explanation)| *explanation)
+}
+
+/// The message IDs in spool text that have an explanation, in the order they appear.
+///
+/// ```
+/// let jesysmsg = "IEF212I TESTJOBX STEP1 INPUT - DATA SET NOT FOUND\n\
+///                 IEF272I TESTJOBX STEP1 - STEP WAS NOT EXECUTED.";
+///
+/// let ids: Vec<&str> = z_osmf::jobs::explain::find_messages(jesysmsg)
+///     .map(|(id, _)| id)
+///     .collect();
+///
+/// assert_eq!(ids, ["IEF212I", "IEF272I"]);
+/// ```
+pub fn find_messages(text: &str) -> impl Iterator<Item = (&str, &'static str)> {
+    text.split_whitespace()
+        .filter_map(|word| Some((word, message(word)?)))
+}
+
+impl CompletionCode {
+    /// A short explanation of why the job failed, if the code is a common one.
+    pub fn explanation(&self) -> Option<&'static str> {
+        match self {
+            CompletionCode::SystemAbend(code) => system_abend(*code),
+            CompletionCode::UserAbend(_) => Some(
+                "the program ended itself with a user abend code; see the program's documentation",
+            ),
+            CompletionCode::JclError => {
+                Some("the JCL has an error; see the messages in JESYSMSG for details")
+            }
+            CompletionCode::Canceled => Some("the job was canceled"),
+            CompletionCode::SecurityError => Some("the job failed a security check"),
+            CompletionCode::ConversionAbend => {
+                Some("the converter ended abnormally while processing the JCL")
+            }
+            CompletionCode::SystemFailure => Some("the system failed while the job was running"),
+            CompletionCode::ConditionCode(_) | CompletionCode::Other(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explanations() {
+        assert!(CompletionCode::from("ABEND S0C7")
+            .explanation()
+            .is_some_and(|e| e.starts_with("data exception")));
+        assert!(CompletionCode::from("ABEND SB37").explanation().is_some());
+        assert_eq!(CompletionCode::from("ABEND S999").explanation(), None);
+        assert_eq!(CompletionCode::from("CC 0004").explanation(), None);
+        assert!(CompletionCode::from("JCL ERROR").explanation().is_some());
+
+        assert_eq!(message("ief212i"), message("IEF212I"));
+        assert!(message("IEF212I").is_some());
+        assert_eq!(message("IEF999I"), None);
+
+        let found: Vec<&str> = find_messages("$HASP373 TESTJOBX STARTED\nIEFC605I UNIDENTIFIED")
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(found, ["IEFC605I"]);
+    }
+}
@@ -28,12 +28,95 @@ pub struct JobFeedback {
     message: Option<Arc<str>>,
 }
 
+impl JobFeedback {
+    /// Whether the request was carried out.
+    pub fn is_success(&self) -> bool {
+        &*self.status == "0"
+    }
+}
+
+/// Summarizes the outcome, e.g. `TESTJOB2(JOB00084) on SY1: succeeded`.
+impl std::fmt::Display for JobFeedback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({}) on {}: ", self.name, self.id, self.system_name)?;
+
+        if self.is_success() {
+            return write!(f, "succeeded");
+        }
+
+        write!(f, "failed with status {}", self.status)?;
+        if let Some(internal_code) = &self.internal_code {
+            write!(f, ", internal code {}", internal_code)?;
+        }
+        match &self.message {
+            Some(message) => write!(f, ": {}", message.trim()),
+            None => Ok(()),
+        }
+    }
+}
+
+impl From<&JobFeedback> for JobIdentifier {
+    fn from(value: &JobFeedback) -> Self {
+        JobIdentifier::NameId(value.name.to_string(), value.id.to_string())
+    }
+}
+
 impl TryFromResponse for JobFeedback {
     async fn try_from_response(value: reqwest::Response) -> Result<Self> {
         Ok(value.json().await?)
     }
 }
 
+/// The acknowledgement of a job modify request made with version 1.0,
+/// returned before the request has been carried out.
+#[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct JobAsynchronousResponse {
+    #[serde(rename = "jobid")]
+    id: Arc<str>,
+    #[serde(rename = "jobname")]
+    name: Arc<str>,
+    #[serde(rename = "original-jobid")]
+    original_id: Option<Arc<str>>,
+    owner: Arc<str>,
+    member: Arc<str>,
+    #[serde(rename = "sysname")]
+    system_name: Arc<str>,
+    job_correlator: Arc<str>,
+    status: Arc<str>,
+}
+
+impl JobAsynchronousResponse {
+    /// Whether the request was accepted for processing.
+    pub fn is_accepted(&self) -> bool {
+        &*self.status == "0"
+    }
+}
+
+/// Summarizes the outcome, e.g. `TESTJOB2(JOB00084) on SY1: accepted`.
+impl std::fmt::Display for JobAsynchronousResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({}) on {}: ", self.name, self.id, self.system_name)?;
+
+        match self.is_accepted() {
+            true => write!(f, "accepted"),
+            false => write!(f, "rejected with status {}", self.status),
+        }
+    }
+}
+
+impl From<&JobAsynchronousResponse> for JobIdentifier {
+    fn from(value: &JobAsynchronousResponse) -> Self {
+        JobIdentifier::NameId(value.name.to_string(), value.id.to_string())
+    }
+}
+
+impl TryFromResponse for JobAsynchronousResponse {
+    async fn try_from_response(value: reqwest::Response) -> Result<Self> {
+        Ok(value.json().await?)
+    }
+}
+
 #[derive(Clone, Debug, Endpoint)]
 #[endpoint(method = put, path = "/zosmf/restjobs/jobs{subsystem}/{identifier}")]
 pub struct JobFeedbackBuilder<T>
@@ -58,7 +141,8 @@ impl<T> JobFeedbackBuilder<T>
 where
     T: TryFromResponse,
 {
-    pub fn asynchronous(self) -> JobFeedbackBuilder<()> {
+    /// Send the request with version 1.0, returning as soon as z/OSMF accepts it.
+    pub fn asynchronous(self) -> JobFeedbackBuilder<JobAsynchronousResponse> {
         JobFeedbackBuilder {
             core: self.core,
             subsystem: self.subsystem,
@@ -68,6 +152,18 @@ where
             target_type: PhantomData,
         }
     }
+
+    /// Send the request with version 2.0, the default, waiting for it to complete.
+    pub fn synchronous(self) -> JobFeedbackBuilder<JobFeedback> {
+        JobFeedbackBuilder {
+            core: self.core,
+            subsystem: self.subsystem,
+            identifier: self.identifier,
+            request: self.request,
+            asynchronous: Some(false),
+            target_type: PhantomData,
+        }
+    }
 }
 
 #[derive(Serialize)]
@@ -197,4 +293,71 @@ mod tests {
 
         assert_eq!(manual_request.json(), job_feedback.json())
     }
+
+    #[test]
+    fn asynchronous() {
+        let zosmf = get_zosmf();
+
+        let identifier = JobIdentifier::NameId("TESTJOB2".to_string(), "JOB00084".to_string());
+        let job_feedback = zosmf
+            .jobs()
+            .release(identifier)
+            .asynchronous()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            job_feedback.json(),
+            Some(serde_json::json!({"request": "release", "version": "1.0"}))
+        );
+
+        let response: JobAsynchronousResponse = serde_json::from_value(serde_json::json!({
+            "jobid": "JOB00084",
+            "jobname": "TESTJOB2",
+            "original-jobid": "JOB00084",
+            "owner": "IBMUSER",
+            "member": "JES2",
+            "sysname": "SY1",
+            "job-correlator": "J0000084SY1.....CC20F378.......:",
+            "status": "0"
+        }))
+        .unwrap();
+        assert!(response.is_accepted());
+        assert_eq!(response.to_string(), "TESTJOB2(JOB00084) on SY1: accepted");
+    }
+
+    #[test]
+    fn purge_feedback() {
+        let feedback = |status: &str, internal_code: Option<&str>, message: Option<&str>| {
+            serde_json::from_value::<JobFeedback>(serde_json::json!({
+                "jobid": "JOB00084",
+                "jobname": "TESTJOB2",
+                "original-jobid": "JOB00084",
+                "owner": "IBMUSER",
+                "member": "JES2",
+                "sysname": "SY1",
+                "job-correlator": "J0000084SY1.....CC20F378.......:",
+                "status": status,
+                "internal-code": internal_code,
+                "message": message
+            }))
+            .unwrap()
+        };
+
+        let purged = feedback("0", None, None);
+        assert!(purged.is_success());
+        assert_eq!(purged.original_id(), Some("JOB00084"));
+        assert_eq!(purged.member(), "JES2");
+        assert_eq!(purged.system_name(), "SY1");
+        assert_eq!(purged.to_string(), "TESTJOB2(JOB00084) on SY1: succeeded");
+
+        let failed = feedback("4", Some("6"), Some("Job is not on the output queue "));
+        assert!(!failed.is_success());
+        assert_eq!(failed.internal_code(), Some("6"));
+        assert_eq!(
+            failed.to_string(),
+            "TESTJOB2(JOB00084) on SY1: failed with status 4, internal code 6: \
+             Job is not on the output queue"
+        );
+    }
 }
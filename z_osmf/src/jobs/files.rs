@@ -1,6 +1,10 @@
+pub mod dd_name;
 pub mod read;
 
+pub use crate::utils::DatasetRecordFormat;
+
 use std::marker::PhantomData;
+use std::num::NonZeroU32;
 use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
@@ -10,17 +14,19 @@ use crate::convert::TryFromResponse;
 use crate::jobs::{get_subsystem, JobIdentifier};
 use crate::{ClientCore, Result};
 
+use self::read::RecordRange;
+
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct JobFile {
     #[serde(rename = "jobname")]
     job_name: Arc<str>,
     #[serde(rename = "recfm")]
-    record_format: Arc<str>,
+    record_format: DatasetRecordFormat,
     #[getter(copy)]
-    byte_count: i32,
+    byte_count: u64,
     #[getter(copy)]
-    record_count: i32,
+    record_count: u64,
     job_correlator: Option<Arc<str>>,
     class: Arc<str>,
     #[serde(rename = "jobid")]
@@ -40,11 +46,70 @@ pub struct JobFile {
     proc_step: Option<Arc<str>>,
 }
 
+impl From<&JobFile> for JobIdentifier {
+    fn from(value: &JobFile) -> Self {
+        JobIdentifier::NameId(value.job_name.to_string(), value.job_id.to_string())
+    }
+}
+
+impl JobFile {
+    /// The step, procedure step and DD name of the file, e.g. `STEP1.SYSPRINT`.
+    pub fn label(&self) -> String {
+        [self.step_name(), self.proc_step(), Some(self.dd_name())]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// The range of the last `count` records of the file, for reading its tail.
+    pub fn last_records(&self, count: NonZeroU32) -> RecordRange {
+        RecordRange::last(count, self.record_count)
+    }
+
+    /// Whether this is the file named by `DD`, `STEP.DD` or `STEP.PROCSTEP.DD`, ignoring case.
+    pub fn is_dd(&self, name: &str) -> bool {
+        let matches = |expected: &str, actual: Option<&str>| {
+            actual.is_some_and(|actual| actual.eq_ignore_ascii_case(expected))
+        };
+
+        match name.split('.').collect::<Vec<_>>()[..] {
+            [dd_name] => matches(dd_name, Some(&self.dd_name)),
+            [step_name, dd_name] => {
+                matches(step_name, self.step_name()) && matches(dd_name, Some(&self.dd_name))
+            }
+            [step_name, proc_step, dd_name] => {
+                matches(step_name, self.step_name())
+                    && matches(proc_step, self.proc_step())
+                    && matches(dd_name, Some(&self.dd_name))
+            }
+            _ => false,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct JobFileList {
     items: Arc<[JobFile]>,
 }
 
+impl JobFileList {
+    /// The file with the spool file ID.
+    pub fn get(&self, id: i32) -> Option<&JobFile> {
+        self.items.iter().find(|file| file.id == id)
+    }
+
+    /// The first file named by `DD`, `STEP.DD` or `STEP.PROCSTEP.DD`, ignoring case.
+    pub fn find_dd(&self, name: &str) -> Option<&JobFile> {
+        self.items.iter().find(|file| file.is_dd(name))
+    }
+
+    /// Every file named by `DD`, `STEP.DD` or `STEP.PROCSTEP.DD`, e.g. `SYSPRINT` from each step.
+    pub fn find_dds<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a JobFile> {
+        self.items.iter().filter(move |file| file.is_dd(name))
+    }
+}
+
 impl TryFromResponse for JobFileList {
     async fn try_from_response(value: reqwest::Response) -> Result<Self> {
         Ok(JobFileList {
@@ -98,4 +163,37 @@ mod tests {
 
         assert_eq!(format!("{:?}", manual_request), format!("{:?}", job_files))
     }
+
+    #[test]
+    fn typed_items() {
+        let job_files: JobFileList = serde_json::from_value(serde_json::json!([
+            {
+                "jobname": "TESTJOB1", "recfm": "UA", "byte-count": 1200, "record-count": 20,
+                "job-correlator": null, "class": "H", "jobid": "JOB00023", "id": 2,
+                "ddname": "JESMSGLG", "records-url": "https://test.com/files/2/records",
+                "lrecl": 133, "subsystem": "JES2", "stepname": "JES2", "procstep": null
+            },
+            {
+                "jobname": "TESTJOB1", "recfm": "FBA", "byte-count": 3000000000u64,
+                "record-count": 40, "job-correlator": null, "class": "H", "jobid": "JOB00023",
+                "id": 102, "ddname": "SYSPRINT", "records-url": "https://test.com/files/102/records",
+                "lrecl": 133, "subsystem": "JES2", "stepname": "STEP1", "procstep": "COMPILE"
+            }
+        ]))
+        .map(|items| JobFileList { items })
+        .unwrap();
+
+        let sysprint = job_files.find_dd("sysprint").unwrap();
+        assert_eq!(sysprint.id(), 102);
+        assert_eq!(sysprint.byte_count(), 3_000_000_000);
+        assert_eq!(sysprint.record_format(), &DatasetRecordFormat::Fba);
+        assert!(sysprint.record_format().is_fixed());
+
+        assert_eq!(
+            job_files.get(2).unwrap().record_format(),
+            &DatasetRecordFormat::Other("UA".to_string())
+        );
+        assert_eq!(job_files.find_dds("STEP1.COMPILE.SYSPRINT").count(), 1);
+        assert!(job_files.find_dd("STEP2.SYSPRINT").is_none());
+    }
 }
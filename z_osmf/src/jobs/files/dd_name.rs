@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use crate::jobs::JobIdentifier;
+use crate::{ClientCore, Error, Result};
+
+use super::read::{JobFileId, JobFileRead, JobFileReadBuilder};
+use super::{JobFile, JobFileList, JobFileListBuilder};
+
+#[derive(Clone, Debug)]
+pub struct JobFileByDdNameBuilder {
+    core: ClientCore,
+
+    identifier: JobIdentifier,
+    dd_name: Arc<str>,
+    subsystem: Option<Arc<str>>,
+    encoding: Option<Arc<str>>,
+}
+
+impl JobFileByDdNameBuilder {
+    pub(crate) fn new<I, D>(core: ClientCore, identifier: I, dd_name: D) -> Self
+    where
+        I: Into<JobIdentifier>,
+        D: std::fmt::Display,
+    {
+        JobFileByDdNameBuilder {
+            core,
+            identifier: identifier.into(),
+            dd_name: dd_name.to_string().into(),
+            subsystem: None,
+            encoding: None,
+        }
+    }
+
+    pub fn subsystem<S>(self, value: S) -> Self
+    where
+        S: std::fmt::Display,
+    {
+        JobFileByDdNameBuilder {
+            subsystem: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    pub fn encoding<E>(self, value: E) -> Self
+    where
+        E: std::fmt::Display,
+    {
+        JobFileByDdNameBuilder {
+            encoding: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// List the job's spool files, find the one with the DD name and read it.
+    ///
+    /// Fails if no file matches, or if more than one step wrote the DD name
+    /// and it was not qualified with a step, e.g. `STEP1.SYSPRINT`.
+    pub async fn build(self) -> Result<JobFileRead<Arc<str>>> {
+        let job_files = self.list_builder().build().await?;
+        let file = find(job_files.items(), &self.dd_name)?;
+
+        self.read_builder(file.id()).build().await
+    }
+
+    fn list_builder(&self) -> JobFileListBuilder<JobFileList> {
+        let builder = JobFileListBuilder::new(self.core.clone(), self.identifier.clone());
+
+        match &self.subsystem {
+            Some(subsystem) => builder.subsystem(subsystem),
+            None => builder,
+        }
+    }
+
+    fn read_builder(&self, id: i32) -> JobFileReadBuilder<JobFileRead<Arc<str>>> {
+        let builder = JobFileReadBuilder::new(
+            self.core.clone(),
+            self.identifier.clone(),
+            JobFileId::Id(id),
+        );
+        let builder = match &self.subsystem {
+            Some(subsystem) => builder.subsystem(subsystem),
+            None => builder,
+        };
+
+        match &self.encoding {
+            Some(encoding) => builder.encoding(encoding),
+            None => builder,
+        }
+    }
+}
+
+/// Find the file named by `DD`, `STEP.DD` or `STEP.PROCSTEP.DD`.
+fn find<'a>(files: &'a [JobFile], name: &str) -> Result<&'a JobFile> {
+    if name.split('.').count() > 3 {
+        return Err(Error::InvalidValue(format!("invalid DD name: {}", name)));
+    }
+
+    let found: Vec<&JobFile> = files.iter().filter(|file| file.is_dd(name)).collect();
+
+    match found[..] {
+        [file] => Ok(file),
+        [] => Err(Error::InvalidValue(format!("DD not found: {}", name))),
+        _ => Err(Error::InvalidValue(format!(
+            "DD {} is ambiguous, qualify it with one of the steps: {}",
+            name,
+            found
+                .iter()
+                .filter_map(|file| file.step_name())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job_file(id: i32, step_name: &str, proc_step: Option<&str>, dd_name: &str) -> JobFile {
+        crate::tests::job_file(
+            id,
+            step_name,
+            dd_name,
+            serde_json::json!({ "procstep": proc_step }),
+        )
+    }
+
+    #[test]
+    fn resolve() {
+        let files = [
+            job_file(2, "JES2", None, "JESMSGLG"),
+            job_file(102, "STEP1", None, "SYSPRINT"),
+            job_file(103, "STEP2", Some("COMPILE"), "SYSPRINT"),
+        ];
+
+        assert_eq!(find(&files, "jesmsglg").unwrap().id(), 2);
+        assert_eq!(find(&files, "STEP2.SYSPRINT").unwrap().id(), 103);
+        assert_eq!(find(&files, "STEP2.COMPILE.SYSPRINT").unwrap().id(), 103);
+
+        assert!(matches!(
+            find(&files, "SYSPRINT"),
+            Err(Error::InvalidValue(message)) if message.ends_with("STEP1, STEP2")
+        ));
+        assert!(find(&files, "SYSOUT").is_err());
+        assert!(find(&files, "STEP1.COMPILE.SYSPRINT").is_err());
+    }
+}
@@ -9,6 +9,7 @@ use z_osmf_macros::Endpoint;
 
 use crate::convert::TryFromResponse;
 use crate::jobs::{get_subsystem, JobIdentifier};
+use crate::utils::{decode_utf8, invalid_utf8};
 use crate::{ClientCore, Result};
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
@@ -65,6 +66,50 @@ impl TryFromResponse for JobFileRead<Bytes> {
     }
 }
 
+/// Spool text as z/OSMF converted it, byte for byte, with the sequences in it that are not valid UTF-8.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct JobFileReadRaw {
+    data: Bytes,
+    invalid_sequences: Arc<[InvalidSequence]>,
+}
+
+impl JobFileReadRaw {
+    pub fn data(&self) -> &Bytes {
+        &self.data
+    }
+
+    pub fn invalid_sequences(&self) -> &[InvalidSequence] {
+        &self.invalid_sequences
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.invalid_sequences.is_empty()
+    }
+
+    /// The text, with each invalid sequence replaced by `U+FFFD`.
+    pub fn text_lossy(&self) -> std::borrow::Cow<'_, str> {
+        String::from_utf8_lossy(&self.data)
+    }
+}
+
+/// Bytes that could not be decoded as UTF-8, e.g. DBCS data or a mis-tagged file.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct InvalidSequence {
+    offset: usize,
+    sequence: Arc<[u8]>,
+}
+
+impl InvalidSequence {
+    /// The position of the first byte of the sequence in the data.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn sequence(&self) -> &[u8] {
+        &self.sequence
+    }
+}
+
 #[derive(Clone, Debug, Endpoint)]
 #[endpoint(method = get, path = "/zosmf/restjobs/jobs{subsystem}/{identifier}/files/{id}/records")]
 pub struct JobFileReadBuilder<T>
@@ -83,6 +128,7 @@ where
     record_range: Option<RecordRange>,
     #[endpoint(skip_setter, query = "mode")]
     data_type: Option<DataType>,
+    /// The code page to convert text from, e.g. `IBM-037`, instead of the system default.
     #[endpoint(query = "fileEncoding")]
     encoding: Option<Arc<str>>,
     #[endpoint(query = "search")]
@@ -153,6 +199,46 @@ where
     }
 }
 
+impl JobFileReadBuilder<JobFileRead<Arc<str>>> {
+    /// Read the spool text, failing with [`Error::Utf8`](crate::Error::Utf8) at the first byte sequence
+    /// z/OSMF could not convert, e.g. DBCS data or the wrong encoding.
+    pub async fn build_strict(self) -> Result<JobFileRead<Arc<str>>> {
+        let data = self.get_response().await?.bytes().await?;
+
+        Ok(JobFileRead {
+            data: decode_utf8(&data)?.into(),
+        })
+    }
+
+    /// Read the spool text, replacing unconvertible byte sequences with `U+FFFD`,
+    /// so a job log with stray binary data can still be shown.
+    ///
+    /// The spool is always decoded as UTF-8, whatever charset the response declares.
+    pub async fn build_lossy(self) -> Result<JobFileRead<Arc<str>>> {
+        let data = self.get_response().await?.bytes().await?;
+
+        Ok(JobFileRead {
+            data: String::from_utf8_lossy(&data).into(),
+        })
+    }
+
+    /// Read the spool text without decoding it, with the sequences that are not valid UTF-8.
+    pub async fn build_raw(self) -> Result<JobFileReadRaw> {
+        let data = self.get_response().await?.bytes().await?;
+        let invalid_sequences = invalid_utf8(&data)
+            .map(|(offset, sequence)| InvalidSequence {
+                offset,
+                sequence: sequence.into(),
+            })
+            .collect();
+
+        Ok(JobFileReadRaw {
+            data,
+            invalid_sequences,
+        })
+    }
+}
+
 #[derive(Clone, Copy, Debug, Serialize)]
 #[serde(rename_all = "lowercase")]
 enum DataType {
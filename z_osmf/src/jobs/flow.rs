@@ -0,0 +1,403 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+
+use crate::{ClientCore, Error, Result};
+
+use super::submit::JobSubmitBuilder;
+use super::wait::JobWaitBuilder;
+use super::{JobAttributes, JobStatus};
+
+/// What to do with the rest of a flow once a job fails.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum JobFlowPolicy {
+    /// Submit no more jobs, but wait for the ones already running.
+    #[default]
+    FailFast,
+    /// Keep running every job that does not depend on the failed one.
+    Continue,
+}
+
+#[derive(Clone, Debug)]
+struct JobFlowNode {
+    name: Arc<str>,
+    submit: JobSubmitBuilder<JobAttributes>,
+    after: Arc<[Arc<str>]>,
+}
+
+#[derive(Clone, Debug)]
+pub struct JobFlowBuilder {
+    core: ClientCore,
+
+    nodes: Vec<JobFlowNode>,
+    policy: JobFlowPolicy,
+    max_cc: u32,
+    interval: Duration,
+    max_interval: Duration,
+    timeout: Duration,
+}
+
+impl JobFlowBuilder {
+    pub(crate) fn new(core: ClientCore) -> Self {
+        JobFlowBuilder {
+            core,
+            nodes: Vec::new(),
+            policy: JobFlowPolicy::default(),
+            max_cc: 0,
+            interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(15),
+            timeout: Duration::from_secs(3600),
+        }
+    }
+
+    /// Add a job with no dependencies, named for the report.
+    pub fn job<N>(self, name: N, submit: JobSubmitBuilder<JobAttributes>) -> Self
+    where
+        N: std::fmt::Display,
+    {
+        self.job_after(name, submit, std::iter::empty::<&str>())
+    }
+
+    /// Add a job, named for the report, that is submitted once the jobs named in `after`
+    /// have all succeeded.
+    pub fn job_after<N, I, A>(
+        mut self,
+        name: N,
+        submit: JobSubmitBuilder<JobAttributes>,
+        after: I,
+    ) -> Self
+    where
+        N: std::fmt::Display,
+        I: IntoIterator<Item = A>,
+        A: std::fmt::Display,
+    {
+        self.nodes.push(JobFlowNode {
+            name: name.to_string().into(),
+            submit,
+            after: after.into_iter().map(|a| a.to_string().into()).collect(),
+        });
+
+        self
+    }
+
+    pub fn policy(self, value: JobFlowPolicy) -> Self {
+        JobFlowBuilder {
+            policy: value,
+            ..self
+        }
+    }
+
+    /// The highest condition code a job may end with and still count as succeeded, 0 unless set.
+    pub fn max_cc(self, value: u32) -> Self {
+        JobFlowBuilder {
+            max_cc: value,
+            ..self
+        }
+    }
+
+    /// The delay before the first status check of each job, doubled after each attempt.
+    pub fn interval(self, value: Duration) -> Self {
+        JobFlowBuilder {
+            interval: value,
+            ..self
+        }
+    }
+
+    /// The upper bound for the delay between status checks.
+    pub fn max_interval(self, value: Duration) -> Self {
+        JobFlowBuilder {
+            max_interval: value,
+            ..self
+        }
+    }
+
+    /// How long to wait for each job to finish, one hour unless set.
+    pub fn timeout(self, value: Duration) -> Self {
+        JobFlowBuilder {
+            timeout: value,
+            ..self
+        }
+    }
+
+    /// Check that the jobs form a graph without cycles, then run them,
+    /// submitting each job as soon as the jobs it depends on have succeeded.
+    ///
+    /// Only an invalid graph fails the build; the outcome of each job is in the report.
+    pub async fn build(self) -> Result<JobFlowReport> {
+        let order = self.order()?;
+
+        let mut outcomes: Vec<Option<JobFlowOutcome>> = self.nodes.iter().map(|_| None).collect();
+        let mut started = vec![false; self.nodes.len()];
+        let mut stopped = false;
+        let mut running = FuturesUnordered::new();
+
+        loop {
+            if !stopped {
+                for &index in &order {
+                    if started[index] || outcomes[index].is_some() {
+                        continue;
+                    }
+
+                    match self.readiness(index, &outcomes) {
+                        Some(true) => {
+                            started[index] = true;
+                            running.push(self.run(index));
+                        }
+                        Some(false) => outcomes[index] = Some(JobFlowOutcome::Skipped),
+                        None => {}
+                    }
+                }
+            }
+
+            let Some((index, result)) = running.next().await else {
+                break;
+            };
+
+            let outcome = match result {
+                Ok(attributes) => match attributes
+                    .completion_code()
+                    .is_some_and(|code| code.is_success(self.max_cc))
+                {
+                    true => JobFlowOutcome::Succeeded(attributes),
+                    false => JobFlowOutcome::Failed(attributes),
+                },
+                Err(err) => JobFlowOutcome::Error(Arc::new(err)),
+            };
+
+            if !outcome.is_success() && self.policy == JobFlowPolicy::FailFast {
+                stopped = true;
+            }
+            outcomes[index] = Some(outcome);
+        }
+
+        Ok(JobFlowReport {
+            nodes: self
+                .nodes
+                .iter()
+                .zip(outcomes)
+                .map(|(node, outcome)| {
+                    (
+                        node.name.clone(),
+                        outcome.unwrap_or(JobFlowOutcome::Skipped),
+                    )
+                })
+                .collect(),
+        })
+    }
+
+    /// Whether the job can start (`Some(true)`), can never start (`Some(false)`),
+    /// or still has jobs to wait for (`None`).
+    fn readiness(&self, index: usize, outcomes: &[Option<JobFlowOutcome>]) -> Option<bool> {
+        let mut ready = true;
+        for name in self.nodes[index].after.iter() {
+            match &outcomes[self.index(name)?] {
+                Some(outcome) if !outcome.is_success() => return Some(false),
+                Some(_) => {}
+                None => ready = false,
+            }
+        }
+
+        ready.then_some(true)
+    }
+
+    fn index(&self, name: &str) -> Option<usize> {
+        self.nodes.iter().position(|node| *node.name == *name)
+    }
+
+    async fn run(&self, index: usize) -> (usize, Result<JobAttributes>) {
+        let result = async {
+            let submitted = self.nodes[index].submit.clone().build().await?;
+
+            JobWaitBuilder::new(self.core.clone(), &submitted, JobStatus::Output)
+                .interval(self.interval)
+                .max_interval(self.max_interval)
+                .timeout(self.timeout)
+                .build()
+                .await
+        };
+
+        (index, result.await)
+    }
+
+    /// The jobs in an order where each comes after all of the jobs it depends on.
+    fn order(&self) -> Result<Vec<usize>> {
+        let mut indexes: HashMap<&str, usize> = HashMap::new();
+        for (index, node) in self.nodes.iter().enumerate() {
+            if indexes.insert(&node.name, index).is_some() {
+                return Err(Error::InvalidValue(format!(
+                    "duplicate job in flow: {}",
+                    node.name
+                )));
+            }
+        }
+
+        let mut waiting = vec![0; self.nodes.len()];
+        let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); self.nodes.len()];
+        for (index, node) in self.nodes.iter().enumerate() {
+            for name in node.after.iter() {
+                let &dependency = indexes.get(&**name).ok_or_else(|| {
+                    Error::InvalidValue(format!(
+                        "job {} depends on unknown job {}",
+                        node.name, name
+                    ))
+                })?;
+
+                waiting[index] += 1;
+                dependents[dependency].push(index);
+            }
+        }
+
+        let mut ready: VecDeque<usize> = (0..self.nodes.len())
+            .filter(|&index| waiting[index] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some(index) = ready.pop_front() {
+            order.push(index);
+            for &dependent in &dependents[index] {
+                waiting[dependent] -= 1;
+                if waiting[dependent] == 0 {
+                    ready.push_back(dependent);
+                }
+            }
+        }
+
+        if order.len() < self.nodes.len() {
+            return Err(Error::InvalidValue(format!(
+                "flow has a cycle between jobs: {}",
+                self.nodes
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| waiting[*index] > 0)
+                    .map(|(_, node)| node.name.as_ref())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )));
+        }
+
+        Ok(order)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum JobFlowOutcome {
+    /// The job ended with a condition code of at most the flow's maximum.
+    Succeeded(JobAttributes),
+    /// The job ended with a higher condition code, an abend or a JCL error.
+    Failed(JobAttributes),
+    /// The job could not be submitted or waited for.
+    Error(Arc<Error>),
+    /// The job was not submitted, because a job it depends on did not succeed
+    /// or the flow stopped early.
+    Skipped,
+}
+
+impl JobFlowOutcome {
+    pub fn is_success(&self) -> bool {
+        matches!(self, JobFlowOutcome::Succeeded(_))
+    }
+
+    /// The attributes of the job when it finished, if it ran.
+    pub fn attributes(&self) -> Option<&JobAttributes> {
+        match self {
+            JobFlowOutcome::Succeeded(attributes) | JobFlowOutcome::Failed(attributes) => {
+                Some(attributes)
+            }
+            JobFlowOutcome::Error(_) | JobFlowOutcome::Skipped => None,
+        }
+    }
+}
+
+/// The outcome of each job in a flow, in the order the jobs were added.
+#[derive(Clone, Debug)]
+pub struct JobFlowReport {
+    nodes: Vec<(Arc<str>, JobFlowOutcome)>,
+}
+
+impl JobFlowReport {
+    pub fn jobs(&self) -> impl Iterator<Item = (&str, &JobFlowOutcome)> {
+        self.nodes
+            .iter()
+            .map(|(name, outcome)| (name.as_ref(), outcome))
+    }
+
+    pub fn get(&self, name: &str) -> Option<&JobFlowOutcome> {
+        self.jobs()
+            .find(|(node, _)| *node == name)
+            .map(|(_, outcome)| outcome)
+    }
+
+    /// Whether every job in the flow succeeded.
+    pub fn is_success(&self) -> bool {
+        self.nodes.iter().all(|(_, outcome)| outcome.is_success())
+    }
+
+    /// The jobs that did not succeed, including skipped ones.
+    pub fn unsuccessful(&self) -> impl Iterator<Item = (&str, &JobFlowOutcome)> {
+        self.jobs().filter(|(_, outcome)| !outcome.is_success())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::jobs::submit::{JclData, JobSource};
+    use crate::tests::*;
+
+    use super::*;
+
+    fn flow(jobs: &[(&str, &[&str])]) -> JobFlowBuilder {
+        let zosmf = get_zosmf();
+
+        jobs.iter()
+            .fold(zosmf.jobs().flow(), |flow, (name, after)| {
+                let submit = zosmf
+                    .jobs()
+                    .submit(JobSource::Jcl(JclData::Text(format!("//{} JOB", name))));
+
+                flow.job_after(name, submit, after.iter())
+            })
+    }
+
+    #[test]
+    fn order() {
+        let flow = flow(&[
+            ("LOAD", &["EXTRACT", "CLEANUP"]),
+            ("EXTRACT", &[]),
+            ("REPORT", &["LOAD"]),
+            ("CLEANUP", &[]),
+        ]);
+
+        assert_eq!(flow.order().unwrap(), vec![1, 3, 0, 2]);
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            flow(&[("A", &[]), ("A", &[])]).order(),
+            Err(Error::InvalidValue(message)) if message.contains("duplicate")
+        ));
+        assert!(matches!(
+            flow(&[("A", &["B"])]).order(),
+            Err(Error::InvalidValue(message)) if message.contains("unknown")
+        ));
+        assert!(matches!(
+            flow(&[("A", &[]), ("B", &["C"]), ("C", &["B"])]).order(),
+            Err(Error::InvalidValue(message)) if message.ends_with("B, C")
+        ));
+    }
+
+    #[test]
+    fn readiness() {
+        let flow = flow(&[("A", &[]), ("B", &["A"]), ("C", &["A", "B"])]);
+
+        let outcomes = [None, None, None];
+        assert_eq!(flow.readiness(0, &outcomes), Some(true));
+        assert_eq!(flow.readiness(1, &outcomes), None);
+
+        let outcomes = [Some(JobFlowOutcome::Skipped), None, None];
+        assert_eq!(flow.readiness(1, &outcomes), Some(false));
+        assert_eq!(flow.readiness(2, &outcomes), Some(false));
+    }
+}
@@ -1,13 +1,16 @@
+use std::collections::VecDeque;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
+use futures::Stream;
 use serde::{Deserialize, Serialize};
 use z_osmf_macros::{Endpoint, Getters};
 
 use crate::convert::TryFromResponse;
 use crate::{ClientCore, Result};
 
-use super::{get_subsystem, JobAttributesExec};
+use super::{get_subsystem, severity, JobAttributes, JobAttributesExec};
 
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct JobList<T> {
@@ -25,6 +28,98 @@ where
     }
 }
 
+impl JobList<JobAttributes> {
+    /// The list sorted client-side.
+    ///
+    /// Without execution data the submit time is unknown, so
+    /// [`Submitted`](JobListSort::Submitted) orders jobs by job ID,
+    /// which JES assigns in submission order until the numbers wrap.
+    pub fn sorted(self, order: JobListSort) -> Self {
+        JobList {
+            items: sort_jobs(&self.items, order, |job| job, |_| None),
+        }
+    }
+}
+
+impl JobList<JobAttributesExec> {
+    /// The list sorted client-side.
+    pub fn sorted(self, order: JobListSort) -> Self {
+        JobList {
+            items: sort_jobs(&self.items, order, |job| job, |job| job.submitted()),
+        }
+    }
+}
+
+impl<T> JobList<T>
+where
+    T: Clone,
+{
+    pub fn reversed(self) -> Self {
+        let mut items = self.items.to_vec();
+        items.reverse();
+
+        JobList {
+            items: items.into(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum JobListSort {
+    /// Oldest first.
+    Submitted,
+    /// By job name, then job ID.
+    Name,
+    /// Jobs still running first, then by increasing severity, with abends and JCL errors last.
+    ReturnCode,
+}
+
+fn sort_jobs<T>(
+    items: &[T],
+    order: JobListSort,
+    job: impl Fn(&T) -> &JobAttributes,
+    submitted: impl Fn(&T) -> Option<DateTime<Utc>>,
+) -> Arc<[T]>
+where
+    T: Clone,
+{
+    let number = |item: &T| job_number(job(item).id());
+
+    let mut items = items.to_vec();
+    match order {
+        JobListSort::Submitted => items.sort_by_cached_key(|item| (submitted(item), number(item))),
+        JobListSort::Name => {
+            items.sort_by(|a, b| (job(a).name(), number(a)).cmp(&(job(b).name(), number(b))))
+        }
+        JobListSort::ReturnCode => items.sort_by_cached_key(|item| {
+            (
+                job(item).completion_code().map(|code| severity(&code)),
+                number(item),
+            )
+        }),
+    }
+
+    items.into()
+}
+
+/// The number part of a job ID, e.g. `23` for `JOB00023`.
+fn job_number(id: &str) -> Option<u32> {
+    id.trim_start_matches(|c: char| c.is_ascii_alphabetic())
+        .parse()
+        .ok()
+}
+
+/// The jobs to list by where they are in their lifecycle.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobListStatus {
+    Active,
+    Input,
+    Output,
+    #[serde(rename = "*")]
+    All,
+}
+
 #[derive(Clone, Debug, Endpoint)]
 #[endpoint(method = get, path = "/zosmf/restjobs/jobs{subsystem}")]
 pub struct JobListBuilder<T>
@@ -47,8 +142,11 @@ where
     user_correlator: Option<Arc<str>>,
     #[endpoint(skip_setter, builder_fn = build_exec_data)]
     exec_data: Option<bool>,
-    #[endpoint(builder_fn = build_active_only)]
+    /// Shorthand for `status(JobListStatus::Active)`.
+    #[endpoint(skip_builder)]
     active_only: Option<bool>,
+    #[endpoint(builder_fn = build_status)]
+    status: Option<JobListStatus>,
 
     target_type: PhantomData<T>,
 }
@@ -68,22 +166,95 @@ where
             user_correlator: self.user_correlator,
             exec_data: Some(true),
             active_only: self.active_only,
+            status: self.status,
             target_type: PhantomData,
         }
     }
 }
 
-fn build_active_only<T>(
-    request_builder: reqwest::RequestBuilder,
-    builder: &JobListBuilder<T>,
-) -> reqwest::RequestBuilder
+impl<T> JobListBuilder<JobList<T>>
 where
-    T: TryFromResponse,
+    T: Clone + for<'de> Deserialize<'de>,
 {
-    match builder.active_only {
-        Some(true) => request_builder.query(&[("status", "active")]),
-        _ => request_builder,
+    /// List the jobs one page per request, for lists longer than
+    /// [`max_jobs`](JobListBuilder::max_jobs), which z/OSMF defaults to 1000.
+    ///
+    /// When a page is full, the list may have been truncated, so the prefix is
+    /// narrowed by one character, e.g. `TEST*` to `TEST`, `TESTA*`, `TESTB*`...,
+    /// and each narrower prefix is listed instead. A page is yielded as is when
+    /// its prefix cannot be narrowed, because it is 8 characters long or has
+    /// wildcards before the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use futures::TryStreamExt;
+    /// # use z_osmf::jobs::list::JobListSort;
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// let pages: Vec<_> = zosmf.jobs().list().owner("*").pages().try_collect().await?;
+    ///
+    /// for page in pages {
+    ///     for job in page.sorted(JobListSort::Submitted).items().iter() {
+    ///         println!("{} {}", job.id(), job.name());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pages(self) -> impl Stream<Item = Result<JobList<T>>> {
+        let prefix = self.prefix.clone().unwrap_or_else(|| "*".into());
+        let prefixes = VecDeque::from([prefix]);
+
+        futures::stream::unfold((self, prefixes), |(builder, mut prefixes)| async move {
+            loop {
+                let prefix = prefixes.pop_front()?;
+                let page = match builder.with_prefix(&prefix).build().await {
+                    Ok(page) => page,
+                    Err(err) => return Some((Err(err), (builder, prefixes))),
+                };
+
+                let limit = builder.max_jobs.unwrap_or(1000).max(1) as usize;
+                match narrow_prefix(&prefix) {
+                    Some(narrower) if page.items.len() >= limit && builder.job_id.is_none() => {
+                        for narrower in narrower.into_iter().rev() {
+                            prefixes.push_front(narrower);
+                        }
+                    }
+                    _ => return Some((Ok(page), (builder, prefixes))),
+                }
+            }
+        })
     }
+
+    fn with_prefix(&self, prefix: &Arc<str>) -> Self {
+        JobListBuilder {
+            prefix: Some(prefix.clone()),
+            ..self.clone()
+        }
+    }
+}
+
+/// The prefixes that together match the same job names as `prefix`, one character longer,
+/// or `None` if it cannot be narrowed.
+fn narrow_prefix(prefix: &str) -> Option<Vec<Arc<str>>> {
+    let stem = prefix.strip_suffix('*')?.to_uppercase();
+    if stem.len() >= 8 || stem.contains(['*', '%']) {
+        return None;
+    }
+
+    let mut narrower: Vec<Arc<str>> = Vec::new();
+    if !stem.is_empty() {
+        narrower.push(stem.as_str().into());
+    }
+    narrower.extend(
+        ('A'..='Z')
+            .chain('0'..='9')
+            .chain(['#', '$', '@'])
+            .filter(|c| !stem.is_empty() || !c.is_ascii_digit())
+            .map(|c| format!("{}{}*", stem, c).into()),
+    );
+
+    Some(narrower)
 }
 
 fn build_exec_data<T>(
@@ -99,6 +270,22 @@ where
     }
 }
 
+fn build_status<T>(
+    request_builder: reqwest::RequestBuilder,
+    builder: &JobListBuilder<T>,
+) -> reqwest::RequestBuilder
+where
+    T: TryFromResponse,
+{
+    let status = match (builder.status, builder.active_only) {
+        (Some(status), _) => status,
+        (None, Some(true)) => JobListStatus::Active,
+        _ => return request_builder,
+    };
+
+    request_builder.query(&[("status", status)])
+}
+
 fn build_subsystem<T>(builder: &JobListBuilder<T>) -> String
 where
     T: TryFromResponse,
@@ -110,6 +297,8 @@ where
 mod tests {
     use crate::tests::*;
 
+    use super::*;
+
     #[test]
     fn example_1() {
         let zosmf = get_zosmf();
@@ -158,4 +347,120 @@ mod tests {
 
         assert_eq!(format!("{:?}", manual_request), format!("{:?}", job_list))
     }
+
+    #[test]
+    fn narrow() {
+        let narrower = narrow_prefix("test*").unwrap();
+        assert_eq!(narrower.len(), 40);
+        assert_eq!(&*narrower[0], "TEST");
+        assert_eq!(&*narrower[1], "TESTA*");
+        assert_eq!(&*narrower[39], "TEST@*");
+
+        let narrower = narrow_prefix("*").unwrap();
+        assert_eq!(narrower.len(), 29);
+        assert_eq!(&*narrower[0], "A*");
+
+        assert_eq!(narrow_prefix("TESTJOB1*"), None);
+        assert_eq!(narrow_prefix("T%ST*"), None);
+        assert_eq!(narrow_prefix("TESTJOB1"), None);
+
+        let zosmf = get_zosmf();
+        let job_list = zosmf
+            .jobs()
+            .list()
+            .owner("IBMUSER")
+            .with_prefix(&narrower[0])
+            .get_request()
+            .unwrap();
+        assert_eq!(job_list.url().query(), Some("owner=IBMUSER&prefix=A*"));
+    }
+
+    #[test]
+    fn sort() {
+        let job = |id: &str, name: &str, return_code: Option<&str>, submitted: &str| {
+            job_attributes::<JobAttributesExec>(
+                "OUTPUT",
+                return_code,
+                serde_json::json!({ "jobid": id, "jobname": name, "exec-submitted": submitted }),
+            )
+        };
+        let list = JobList {
+            items: vec![
+                job(
+                    "JOB00100",
+                    "NIGHTLY",
+                    Some("ABEND S0C4"),
+                    "2024-02-27T09:00:00Z",
+                ),
+                job("J0000099", "DAILY", Some("CC 0004"), "2024-02-27T10:00:00Z"),
+                job("JOB00098", "BACKUP", None, "2024-02-27T08:00:00Z"),
+                job("JOB00097", "DAILY", Some("CC 0000"), "2024-02-27T11:00:00Z"),
+            ]
+            .into(),
+        };
+        let ids = |list: JobList<JobAttributesExec>| -> Vec<String> {
+            list.items()
+                .iter()
+                .map(|job| job.id().to_string())
+                .collect()
+        };
+
+        assert_eq!(
+            ids(list.clone().sorted(JobListSort::Submitted)),
+            ["JOB00098", "JOB00100", "J0000099", "JOB00097"]
+        );
+        assert_eq!(
+            ids(list.clone().sorted(JobListSort::Name)),
+            ["JOB00098", "JOB00097", "J0000099", "JOB00100"]
+        );
+        assert_eq!(
+            ids(list.clone().sorted(JobListSort::ReturnCode).reversed()),
+            ["JOB00100", "J0000099", "JOB00097", "JOB00098"]
+        );
+
+        let list = JobList {
+            items: list.items().iter().map(|job| (**job).clone()).collect(),
+        };
+        let ids: Vec<String> = list
+            .sorted(JobListSort::Submitted)
+            .items()
+            .iter()
+            .map(|job| job.id().to_string())
+            .collect();
+        assert_eq!(ids, ["JOB00097", "JOB00098", "J0000099", "JOB00100"]);
+    }
+
+    #[test]
+    fn status() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restjobs/jobs")
+            .query(&[("owner", "IBMUSER"), ("status", "output")])
+            .build()
+            .unwrap();
+
+        let job_list = zosmf
+            .jobs()
+            .list()
+            .owner("IBMUSER")
+            .status(JobListStatus::Output)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", job_list));
+
+        let job_list = zosmf.jobs().list().active_only(true).get_request().unwrap();
+        assert_eq!(job_list.url().query(), Some("status=active"));
+
+        let job_list = zosmf
+            .jobs()
+            .list()
+            .status(JobListStatus::All)
+            .get_request()
+            .unwrap();
+        assert_eq!(job_list.url().query(), Some("status=*"));
+    }
 }
@@ -0,0 +1,262 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::Stream;
+
+use crate::{ClientCore, Result};
+
+use super::status::JobStatusBuilder;
+use super::{JobAttributes, JobIdentifier, JobStatus};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum JobMonitorEventKind {
+    /// The job was first seen, or moved to another status.
+    Status,
+    /// The job moved to another phase without changing status.
+    Phase,
+    /// The job reached the output queue, the last event of the stream.
+    Completed,
+}
+
+#[derive(Clone, Debug)]
+pub struct JobMonitorEvent {
+    kind: JobMonitorEventKind,
+    attributes: JobAttributes,
+}
+
+impl JobMonitorEvent {
+    pub fn kind(&self) -> JobMonitorEventKind {
+        self.kind
+    }
+
+    /// The attributes of the job when the change was seen.
+    pub fn attributes(&self) -> &JobAttributes {
+        &self.attributes
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct JobMonitorBuilder {
+    core: ClientCore,
+
+    identifier: JobIdentifier,
+    subsystem: Option<Arc<str>>,
+    interval: Duration,
+    max_interval: Duration,
+    max_errors: u32,
+}
+
+impl JobMonitorBuilder {
+    pub(crate) fn new<I>(core: ClientCore, identifier: I) -> Self
+    where
+        I: Into<JobIdentifier>,
+    {
+        JobMonitorBuilder {
+            core,
+            identifier: identifier.into(),
+            subsystem: None,
+            interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(30),
+            max_errors: 5,
+        }
+    }
+
+    pub fn subsystem<S>(self, value: S) -> Self
+    where
+        S: std::fmt::Display,
+    {
+        JobMonitorBuilder {
+            subsystem: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// The time between polls after a change, 1 second unless set.
+    pub fn interval(self, value: Duration) -> Self {
+        JobMonitorBuilder {
+            interval: value,
+            ..self
+        }
+    }
+
+    /// The longest time between polls while nothing changes, 30 seconds unless set.
+    pub fn max_interval(self, value: Duration) -> Self {
+        JobMonitorBuilder {
+            max_interval: value,
+            ..self
+        }
+    }
+
+    /// The number of polls in a row that may fail in transit or with a server error
+    /// before the stream ends, 5 unless set.
+    pub fn max_errors(self, value: u32) -> Self {
+        JobMonitorBuilder {
+            max_errors: value,
+            ..self
+        }
+    }
+
+    /// Poll the job until it reaches the output queue, yielding an event for each change.
+    ///
+    /// The interval doubles after each poll that sees no change or fails, up to the maximum,
+    /// and drops back after a change. The final event is
+    /// [`Completed`](JobMonitorEventKind::Completed), which carries the return code.
+    ///
+    /// A failed poll is yielded as an error. The stream ends after an error that polling
+    /// again cannot fix, e.g. a 404 once the job is purged, or after
+    /// [`max_errors`](Self::max_errors) failures in a row.
+    pub fn build(self) -> impl Stream<Item = Result<JobMonitorEvent>> {
+        let state = MonitorState {
+            delay: None,
+            previous: None,
+            ready: VecDeque::new(),
+            errors: 0,
+            done: false,
+        };
+
+        futures::stream::unfold((self, state), |(builder, mut state)| async move {
+            loop {
+                if let Some(event) = state.ready.pop_front() {
+                    return Some((Ok(event), (builder, state)));
+                }
+
+                if state.done {
+                    return None;
+                }
+
+                if let Some(delay) = state.delay {
+                    tokio::time::sleep(delay).await;
+                }
+
+                let attributes = match builder.status_builder().build().await {
+                    Ok(attributes) => attributes,
+                    Err(err) => {
+                        state.errors += 1;
+                        state.done = !err.is_retryable() || state.errors >= builder.max_errors;
+                        state.delay = Some(builder.next_delay(state.delay));
+
+                        return Some((Err(err), (builder, state)));
+                    }
+                };
+                state.errors = 0;
+
+                let kinds = changes(state.previous.as_ref(), &attributes);
+                state.delay = Some(match kinds.is_empty() {
+                    true => builder.next_delay(state.delay),
+                    false => builder.interval,
+                });
+                state.done = kinds.contains(&JobMonitorEventKind::Completed);
+                state
+                    .ready
+                    .extend(kinds.into_iter().map(|kind| JobMonitorEvent {
+                        kind,
+                        attributes: attributes.clone(),
+                    }));
+                state.previous = Some(attributes);
+            }
+        })
+    }
+
+    fn next_delay(&self, delay: Option<Duration>) -> Duration {
+        match delay {
+            Some(delay) => (delay * 2).min(self.max_interval),
+            None => self.interval,
+        }
+    }
+
+    fn status_builder(&self) -> JobStatusBuilder<JobAttributes> {
+        let builder = JobStatusBuilder::new(self.core.clone(), self.identifier.clone());
+
+        match &self.subsystem {
+            Some(subsystem) => builder.subsystem(subsystem),
+            None => builder,
+        }
+    }
+}
+
+struct MonitorState {
+    delay: Option<Duration>,
+    previous: Option<JobAttributes>,
+    ready: VecDeque<JobMonitorEvent>,
+    errors: u32,
+    done: bool,
+}
+
+fn changes(previous: Option<&JobAttributes>, current: &JobAttributes) -> Vec<JobMonitorEventKind> {
+    let mut kinds = Vec::new();
+
+    match previous {
+        Some(previous) if previous.status() == current.status() => {
+            if previous.phase() != current.phase() {
+                kinds.push(JobMonitorEventKind::Phase);
+            }
+        }
+        _ => kinds.push(JobMonitorEventKind::Status),
+    }
+
+    if current.status() == Some(JobStatus::Output) {
+        kinds.push(JobMonitorEventKind::Completed);
+    }
+
+    kinds
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    fn attributes(status: &str, phase: i32, return_code: Option<&str>) -> JobAttributes {
+        job_attributes(status, return_code, serde_json::json!({ "phase": phase }))
+    }
+
+    #[test]
+    fn transitions() {
+        let input = attributes("INPUT", 10, None);
+        let active = attributes("ACTIVE", 14, None);
+        let active_later = attributes("ACTIVE", 15, None);
+        let output = attributes("OUTPUT", 20, Some("CC 0000"));
+
+        assert_eq!(changes(None, &input), vec![JobMonitorEventKind::Status]);
+        assert_eq!(changes(Some(&input), &input), vec![]);
+        assert_eq!(
+            changes(Some(&input), &active),
+            vec![JobMonitorEventKind::Status]
+        );
+        assert_eq!(
+            changes(Some(&active), &active_later),
+            vec![JobMonitorEventKind::Phase]
+        );
+        assert_eq!(
+            changes(Some(&active_later), &output),
+            vec![JobMonitorEventKind::Status, JobMonitorEventKind::Completed]
+        );
+        assert_eq!(
+            changes(None, &output),
+            vec![JobMonitorEventKind::Status, JobMonitorEventKind::Completed]
+        );
+    }
+
+    #[test]
+    fn status_request() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restjobs/jobs/J0000023SY1.....D5E0A2F1")
+            .build()
+            .unwrap();
+
+        let request = zosmf
+            .jobs()
+            .monitor(JobIdentifier::Correlator("J0000023SY1.....D5E0A2F1".into()))
+            .status_builder()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", request));
+    }
+}
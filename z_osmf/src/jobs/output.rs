@@ -0,0 +1,288 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use futures::{StreamExt, TryStreamExt};
+
+use crate::{ClientCore, Error, Result};
+
+use super::files::read::{JobFileId, JobFileRead, JobFileReadBuilder};
+use super::files::{JobFile, JobFileList, JobFileListBuilder};
+use super::JobIdentifier;
+
+#[derive(Clone, Debug)]
+pub struct JobOutputBuilder {
+    core: ClientCore,
+
+    identifier: JobIdentifier,
+    subsystem: Option<Arc<str>>,
+    dd_names: Option<Arc<[Arc<str>]>>,
+    step_names: Option<Arc<[Arc<str>]>>,
+    encoding: Option<Arc<str>>,
+    concurrency: usize,
+}
+
+impl JobOutputBuilder {
+    pub(crate) fn new<I>(core: ClientCore, identifier: I) -> Self
+    where
+        I: Into<JobIdentifier>,
+    {
+        JobOutputBuilder {
+            core,
+            identifier: identifier.into(),
+            subsystem: None,
+            dd_names: None,
+            step_names: None,
+            encoding: None,
+            concurrency: 4,
+        }
+    }
+
+    pub fn subsystem<S>(self, value: S) -> Self
+    where
+        S: std::fmt::Display,
+    {
+        JobOutputBuilder {
+            subsystem: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// Only read the spool files with these DD names, e.g. `SYSPRINT`.
+    pub fn dd_names<I, D>(self, value: I) -> Self
+    where
+        I: IntoIterator<Item = D>,
+        D: std::fmt::Display,
+    {
+        JobOutputBuilder {
+            dd_names: Some(value.into_iter().map(|d| d.to_string().into()).collect()),
+            ..self
+        }
+    }
+
+    /// Only read the spool files written by these steps, e.g. `STEP1` or `JES2`.
+    pub fn step_names<I, S>(self, value: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: std::fmt::Display,
+    {
+        JobOutputBuilder {
+            step_names: Some(value.into_iter().map(|s| s.to_string().into()).collect()),
+            ..self
+        }
+    }
+
+    pub fn encoding<E>(self, value: E) -> Self
+    where
+        E: std::fmt::Display,
+    {
+        JobOutputBuilder {
+            encoding: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// The maximum number of spool files read at once.
+    pub fn concurrency(self, value: usize) -> Self {
+        JobOutputBuilder {
+            concurrency: value.max(1),
+            ..self
+        }
+    }
+
+    /// List the job's spool files and read the selected ones, in spool order.
+    pub async fn build(self) -> Result<JobOutput> {
+        let builder = &self;
+        let job_files = self.list_builder().build().await?;
+
+        let files = futures::stream::iter(
+            job_files
+                .items()
+                .iter()
+                .filter(|file| self.is_selected(file))
+                .cloned(),
+        )
+        .map(|file| async move {
+            let read = builder.read_builder(file.id()).build().await?;
+
+            Ok::<_, Error>(JobOutputFile {
+                file,
+                data: read.data().into(),
+            })
+        })
+        .buffered(self.concurrency)
+        .try_collect()
+        .await?;
+
+        Ok(JobOutput { files })
+    }
+
+    fn is_selected(&self, file: &JobFile) -> bool {
+        let matches = |names: &Option<Arc<[Arc<str>]>>, name: Option<&str>| match names {
+            Some(names) => name.is_some_and(|name| {
+                names
+                    .iter()
+                    .any(|selected| selected.eq_ignore_ascii_case(name))
+            }),
+            None => true,
+        };
+
+        matches(&self.dd_names, Some(file.dd_name())) && matches(&self.step_names, file.step_name())
+    }
+
+    fn list_builder(&self) -> JobFileListBuilder<JobFileList> {
+        let builder = JobFileListBuilder::new(self.core.clone(), self.identifier.clone());
+
+        match &self.subsystem {
+            Some(subsystem) => builder.subsystem(subsystem),
+            None => builder,
+        }
+    }
+
+    fn read_builder(&self, id: i32) -> JobFileReadBuilder<JobFileRead<Arc<str>>> {
+        let builder = JobFileReadBuilder::new(
+            self.core.clone(),
+            self.identifier.clone(),
+            JobFileId::Id(id),
+        );
+        let builder = match &self.subsystem {
+            Some(subsystem) => builder.subsystem(subsystem),
+            None => builder,
+        };
+
+        match &self.encoding {
+            Some(encoding) => builder.encoding(encoding),
+            None => builder,
+        }
+    }
+}
+
+/// The contents of a job's spool files.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct JobOutput {
+    files: Vec<JobOutputFile>,
+}
+
+impl JobOutput {
+    /// The spool files that were read, in spool order.
+    pub fn files(&self) -> &[JobOutputFile] {
+        &self.files
+    }
+
+    /// The files with the given DD name, from every step that wrote one.
+    pub fn get<'a>(&'a self, dd_name: &'a str) -> impl Iterator<Item = &'a JobOutputFile> {
+        self.files
+            .iter()
+            .filter(move |file| file.file.dd_name().eq_ignore_ascii_case(dd_name))
+    }
+
+    /// The contents of each DD name, joining files from different steps in spool order.
+    pub fn by_dd_name(&self) -> BTreeMap<&str, String> {
+        let mut map: BTreeMap<&str, String> = BTreeMap::new();
+        for file in &self.files {
+            let data = map.entry(file.file.dd_name()).or_default();
+            push_data(data, &file.data);
+        }
+
+        map
+    }
+
+    /// All of the output as one string, with a separator line naming each file.
+    pub fn concatenated(&self) -> String {
+        let mut output = String::new();
+        for file in &self.files {
+            output.push_str(&format!("---------- {} ----------\n", file.label()));
+            push_data(&mut output, &file.data);
+        }
+
+        output
+    }
+}
+
+fn push_data(output: &mut String, data: &str) {
+    output.push_str(data);
+    if !data.is_empty() && !data.ends_with('\n') {
+        output.push('\n');
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct JobOutputFile {
+    file: JobFile,
+    data: Arc<str>,
+}
+
+impl JobOutputFile {
+    pub fn file(&self) -> &JobFile {
+        &self.file
+    }
+
+    pub fn data(&self) -> &str {
+        &self.data
+    }
+
+    /// The step, procedure step and DD name of the file, e.g. `STEP1.SYSPRINT`.
+    pub fn label(&self) -> String {
+        self.file.label()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    fn job_file(id: i32, step_name: &str, dd_name: &str) -> JobFile {
+        crate::tests::job_file(id, step_name, dd_name, serde_json::json!({}))
+    }
+
+    #[test]
+    fn selection() {
+        let zosmf = get_zosmf();
+
+        let builder = zosmf
+            .jobs()
+            .output(JobIdentifier::Correlator("J0000023SY1.....D5E0A2F1".into()))
+            .dd_names(["sysprint"])
+            .step_names(["STEP1"]);
+
+        assert!(builder.is_selected(&job_file(102, "STEP1", "SYSPRINT")));
+        assert!(!builder.is_selected(&job_file(103, "STEP2", "SYSPRINT")));
+        assert!(!builder.is_selected(&job_file(2, "JES2", "JESMSGLG")));
+    }
+
+    #[test]
+    fn report() {
+        let output = JobOutput {
+            files: vec![
+                JobOutputFile {
+                    file: job_file(2, "JES2", "JESMSGLG"),
+                    data: "J E S 2  J O B  L O G".into(),
+                },
+                JobOutputFile {
+                    file: job_file(102, "STEP1", "SYSPRINT"),
+                    data: "STEP1 OUTPUT\n".into(),
+                },
+                JobOutputFile {
+                    file: job_file(103, "STEP2", "SYSPRINT"),
+                    data: "STEP2 OUTPUT\n".into(),
+                },
+            ],
+        };
+
+        assert_eq!(output.get("SYSPRINT").count(), 2);
+        assert_eq!(
+            output.by_dd_name().get("SYSPRINT").unwrap(),
+            "STEP1 OUTPUT\nSTEP2 OUTPUT\n"
+        );
+        assert_eq!(
+            output.concatenated(),
+            "---------- JES2.JESMSGLG ----------\n\
+             J E S 2  J O B  L O G\n\
+             ---------- STEP1.SYSPRINT ----------\n\
+             STEP1 OUTPUT\n\
+             ---------- STEP2.SYSPRINT ----------\n\
+             STEP2 OUTPUT\n"
+        );
+    }
+}
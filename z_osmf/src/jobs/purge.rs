@@ -6,6 +6,7 @@ use z_osmf_macros::Endpoint;
 use crate::convert::TryFromResponse;
 use crate::ClientCore;
 
+use super::feedback::{JobAsynchronousResponse, JobFeedback};
 use super::{get_subsystem, JobIdentifier};
 
 #[derive(Clone, Debug, Endpoint)]
@@ -30,7 +31,8 @@ impl<T> JobPurgeBuilder<T>
 where
     T: TryFromResponse,
 {
-    pub fn asynchronous(self) -> JobPurgeBuilder<()> {
+    /// Send the request with version 1.0, returning as soon as z/OSMF accepts it.
+    pub fn asynchronous(self) -> JobPurgeBuilder<JobAsynchronousResponse> {
         JobPurgeBuilder {
             core: self.core,
             subsystem: self.subsystem,
@@ -39,6 +41,17 @@ where
             target_type: PhantomData,
         }
     }
+
+    /// Send the request with version 2.0, the default, waiting for it to complete.
+    pub fn synchronous(self) -> JobPurgeBuilder<JobFeedback> {
+        JobPurgeBuilder {
+            core: self.core,
+            subsystem: self.subsystem,
+            identifier: self.identifier,
+            asynchronous: Some(false),
+            target_type: PhantomData,
+        }
+    }
 }
 
 fn build_asynchronous<T>(
@@ -95,4 +108,25 @@ mod tests {
             format!("{:?}", job_feedback)
         )
     }
+
+    #[test]
+    fn asynchronous() {
+        let zosmf = get_zosmf();
+
+        let identifier = JobIdentifier::Correlator("J0000085SY1.....CC20F378".to_string());
+        let job_feedback = zosmf
+            .jobs()
+            .cancel_and_purge(identifier)
+            .asynchronous()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            job_feedback
+                .headers()
+                .get("X-IBM-Job-Modify-Version")
+                .unwrap(),
+            "1.0"
+        );
+    }
 }
@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::{ClientCore, Error, Result};
+
+use super::files::read::{JobFileId, JobFileRead, JobFileReadBuilder};
+use super::submit::JobSubmitBuilder;
+use super::{is_valid_job_name, JobAttributes, JobIdentifier};
+
+#[derive(Clone, Debug)]
+pub struct JobResubmitBuilder {
+    core: ClientCore,
+
+    identifier: JobIdentifier,
+    subsystem: Option<Arc<str>>,
+    encoding: Option<Arc<str>>,
+    job_name: Option<Arc<str>>,
+    symbols: HashMap<Arc<str>, Arc<str>>,
+}
+
+impl JobResubmitBuilder {
+    pub(crate) fn new<I>(core: ClientCore, identifier: I) -> Self
+    where
+        I: Into<JobIdentifier>,
+    {
+        JobResubmitBuilder {
+            core,
+            identifier: identifier.into(),
+            subsystem: None,
+            encoding: None,
+            job_name: None,
+            symbols: HashMap::new(),
+        }
+    }
+
+    pub fn subsystem<S>(self, value: S) -> Self
+    where
+        S: std::fmt::Display,
+    {
+        JobResubmitBuilder {
+            subsystem: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// The EBCDIC code page used to read the JCL and to submit it again, e.g. `IBM-037`.
+    pub fn encoding<E>(self, value: E) -> Self
+    where
+        E: std::fmt::Display,
+    {
+        JobResubmitBuilder {
+            encoding: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// Replace the name on the JOB statement, e.g. to tell the rerun apart from the original.
+    pub fn job_name<N>(self, value: N) -> Self
+    where
+        N: std::fmt::Display,
+    {
+        JobResubmitBuilder {
+            job_name: Some(value.to_string().to_uppercase().into()),
+            ..self
+        }
+    }
+
+    /// Override the value of a JCL symbol for the new job.
+    pub fn symbol<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: std::fmt::Display,
+        V: std::fmt::Display,
+    {
+        self.symbols
+            .insert(name.to_string().into(), value.to_string().into());
+
+        self
+    }
+
+    /// Read the job's JCL and submit it as a new job.
+    pub async fn build(self) -> Result<JobAttributes> {
+        let read = self.read_builder().build().await?;
+
+        let jcl = match &self.job_name {
+            Some(job_name) => rename_job(read.data(), job_name)?,
+            None => read.data().to_string(),
+        };
+
+        let builder = JobSubmitBuilder::from_jcl(self.core.clone(), &jcl)
+            .ok_or_else(|| Error::InvalidValue(format!("job {} has no JCL", self.identifier)))?;
+        let builder = match self.subsystem {
+            Some(subsystem) => builder.subsystem(subsystem),
+            None => builder,
+        };
+        let builder = match self.encoding {
+            Some(encoding) => builder.encoding(encoding),
+            None => builder,
+        };
+        let builder = match self.symbols.is_empty() {
+            true => builder,
+            false => builder.symbols(self.symbols),
+        };
+
+        builder.build().await
+    }
+
+    fn read_builder(&self) -> JobFileReadBuilder<JobFileRead<Arc<str>>> {
+        let builder =
+            JobFileReadBuilder::new(self.core.clone(), self.identifier.clone(), JobFileId::Jcl);
+        let builder = match &self.subsystem {
+            Some(subsystem) => builder.subsystem(subsystem),
+            None => builder,
+        };
+
+        match &self.encoding {
+            Some(encoding) => builder.encoding(encoding),
+            None => builder,
+        }
+    }
+}
+
+/// Replace the name on the first JOB statement, keeping the rest of the statement as is.
+fn rename_job(jcl: &str, job_name: &str) -> Result<String> {
+    if !is_valid_job_name(job_name) {
+        return Err(Error::InvalidValue(format!(
+            "invalid job name: {}",
+            job_name
+        )));
+    }
+
+    let mut renamed = false;
+    let mut output = String::with_capacity(jcl.len() + job_name.len());
+    for line in jcl.lines() {
+        let statement = line.strip_prefix("//").filter(|_| !renamed);
+        match statement.and_then(|s| Some((s, job_statement_name(s)?))) {
+            Some((statement, old_name)) => {
+                output.push_str("//");
+                output.push_str(job_name);
+                output.push_str(&statement[old_name.len()..]);
+                renamed = true;
+            }
+            None => output.push_str(line),
+        }
+        output.push('\n');
+    }
+
+    match renamed {
+        true => Ok(output),
+        false => Err(Error::InvalidValue("JCL has no JOB statement".into())),
+    }
+}
+
+/// The name field of `statement`, if it is a JOB statement.
+fn job_statement_name(statement: &str) -> Option<&str> {
+    if statement.starts_with([' ', '*']) {
+        return None;
+    }
+
+    let mut fields = statement.split_whitespace();
+    match (fields.next(), fields.next()) {
+        (Some(name), Some("JOB")) => Some(name),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn read_request() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restjobs/jobs/J0000023SY1.....D5E0A2F1/files/JCL/records")
+            .build()
+            .unwrap();
+
+        let resubmit = zosmf
+            .jobs()
+            .resubmit(JobIdentifier::Correlator("J0000023SY1.....D5E0A2F1".into()))
+            .read_builder()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", resubmit));
+    }
+
+    #[test]
+    fn rename() {
+        let jcl = "//* COMMENT JOB\n\
+                   //TESTJOBX JOB (),MSGCLASS=H\n\
+                   //STEP1    EXEC PGM=IEFBR14\n";
+
+        assert_eq!(
+            rename_job(jcl, "RERUN1").unwrap(),
+            "//* COMMENT JOB\n\
+             //RERUN1 JOB (),MSGCLASS=H\n\
+             //STEP1    EXEC PGM=IEFBR14\n"
+        );
+        assert!(rename_job(jcl, "1RERUN").is_err());
+        assert!(rename_job("//STEP1 EXEC PGM=IEFBR14\n", "RERUN1").is_err());
+    }
+}
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::marker::PhantomData;
+use std::path::Path;
 use std::sync::Arc;
 
 use bytes::Bytes;
@@ -7,10 +8,12 @@ use serde::{Deserialize, Serialize};
 use z_osmf_macros::Endpoint;
 
 use crate::convert::TryFromResponse;
-use crate::ClientCore;
+use crate::{ClientCore, Error, Result};
 
 use super::get_subsystem;
 
+pub use crate::utils::{DatasetName, MemberName};
+
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum JclData {
     Binary(Bytes),
@@ -18,11 +21,95 @@ pub enum JclData {
     Text(String),
 }
 
+impl JclData {
+    fn mode(&self) -> JobIntrdrMode {
+        match self {
+            JclData::Binary(_) => JobIntrdrMode::Binary,
+            JclData::Record(_) => JobIntrdrMode::Record,
+            JclData::Text(_) => JobIntrdrMode::Text,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum JobSource {
-    Dataset(String),
+    /// A sequential dataset, or a member of a partitioned dataset.
+    Dataset(DatasetName, Option<MemberName>),
+    /// A `file` reference sent exactly as given, e.g. `//'SYS1.PROCLIB(IEFBR14)'`.
     File(String),
     Jcl(JclData),
+    /// An absolute path to a file in the z/OS UNIX file system.
+    UssFile(String),
+}
+
+impl JobSource {
+    /// JCL in a sequential dataset.
+    pub fn dataset<D>(dataset: D) -> Result<Self>
+    where
+        D: AsRef<str>,
+    {
+        Ok(JobSource::Dataset(dataset.as_ref().parse()?, None))
+    }
+
+    /// JCL in a member of a partitioned dataset.
+    pub fn member<D, M>(dataset: D, member: M) -> Result<Self>
+    where
+        D: AsRef<str>,
+        M: AsRef<str>,
+    {
+        Ok(JobSource::Dataset(
+            dataset.as_ref().parse()?,
+            Some(member.as_ref().parse()?),
+        ))
+    }
+
+    /// JCL in a z/OS UNIX file.
+    pub fn uss_file<P>(path: P) -> Result<Self>
+    where
+        P: std::fmt::Display,
+    {
+        let path = path.to_string();
+        validate_uss_file(&path)?;
+
+        Ok(JobSource::UssFile(path))
+    }
+
+    fn file(&self) -> Option<String> {
+        match self {
+            JobSource::Dataset(dataset, Some(member)) => {
+                Some(format!("//'{}({})'", dataset, member))
+            }
+            JobSource::Dataset(dataset, None) => Some(format!("//'{}'", dataset)),
+            JobSource::File(file) | JobSource::UssFile(file) => Some(file.clone()),
+            JobSource::Jcl(_) => None,
+        }
+    }
+}
+
+/// How the internal reader reads the JCL.
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+pub enum JobIntrdrMode {
+    /// Records are lines of text, converted from the file encoding to EBCDIC.
+    #[default]
+    Text,
+    /// Each record is prefixed with its length, and sent without conversion.
+    Record,
+    /// Records of the fixed record length, sent without conversion.
+    Binary,
+}
+
+impl From<JobIntrdrMode> for reqwest::header::HeaderValue {
+    fn from(value: JobIntrdrMode) -> Self {
+        match value {
+            JobIntrdrMode::Text => "TEXT",
+            JobIntrdrMode::Record => "RECORD",
+            JobIntrdrMode::Binary => "BINARY",
+        }
+        .try_into()
+        .unwrap()
+    }
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
@@ -50,7 +137,11 @@ impl From<JobRecordFormat> for reqwest::header::HeaderValue {
 }
 
 #[derive(Clone, Debug, Endpoint)]
-#[endpoint(method = put, path = "/zosmf/restjobs/jobs{subsystem}")]
+#[endpoint(
+    method = put,
+    path = "/zosmf/restjobs/jobs{subsystem}",
+    validate_fn = validate
+)]
 pub struct JobSubmitBuilder<T>
 where
     T: TryFromResponse,
@@ -63,8 +154,15 @@ where
     message_class: Option<Arc<str>>,
     #[endpoint(header = "X-IBM-Intrdr-Recfm")]
     record_format: Option<JobRecordFormat>,
+    /// The length of each record, from 1 to 32760.
     #[endpoint(header = "X-IBM-Intrdr-Lrecl")]
     record_length: Option<i32>,
+    /// The mode used to read a dataset or file of JCL.
+    /// Inline JCL is always read in the mode of its [`JclData`] variant.
+    #[endpoint(skip_builder)]
+    intrdr_mode: Option<JobIntrdrMode>,
+    /// The user portion of the job correlator, used to find the job again with
+    /// [`JobListBuilder::user_correlator`](super::list::JobListBuilder::user_correlator).
     #[endpoint(header = "X-IBM-User-Correlator")]
     user_correlator: Option<Arc<str>>,
     #[endpoint(builder_fn = build_symbols)]
@@ -75,6 +173,7 @@ where
     notification_url: Option<Arc<str>>,
     #[endpoint(builder_fn = build_notification_events)]
     notification_events: Option<Arc<[JobNotificationEvent]>>,
+    /// The code page of the JCL, e.g. `UTF-8` or `IBM-1047`, only used in text mode.
     #[endpoint(header = "X-IBM-Intrdr-File-Encoding")]
     encoding: Option<Arc<str>>,
 
@@ -95,6 +194,58 @@ where
     }
 }
 
+impl JobSubmitBuilder<super::JobAttributes> {
+    pub(super) async fn from_file<P>(core: ClientCore, path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let text = tokio::fs::read_to_string(path).await?;
+
+        Self::from_jcl(core, &text)
+            .ok_or_else(|| Error::InvalidValue(format!("{} contains no JCL", path.display())))
+    }
+
+    /// Prepare JCL text for submission, or `None` if there is no JCL in it.
+    pub(super) fn from_jcl(core: ClientCore, text: &str) -> Option<Self> {
+        let (jcl, longest) = normalize_jcl(text);
+        if jcl.is_empty() {
+            return None;
+        }
+
+        let builder = JobSubmitBuilder::new(core, JobSource::Jcl(JclData::Text(jcl)));
+
+        Some(if longest <= 80 {
+            builder
+                .record_format(JobRecordFormat::Fixed)
+                .record_length(80)
+        } else {
+            // variable length records include the 4 byte record descriptor word
+            builder
+                .record_format(JobRecordFormat::Variable)
+                .record_length(longest as i32 + 4)
+        })
+    }
+}
+
+/// Convert line endings to `\n` and strip trailing blanks,
+/// returning the JCL and the length of its longest record.
+fn normalize_jcl(text: &str) -> (String, usize) {
+    let text = text.replace("\r\n", "\n").replace('\r', "\n");
+
+    let mut jcl = String::with_capacity(text.len());
+    let mut longest = 0;
+    for line in text.trim_end().lines() {
+        let line = line.trim_end();
+
+        longest = longest.max(line.chars().count());
+        jcl.push_str(line);
+        jcl.push('\n');
+    }
+
+    (jcl, longest)
+}
+
 #[derive(Serialize)]
 struct Source<'a> {
     file: &'a str,
@@ -107,27 +258,31 @@ fn build_jcl_source<T>(
 where
     T: TryFromResponse,
 {
-    match &builder.jcl_source {
-        JobSource::Dataset(dataset) => request_builder
-            .header("Content-Type", "application/json")
-            .json(&Source {
-                file: &format!("//'{}'", dataset),
-            }),
-        JobSource::File(file) => request_builder
+    if let Some(file) = builder.jcl_source.file() {
+        let request_builder = match builder.intrdr_mode {
+            Some(mode) => request_builder.header("X-IBM-Intrdr-Mode", mode),
+            None => request_builder,
+        };
+
+        return request_builder
             .header("Content-Type", "application/json")
-            .json(&Source { file }),
-        JobSource::Jcl(JclData::Binary(binary)) => request_builder
+            .json(&Source { file: &file });
+    }
+
+    match &builder.jcl_source {
+        JobSource::Jcl(data @ JclData::Binary(binary)) => request_builder
             .header("Content-Type", "application/octet-stream")
-            .header("X-IBM-Intrdr-Mode", "BINARY")
+            .header("X-IBM-Intrdr-Mode", data.mode())
             .body(binary.clone()),
-        JobSource::Jcl(JclData::Record(record)) => request_builder
+        JobSource::Jcl(data @ JclData::Record(record)) => request_builder
             .header("Content-Type", "application/octet-stream")
-            .header("X-IBM-Intrdr-Mode", "RECORD")
+            .header("X-IBM-Intrdr-Mode", data.mode())
             .body(record.clone()),
-        JobSource::Jcl(JclData::Text(text)) => request_builder
+        JobSource::Jcl(data @ JclData::Text(text)) => request_builder
             .header("Content-Type", "text/plain")
-            .header("X-IBM-Intrdr-Mode", "TEXT")
+            .header("X-IBM-Intrdr-Mode", data.mode())
             .body(text.to_string()),
+        _ => request_builder,
     }
 }
 
@@ -190,6 +345,113 @@ where
     request_builder
 }
 
+fn validate<T>(builder: &JobSubmitBuilder<T>) -> Result<()>
+where
+    T: TryFromResponse,
+{
+    if let Some(correlator) = &builder.user_correlator {
+        validate_user_correlator(correlator)?;
+    }
+
+    validate_intrdr(builder)?;
+
+    match &builder.jcl_source {
+        JobSource::UssFile(path) => validate_uss_file(path),
+        _ => Ok(()),
+    }
+}
+
+/// Check that the internal reader options agree with each other and with the JCL.
+fn validate_intrdr<T>(builder: &JobSubmitBuilder<T>) -> Result<()>
+where
+    T: TryFromResponse,
+{
+    let mode = match (&builder.jcl_source, builder.intrdr_mode) {
+        (JobSource::Jcl(data), Some(mode)) if data.mode() != mode => {
+            return Err(Error::InvalidValue(format!(
+                "internal reader mode {:?} does not match {:?} JCL",
+                mode,
+                data.mode()
+            )));
+        }
+        (JobSource::Jcl(data), _) => data.mode(),
+        (_, mode) => mode.unwrap_or_default(),
+    };
+
+    if let Some(length) = builder.record_length {
+        if !(1..=32760).contains(&length) {
+            return Err(Error::InvalidValue(format!(
+                "invalid record length: {}",
+                length
+            )));
+        }
+    }
+
+    if builder.encoding.is_some() && mode != JobIntrdrMode::Text {
+        return Err(Error::InvalidValue(format!(
+            "file encoding is only used in text mode, not {:?}",
+            mode
+        )));
+    }
+
+    if mode == JobIntrdrMode::Binary {
+        if builder.record_format == Some(JobRecordFormat::Variable) {
+            return Err(Error::InvalidValue(
+                "binary mode requires fixed length records".into(),
+            ));
+        }
+
+        // z/OSMF reads 80 byte records unless told otherwise
+        let length = builder.record_length.unwrap_or(80) as usize;
+        if let JobSource::Jcl(JclData::Binary(binary)) = &builder.jcl_source {
+            if binary.len() % length != 0 {
+                return Err(Error::InvalidValue(format!(
+                    "binary JCL of {} bytes is not a whole number of {} byte records",
+                    binary.len(),
+                    length
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// 1 to 32 uppercase letters, digits, national characters or blanks,
+/// starting with a letter or national character.
+fn validate_user_correlator(correlator: &str) -> Result<()> {
+    let is_national = |c: char| "#$@".contains(c);
+
+    let valid = (1..=32).contains(&correlator.len())
+        && correlator
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_uppercase() || is_national(c))
+        && correlator
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || is_national(c) || c == ' ');
+
+    if !valid {
+        return Err(Error::InvalidValue(format!(
+            "invalid user correlator: {:?}",
+            correlator
+        )));
+    }
+
+    Ok(())
+}
+
+fn validate_uss_file(path: &str) -> Result<()> {
+    if !path.starts_with('/') || path.ends_with('/') || path.contains('\0') {
+        return Err(Error::InvalidValue(format!(
+            "invalid z/OS UNIX file path: {}",
+            path
+        )));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use crate::tests::*;
@@ -234,6 +496,140 @@ mod tests {
         )
     }
 
+    #[test]
+    fn user_correlator() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restjobs/jobs")
+            .header("X-IBM-User-Correlator", "MYAPP01")
+            .header("Content-Type", "application/json")
+            .body(r#"{"file":"//'IBMUSER.JCL'"}"#)
+            .build()
+            .unwrap();
+
+        let job_data = zosmf
+            .jobs()
+            .submit(JobSource::dataset("IBMUSER.JCL").unwrap())
+            .user_correlator("MYAPP01")
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", job_data));
+
+        assert!(validate_user_correlator("#ORDER 1234").is_ok());
+        assert!(validate_user_correlator(&"A".repeat(32)).is_ok());
+        assert!(validate_user_correlator(&"A".repeat(33)).is_err());
+        assert!(validate_user_correlator("").is_err());
+        assert!(validate_user_correlator("1APP").is_err());
+        assert!(validate_user_correlator("myapp01").is_err());
+    }
+
+    #[test]
+    fn sources() {
+        let zosmf = get_zosmf();
+
+        let body = |source: JobSource| {
+            let request = zosmf.jobs().submit(source).get_request().unwrap();
+
+            String::from_utf8(request.body().unwrap().as_bytes().unwrap().to_vec()).unwrap()
+        };
+
+        assert_eq!(
+            body(JobSource::member("sys1.proclib", "iefbr14").unwrap()),
+            r#"{"file":"//'SYS1.PROCLIB(IEFBR14)'"}"#
+        );
+        assert_eq!(
+            body(JobSource::dataset("IBMUSER.JCL").unwrap()),
+            r#"{"file":"//'IBMUSER.JCL'"}"#
+        );
+        assert_eq!(
+            body(JobSource::uss_file("/u/ibmuser/job.jcl").unwrap()),
+            r#"{"file":"/u/ibmuser/job.jcl"}"#
+        );
+
+        assert!(JobSource::member("IBMUSER.JCL", "TOOLONGNAME").is_err());
+        assert!(JobSource::uss_file("job.jcl").is_err());
+        assert!(zosmf
+            .jobs()
+            .submit(JobSource::UssFile("job.jcl".to_string()))
+            .get_request()
+            .is_err());
+    }
+
+    #[test]
+    fn normalize() {
+        let (jcl, longest) =
+            normalize_jcl("//TESTJOBX JOB (),MSGCLASS=H  \r\n// EXEC PGM=IEFBR14\r\n\r\n");
+        assert_eq!(jcl, "//TESTJOBX JOB (),MSGCLASS=H\n// EXEC PGM=IEFBR14\n");
+        assert_eq!(longest, 28);
+
+        let (jcl, longest) =
+            normalize_jcl(&format!("//STEP1 EXEC PGM=IEFBR14\r//* {}", "X".repeat(90)));
+        assert_eq!(jcl.lines().count(), 2);
+        assert_eq!(longest, 94);
+
+        assert_eq!(normalize_jcl(" \r\n\n"), (String::new(), 0));
+    }
+
+    #[test]
+    fn intrdr_options() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .put("https://test.com/zosmf/restjobs/jobs")
+            .header("X-IBM-Intrdr-Recfm", "F")
+            .header("X-IBM-Intrdr-Lrecl", "80")
+            .header("X-IBM-Intrdr-Mode", "RECORD")
+            .header("Content-Type", "application/json")
+            .body(r#"{"file":"//'IBMUSER.JCL'"}"#)
+            .build()
+            .unwrap();
+
+        let job_data = zosmf
+            .jobs()
+            .submit(JobSource::dataset("IBMUSER.JCL").unwrap())
+            .record_format(JobRecordFormat::Fixed)
+            .record_length(80)
+            .intrdr_mode(JobIntrdrMode::Record)
+            .get_request()
+            .unwrap();
+
+        assert_eq!(format!("{:?}", manual_request), format!("{:?}", job_data));
+
+        let submit = |data: JclData| zosmf.jobs().submit(JobSource::Jcl(data));
+        let text = || JclData::Text("//TESTJOBX JOB\n".into());
+        let binary = |length: usize| JclData::Binary(vec![0x40; length].into());
+
+        assert!(submit(text()).encoding("UTF-8").get_request().is_ok());
+        assert!(submit(text())
+            .intrdr_mode(JobIntrdrMode::Binary)
+            .get_request()
+            .is_err());
+        assert!(submit(text()).record_length(0).get_request().is_err());
+        assert!(submit(text()).record_length(32761).get_request().is_err());
+
+        assert!(submit(binary(160)).get_request().is_ok());
+        assert!(submit(binary(100)).get_request().is_err());
+        assert!(submit(binary(100)).record_length(100).get_request().is_ok());
+        assert!(submit(binary(160)).encoding("UTF-8").get_request().is_err());
+        assert!(submit(binary(160))
+            .record_format(JobRecordFormat::Variable)
+            .get_request()
+            .is_err());
+        assert!(zosmf
+            .jobs()
+            .submit(JobSource::dataset("IBMUSER.JCL").unwrap())
+            .intrdr_mode(JobIntrdrMode::Binary)
+            .encoding("IBM-1047")
+            .get_request()
+            .is_err());
+    }
+
     #[test]
     fn notification_events() {
         let zosmf = get_zosmf();
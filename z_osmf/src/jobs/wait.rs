@@ -0,0 +1,153 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::utils::Backoff;
+use crate::{ClientCore, Result};
+
+use super::status::JobStatusBuilder;
+use super::{JobAttributes, JobIdentifier, JobStatus};
+
+#[derive(Clone, Debug)]
+pub struct JobWaitBuilder {
+    core: ClientCore,
+
+    identifier: JobIdentifier,
+    subsystem: Option<Arc<str>>,
+    status: JobStatus,
+    interval: Duration,
+    max_interval: Duration,
+    timeout: Duration,
+}
+
+impl JobWaitBuilder {
+    pub(crate) fn new<I>(core: ClientCore, identifier: I, status: JobStatus) -> Self
+    where
+        I: Into<JobIdentifier>,
+    {
+        JobWaitBuilder {
+            core,
+            identifier: identifier.into(),
+            subsystem: None,
+            status,
+            interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(15),
+            timeout: Duration::from_secs(600),
+        }
+    }
+
+    pub fn subsystem<S>(self, value: S) -> Self
+    where
+        S: std::fmt::Display,
+    {
+        JobWaitBuilder {
+            subsystem: Some(value.to_string().into()),
+            ..self
+        }
+    }
+
+    /// The delay before the first re-check, doubled after each attempt.
+    pub fn interval(self, value: Duration) -> Self {
+        JobWaitBuilder {
+            interval: value,
+            ..self
+        }
+    }
+
+    /// The upper bound for the delay between checks.
+    pub fn max_interval(self, value: Duration) -> Self {
+        JobWaitBuilder {
+            max_interval: value,
+            ..self
+        }
+    }
+
+    /// How long to wait before giving up with [`Error::Timeout`](crate::Error::Timeout).
+    pub fn timeout(self, value: Duration) -> Self {
+        JobWaitBuilder {
+            timeout: value,
+            ..self
+        }
+    }
+
+    /// Poll the job until it reaches the requested status or a later one,
+    /// returning its attributes at that point.
+    pub async fn build(self) -> Result<JobAttributes> {
+        let backoff = Backoff::new(self.interval, self.max_interval, self.timeout);
+
+        let (attributes, _) = backoff
+            .poll(|| async {
+                let attributes = self.status_builder().build().await?;
+
+                Ok(is_done(&attributes, self.status).then_some(attributes))
+            })
+            .await?;
+
+        Ok(attributes)
+    }
+
+    fn status_builder(&self) -> JobStatusBuilder<JobAttributes> {
+        let builder = JobStatusBuilder::new(self.core.clone(), self.identifier.clone());
+
+        match &self.subsystem {
+            Some(subsystem) => builder.subsystem(subsystem),
+            None => builder,
+        }
+    }
+}
+
+/// Whether the job has reached `status`, treating the statuses as the stages
+/// input, active and output, in that order.
+fn is_done(attributes: &JobAttributes, status: JobStatus) -> bool {
+    let stage = |status| match status {
+        JobStatus::Input => 0,
+        JobStatus::Active => 1,
+        JobStatus::Output => 2,
+    };
+
+    attributes
+        .status()
+        .is_some_and(|current| stage(current) >= stage(status))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+
+    use super::*;
+
+    #[test]
+    fn status_request() {
+        let zosmf = get_zosmf();
+
+        let manual_request = zosmf
+            .core
+            .client
+            .get("https://test.com/zosmf/restjobs/jobs/J0000023SY1.....D5E0A2F1")
+            .build()
+            .unwrap();
+
+        let wait_for_output = zosmf
+            .jobs()
+            .wait_for_output(JobIdentifier::Correlator("J0000023SY1.....D5E0A2F1".into()))
+            .status_builder()
+            .get_request()
+            .unwrap();
+
+        assert_eq!(
+            format!("{:?}", manual_request),
+            format!("{:?}", wait_for_output)
+        );
+    }
+
+    #[test]
+    fn stages() {
+        let attributes =
+            |status: &str| -> JobAttributes { job_attributes(status, None, serde_json::json!({})) };
+
+        assert!(is_done(&attributes("ACTIVE"), JobStatus::Active));
+        assert!(!is_done(&attributes("ACTIVE"), JobStatus::Output));
+        assert!(!is_done(&attributes("INPUT"), JobStatus::Active));
+        assert!(is_done(&attributes("OUTPUT"), JobStatus::Active));
+        assert!(is_done(&attributes("OUTPUT"), JobStatus::Output));
+    }
+}
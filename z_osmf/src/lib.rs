@@ -69,8 +69,9 @@ pub use bytes::Bytes;
 
 pub use self::error::{Error, Result};
 
-pub mod info;
+pub mod encoding;
 pub mod error;
+pub mod info;
 
 #[cfg(feature = "datasets")]
 pub mod datasets;
@@ -141,8 +142,14 @@ impl ZOsmf {
     {
         let token = Arc::new(RwLock::new(None));
         let url = url.to_string().into();
+        let user = Arc::new(RwLock::new(None));
 
-        let core = ClientCore { client, token, url };
+        let core = ClientCore {
+            client,
+            token,
+            url,
+            user,
+        };
 
         ZOsmf { core }
     }
@@ -174,11 +181,13 @@ impl ZOsmf {
         U: std::fmt::Display,
         P: std::fmt::Display,
     {
+        let username = username.to_string();
+
         let response = self
             .core
             .client
             .post(format!("{}/zosmf/services/authenticate", self.core.url))
-            .basic_auth(username, Some(password))
+            .basic_auth(&username, Some(password))
             .send()
             .await?
             .check_status()
@@ -193,6 +202,7 @@ impl ZOsmf {
         tokens.sort_unstable();
 
         self.set_token(tokens.first().cloned())?;
+        self.set_user(Some(username.to_uppercase().into()))?;
 
         Ok(tokens)
     }
@@ -221,10 +231,26 @@ impl ZOsmf {
             .await?;
 
         self.set_token(None)?;
+        self.set_user(None)?;
 
         Ok(())
     }
 
+    /// The user ID of the last successful [`login`](ZOsmf::login), uppercased.
+    ///
+    /// # Example
+    /// ```
+    /// # async fn example(zosmf: z_osmf::ZOsmf) -> anyhow::Result<()> {
+    /// zosmf.login("ibmuser", "PASSWORD").await?;
+    ///
+    /// assert_eq!(zosmf.user()?.as_deref(), Some("IBMUSER"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn user(&self) -> Result<Option<Arc<str>>> {
+        self.core.user()
+    }
+
     /// Create a sub-client for interacting with datasets.
     ///
     /// # Example
@@ -305,6 +331,17 @@ impl ZOsmf {
 
         Ok(())
     }
+
+    fn set_user(&self, user: Option<Arc<str>>) -> Result<()> {
+        let mut write = self
+            .core
+            .user
+            .write()
+            .map_err(|err| Error::RwLockPoisonError(err.to_string()))?;
+        *write = user;
+
+        Ok(())
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
@@ -387,6 +424,18 @@ struct ClientCore {
     client: reqwest::Client,
     token: Arc<RwLock<Option<AuthToken>>>,
     url: Arc<str>,
+    user: Arc<RwLock<Option<Arc<str>>>>,
+}
+
+impl ClientCore {
+    fn user(&self) -> Result<Option<Arc<str>>> {
+        let read = self
+            .user
+            .read()
+            .map_err(|err| Error::RwLockPoisonError(err.to_string()))?;
+
+        Ok(read.clone())
+    }
 }
 
 #[cfg(test)]
@@ -397,6 +446,76 @@ mod tests {
         ZOsmf::new(reqwest::Client::new(), "https://test.com")
     }
 
+    /// A job with the given status and return code, extended or overridden by the
+    /// properties in `fields`, e.g. `exec-ended` or `step-data`.
+    #[cfg(feature = "jobs")]
+    pub(crate) fn job_attributes<T>(
+        status: &str,
+        return_code: Option<&str>,
+        fields: serde_json::Value,
+    ) -> T
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        with_fields(
+            serde_json::json!({
+                "jobid": "JOB00023",
+                "jobname": "TESTJOB",
+                "owner": "IBMUSER",
+                "status": status,
+                "type": "JOB",
+                "class": "A",
+                "retcode": return_code,
+                "url": "https://test.com/zosmf/restjobs/jobs/TESTJOB/JOB00023",
+                "files-url": "https://test.com/zosmf/restjobs/jobs/TESTJOB/JOB00023/files",
+                "phase": 20,
+                "phase-name": "Job is on the hard copy queue"
+            }),
+            fields,
+        )
+    }
+
+    /// A spool file of the job from [`job_attributes`], extended or overridden by `fields`.
+    #[cfg(feature = "jobs")]
+    pub(crate) fn job_file(
+        id: i32,
+        step_name: &str,
+        dd_name: &str,
+        fields: serde_json::Value,
+    ) -> jobs::files::JobFile {
+        with_fields(
+            serde_json::json!({
+                "jobname": "TESTJOB",
+                "recfm": "FBA",
+                "byte-count": 100,
+                "record-count": 2,
+                "job-correlator": null,
+                "class": "A",
+                "jobid": "JOB00023",
+                "id": id,
+                "ddname": dd_name,
+                "records-url": "https://test.com/records",
+                "lrecl": 133,
+                "subsystem": "JES2",
+                "stepname": step_name,
+                "procstep": null
+            }),
+            fields,
+        )
+    }
+
+    #[cfg(feature = "jobs")]
+    fn with_fields<T>(mut value: serde_json::Value, fields: serde_json::Value) -> T
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        if let (Some(value), serde_json::Value::Object(fields)) = (value.as_object_mut(), fields) {
+            value.extend(fields);
+        }
+
+        serde_json::from_value(value).expect("invalid test fixture")
+    }
+
     pub(crate) trait GetJson {
         fn json(&self) -> Option<serde_json::Value>;
     }
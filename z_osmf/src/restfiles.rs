@@ -19,16 +19,21 @@ pub enum CopyDataType {
 #[derive(Clone, Debug, Deserialize, Eq, Getters, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct Etag {
     etag: Option<Arc<str>>,
+    /// The session holding the ENQ obtained with `X-IBM-Obtain-ENQ`, if any.
+    #[serde(default)]
+    session_ref: Option<Arc<str>>,
     transaction_id: Arc<str>,
 }
 
 impl TryFromResponse for Etag {
     async fn try_from_response(value: reqwest::Response) -> Result<Self> {
         let etag = get_etag(&value)?;
+        let session_ref = get_session_ref(&value)?;
         let transaction_id = get_transaction_id(&value)?;
 
         Ok(Etag {
             etag,
+            session_ref,
             transaction_id,
         })
     }
@@ -43,6 +48,15 @@ pub(crate) fn get_etag(response: &reqwest::Response) -> Result<Option<Arc<str>>>
         .map(|v| v.into()))
 }
 
+pub(crate) fn get_session_ref(response: &reqwest::Response) -> Result<Option<Arc<str>>> {
+    Ok(response
+        .headers()
+        .get("X-IBM-Session-Ref")
+        .map(|v| v.to_str())
+        .transpose()?
+        .map(|v| v.into()))
+}
+
 pub(crate) fn get_transaction_id(response: &reqwest::Response) -> Result<Arc<str>> {
     Ok(response
         .headers()
@@ -76,6 +90,20 @@ mod tests {
         assert_eq!(get_etag(&response).unwrap(), None);
     }
 
+    #[test]
+    fn test_get_session_ref() {
+        let response = reqwest::Response::from(
+            http::Response::builder()
+                .header("X-IBM-Session-Ref", "ABCD1234")
+                .body("")
+                .unwrap(),
+        );
+        assert_eq!(get_session_ref(&response).unwrap(), Some("ABCD1234".into()));
+
+        let response = reqwest::Response::from(http::Response::new(""));
+        assert_eq!(get_session_ref(&response).unwrap(), None);
+    }
+
     #[test]
     fn test_get_transaction_id() {
         let response = reqwest::Response::from(
@@ -89,4 +117,22 @@ mod tests {
         let response = reqwest::Response::from(http::Response::new(""));
         assert!(get_transaction_id(&response).is_err());
     }
+
+    #[tokio::test]
+    async fn etag_session_ref() {
+        let response = reqwest::Response::from(
+            http::Response::builder()
+                .header("Etag", "1234")
+                .header("X-IBM-Session-Ref", "ABCD1234")
+                .header("X-IBM-Txid", "5678")
+                .body("")
+                .unwrap(),
+        );
+
+        let etag = Etag::try_from_response(response).await.unwrap();
+
+        assert_eq!(etag.etag(), Some("1234"));
+        assert_eq!(etag.session_ref(), Some("ABCD1234"));
+        assert_eq!(etag.transaction_id(), "5678");
+    }
 }
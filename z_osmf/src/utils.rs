@@ -1,5 +1,8 @@
 use std::num::NonZeroU32;
+use std::ops::{Range, RangeFrom, RangeInclusive};
 use std::str::FromStr;
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+use std::time::{Duration, Instant};
 
 use reqwest::header::HeaderValue;
 use serde::{Deserialize, Serialize};
@@ -9,13 +12,60 @@ use crate::{Error, Result};
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum RecordRange {
     StartCount(u32, NonZeroU32),
+    /// An end of `u32::MAX` reads every record from the start, sent as e.g. `100-`.
     StartEnd(Option<u32>, u32),
 }
 
+impl RecordRange {
+    /// The last `count` records of a file with `record_count` records,
+    /// such as the record count of a spool file.
+    pub fn last(count: NonZeroU32, record_count: u64) -> Self {
+        let start = record_count.saturating_sub(count.get().into());
+
+        RecordRange::StartCount(u32::try_from(start).unwrap_or(u32::MAX), count)
+    }
+}
+
+/// Records `start` up to but not including `end`, which must not be empty.
+impl TryFrom<Range<u32>> for RecordRange {
+    type Error = Error;
+
+    fn try_from(value: Range<u32>) -> Result<Self> {
+        let count = value
+            .end
+            .checked_sub(value.start)
+            .and_then(NonZeroU32::new)
+            .ok_or_else(|| Error::RecordRange(format!("{:?}", value)))?;
+
+        Ok(RecordRange::StartCount(value.start, count))
+    }
+}
+
+/// Records `start` up to and including `end`, which must not be empty.
+impl TryFrom<RangeInclusive<u32>> for RecordRange {
+    type Error = Error;
+
+    fn try_from(value: RangeInclusive<u32>) -> Result<Self> {
+        if value.is_empty() {
+            return Err(Error::RecordRange(format!("{:?}", value)));
+        }
+
+        Ok(RecordRange::StartEnd(Some(*value.start()), *value.end()))
+    }
+}
+
+/// Every record from `start` to the end of the file.
+impl From<RangeFrom<u32>> for RecordRange {
+    fn from(value: RangeFrom<u32>) -> Self {
+        RecordRange::StartEnd(Some(value.start), u32::MAX)
+    }
+}
+
 impl From<RecordRange> for HeaderValue {
     fn from(value: RecordRange) -> Self {
         match value {
             RecordRange::StartCount(start, count) => format!("{},{}", start, count),
+            RecordRange::StartEnd(Some(start), u32::MAX) => format!("{}-", start),
             RecordRange::StartEnd(Some(start), end) => format!("{}-{}", start, end),
             RecordRange::StartEnd(None, end) => format!("-{}", end),
         }
@@ -32,6 +82,10 @@ impl FromStr for RecordRange {
             return Ok(RecordRange::StartEnd(None, s.parse()?));
         }
 
+        if let Some(s) = s.strip_suffix('-') {
+            return Ok(RecordRange::StartEnd(Some(s.parse()?), u32::MAX));
+        }
+
         if let Some((start, end)) = s.split_once('-') {
             return Ok(RecordRange::StartEnd(Some(start.parse()?), end.parse()?));
         }
@@ -44,10 +98,489 @@ impl FromStr for RecordRange {
     }
 }
 
+/// A fully qualified dataset name, validated and uppercased.
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct DatasetName(std::sync::Arc<str>);
+
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+impl DatasetName {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+impl std::fmt::Display for DatasetName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+impl std::str::FromStr for DatasetName {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let name = s.to_uppercase();
+
+        let valid = name.len() <= 44 && name.split('.').all(is_valid_qualifier);
+        if !valid {
+            return Err(Error::InvalidValue(format!("invalid dataset name: {}", s)));
+        }
+
+        Ok(DatasetName(name.into()))
+    }
+}
+
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+impl TryFrom<&str> for DatasetName {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        value.parse()
+    }
+}
+
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+impl TryFrom<String> for DatasetName {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        value.parse()
+    }
+}
+
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+impl From<DatasetName> for String {
+    fn from(value: DatasetName) -> Self {
+        value.0.to_string()
+    }
+}
+
+/// A partitioned dataset member name, validated and uppercased.
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct MemberName(std::sync::Arc<str>);
+
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+impl MemberName {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+impl std::fmt::Display for MemberName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+impl std::str::FromStr for MemberName {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let name = s.to_uppercase();
+
+        if !is_valid_qualifier(&name) || name.contains('-') {
+            return Err(Error::InvalidValue(format!("invalid member name: {}", s)));
+        }
+
+        Ok(MemberName(name.into()))
+    }
+}
+
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+impl TryFrom<&str> for MemberName {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        value.parse()
+    }
+}
+
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+impl TryFrom<String> for MemberName {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        value.parse()
+    }
+}
+
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+impl From<MemberName> for String {
+    fn from(value: MemberName) -> Self {
+        value.0.to_string()
+    }
+}
+
+/// The record format (`recfm`) of a non-VSAM dataset.
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DatasetRecordFormat {
+    F,
+    Fa,
+    Fb,
+    Fba,
+    Fbs,
+    Fs,
+    V,
+    Va,
+    Vb,
+    Vba,
+    Vbs,
+    Vs,
+    U,
+    Other(String),
+}
+
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+impl From<String> for DatasetRecordFormat {
+    fn from(value: String) -> Self {
+        match value.to_ascii_uppercase().as_str() {
+            "F" => DatasetRecordFormat::F,
+            "FA" => DatasetRecordFormat::Fa,
+            "FB" => DatasetRecordFormat::Fb,
+            "FBA" => DatasetRecordFormat::Fba,
+            "FBS" => DatasetRecordFormat::Fbs,
+            "FS" => DatasetRecordFormat::Fs,
+            "V" => DatasetRecordFormat::V,
+            "VA" => DatasetRecordFormat::Va,
+            "VB" => DatasetRecordFormat::Vb,
+            "VBA" => DatasetRecordFormat::Vba,
+            "VBS" => DatasetRecordFormat::Vbs,
+            "VS" => DatasetRecordFormat::Vs,
+            "U" => DatasetRecordFormat::U,
+            _ => DatasetRecordFormat::Other(value),
+        }
+    }
+}
+
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+impl From<&str> for DatasetRecordFormat {
+    fn from(value: &str) -> Self {
+        DatasetRecordFormat::from(value.to_string())
+    }
+}
+
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+impl std::str::FromStr for DatasetRecordFormat {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+impl DatasetRecordFormat {
+    /// Whether every record has the same length, i.e. the format starts with `F`.
+    pub fn is_fixed(&self) -> bool {
+        self.to_string().starts_with('F')
+    }
+
+    /// Whether each record carries a 4 byte record descriptor word, i.e. the format starts with `V`.
+    pub fn is_variable(&self) -> bool {
+        self.to_string().starts_with('V')
+    }
+}
+
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+impl std::fmt::Display for DatasetRecordFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            DatasetRecordFormat::F => "F",
+            DatasetRecordFormat::Fa => "FA",
+            DatasetRecordFormat::Fb => "FB",
+            DatasetRecordFormat::Fba => "FBA",
+            DatasetRecordFormat::Fbs => "FBS",
+            DatasetRecordFormat::Fs => "FS",
+            DatasetRecordFormat::V => "V",
+            DatasetRecordFormat::Va => "VA",
+            DatasetRecordFormat::Vb => "VB",
+            DatasetRecordFormat::Vba => "VBA",
+            DatasetRecordFormat::Vbs => "VBS",
+            DatasetRecordFormat::Vs => "VS",
+            DatasetRecordFormat::U => "U",
+            DatasetRecordFormat::Other(value) => value.as_ref(),
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+impl<'de> Deserialize<'de> for DatasetRecordFormat {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+
+        Ok(s.parse().unwrap())
+    }
+}
+
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+impl Serialize for DatasetRecordFormat {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// Whether `qualifier` is a valid dataset name qualifier: 1 to 8 characters,
+/// starting with a letter or `#$@`.
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+pub(crate) fn is_valid_qualifier(qualifier: &str) -> bool {
+    (1..=8).contains(&qualifier.len())
+        && qualifier
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || "#$@".contains(c))
+        && qualifier
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "#$@-".contains(c))
+}
+
+/// Exponential backoff between polling attempts, bounded by an overall timeout.
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Backoff {
+    pub interval: Duration,
+    pub max_interval: Duration,
+    pub timeout: Duration,
+}
+
+#[cfg(any(feature = "datasets", feature = "jobs"))]
+impl Backoff {
+    pub fn new(interval: Duration, max_interval: Duration, timeout: Duration) -> Self {
+        Backoff {
+            interval,
+            max_interval: max_interval.max(interval),
+            timeout,
+        }
+    }
+
+    /// Call `poll` until it returns `Some`, sleeping with a doubling interval between calls.
+    ///
+    /// Returns the value along with the time elapsed since polling began.
+    pub async fn poll<F, Fut, T>(self, mut poll: F) -> Result<(T, Duration)>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<Option<T>>>,
+    {
+        let start = Instant::now();
+        let mut interval = self.interval;
+
+        loop {
+            if let Some(value) = poll().await? {
+                return Ok((value, start.elapsed()));
+            }
+
+            let elapsed = start.elapsed();
+            if elapsed >= self.timeout {
+                return Err(Error::Timeout(elapsed));
+            }
+
+            tokio::time::sleep(interval.min(self.timeout - elapsed)).await;
+            interval = (interval * 2).min(self.max_interval);
+        }
+    }
+}
+
+/// The number of records, found with `has_record` probes for single records,
+/// which takes a number of requests logarithmic in the record count.
+#[cfg(any(feature = "datasets", feature = "files"))]
+pub(crate) async fn count_records<F, Fut>(has_record: F) -> Result<u32>
+where
+    F: Fn(u32) -> Fut,
+    Fut: std::future::Future<Output = Result<bool>>,
+{
+    if !has_record(0).await? {
+        return Ok(0);
+    }
+
+    // grow the upper bound until it is past the last record, then bisect
+    let (mut low, mut high) = (0, 1);
+    while high < u32::MAX && has_record(high).await? {
+        low = high;
+        high = high.saturating_mul(2);
+    }
+
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+
+        if has_record(mid).await? {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    Ok(high)
+}
+
+/// Match `text` against a wildcard pattern where `*` matches any run of characters
+/// and `?` matches exactly one.
+#[cfg(any(feature = "datasets", feature = "files"))]
+pub(crate) fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// The byte sequences in `data` that are not valid UTF-8, with the offset each starts at.
+///
+/// z/OSMF converts text to UTF-8 on the host, so these are bytes it could not convert,
+/// e.g. DBCS data or text tagged with the wrong code page.
+#[cfg(any(feature = "files", feature = "jobs"))]
+pub(crate) fn invalid_utf8(data: &[u8]) -> impl Iterator<Item = (usize, &[u8])> {
+    let mut offset = 0;
+
+    data.utf8_chunks().filter_map(move |chunk| {
+        offset += chunk.valid().len();
+        let start = offset;
+        offset += chunk.invalid().len();
+
+        (!chunk.invalid().is_empty()).then(|| (start, chunk.invalid()))
+    })
+}
+
+/// Decode UTF-8, failing with [`Error::Utf8`] at the first sequence from [`invalid_utf8`].
+#[cfg(any(feature = "files", feature = "jobs"))]
+pub(crate) fn decode_utf8(data: &[u8]) -> Result<&str> {
+    match invalid_utf8(data).next() {
+        Some((offset, sequence)) => Err(Error::Utf8 {
+            offset,
+            sequence: sequence.into(),
+        }),
+        // every chunk was valid
+        None => Ok(std::str::from_utf8(data).unwrap_or_default()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[cfg(feature = "datasets")]
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*", ""));
+        assert!(wildcard_match("IEF*", "IEFBR14"));
+        assert!(wildcard_match("IEF?R14", "IEFBR14"));
+        assert!(wildcard_match("*R1*", "IEFBR14"));
+        assert!(wildcard_match("*.txt", "notes.txt"));
+        assert!(!wildcard_match("*.txt", "notes.txt.bak"));
+        assert!(!wildcard_match("IEF?", "IEFBR14"));
+        assert!(!wildcard_match("", "IEFBR14"));
+    }
+
+    #[cfg(any(feature = "files", feature = "jobs"))]
+    #[test]
+    fn test_decode_utf8() {
+        assert_eq!(decode_utf8("caf\u{e9}".as_bytes()).unwrap(), "caf\u{e9}");
+        assert_eq!(invalid_utf8("caf\u{e9}".as_bytes()).count(), 0);
+
+        assert_eq!(
+            invalid_utf8(b"abc\xC1\xC2def\xE2\x82").collect::<Vec<_>>(),
+            vec![(3, &[0xC1][..]), (4, &[0xC2][..]), (8, &[0xE2, 0x82][..])]
+        );
+
+        let err = decode_utf8(b"abc\xC1\xC2def").unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Utf8 { offset: 3, ref sequence } if **sequence == [0xC1]
+        ));
+        assert_eq!(err.to_string(), "invalid UTF-8 sequence [C1] at byte 3");
+
+        let err = decode_utf8(b"ok\xE2\x82").unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Utf8 { offset: 2, ref sequence } if **sequence == [0xE2, 0x82]
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_backoff_poll() {
+        let backoff = Backoff::new(
+            Duration::from_millis(1),
+            Duration::from_millis(4),
+            Duration::from_secs(5),
+        );
+
+        let mut attempts = 0;
+        let (value, _) = backoff
+            .poll(|| {
+                attempts += 1;
+                let attempt = attempts;
+
+                async move { Ok((attempt == 3).then_some(attempt)) }
+            })
+            .await
+            .unwrap();
+        assert_eq!(value, 3);
+
+        let backoff = Backoff::new(
+            Duration::from_millis(1),
+            Duration::from_millis(1),
+            Duration::from_millis(5),
+        );
+        let result = backoff.poll(|| async { Ok(None::<()>) }).await;
+        assert!(matches!(result, Err(Error::Timeout(_))));
+    }
+
+    #[cfg(any(feature = "datasets", feature = "jobs"))]
+    #[test]
+    fn names() {
+        let dataset: DatasetName = "sys1.proclib".parse().unwrap();
+        assert_eq!(dataset.as_str(), "SYS1.PROCLIB");
+        assert!("SYS1..PROCLIB".parse::<DatasetName>().is_err());
+        assert!("SYS1.PROCLIB9X".parse::<DatasetName>().is_err());
+        assert!(format!("A{}", ".ABCDEFGH".repeat(5))
+            .parse::<DatasetName>()
+            .is_err());
+
+        assert_eq!(MemberName::try_from("iefbr14").unwrap().as_str(), "IEFBR14");
+        assert!(MemberName::try_from("BAD-NAME").is_err());
+        assert!(MemberName::try_from("").is_err());
+
+        assert!(serde_json::from_str::<DatasetName>(r#""1BAD.NAME""#).is_err());
+    }
+
     #[test]
     fn test_record_range_into_header_value() {
         let header_value: HeaderValue = RecordRange::StartEnd(Some(0), 249).into();
@@ -86,5 +619,68 @@ mod tests {
         assert!(RecordRange::from_str("0,SENSE").is_err());
 
         assert!(RecordRange::from_str("NONSENSE").is_err());
+
+        let record_range = RecordRange::from_str("100-").unwrap();
+        assert_eq!(record_range, RecordRange::StartEnd(Some(100), u32::MAX));
+
+        assert!(RecordRange::from_str("NON-").is_err());
+    }
+
+    #[test]
+    fn test_record_range_from_range() {
+        assert_eq!(
+            RecordRange::try_from(10..20).unwrap(),
+            RecordRange::StartCount(10, NonZeroU32::new(10).unwrap())
+        );
+        assert_eq!(
+            RecordRange::try_from(10..=20).unwrap(),
+            RecordRange::StartEnd(Some(10), 20)
+        );
+        assert_eq!(
+            RecordRange::from(10..),
+            RecordRange::StartEnd(Some(10), u32::MAX)
+        );
+
+        let header_value: HeaderValue = RecordRange::from(10..).into();
+        assert_eq!(header_value, HeaderValue::from_static("10-"));
+
+        assert!(matches!(
+            RecordRange::try_from(10..10),
+            Err(Error::RecordRange(_))
+        ));
+        #[allow(clippy::reversed_empty_ranges)]
+        let (reversed, reversed_inclusive) = (20..10, 20..=10);
+        assert!(matches!(
+            RecordRange::try_from(reversed),
+            Err(Error::RecordRange(_))
+        ));
+        assert!(matches!(
+            RecordRange::try_from(reversed_inclusive),
+            Err(Error::RecordRange(_))
+        ));
+    }
+
+    #[cfg(any(feature = "datasets", feature = "files"))]
+    #[tokio::test]
+    async fn test_count_records() {
+        for total in [0, 1, 2, 5, 1000, 1024, 1025] {
+            let count = count_records(|record| async move { Ok(record < total) }).await;
+
+            assert_eq!(count.unwrap(), total);
+        }
+    }
+
+    #[test]
+    fn test_record_range_last() {
+        let fifty = NonZeroU32::new(50).unwrap();
+
+        assert_eq!(
+            RecordRange::last(fifty, 1000),
+            RecordRange::StartCount(950, fifty)
+        );
+        assert_eq!(
+            RecordRange::last(fifty, 20),
+            RecordRange::StartCount(0, fifty)
+        );
     }
 }
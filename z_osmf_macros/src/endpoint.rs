@@ -56,6 +56,7 @@ pub(crate) struct Endpoint {
 
     method: syn::Ident,
     path: String,
+    validate_fn: Option<syn::ExprPath>,
 }
 
 impl Endpoint {
@@ -108,16 +109,28 @@ impl Endpoint {
 
     fn get_response_fn(&self) -> TokenStream {
         let Endpoint {
-            data, method, path, ..
+            data,
+            method,
+            path,
+            validate_fn,
+            ..
         } = &self;
 
         let fields = data.as_ref().take_struct().unwrap();
 
+        let validate = validate_fn.as_ref().map(|validate_fn| {
+            quote! {
+                #validate_fn(self)?;
+            }
+        });
+
         let path_builders: Vec<_> = fields.iter().map(|f| f.path_builder()).collect();
         let request_builders: Vec<_> = fields.iter().map(|f| f.request_builder()).collect();
 
         quote! {
-            fn get_request(&self) -> crate::Result<reqwest::Request> {
+            pub(crate) fn get_request(&self) -> crate::Result<reqwest::Request> {
+                #validate
+
                 let path = {
                     #( #path_builders )*
 
@@ -167,6 +180,7 @@ struct EndpointField {
     #[darling(default)]
     skip_builder: bool,
     builder_fn: Option<syn::ExprPath>,
+    try_builder_fn: Option<syn::ExprPath>,
 }
 
 impl EndpointField {
@@ -204,6 +218,12 @@ impl EndpointField {
             } => Some(quote! {
                 request_builder = #builder_fn(request_builder, self);
             }),
+            EndpointField {
+                try_builder_fn: Some(try_builder_fn),
+                ..
+            } => Some(quote! {
+                request_builder = #try_builder_fn(request_builder, self)?;
+            }),
             EndpointField {
                 header: Some(header),
                 ident: Some(ident),